@@ -1,6 +1,26 @@
 //! Submodule defining properties and structures shared across different types
 //! of Mermaid diagrams.
 
+pub mod architecture_diagram;
+pub mod block_diagram;
+pub mod c4_diagram;
 pub mod class_diagram;
 pub mod entity_relationship;
 pub mod flowchart;
+pub mod gantt;
+pub mod git_graph;
+pub mod journey;
+pub mod kanban;
+pub mod mindmap;
+pub mod packet_diagram;
+pub mod pie_chart;
+pub mod quadrant_chart;
+pub mod radar_chart;
+pub mod requirement_diagram;
+pub mod sankey_diagram;
+pub mod sequence_diagram;
+pub mod state_diagram;
+pub mod timeline;
+pub mod treemap;
+pub mod xy_chart;
+pub mod zenuml;
@@ -0,0 +1,346 @@
+//! Submodule providing structs for creating architecture diagrams in Mermaid
+//! syntax.
+
+mod architecture_edge;
+mod architecture_group;
+mod architecture_icon;
+mod architecture_junction;
+mod architecture_service;
+mod builder;
+use alloc::{rc::Rc, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use architecture_edge::{
+    ArchitectureEdge, ArchitectureEdgeBuilder, ArchitectureEndpoint, ArchitectureSide,
+};
+pub use architecture_group::{ArchitectureGroup, ArchitectureGroupBuilder};
+pub use architecture_icon::ArchitectureIcon;
+pub use architecture_junction::ArchitectureJunction;
+pub use architecture_service::{ArchitectureService, ArchitectureServiceBuilder};
+pub use builder::ArchitectureDiagramBuilder;
+
+use crate::{
+    errors::ArchitectureDiagramError,
+    shared::{
+        NODE_LETTER,
+        generic_configuration::{GenericConfiguration, GenericConfigurationBuilder},
+    },
+    traits::TabbedDisplay,
+};
+
+/// Represents the configuration for an architecture diagram.
+pub type ArchitectureDiagramConfiguration = GenericConfiguration;
+/// Represents the configuration builder for an architecture diagram.
+pub type ArchitectureDiagramConfigurationBuilder = GenericConfigurationBuilder;
+
+/// Validates a label of an architecture diagram, which is always written
+/// between square brackets.
+pub(crate) fn validate_text(text: impl Into<String>) -> Result<String, ArchitectureDiagramError> {
+    let text = text.into();
+    if text.trim().is_empty() {
+        return Err(ArchitectureDiagramError::EmptyText);
+    }
+    if text.contains(['[', ']', '\n', '\r']) {
+        return Err(ArchitectureDiagramError::InvalidText(text));
+    }
+    Ok(text)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a member of an architecture diagram, kept in declaration order.
+pub(crate) enum ArchitectureMember {
+    /// A group, which may enclose other members.
+    Group(Rc<ArchitectureGroup>),
+    /// A service.
+    Service(Rc<ArchitectureService>),
+    /// A junction.
+    Junction(Rc<ArchitectureJunction>),
+}
+
+impl ArchitectureMember {
+    /// Returns the unique identifier of the member.
+    pub(crate) fn id(&self) -> u64 {
+        match self {
+            ArchitectureMember::Group(group) => group.id(),
+            ArchitectureMember::Service(service) => service.id(),
+            ArchitectureMember::Junction(junction) => junction.id(),
+        }
+    }
+}
+
+impl Display for ArchitectureMember {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArchitectureMember::Group(group) => write!(f, "{group}"),
+            ArchitectureMember::Service(service) => write!(f, "{service}"),
+            ArchitectureMember::Junction(junction) => write!(f, "{junction}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents an architecture diagram in Mermaid syntax.
+///
+/// Groups, services and junctions are declared in order, each followed by the
+/// group enclosing it, and then connected by edges.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = ArchitectureDiagramBuilder::default();
+///     let api = builder.group(
+///         None,
+///         ArchitectureGroupBuilder::default().label("API")?.icon(ArchitectureIcon::Cloud),
+///     )?;
+///     let db = builder.service(
+///         Some(&api),
+///         ArchitectureServiceBuilder::default()
+///             .label("Database")?
+///             .icon(ArchitectureIcon::Database),
+///     )?;
+///     let server = builder.service(
+///         Some(&api),
+///         ArchitectureServiceBuilder::default().label("Server")?.icon(ArchitectureIcon::Server),
+///     )?;
+///     builder.edge(
+///         ArchitectureEdgeBuilder::default()
+///             .source(db, ArchitectureSide::Left)
+///             .destination(server, ArchitectureSide::Right),
+///     )?;
+///
+///     let diagram = ArchitectureDiagram::from(builder);
+///     assert_eq!(
+///         diagram.to_string(),
+///         concat!(
+///             "architecture-beta\n",
+///             "  group v0(cloud)[API]\n",
+///             "  service v1(database)[Database] in v0\n",
+///             "  service v2(server)[Server] in v0\n",
+///             "  v1:L -- R:v2\n",
+///         )
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct ArchitectureDiagram {
+    /// Configuration of the diagram.
+    configuration: ArchitectureDiagramConfiguration,
+    /// Members of the diagram in declaration order, along with the identifier
+    /// of the group enclosing them, if any.
+    members: Vec<(Option<u64>, ArchitectureMember)>,
+    /// Edges between the services and junctions of the diagram.
+    edges: Vec<ArchitectureEdge>,
+}
+
+impl ArchitectureDiagram {
+    #[must_use]
+    /// Returns the configuration of the diagram.
+    pub fn configuration(&self) -> &ArchitectureDiagramConfiguration {
+        &self.configuration
+    }
+
+    /// Returns an iterator over the groups of the diagram.
+    pub fn groups(&self) -> impl Iterator<Item = &Rc<ArchitectureGroup>> {
+        self.members.iter().filter_map(|(_, member)| match member {
+            ArchitectureMember::Group(group) => Some(group),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the services of the diagram.
+    pub fn services(&self) -> impl Iterator<Item = &Rc<ArchitectureService>> {
+        self.members.iter().filter_map(|(_, member)| match member {
+            ArchitectureMember::Service(service) => Some(service),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the junctions of the diagram.
+    pub fn junctions(&self) -> impl Iterator<Item = &Rc<ArchitectureJunction>> {
+        self.members.iter().filter_map(|(_, member)| match member {
+            ArchitectureMember::Junction(junction) => Some(junction),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the edges of the diagram.
+    pub fn edges(&self) -> impl Iterator<Item = &ArchitectureEdge> {
+        self.edges.iter()
+    }
+
+    #[must_use]
+    /// Returns the group enclosing the member with the provided ID, if any.
+    pub fn parent(&self, id: u64) -> Option<&Rc<ArchitectureGroup>> {
+        let parent_id = self
+            .members
+            .iter()
+            .find_map(|(parent, member)| (member.id() == id).then_some(*parent))??;
+        self.groups().find(|group| group.id() == parent_id)
+    }
+}
+
+impl Display for ArchitectureDiagram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for ArchitectureDiagram {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat((tab_count + 1) * 2);
+        self.configuration.fmt_frontmatter(f, true, &[])?;
+        writeln!(f, "{}architecture-beta", " ".repeat(tab_count * 2))?;
+        for (parent, member) in &self.members {
+            write!(f, "{indent}{member}")?;
+            if let Some(parent) = parent {
+                write!(f, " in {NODE_LETTER}{parent}")?;
+            }
+            writeln!(f)?;
+        }
+        for edge in &self.edges {
+            writeln!(f, "{indent}{edge}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::traits::ConfigurationBuilder;
+
+    #[test]
+    fn test_architecture_diagram_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = ArchitectureDiagramBuilder::default().configuration(
+            ArchitectureDiagramConfigurationBuilder::default().title("Infrastructure")?,
+        )?;
+        let public = builder.group(
+            None,
+            ArchitectureGroupBuilder::default().label("Public API")?.icon(ArchitectureIcon::Cloud),
+        )?;
+        let private = builder
+            .group(Some(&public), ArchitectureGroupBuilder::default().label("Private API")?)?;
+        let gateway = builder.service(
+            None,
+            ArchitectureServiceBuilder::default()
+                .label("Gateway")?
+                .icon(ArchitectureIcon::Internet),
+        )?;
+        let server = builder.service(
+            Some(&public),
+            ArchitectureServiceBuilder::default().label("Server")?.icon(ArchitectureIcon::Server),
+        )?;
+        let split = builder.junction(Some(&private))?;
+        let bucket = builder.service(
+            Some(&private),
+            ArchitectureServiceBuilder::default()
+                .label("Bucket")?
+                .icon(ArchitectureIcon::custom("logos:aws-s3")?),
+        )?;
+        builder.edge(
+            ArchitectureEdgeBuilder::default()
+                .source(gateway, ArchitectureSide::Right)
+                .destination(server.clone(), ArchitectureSide::Left)
+                .destination_arrow(true),
+        )?;
+        builder.edge(
+            ArchitectureEdgeBuilder::default()
+                .source(server, ArchitectureSide::Bottom)
+                .destination(split.clone(), ArchitectureSide::Top)
+                .source_arrow(true)
+                .destination_arrow(true),
+        )?;
+        builder.edge(
+            ArchitectureEdgeBuilder::default()
+                .source(split, ArchitectureSide::Bottom)
+                .destination(bucket.clone(), ArchitectureSide::Top)
+                .source_arrow(true),
+        )?;
+
+        let diagram = ArchitectureDiagram::from(builder);
+        assert_eq!(diagram.groups().count(), 2);
+        assert_eq!(diagram.services().count(), 3);
+        assert_eq!(diagram.junctions().count(), 1);
+        assert_eq!(diagram.parent(bucket.id()), Some(&private));
+        assert_eq!(diagram.parent(private.id()), Some(&public));
+        assert_eq!(diagram.parent(public.id()), None);
+        assert_eq!(
+            diagram.to_string(),
+            concat!(
+                "---\n",
+                "title: Infrastructure\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "---\n",
+                "architecture-beta\n",
+                "  group v0(cloud)[Public API]\n",
+                "  group v1[Private API] in v0\n",
+                "  service v2(internet)[Gateway]\n",
+                "  service v3(server)[Server] in v0\n",
+                "  junction v4 in v1\n",
+                "  service v5(logos:aws-s3)[Bucket] in v1\n",
+                "  v2:R --> L:v3\n",
+                "  v3:B <--> T:v4\n",
+                "  v4:B <-- T:v5\n",
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_architecture_diagram_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = ArchitectureDiagramBuilder::default();
+        let server =
+            builder.service(None, ArchitectureServiceBuilder::default().label("Server")?)?;
+        assert_eq!(
+            builder.group(None, ArchitectureGroupBuilder::default().label("Cloud")?.id(0)),
+            Err(ArchitectureDiagramError::DuplicateId("v0".to_owned()))
+        );
+        assert_eq!(
+            ArchitectureServiceBuilder::default().label("[Server]"),
+            Err(ArchitectureDiagramError::InvalidText("[Server]".to_owned()))
+        );
+
+        let foreign_group =
+            Rc::new(ArchitectureGroupBuilder::default().label("Cloud")?.id(5).build()?);
+        assert_eq!(
+            builder.junction(Some(&foreign_group)),
+            Err(ArchitectureDiagramError::GroupNotFound("v5".to_owned()))
+        );
+
+        let foreign_junction = Rc::new(ArchitectureJunction::new(8));
+        assert_eq!(
+            builder.edge(
+                ArchitectureEdgeBuilder::default()
+                    .source(server, ArchitectureSide::Left)
+                    .destination(foreign_junction, ArchitectureSide::Right)
+            ),
+            Err(ArchitectureDiagramError::EndpointNotFound("v8".to_owned()))
+        );
+        assert_eq!(
+            ArchitectureDiagram::from(builder).to_string(),
+            "architecture-beta\n  service v0[Server]\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_architecture_diagram_builder_skips_taken_ids() -> Result<(), Box<dyn core::error::Error>>
+    {
+        let mut builder = ArchitectureDiagramBuilder::default();
+        let server =
+            builder.service(None, ArchitectureServiceBuilder::default().label("Server")?.id(1))?;
+        let database =
+            builder.service(None, ArchitectureServiceBuilder::default().label("Database")?)?;
+        assert_eq!((server.id(), database.id()), (1, 2));
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+//! Submodule defining the edges between the services and junctions of
+//! architecture diagrams in Mermaid syntax.
+
+mod builder;
+use alloc::rc::Rc;
+use core::fmt::{self, Display};
+
+pub use builder::ArchitectureEdgeBuilder;
+
+use crate::{
+    diagrams::architecture_diagram::{ArchitectureJunction, ArchitectureService},
+    shared::NODE_LETTER,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the side of a service or junction to which an edge is
+/// attached.
+pub enum ArchitectureSide {
+    /// The left side.
+    Left,
+    /// The right side.
+    Right,
+    /// The top side.
+    Top,
+    /// The bottom side.
+    Bottom,
+}
+
+impl Display for ArchitectureSide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArchitectureSide::Left => write!(f, "L"),
+            ArchitectureSide::Right => write!(f, "R"),
+            ArchitectureSide::Top => write!(f, "T"),
+            ArchitectureSide::Bottom => write!(f, "B"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents either end of an edge, i.e. a service or a junction.
+pub enum ArchitectureEndpoint {
+    /// A service of the diagram.
+    Service(Rc<ArchitectureService>),
+    /// A junction of the diagram.
+    Junction(Rc<ArchitectureJunction>),
+}
+
+impl ArchitectureEndpoint {
+    #[must_use]
+    /// Returns the unique identifier of the service or junction.
+    pub fn id(&self) -> u64 {
+        match self {
+            ArchitectureEndpoint::Service(service) => service.id(),
+            ArchitectureEndpoint::Junction(junction) => junction.id(),
+        }
+    }
+}
+
+impl From<Rc<ArchitectureService>> for ArchitectureEndpoint {
+    fn from(service: Rc<ArchitectureService>) -> Self {
+        ArchitectureEndpoint::Service(service)
+    }
+}
+
+impl From<Rc<ArchitectureJunction>> for ArchitectureEndpoint {
+    fn from(junction: Rc<ArchitectureJunction>) -> Self {
+        ArchitectureEndpoint::Junction(junction)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents an edge between two services or junctions of an architecture
+/// diagram, attached to a side of each.
+///
+/// # Example
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let server = Rc::new(ArchitectureServiceBuilder::default().label("Server")?.id(0).build()?);
+///     let junction = Rc::new(ArchitectureJunction::new(1));
+///
+///     let edge = ArchitectureEdgeBuilder::default()
+///         .source(server, ArchitectureSide::Right)
+///         .destination(junction, ArchitectureSide::Left)
+///         .destination_arrow(true)
+///         .build()?;
+///
+///     assert_eq!(edge.to_string(), "v0:R --> L:v1");
+///     Ok(())
+/// }
+/// ```
+pub struct ArchitectureEdge {
+    /// Service or junction from which the edge starts.
+    source: ArchitectureEndpoint,
+    /// Side of the source to which the edge is attached.
+    source_side: ArchitectureSide,
+    /// Service or junction at which the edge ends.
+    destination: ArchitectureEndpoint,
+    /// Side of the destination to which the edge is attached.
+    destination_side: ArchitectureSide,
+    /// Whether an arrowhead points at the source.
+    source_arrow: bool,
+    /// Whether an arrowhead points at the destination.
+    destination_arrow: bool,
+}
+
+impl ArchitectureEdge {
+    #[must_use]
+    /// Returns the service or junction from which the edge starts.
+    pub fn source(&self) -> &ArchitectureEndpoint {
+        &self.source
+    }
+
+    #[must_use]
+    /// Returns the side of the source to which the edge is attached.
+    pub fn source_side(&self) -> ArchitectureSide {
+        self.source_side
+    }
+
+    #[must_use]
+    /// Returns the service or junction at which the edge ends.
+    pub fn destination(&self) -> &ArchitectureEndpoint {
+        &self.destination
+    }
+
+    #[must_use]
+    /// Returns the side of the destination to which the edge is attached.
+    pub fn destination_side(&self) -> ArchitectureSide {
+        self.destination_side
+    }
+
+    #[must_use]
+    /// Returns whether an arrowhead points at the source.
+    pub fn has_source_arrow(&self) -> bool {
+        self.source_arrow
+    }
+
+    #[must_use]
+    /// Returns whether an arrowhead points at the destination.
+    pub fn has_destination_arrow(&self) -> bool {
+        self.destination_arrow
+    }
+}
+
+impl Display for ArchitectureEdge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{NODE_LETTER}{}:{} {}--{} {}:{NODE_LETTER}{}",
+            self.source.id(),
+            self.source_side,
+            if self.source_arrow { "<" } else { "" },
+            if self.destination_arrow { ">" } else { "" },
+            self.destination_side,
+            self.destination.id()
+        )
+    }
+}
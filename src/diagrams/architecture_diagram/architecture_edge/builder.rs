@@ -0,0 +1,93 @@
+//! Submodule defining the struct for building an edge of an architecture
+//! diagram.
+
+use crate::{
+    diagrams::architecture_diagram::architecture_edge::{
+        ArchitectureEdge, ArchitectureEndpoint, ArchitectureSide,
+    },
+    errors::ArchitectureDiagramError,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating an `ArchitectureEdge`.
+pub struct ArchitectureEdgeBuilder {
+    /// Service or junction from which the edge starts, and the side to which
+    /// the edge is attached.
+    source: Option<(ArchitectureEndpoint, ArchitectureSide)>,
+    /// Service or junction at which the edge ends, and the side to which the
+    /// edge is attached.
+    destination: Option<(ArchitectureEndpoint, ArchitectureSide)>,
+    /// Whether an arrowhead points at the source.
+    source_arrow: bool,
+    /// Whether an arrowhead points at the destination.
+    destination_arrow: bool,
+}
+
+impl ArchitectureEdgeBuilder {
+    #[must_use]
+    /// Sets the service or junction from which the edge starts, and the side
+    /// to which the edge is attached.
+    pub fn source(
+        mut self,
+        source: impl Into<ArchitectureEndpoint>,
+        side: ArchitectureSide,
+    ) -> Self {
+        self.source = Some((source.into(), side));
+        self
+    }
+
+    #[must_use]
+    /// Sets the service or junction at which the edge ends, and the side to
+    /// which the edge is attached.
+    pub fn destination(
+        mut self,
+        destination: impl Into<ArchitectureEndpoint>,
+        side: ArchitectureSide,
+    ) -> Self {
+        self.destination = Some((destination.into(), side));
+        self
+    }
+
+    #[must_use]
+    /// Sets whether an arrowhead points at the source.
+    pub fn source_arrow(mut self, source_arrow: bool) -> Self {
+        self.source_arrow = source_arrow;
+        self
+    }
+
+    #[must_use]
+    /// Sets whether an arrowhead points at the destination.
+    pub fn destination_arrow(mut self, destination_arrow: bool) -> Self {
+        self.destination_arrow = destination_arrow;
+        self
+    }
+
+    /// Builds the edge.
+    ///
+    /// # Errors
+    ///
+    /// * If the source or the destination is missing.
+    pub fn build(self) -> Result<ArchitectureEdge, ArchitectureDiagramError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<ArchitectureEdgeBuilder> for ArchitectureEdge {
+    type Error = ArchitectureDiagramError;
+
+    fn try_from(builder: ArchitectureEdgeBuilder) -> Result<Self, Self::Error> {
+        let (source, source_side) =
+            builder.source.ok_or(ArchitectureDiagramError::MissingSource)?;
+        let (destination, destination_side) =
+            builder.destination.ok_or(ArchitectureDiagramError::MissingDestination)?;
+        Ok(ArchitectureEdge {
+            source,
+            source_side,
+            destination,
+            destination_side,
+            source_arrow: builder.source_arrow,
+            destination_arrow: builder.destination_arrow,
+        })
+    }
+}
@@ -0,0 +1,71 @@
+//! Submodule defining the groups of architecture diagrams in Mermaid syntax.
+
+mod builder;
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+pub use builder::ArchitectureGroupBuilder;
+
+use crate::{diagrams::architecture_diagram::ArchitectureIcon, shared::NODE_LETTER};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a group of an architecture diagram, which encloses services,
+/// junctions and further groups.
+///
+/// The enclosing group, if any, is tracked by the diagram.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let group = ArchitectureGroupBuilder::default()
+///         .label("Platform")?
+///         .icon(ArchitectureIcon::Cloud)
+///         .id(0)
+///         .build()?;
+///
+///     assert_eq!(group.to_string(), "group v0(cloud)[Platform]");
+///     Ok(())
+/// }
+/// ```
+pub struct ArchitectureGroup {
+    /// Unique identifier of the group.
+    id: u64,
+    /// Label of the group.
+    label: String,
+    /// Icon of the group, if any.
+    icon: Option<ArchitectureIcon>,
+}
+
+impl ArchitectureGroup {
+    #[must_use]
+    /// Returns the unique identifier of the group.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[must_use]
+    /// Returns the label of the group.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the icon of the group, if any.
+    pub fn icon(&self) -> Option<&ArchitectureIcon> {
+        self.icon.as_ref()
+    }
+}
+
+impl Display for ArchitectureGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "group {NODE_LETTER}{}", self.id)?;
+        if let Some(icon) = &self.icon {
+            write!(f, "({icon})")?;
+        }
+        write!(f, "[{}]", self.label)
+    }
+}
@@ -0,0 +1,76 @@
+//! Submodule defining the struct for building a group of an architecture
+//! diagram.
+
+use alloc::string::String;
+
+use crate::{
+    diagrams::architecture_diagram::{
+        ArchitectureIcon, architecture_group::ArchitectureGroup, validate_text,
+    },
+    errors::ArchitectureDiagramError,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating an `ArchitectureGroup`.
+pub struct ArchitectureGroupBuilder {
+    /// Unique identifier of the group.
+    id: Option<u64>,
+    /// Label of the group.
+    label: Option<String>,
+    /// Icon of the group, if any.
+    icon: Option<ArchitectureIcon>,
+}
+
+impl ArchitectureGroupBuilder {
+    /// Sets the label of the group.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains square brackets or line breaks.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, ArchitectureDiagramError> {
+        self.label = Some(validate_text(label)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the icon of the group.
+    pub fn icon(mut self, icon: ArchitectureIcon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    #[must_use]
+    /// Sets the unique identifier of the group.
+    pub fn id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[must_use]
+    /// Returns the identifier of the group, if set.
+    pub fn get_id(&self) -> Option<u64> {
+        self.id
+    }
+
+    /// Builds the group.
+    ///
+    /// # Errors
+    ///
+    /// * If the ID or the label is missing.
+    pub fn build(self) -> Result<ArchitectureGroup, ArchitectureDiagramError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<ArchitectureGroupBuilder> for ArchitectureGroup {
+    type Error = ArchitectureDiagramError;
+
+    fn try_from(builder: ArchitectureGroupBuilder) -> Result<Self, Self::Error> {
+        Ok(ArchitectureGroup {
+            id: builder.id.ok_or(ArchitectureDiagramError::MissingId)?,
+            label: builder.label.ok_or(ArchitectureDiagramError::MissingLabel)?,
+            icon: builder.icon,
+        })
+    }
+}
@@ -0,0 +1,69 @@
+//! Submodule defining the icons of the groups and services of architecture
+//! diagrams in Mermaid syntax.
+
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+use crate::errors::ArchitectureDiagramError;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the icon of a group or service of an architecture diagram.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     assert_eq!(ArchitectureIcon::Database.to_string(), "database");
+///     assert_eq!(ArchitectureIcon::custom("logos:aws-s3")?.to_string(), "logos:aws-s3");
+///     assert!(ArchitectureIcon::custom("aws s3").is_err());
+///     Ok(())
+/// }
+/// ```
+pub enum ArchitectureIcon {
+    /// A cloud.
+    Cloud,
+    /// A database.
+    Database,
+    /// A disk.
+    Disk,
+    /// The internet.
+    Internet,
+    /// A server.
+    Server,
+    /// An icon from a registered icon pack, such as `logos:aws-s3`.
+    Custom(String),
+}
+
+impl ArchitectureIcon {
+    /// Creates an icon from a registered icon pack.
+    ///
+    /// # Errors
+    ///
+    /// * If the name is empty or contains characters other than ASCII
+    ///   alphanumerics, `-`, `_` and `:`.
+    pub fn custom(name: impl Into<String>) -> Result<Self, ArchitectureDiagramError> {
+        let name = name.into();
+        if name.is_empty()
+            || !name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':'))
+        {
+            return Err(ArchitectureDiagramError::InvalidIcon(name));
+        }
+        Ok(ArchitectureIcon::Custom(name))
+    }
+}
+
+impl Display for ArchitectureIcon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArchitectureIcon::Cloud => write!(f, "cloud"),
+            ArchitectureIcon::Database => write!(f, "database"),
+            ArchitectureIcon::Disk => write!(f, "disk"),
+            ArchitectureIcon::Internet => write!(f, "internet"),
+            ArchitectureIcon::Server => write!(f, "server"),
+            ArchitectureIcon::Custom(name) => write!(f, "{name}"),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Submodule defining the junctions of architecture diagrams in Mermaid
+//! syntax, which split or merge edges.
+
+use core::fmt::{self, Display};
+
+use crate::shared::NODE_LETTER;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a junction of an architecture diagram, acting as a four-way
+/// splitter between edges.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// let junction = ArchitectureJunction::new(4);
+/// assert_eq!(junction.to_string(), "junction v4");
+/// ```
+pub struct ArchitectureJunction {
+    /// Unique identifier of the junction.
+    id: u64,
+}
+
+impl ArchitectureJunction {
+    #[must_use]
+    /// Creates a junction with the provided unique identifier.
+    pub fn new(id: u64) -> Self {
+        Self { id }
+    }
+
+    #[must_use]
+    /// Returns the unique identifier of the junction.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+impl Display for ArchitectureJunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "junction {NODE_LETTER}{}", self.id)
+    }
+}
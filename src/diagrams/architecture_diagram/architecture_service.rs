@@ -0,0 +1,70 @@
+//! Submodule defining the services of architecture diagrams in Mermaid syntax.
+
+mod builder;
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+pub use builder::ArchitectureServiceBuilder;
+
+use crate::{diagrams::architecture_diagram::ArchitectureIcon, shared::NODE_LETTER};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a service of an architecture diagram.
+///
+/// The enclosing group, if any, is tracked by the diagram.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let service = ArchitectureServiceBuilder::default()
+///         .label("Orders")?
+///         .icon(ArchitectureIcon::Database)
+///         .id(0)
+///         .build()?;
+///
+///     assert_eq!(service.to_string(), "service v0(database)[Orders]");
+///     Ok(())
+/// }
+/// ```
+pub struct ArchitectureService {
+    /// Unique identifier of the service.
+    id: u64,
+    /// Label of the service.
+    label: String,
+    /// Icon of the service, if any.
+    icon: Option<ArchitectureIcon>,
+}
+
+impl ArchitectureService {
+    #[must_use]
+    /// Returns the unique identifier of the service.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[must_use]
+    /// Returns the label of the service.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the icon of the service, if any.
+    pub fn icon(&self) -> Option<&ArchitectureIcon> {
+        self.icon.as_ref()
+    }
+}
+
+impl Display for ArchitectureService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "service {NODE_LETTER}{}", self.id)?;
+        if let Some(icon) = &self.icon {
+            write!(f, "({icon})")?;
+        }
+        write!(f, "[{}]", self.label)
+    }
+}
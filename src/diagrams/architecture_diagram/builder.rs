@@ -0,0 +1,236 @@
+//! Submodule providing a builder for architecture diagrams.
+
+use alloc::{format, rc::Rc, vec::Vec};
+
+use crate::{
+    diagrams::architecture_diagram::{
+        ArchitectureDiagram, ArchitectureDiagramConfiguration,
+        ArchitectureDiagramConfigurationBuilder, ArchitectureMember,
+        architecture_edge::{ArchitectureEdge, ArchitectureEdgeBuilder, ArchitectureEndpoint},
+        architecture_group::{ArchitectureGroup, ArchitectureGroupBuilder},
+        architecture_junction::ArchitectureJunction,
+        architecture_service::{ArchitectureService, ArchitectureServiceBuilder},
+    },
+    errors::{ArchitectureDiagramError, ConfigError},
+    shared::NODE_LETTER,
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating an architecture diagram.
+///
+/// Groups, services and junctions share the same IDs, and are inserted either
+/// at the top level of the diagram or within a group which is already part
+/// of it.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = ArchitectureDiagramBuilder::default();
+///     let left = builder.service(None, ArchitectureServiceBuilder::default().label("Left")?)?;
+///     let center = builder.junction(None)?;
+///     builder.edge(
+///         ArchitectureEdgeBuilder::default()
+///             .source(left, ArchitectureSide::Right)
+///             .destination(center, ArchitectureSide::Left),
+///     )?;
+///
+///     let diagram = ArchitectureDiagram::from(builder);
+///     assert_eq!(diagram.edges().count(), 1);
+///     Ok(())
+/// }
+/// ```
+pub struct ArchitectureDiagramBuilder {
+    /// Configuration of the diagram.
+    configuration: ArchitectureDiagramConfiguration,
+    /// Members of the diagram in declaration order, along with the identifier
+    /// of the group enclosing them, if any.
+    members: Vec<(Option<u64>, ArchitectureMember)>,
+    /// Edges between the services and junctions of the diagram.
+    edges: Vec<ArchitectureEdge>,
+}
+
+impl ArchitectureDiagramBuilder {
+    /// Sets the configuration of the diagram.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: ArchitectureDiagramConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Adds a group to the diagram.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The group enclosing the new group, if any.
+    /// * `group` - The builder of the group to add.
+    ///
+    /// # Errors
+    ///
+    /// * If the parent group is not part of the diagram.
+    /// * If a member with the same ID already exists in the diagram.
+    /// * If the group builder fails to build.
+    pub fn group(
+        &mut self,
+        parent: Option<&Rc<ArchitectureGroup>>,
+        group: ArchitectureGroupBuilder,
+    ) -> Result<Rc<ArchitectureGroup>, ArchitectureDiagramError> {
+        let parent = self.check_parent(parent)?;
+        let id = self.claim_id(group.get_id())?;
+        let group = Rc::new(group.id(id).build()?);
+        self.members.push((parent, ArchitectureMember::Group(group.clone())));
+        Ok(group)
+    }
+
+    /// Adds a service to the diagram.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The group enclosing the new service, if any.
+    /// * `service` - The builder of the service to add.
+    ///
+    /// # Errors
+    ///
+    /// * If the parent group is not part of the diagram.
+    /// * If a member with the same ID already exists in the diagram.
+    /// * If the service builder fails to build.
+    pub fn service(
+        &mut self,
+        parent: Option<&Rc<ArchitectureGroup>>,
+        service: ArchitectureServiceBuilder,
+    ) -> Result<Rc<ArchitectureService>, ArchitectureDiagramError> {
+        let parent = self.check_parent(parent)?;
+        let id = self.claim_id(service.get_id())?;
+        let service = Rc::new(service.id(id).build()?);
+        self.members.push((parent, ArchitectureMember::Service(service.clone())));
+        Ok(service)
+    }
+
+    /// Adds a junction to the diagram.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The group enclosing the new junction, if any.
+    ///
+    /// # Errors
+    ///
+    /// * If the parent group is not part of the diagram.
+    pub fn junction(
+        &mut self,
+        parent: Option<&Rc<ArchitectureGroup>>,
+    ) -> Result<Rc<ArchitectureJunction>, ArchitectureDiagramError> {
+        let parent = self.check_parent(parent)?;
+        let junction = Rc::new(ArchitectureJunction::new(self.claim_id(None)?));
+        self.members.push((parent, ArchitectureMember::Junction(junction.clone())));
+        Ok(junction)
+    }
+
+    /// Adds an edge between two services or junctions of the diagram.
+    ///
+    /// # Errors
+    ///
+    /// * If the edge builder fails to build.
+    /// * If the source or the destination is not part of the diagram.
+    pub fn edge(&mut self, edge: ArchitectureEdgeBuilder) -> Result<(), ArchitectureDiagramError> {
+        let edge = edge.build()?;
+        for endpoint in [edge.source(), edge.destination()] {
+            let found = match endpoint {
+                ArchitectureEndpoint::Service(service) => {
+                    self.get_service_by_id(service.id()).as_ref() == Some(service)
+                }
+                ArchitectureEndpoint::Junction(junction) => {
+                    self.get_junction_by_id(junction.id()).as_ref() == Some(junction)
+                }
+            };
+            if !found {
+                return Err(ArchitectureDiagramError::EndpointNotFound(format!(
+                    "{NODE_LETTER}{}",
+                    endpoint.id()
+                )));
+            }
+        }
+        self.edges.push(edge);
+        Ok(())
+    }
+
+    #[must_use]
+    /// Returns the group with the provided ID, if any.
+    pub fn get_group_by_id(&self, id: u64) -> Option<Rc<ArchitectureGroup>> {
+        self.members.iter().find_map(|(_, member)| match member {
+            ArchitectureMember::Group(group) if group.id() == id => Some(group.clone()),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    /// Returns the service with the provided ID, if any.
+    pub fn get_service_by_id(&self, id: u64) -> Option<Rc<ArchitectureService>> {
+        self.members.iter().find_map(|(_, member)| match member {
+            ArchitectureMember::Service(service) if service.id() == id => Some(service.clone()),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    /// Returns the junction with the provided ID, if any.
+    pub fn get_junction_by_id(&self, id: u64) -> Option<Rc<ArchitectureJunction>> {
+        self.members.iter().find_map(|(_, member)| match member {
+            ArchitectureMember::Junction(junction) if junction.id() == id => Some(junction.clone()),
+            _ => None,
+        })
+    }
+
+    /// Checks that the provided parent group, if any, is part of the diagram,
+    /// and returns its identifier.
+    fn check_parent(
+        &self,
+        parent: Option<&Rc<ArchitectureGroup>>,
+    ) -> Result<Option<u64>, ArchitectureDiagramError> {
+        if let Some(parent) = parent
+            && self.get_group_by_id(parent.id()).as_ref() != Some(parent)
+        {
+            return Err(ArchitectureDiagramError::GroupNotFound(format!(
+                "{NODE_LETTER}{}",
+                parent.id()
+            )));
+        }
+        Ok(parent.map(|parent| parent.id()))
+    }
+
+    /// Returns the provided ID after checking that no member of the diagram
+    /// uses it, or else the first unused ID from the number of members.
+    fn claim_id(&self, id: Option<u64>) -> Result<u64, ArchitectureDiagramError> {
+        let taken = |id: u64| self.members.iter().any(|(_, member)| member.id() == id);
+        let Some(id) = id else {
+            let mut id = self.members.len() as u64;
+            while taken(id) {
+                id += 1;
+            }
+            return Ok(id);
+        };
+        if taken(id) {
+            return Err(ArchitectureDiagramError::DuplicateId(format!("{NODE_LETTER}{id}")));
+        }
+        Ok(id)
+    }
+}
+
+impl From<ArchitectureDiagramBuilder> for ArchitectureDiagram {
+    fn from(builder: ArchitectureDiagramBuilder) -> Self {
+        ArchitectureDiagram {
+            configuration: builder.configuration,
+            members: builder.members,
+            edges: builder.edges,
+        }
+    }
+}
@@ -0,0 +1,356 @@
+//! Submodule providing structs for creating block diagrams in Mermaid syntax.
+
+mod block;
+mod block_arrow;
+mod block_edge;
+mod builder;
+mod composite_block;
+use alloc::{rc::Rc, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use block::{Block, BlockBuilder};
+pub use block_arrow::{BlockArrow, BlockArrowBuilder, BlockArrowDirection};
+pub use block_edge::{BlockEdge, BlockEdgeBuilder};
+pub use builder::BlockDiagramBuilder;
+pub use composite_block::{CompositeBlock, CompositeBlockBuilder};
+
+use crate::{
+    errors::BlockDiagramError,
+    shared::generic_configuration::{GenericConfiguration, GenericConfigurationBuilder},
+    traits::TabbedDisplay,
+};
+
+/// Represents the configuration for a block diagram.
+pub type BlockDiagramConfiguration = GenericConfiguration;
+/// Represents the configuration builder for a block diagram.
+pub type BlockDiagramConfigurationBuilder = GenericConfigurationBuilder;
+
+/// Validates a text of a block diagram, which is always written between
+/// double quotes.
+pub(crate) fn validate_text(text: impl Into<String>) -> Result<String, BlockDiagramError> {
+    let text = text.into();
+    if text.trim().is_empty() {
+        return Err(BlockDiagramError::EmptyText);
+    }
+    if text.contains(['"', '\n', '\r']) {
+        return Err(BlockDiagramError::InvalidText(text));
+    }
+    Ok(text)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a member of a scope of a block diagram, kept in declaration
+/// order.
+pub(crate) enum BlockMember {
+    /// A block.
+    Block(Rc<Block>),
+    /// A composite block, whose own members are declared within it.
+    Composite(Rc<CompositeBlock>),
+    /// A block arrow.
+    Arrow(Rc<BlockArrow>),
+    /// An empty space spanning the provided number of columns.
+    Space(u8),
+}
+
+impl BlockMember {
+    /// Returns the unique identifier of the member, if it has one.
+    pub(crate) fn id(&self) -> Option<u64> {
+        match self {
+            BlockMember::Block(block) => Some(block.id()),
+            BlockMember::Composite(composite) => Some(composite.id()),
+            BlockMember::Arrow(arrow) => Some(arrow.id()),
+            BlockMember::Space(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a block diagram in Mermaid syntax.
+///
+/// Blocks are laid out from left to right over the columns of the scope
+/// declaring them, wrapping to a new row once the columns are filled.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = BlockDiagramBuilder::default().columns(3)?;
+///     let frontend = builder.block(None, BlockBuilder::default().label("Frontend")?)?;
+///     builder.space(None, 1)?;
+///     let storage = builder.composite_block(None, CompositeBlockBuilder::default())?;
+///     let database = builder.block(
+///         Some(&storage),
+///         BlockBuilder::default().label("Database")?.shape(FlowchartNodeShape::Cylinder)?,
+///     )?;
+///     builder.edge(
+///         BlockEdgeBuilder::default()
+///             .source(frontend)
+///             .destination(database)
+///             .right_arrow_shape(ArrowShape::Normal)?,
+///     )?;
+///
+///     let diagram = BlockDiagram::from(builder);
+///     assert_eq!(
+///         diagram.to_string(),
+///         concat!(
+///             "block-beta\n",
+///             "  columns 3\n",
+///             "  v0[\"Frontend\"]\n",
+///             "  space\n",
+///             "  block:v1\n",
+///             "    v2[(\"Database\")]\n",
+///             "  end\n",
+///             "  v0 --> v2\n",
+///         )
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct BlockDiagram {
+    /// Configuration of the diagram.
+    configuration: BlockDiagramConfiguration,
+    /// Number of columns in which the top-level blocks are laid out, if
+    /// fixed.
+    columns: Option<u8>,
+    /// Members of the diagram in declaration order, along with the identifier
+    /// of the composite block enclosing them, if any.
+    members: Vec<(Option<u64>, BlockMember)>,
+    /// Edges between the blocks of the diagram.
+    edges: Vec<BlockEdge>,
+}
+
+impl BlockDiagram {
+    #[must_use]
+    /// Returns the configuration of the diagram.
+    pub fn configuration(&self) -> &BlockDiagramConfiguration {
+        &self.configuration
+    }
+
+    #[must_use]
+    /// Returns the number of columns in which the top-level blocks are laid
+    /// out, if fixed.
+    pub fn columns(&self) -> Option<u8> {
+        self.columns
+    }
+
+    /// Returns an iterator over the blocks of the diagram.
+    pub fn blocks(&self) -> impl Iterator<Item = &Rc<Block>> {
+        self.members.iter().filter_map(|(_, member)| match member {
+            BlockMember::Block(block) => Some(block),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the composite blocks of the diagram.
+    pub fn composite_blocks(&self) -> impl Iterator<Item = &Rc<CompositeBlock>> {
+        self.members.iter().filter_map(|(_, member)| match member {
+            BlockMember::Composite(composite) => Some(composite),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the block arrows of the diagram.
+    pub fn block_arrows(&self) -> impl Iterator<Item = &Rc<BlockArrow>> {
+        self.members.iter().filter_map(|(_, member)| match member {
+            BlockMember::Arrow(arrow) => Some(arrow),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the edges of the diagram.
+    pub fn edges(&self) -> impl Iterator<Item = &BlockEdge> {
+        self.edges.iter()
+    }
+
+    #[must_use]
+    /// Returns the composite block enclosing the member with the provided
+    /// ID, if any.
+    pub fn parent(&self, id: u64) -> Option<&Rc<CompositeBlock>> {
+        let parent_id = self
+            .members
+            .iter()
+            .find_map(|(parent, member)| (member.id() == Some(id)).then_some(*parent))??;
+        self.composite_blocks().find(|composite| composite.id() == parent_id)
+    }
+
+    /// Writes the members of the provided scope, recursing into composite
+    /// blocks.
+    fn fmt_scope(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        scope: Option<u64>,
+        tab_count: usize,
+    ) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        for (parent, member) in &self.members {
+            if *parent != scope {
+                continue;
+            }
+            match member {
+                BlockMember::Block(block) => writeln!(f, "{indent}{block}")?,
+                BlockMember::Arrow(arrow) => writeln!(f, "{indent}{arrow}")?,
+                BlockMember::Space(1) => writeln!(f, "{indent}space")?,
+                BlockMember::Space(span) => writeln!(f, "{indent}space:{span}")?,
+                BlockMember::Composite(composite) => {
+                    writeln!(f, "{indent}{composite}")?;
+                    if let Some(columns) = composite.columns() {
+                        writeln!(f, "{indent}  columns {columns}")?;
+                    }
+                    self.fmt_scope(f, Some(composite.id()), tab_count + 1)?;
+                    writeln!(f, "{indent}end")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for BlockDiagram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for BlockDiagram {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat((tab_count + 1) * 2);
+        self.configuration.fmt_frontmatter(f, true, &[])?;
+        writeln!(f, "{}block-beta", " ".repeat(tab_count * 2))?;
+        if let Some(columns) = self.columns {
+            writeln!(f, "{indent}columns {columns}")?;
+        }
+        self.fmt_scope(f, None, tab_count + 1)?;
+        for edge in &self.edges {
+            writeln!(f, "{indent}{edge}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::{
+        diagrams::flowchart::FlowchartNodeShape,
+        shared::{ArrowShape, LineStyle},
+        traits::ConfigurationBuilder,
+    };
+
+    #[test]
+    fn test_block_diagram_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = BlockDiagramBuilder::default()
+            .columns(3)?
+            .configuration(BlockDiagramConfigurationBuilder::default().title("Pipeline")?)?;
+        let input = builder.block(
+            None,
+            BlockBuilder::default().label("Input")?.shape(FlowchartNodeShape::StadiumShape)?,
+        )?;
+        builder.block_arrow(
+            None,
+            BlockArrowBuilder::default().label("Feeds")?.direction(BlockArrowDirection::Right),
+        )?;
+        let workers =
+            builder.composite_block(None, CompositeBlockBuilder::default().columns(2)?)?;
+        let parser = builder.block(Some(&workers), BlockBuilder::default().label("Parser")?)?;
+        builder.space(Some(&workers), 1)?;
+        let checker = builder.block(
+            Some(&workers),
+            BlockBuilder::default()
+                .label("Checker")?
+                .shape(FlowchartNodeShape::Diamond)?
+                .span(2)?,
+        )?;
+        builder.space(None, 2)?;
+        let output = builder.block(
+            None,
+            BlockBuilder::default().label("Output")?.shape(FlowchartNodeShape::Hexagon)?,
+        )?;
+        builder.edge(
+            BlockEdgeBuilder::default()
+                .source(input)
+                .destination(parser.clone())
+                .right_arrow_shape(ArrowShape::Normal)?,
+        )?;
+        builder.edge(
+            BlockEdgeBuilder::default()
+                .source(checker.clone())
+                .destination(output)
+                .line_style(LineStyle::Thick)
+                .label("Valid")?
+                .right_arrow_shape(ArrowShape::Normal)?,
+        )?;
+
+        let diagram = BlockDiagram::from(builder);
+        assert_eq!(diagram.blocks().count(), 4);
+        assert_eq!(diagram.block_arrows().count(), 1);
+        assert_eq!(diagram.parent(checker.id()), Some(&workers));
+        assert_eq!(diagram.parent(workers.id()), None);
+        assert_eq!(
+            diagram.to_string(),
+            concat!(
+                "---\n",
+                "title: Pipeline\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "---\n",
+                "block-beta\n",
+                "  columns 3\n",
+                "  v0([\"Input\"])\n",
+                "  v1<[\"Feeds\"]>(right)\n",
+                "  block:v2\n",
+                "    columns 2\n",
+                "    v3[\"Parser\"]\n",
+                "    space\n",
+                "    v4{\"Checker\"}:2\n",
+                "  end\n",
+                "  space:2\n",
+                "  v5{{\"Output\"}}\n",
+                "  v0 --> v3\n",
+                "  v4 == \"Valid\" ==> v5\n",
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_block_diagram_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = BlockDiagramBuilder::default();
+        let block = builder.block(None, BlockBuilder::default().label("A")?)?;
+        assert_eq!(
+            builder.block(None, BlockBuilder::default().label("B")?.id(0)),
+            Err(BlockDiagramError::DuplicateId("v0".to_owned()))
+        );
+        assert_eq!(builder.space(None, 0), Err(BlockDiagramError::ZeroSpan));
+        assert_eq!(BlockDiagramBuilder::default().columns(0), Err(BlockDiagramError::ZeroColumns));
+
+        let foreign_composite = Rc::new(CompositeBlockBuilder::default().id(4).build()?);
+        assert_eq!(
+            builder.block(Some(&foreign_composite), BlockBuilder::default().label("C")?),
+            Err(BlockDiagramError::BlockNotFound("v4".to_owned()))
+        );
+
+        let foreign_block = Rc::new(BlockBuilder::default().label("D")?.id(7).build()?);
+        assert_eq!(
+            builder.edge(BlockEdgeBuilder::default().source(block).destination(foreign_block)),
+            Err(BlockDiagramError::BlockNotFound("v7".to_owned()))
+        );
+        assert_eq!(BlockDiagram::from(builder).to_string(), "block-beta\n  v0[\"A\"]\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_block_diagram_builder_skips_taken_ids() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = BlockDiagramBuilder::default();
+        let first = builder.block(None, BlockBuilder::default().label("A")?.id(1))?;
+        let second = builder.block(None, BlockBuilder::default().label("B")?)?;
+        assert_eq!((first.id(), second.id()), (1, 2));
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+//! Submodule defining the blocks of block diagrams in Mermaid syntax.
+
+mod builder;
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+pub use builder::BlockBuilder;
+
+use crate::{diagrams::flowchart::FlowchartNodeShape, shared::NODE_LETTER};
+
+/// Returns the delimiters surrounding the label of a block with the provided
+/// shape, if the shape can be drawn in block diagrams.
+pub(crate) fn shape_delimiters(shape: FlowchartNodeShape) -> Option<(&'static str, &'static str)> {
+    Some(match shape {
+        FlowchartNodeShape::Rectangle => ("[", "]"),
+        FlowchartNodeShape::RoundEdges => ("(", ")"),
+        FlowchartNodeShape::StadiumShape => ("([", "])"),
+        FlowchartNodeShape::Subprocess => ("[[", "]]"),
+        FlowchartNodeShape::Cylinder => ("[(", ")]"),
+        FlowchartNodeShape::Circle => ("((", "))"),
+        FlowchartNodeShape::Odd => (">", "]"),
+        FlowchartNodeShape::Diamond => ("{", "}"),
+        FlowchartNodeShape::Hexagon => ("{{", "}}"),
+        FlowchartNodeShape::LRParallelogram => ("[/", "/]"),
+        FlowchartNodeShape::LLParallelogram => ("[\\", "\\]"),
+        FlowchartNodeShape::Trapezoid => ("[/", "\\]"),
+        FlowchartNodeShape::ReverseTrapezoid => ("[\\", "/]"),
+        FlowchartNodeShape::DoubleCircle => ("(((", ")))"),
+        _ => return None,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a block of a block diagram, spanning one or more columns.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let block = BlockBuilder::default()
+///         .label("Database")?
+///         .shape(FlowchartNodeShape::Cylinder)?
+///         .span(2)?
+///         .id(3)
+///         .build()?;
+///
+///     assert_eq!(block.to_string(), "v3[(\"Database\")]:2");
+///     Ok(())
+/// }
+/// ```
+pub struct Block {
+    /// Unique identifier of the block.
+    id: u64,
+    /// Label of the block.
+    label: String,
+    /// Shape of the block.
+    shape: FlowchartNodeShape,
+    /// Number of columns spanned by the block.
+    span: u8,
+}
+
+impl Block {
+    #[must_use]
+    /// Returns the unique identifier of the block.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[must_use]
+    /// Returns the label of the block.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the shape of the block.
+    pub fn shape(&self) -> FlowchartNodeShape {
+        self.shape
+    }
+
+    #[must_use]
+    /// Returns the number of columns spanned by the block.
+    pub fn span(&self) -> u8 {
+        self.span
+    }
+}
+
+impl Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (open, close) = shape_delimiters(self.shape).unwrap_or(("[", "]"));
+        write!(f, "{NODE_LETTER}{}{open}\"{}\"{close}", self.id, self.label)?;
+        if self.span > 1 {
+            write!(f, ":{}", self.span)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+//! Submodule defining the struct for building a block of a block diagram.
+
+use alloc::string::String;
+
+use crate::{
+    diagrams::{
+        block_diagram::{
+            block::{Block, shape_delimiters},
+            validate_text,
+        },
+        flowchart::FlowchartNodeShape,
+    },
+    errors::BlockDiagramError,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `Block`.
+pub struct BlockBuilder {
+    /// Unique identifier of the block.
+    id: Option<u64>,
+    /// Label of the block.
+    label: Option<String>,
+    /// Shape of the block.
+    shape: FlowchartNodeShape,
+    /// Number of columns spanned by the block.
+    span: u8,
+}
+
+impl Default for BlockBuilder {
+    fn default() -> Self {
+        Self { id: None, label: None, shape: FlowchartNodeShape::default(), span: 1 }
+    }
+}
+
+impl BlockBuilder {
+    /// Sets the label of the block.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains double quotes or line breaks.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, BlockDiagramError> {
+        self.label = Some(validate_text(label)?);
+        Ok(self)
+    }
+
+    /// Sets the shape of the block.
+    ///
+    /// # Errors
+    ///
+    /// * If the shape cannot be drawn in block diagrams.
+    pub fn shape(mut self, shape: FlowchartNodeShape) -> Result<Self, BlockDiagramError> {
+        if shape_delimiters(shape).is_none() {
+            return Err(BlockDiagramError::UnsupportedShape(shape));
+        }
+        self.shape = shape;
+        Ok(self)
+    }
+
+    /// Sets the number of columns spanned by the block.
+    ///
+    /// # Errors
+    ///
+    /// * If the span is zero.
+    pub fn span(mut self, span: u8) -> Result<Self, BlockDiagramError> {
+        if span == 0 {
+            return Err(BlockDiagramError::ZeroSpan);
+        }
+        self.span = span;
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the unique identifier of the block.
+    pub fn id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[must_use]
+    /// Returns the identifier of the block, if set.
+    pub fn get_id(&self) -> Option<u64> {
+        self.id
+    }
+
+    /// Builds the block.
+    ///
+    /// # Errors
+    ///
+    /// * If the ID or the label is missing.
+    pub fn build(self) -> Result<Block, BlockDiagramError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<BlockBuilder> for Block {
+    type Error = BlockDiagramError;
+
+    fn try_from(builder: BlockBuilder) -> Result<Self, Self::Error> {
+        Ok(Block {
+            id: builder.id.ok_or(BlockDiagramError::MissingId)?,
+            label: builder.label.ok_or(BlockDiagramError::MissingLabel)?,
+            shape: builder.shape,
+            span: builder.span,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_builder_errors() -> Result<(), BlockDiagramError> {
+        assert_eq!(
+            BlockBuilder::default().shape(FlowchartNodeShape::Hourglass),
+            Err(BlockDiagramError::UnsupportedShape(FlowchartNodeShape::Hourglass))
+        );
+        assert_eq!(BlockBuilder::default().span(0), Err(BlockDiagramError::ZeroSpan));
+        assert_eq!(BlockBuilder::default().label("A")?.build(), Err(BlockDiagramError::MissingId));
+        assert_eq!(BlockBuilder::default().id(0).build(), Err(BlockDiagramError::MissingLabel));
+        Ok(())
+    }
+}
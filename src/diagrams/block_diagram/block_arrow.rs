@@ -0,0 +1,109 @@
+//! Submodule defining the block arrows of block diagrams in Mermaid syntax,
+//! which are blocks drawn as arrows pointing in a direction.
+
+mod builder;
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+pub use builder::BlockArrowBuilder;
+
+use crate::shared::NODE_LETTER;
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Direction in which a block arrow points.
+pub enum BlockArrowDirection {
+    /// The arrow points to the right.
+    #[default]
+    Right,
+    /// The arrow points to the left.
+    Left,
+    /// The arrow points upwards.
+    Up,
+    /// The arrow points downwards.
+    Down,
+    /// The arrow points both to the left and to the right.
+    Horizontal,
+    /// The arrow points both upwards and downwards.
+    Vertical,
+}
+
+impl Display for BlockArrowDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BlockArrowDirection::Right => "right",
+            BlockArrowDirection::Left => "left",
+            BlockArrowDirection::Up => "up",
+            BlockArrowDirection::Down => "down",
+            BlockArrowDirection::Horizontal => "x",
+            BlockArrowDirection::Vertical => "y",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a block arrow of a block diagram.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let arrow = BlockArrowBuilder::default()
+///         .label("Next")?
+///         .direction(BlockArrowDirection::Down)
+///         .id(2)
+///         .build()?;
+///
+///     assert_eq!(arrow.to_string(), "v2<[\"Next\"]>(down)");
+///     Ok(())
+/// }
+/// ```
+pub struct BlockArrow {
+    /// Unique identifier of the block arrow.
+    id: u64,
+    /// Label of the block arrow.
+    label: String,
+    /// Direction in which the block arrow points.
+    direction: BlockArrowDirection,
+    /// Number of columns spanned by the block arrow.
+    span: u8,
+}
+
+impl BlockArrow {
+    #[must_use]
+    /// Returns the unique identifier of the block arrow.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[must_use]
+    /// Returns the label of the block arrow.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the direction in which the block arrow points.
+    pub fn direction(&self) -> BlockArrowDirection {
+        self.direction
+    }
+
+    #[must_use]
+    /// Returns the number of columns spanned by the block arrow.
+    pub fn span(&self) -> u8 {
+        self.span
+    }
+}
+
+impl Display for BlockArrow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{NODE_LETTER}{}<[\"{}\"]>({})", self.id, self.label, self.direction)?;
+        if self.span > 1 {
+            write!(f, ":{}", self.span)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+//! Submodule defining the struct for building a block arrow of a block
+//! diagram.
+
+use alloc::string::String;
+
+use crate::{
+    diagrams::block_diagram::{
+        block_arrow::{BlockArrow, BlockArrowDirection},
+        validate_text,
+    },
+    errors::BlockDiagramError,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `BlockArrow`.
+pub struct BlockArrowBuilder {
+    /// Unique identifier of the block arrow.
+    id: Option<u64>,
+    /// Label of the block arrow.
+    label: Option<String>,
+    /// Direction in which the block arrow points.
+    direction: BlockArrowDirection,
+    /// Number of columns spanned by the block arrow.
+    span: u8,
+}
+
+impl Default for BlockArrowBuilder {
+    fn default() -> Self {
+        Self { id: None, label: None, direction: BlockArrowDirection::default(), span: 1 }
+    }
+}
+
+impl BlockArrowBuilder {
+    /// Sets the label of the block arrow.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains double quotes or line breaks.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, BlockDiagramError> {
+        self.label = Some(validate_text(label)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the direction in which the block arrow points.
+    pub fn direction(mut self, direction: BlockArrowDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the number of columns spanned by the block arrow.
+    ///
+    /// # Errors
+    ///
+    /// * If the span is zero.
+    pub fn span(mut self, span: u8) -> Result<Self, BlockDiagramError> {
+        if span == 0 {
+            return Err(BlockDiagramError::ZeroSpan);
+        }
+        self.span = span;
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the unique identifier of the block arrow.
+    pub fn id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[must_use]
+    /// Returns the identifier of the block arrow, if set.
+    pub fn get_id(&self) -> Option<u64> {
+        self.id
+    }
+
+    /// Builds the block arrow.
+    ///
+    /// # Errors
+    ///
+    /// * If the ID or the label is missing.
+    pub fn build(self) -> Result<BlockArrow, BlockDiagramError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<BlockArrowBuilder> for BlockArrow {
+    type Error = BlockDiagramError;
+
+    fn try_from(builder: BlockArrowBuilder) -> Result<Self, Self::Error> {
+        Ok(BlockArrow {
+            id: builder.id.ok_or(BlockDiagramError::MissingId)?,
+            label: builder.label.ok_or(BlockDiagramError::MissingLabel)?,
+            direction: builder.direction,
+            span: builder.span,
+        })
+    }
+}
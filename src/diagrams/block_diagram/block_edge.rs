@@ -0,0 +1,160 @@
+//! Submodule defining the edges between the blocks of block diagrams in
+//! Mermaid syntax.
+
+mod builder;
+use alloc::{rc::Rc, string::String};
+use core::fmt::{self, Display};
+
+pub use builder::BlockEdgeBuilder;
+
+use crate::{
+    diagrams::block_diagram::block::Block,
+    shared::{ArrowShape, LineStyle, NODE_LETTER},
+};
+
+/// Returns whether the arrow shape can be drawn at the ends of block diagram
+/// edges.
+pub(crate) fn is_compatible_arrow_shape(shape: ArrowShape) -> bool {
+    matches!(shape, ArrowShape::Normal | ArrowShape::Circle | ArrowShape::X)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents an edge between two blocks of a block diagram.
+///
+/// # Example
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let source = Rc::new(BlockBuilder::default().label("A")?.id(0).build()?);
+///     let destination = Rc::new(BlockBuilder::default().label("B")?.id(1).build()?);
+///
+///     let edge = BlockEdgeBuilder::default()
+///         .source(source)
+///         .destination(destination)
+///         .label("Sends")?
+///         .line_style(LineStyle::Dashed)
+///         .right_arrow_shape(ArrowShape::Normal)?
+///         .build()?;
+///
+///     assert_eq!(edge.to_string(), "v0 -. \"Sends\" .-> v1");
+///     Ok(())
+/// }
+/// ```
+pub struct BlockEdge {
+    /// Block from which the edge starts.
+    source: Rc<Block>,
+    /// Block at which the edge ends.
+    destination: Rc<Block>,
+    /// Label of the edge.
+    label: Option<String>,
+    /// Style of the line of the edge.
+    line_style: LineStyle,
+    /// Shape of the arrow at the source end of the edge.
+    left_arrow_shape: Option<ArrowShape>,
+    /// Shape of the arrow at the destination end of the edge.
+    right_arrow_shape: Option<ArrowShape>,
+}
+
+impl BlockEdge {
+    #[must_use]
+    /// Returns the block from which the edge starts.
+    pub fn source(&self) -> &Rc<Block> {
+        &self.source
+    }
+
+    #[must_use]
+    /// Returns the block at which the edge ends.
+    pub fn destination(&self) -> &Rc<Block> {
+        &self.destination
+    }
+
+    #[must_use]
+    /// Returns the label of the edge, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    #[must_use]
+    /// Returns the style of the line of the edge.
+    pub fn line_style(&self) -> LineStyle {
+        self.line_style
+    }
+
+    #[must_use]
+    /// Returns the shape of the arrow at the source end of the edge, if any.
+    pub fn left_arrow_shape(&self) -> Option<ArrowShape> {
+        self.left_arrow_shape
+    }
+
+    #[must_use]
+    /// Returns the shape of the arrow at the destination end of the edge, if
+    /// any.
+    pub fn right_arrow_shape(&self) -> Option<ArrowShape> {
+        self.right_arrow_shape
+    }
+}
+
+impl Display for BlockEdge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (open, close) = match self.line_style {
+            LineStyle::Solid => ("--", "--"),
+            LineStyle::Thick => ("==", "=="),
+            LineStyle::Dashed => ("-.", ".-"),
+        };
+        let left = self.left_arrow_shape.as_ref().map_or("", ArrowShape::left);
+        let right = self.right_arrow_shape.as_ref().map_or("", ArrowShape::right);
+        // Solid and thick lines need a third character when no arrow ends them.
+        let tail = match self.line_style {
+            LineStyle::Solid if right.is_empty() => "-",
+            LineStyle::Thick if right.is_empty() => "=",
+            _ => "",
+        };
+        write!(f, "{NODE_LETTER}{} {left}", self.source.id())?;
+        match &self.label {
+            Some(label) => write!(f, "{open} \"{label}\" {close}{tail}")?,
+            None if self.line_style == LineStyle::Dashed => write!(f, "-.-")?,
+            None => write!(f, "{open}{tail}")?,
+        }
+        write!(f, "{right} {NODE_LETTER}{}", self.destination.id())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::{diagrams::block_diagram::BlockBuilder, errors::BlockDiagramError};
+
+    #[test]
+    fn test_block_edge_display() -> Result<(), Box<dyn core::error::Error>> {
+        let source = Rc::new(BlockBuilder::default().label("A")?.id(0).build()?);
+        let destination = Rc::new(BlockBuilder::default().label("B")?.id(1).build()?);
+        let edge =
+            || BlockEdgeBuilder::default().source(source.clone()).destination(destination.clone());
+
+        assert_eq!(edge().build()?.to_string(), "v0 --- v1");
+        assert_eq!(edge().right_arrow_shape(ArrowShape::Normal)?.build()?.to_string(), "v0 --> v1");
+        assert_eq!(
+            edge()
+                .line_style(LineStyle::Thick)
+                .left_arrow_shape(ArrowShape::Circle)?
+                .right_arrow_shape(ArrowShape::X)?
+                .build()?
+                .to_string(),
+            "v0 o==x v1"
+        );
+        assert_eq!(edge().line_style(LineStyle::Dashed).build()?.to_string(), "v0 -.- v1");
+        assert_eq!(edge().label("Uses")?.build()?.to_string(), "v0 -- \"Uses\" --- v1");
+        assert_eq!(
+            edge().right_arrow_shape(ArrowShape::Triangle),
+            Err(BlockDiagramError::IncompatibleArrowShape(ArrowShape::Triangle))
+        );
+        Ok(())
+    }
+}
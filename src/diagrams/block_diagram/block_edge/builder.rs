@@ -0,0 +1,114 @@
+//! Submodule defining the struct for building an edge of a block diagram.
+
+use alloc::{rc::Rc, string::String};
+
+use crate::{
+    diagrams::block_diagram::{
+        block::Block,
+        block_edge::{BlockEdge, is_compatible_arrow_shape},
+        validate_text,
+    },
+    errors::BlockDiagramError,
+    shared::{ArrowShape, LineStyle},
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `BlockEdge`.
+pub struct BlockEdgeBuilder {
+    /// Block from which the edge starts.
+    source: Option<Rc<Block>>,
+    /// Block at which the edge ends.
+    destination: Option<Rc<Block>>,
+    /// Label of the edge.
+    label: Option<String>,
+    /// Style of the line of the edge.
+    line_style: LineStyle,
+    /// Shape of the arrow at the source end of the edge.
+    left_arrow_shape: Option<ArrowShape>,
+    /// Shape of the arrow at the destination end of the edge.
+    right_arrow_shape: Option<ArrowShape>,
+}
+
+impl BlockEdgeBuilder {
+    #[must_use]
+    /// Sets the block from which the edge starts.
+    pub fn source(mut self, source: Rc<Block>) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    #[must_use]
+    /// Sets the block at which the edge ends.
+    pub fn destination(mut self, destination: Rc<Block>) -> Self {
+        self.destination = Some(destination);
+        self
+    }
+
+    /// Sets the label of the edge.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains double quotes or line breaks.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, BlockDiagramError> {
+        self.label = Some(validate_text(label)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the style of the line of the edge.
+    pub fn line_style(mut self, line_style: LineStyle) -> Self {
+        self.line_style = line_style;
+        self
+    }
+
+    /// Sets the shape of the arrow at the source end of the edge.
+    ///
+    /// # Errors
+    ///
+    /// * If the arrow shape cannot be drawn in block diagrams.
+    pub fn left_arrow_shape(mut self, shape: ArrowShape) -> Result<Self, BlockDiagramError> {
+        if !is_compatible_arrow_shape(shape) {
+            return Err(BlockDiagramError::IncompatibleArrowShape(shape));
+        }
+        self.left_arrow_shape = Some(shape);
+        Ok(self)
+    }
+
+    /// Sets the shape of the arrow at the destination end of the edge.
+    ///
+    /// # Errors
+    ///
+    /// * If the arrow shape cannot be drawn in block diagrams.
+    pub fn right_arrow_shape(mut self, shape: ArrowShape) -> Result<Self, BlockDiagramError> {
+        if !is_compatible_arrow_shape(shape) {
+            return Err(BlockDiagramError::IncompatibleArrowShape(shape));
+        }
+        self.right_arrow_shape = Some(shape);
+        Ok(self)
+    }
+
+    /// Builds the edge.
+    ///
+    /// # Errors
+    ///
+    /// * If the source or the destination is missing.
+    pub fn build(self) -> Result<BlockEdge, BlockDiagramError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<BlockEdgeBuilder> for BlockEdge {
+    type Error = BlockDiagramError;
+
+    fn try_from(builder: BlockEdgeBuilder) -> Result<Self, Self::Error> {
+        Ok(BlockEdge {
+            source: builder.source.ok_or(BlockDiagramError::MissingSource)?,
+            destination: builder.destination.ok_or(BlockDiagramError::MissingDestination)?,
+            label: builder.label,
+            line_style: builder.line_style,
+            left_arrow_shape: builder.left_arrow_shape,
+            right_arrow_shape: builder.right_arrow_shape,
+        })
+    }
+}
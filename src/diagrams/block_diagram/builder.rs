@@ -0,0 +1,265 @@
+//! Submodule providing a builder for block diagrams.
+
+use alloc::{format, rc::Rc, vec::Vec};
+
+use crate::{
+    diagrams::block_diagram::{
+        BlockDiagram, BlockDiagramConfiguration, BlockDiagramConfigurationBuilder, BlockMember,
+        block::{Block, BlockBuilder},
+        block_arrow::{BlockArrow, BlockArrowBuilder},
+        block_edge::{BlockEdge, BlockEdgeBuilder},
+        composite_block::{CompositeBlock, CompositeBlockBuilder},
+    },
+    errors::{BlockDiagramError, ConfigError},
+    shared::NODE_LETTER,
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a block diagram.
+///
+/// Blocks, composite blocks and block arrows share the same IDs, and are
+/// inserted either at the top level of the diagram or within a composite
+/// block which is already part of it.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = BlockDiagramBuilder::default();
+///     let group = builder.composite_block(None, CompositeBlockBuilder::default().span(2)?)?;
+///     builder.block(Some(&group), BlockBuilder::default().label("Cache")?)?;
+///     builder.block_arrow(
+///         None,
+///         BlockArrowBuilder::default().label("Flush")?.direction(BlockArrowDirection::Down),
+///     )?;
+///
+///     let diagram = BlockDiagram::from(builder);
+///     assert_eq!(diagram.blocks().count(), 1);
+///     Ok(())
+/// }
+/// ```
+pub struct BlockDiagramBuilder {
+    /// Configuration of the diagram.
+    configuration: BlockDiagramConfiguration,
+    /// Number of columns in which the top-level blocks are laid out, if
+    /// fixed.
+    columns: Option<u8>,
+    /// Members of the diagram in declaration order, along with the identifier
+    /// of the composite block enclosing them, if any.
+    members: Vec<(Option<u64>, BlockMember)>,
+    /// Edges between the blocks of the diagram.
+    edges: Vec<BlockEdge>,
+}
+
+impl BlockDiagramBuilder {
+    /// Sets the configuration of the diagram.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: BlockDiagramConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Sets the number of columns in which the top-level blocks are laid out.
+    ///
+    /// # Errors
+    ///
+    /// * If the number of columns is zero.
+    pub fn columns(mut self, columns: u8) -> Result<Self, BlockDiagramError> {
+        if columns == 0 {
+            return Err(BlockDiagramError::ZeroColumns);
+        }
+        self.columns = Some(columns);
+        Ok(self)
+    }
+
+    /// Adds a block to the diagram.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The composite block enclosing the new block, if any.
+    /// * `block` - The builder of the block to add.
+    ///
+    /// # Errors
+    ///
+    /// * If the parent composite block is not part of the diagram.
+    /// * If a member with the same ID already exists in the diagram.
+    /// * If the block builder fails to build.
+    pub fn block(
+        &mut self,
+        parent: Option<&Rc<CompositeBlock>>,
+        block: BlockBuilder,
+    ) -> Result<Rc<Block>, BlockDiagramError> {
+        let parent = self.check_parent(parent)?;
+        let id = self.claim_id(block.get_id())?;
+        let block = Rc::new(block.id(id).build()?);
+        self.members.push((parent, BlockMember::Block(block.clone())));
+        Ok(block)
+    }
+
+    /// Adds a composite block to the diagram, within which further members
+    /// may be nested.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The composite block enclosing the new composite block, if
+    ///   any.
+    /// * `composite` - The builder of the composite block to add.
+    ///
+    /// # Errors
+    ///
+    /// * If the parent composite block is not part of the diagram.
+    /// * If a member with the same ID already exists in the diagram.
+    /// * If the composite block builder fails to build.
+    pub fn composite_block(
+        &mut self,
+        parent: Option<&Rc<CompositeBlock>>,
+        composite: CompositeBlockBuilder,
+    ) -> Result<Rc<CompositeBlock>, BlockDiagramError> {
+        let parent = self.check_parent(parent)?;
+        let id = self.claim_id(composite.get_id())?;
+        let composite = Rc::new(composite.id(id).build()?);
+        self.members.push((parent, BlockMember::Composite(composite.clone())));
+        Ok(composite)
+    }
+
+    /// Adds a block arrow to the diagram.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The composite block enclosing the new block arrow, if any.
+    /// * `arrow` - The builder of the block arrow to add.
+    ///
+    /// # Errors
+    ///
+    /// * If the parent composite block is not part of the diagram.
+    /// * If a member with the same ID already exists in the diagram.
+    /// * If the block arrow builder fails to build.
+    pub fn block_arrow(
+        &mut self,
+        parent: Option<&Rc<CompositeBlock>>,
+        arrow: BlockArrowBuilder,
+    ) -> Result<Rc<BlockArrow>, BlockDiagramError> {
+        let parent = self.check_parent(parent)?;
+        let id = self.claim_id(arrow.get_id())?;
+        let arrow = Rc::new(arrow.id(id).build()?);
+        self.members.push((parent, BlockMember::Arrow(arrow.clone())));
+        Ok(arrow)
+    }
+
+    /// Adds an empty space spanning the provided number of columns to the
+    /// diagram.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The composite block enclosing the space, if any.
+    /// * `span` - The number of columns spanned by the space.
+    ///
+    /// # Errors
+    ///
+    /// * If the parent composite block is not part of the diagram.
+    /// * If the span is zero.
+    pub fn space(
+        &mut self,
+        parent: Option<&Rc<CompositeBlock>>,
+        span: u8,
+    ) -> Result<(), BlockDiagramError> {
+        let parent = self.check_parent(parent)?;
+        if span == 0 {
+            return Err(BlockDiagramError::ZeroSpan);
+        }
+        self.members.push((parent, BlockMember::Space(span)));
+        Ok(())
+    }
+
+    /// Adds an edge between two blocks of the diagram.
+    ///
+    /// # Errors
+    ///
+    /// * If the edge builder fails to build.
+    /// * If the source or the destination is not part of the diagram.
+    pub fn edge(&mut self, edge: BlockEdgeBuilder) -> Result<(), BlockDiagramError> {
+        let edge = edge.build()?;
+        for block in [edge.source(), edge.destination()] {
+            if self.get_block_by_id(block.id()).as_ref() != Some(block) {
+                return Err(BlockDiagramError::BlockNotFound(format!(
+                    "{NODE_LETTER}{}",
+                    block.id()
+                )));
+            }
+        }
+        self.edges.push(edge);
+        Ok(())
+    }
+
+    #[must_use]
+    /// Returns the block with the provided ID, if any.
+    pub fn get_block_by_id(&self, id: u64) -> Option<Rc<Block>> {
+        self.members.iter().find_map(|(_, member)| match member {
+            BlockMember::Block(block) if block.id() == id => Some(block.clone()),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    /// Returns the composite block with the provided ID, if any.
+    pub fn get_composite_block_by_id(&self, id: u64) -> Option<Rc<CompositeBlock>> {
+        self.members.iter().find_map(|(_, member)| match member {
+            BlockMember::Composite(composite) if composite.id() == id => Some(composite.clone()),
+            _ => None,
+        })
+    }
+
+    /// Checks that the provided parent composite block, if any, is part of
+    /// the diagram, and returns its identifier.
+    fn check_parent(
+        &self,
+        parent: Option<&Rc<CompositeBlock>>,
+    ) -> Result<Option<u64>, BlockDiagramError> {
+        if let Some(parent) = parent
+            && self.get_composite_block_by_id(parent.id()).as_ref() != Some(parent)
+        {
+            return Err(BlockDiagramError::BlockNotFound(format!("{NODE_LETTER}{}", parent.id())));
+        }
+        Ok(parent.map(|parent| parent.id()))
+    }
+
+    /// Returns the provided ID after checking that no member of the diagram
+    /// uses it, or else the first unused ID from the number of identified
+    /// members.
+    fn claim_id(&self, id: Option<u64>) -> Result<u64, BlockDiagramError> {
+        let ids = self.members.iter().filter_map(|(_, member)| member.id());
+        let taken = |id: u64| ids.clone().any(|member_id| member_id == id);
+        let Some(id) = id else {
+            let mut id = ids.clone().count() as u64;
+            while taken(id) {
+                id += 1;
+            }
+            return Ok(id);
+        };
+        if taken(id) {
+            return Err(BlockDiagramError::DuplicateId(format!("{NODE_LETTER}{id}")));
+        }
+        Ok(id)
+    }
+}
+
+impl From<BlockDiagramBuilder> for BlockDiagram {
+    fn from(builder: BlockDiagramBuilder) -> Self {
+        BlockDiagram {
+            configuration: builder.configuration,
+            columns: builder.columns,
+            members: builder.members,
+            edges: builder.edges,
+        }
+    }
+}
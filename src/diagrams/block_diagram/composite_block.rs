@@ -0,0 +1,67 @@
+//! Submodule defining the composite blocks of block diagrams in Mermaid
+//! syntax, which lay out nested blocks within their own columns.
+
+mod builder;
+use core::fmt::{self, Display};
+
+pub use builder::CompositeBlockBuilder;
+
+use crate::shared::NODE_LETTER;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a composite block of a block diagram, whose nested blocks are
+/// declared between its header and the `end` keyword.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let composite = CompositeBlockBuilder::default().span(2)?.columns(3)?.id(1).build()?;
+///
+///     assert_eq!(composite.columns(), Some(3));
+///     assert_eq!(composite.to_string(), "block:v1:2");
+///     Ok(())
+/// }
+/// ```
+pub struct CompositeBlock {
+    /// Unique identifier of the composite block.
+    id: u64,
+    /// Number of columns spanned by the composite block.
+    span: u8,
+    /// Number of columns in which the nested blocks are laid out, if fixed.
+    columns: Option<u8>,
+}
+
+impl CompositeBlock {
+    #[must_use]
+    /// Returns the unique identifier of the composite block.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[must_use]
+    /// Returns the number of columns spanned by the composite block.
+    pub fn span(&self) -> u8 {
+        self.span
+    }
+
+    #[must_use]
+    /// Returns the number of columns in which the nested blocks are laid
+    /// out, if fixed.
+    pub fn columns(&self) -> Option<u8> {
+        self.columns
+    }
+}
+
+impl Display for CompositeBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "block:{NODE_LETTER}{}", self.id)?;
+        if self.span > 1 {
+            write!(f, ":{}", self.span)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+//! Submodule defining the struct for building a composite block of a block
+//! diagram.
+
+use crate::{diagrams::block_diagram::composite_block::CompositeBlock, errors::BlockDiagramError};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `CompositeBlock`.
+pub struct CompositeBlockBuilder {
+    /// Unique identifier of the composite block.
+    id: Option<u64>,
+    /// Number of columns spanned by the composite block.
+    span: u8,
+    /// Number of columns in which the nested blocks are laid out, if fixed.
+    columns: Option<u8>,
+}
+
+impl Default for CompositeBlockBuilder {
+    fn default() -> Self {
+        Self { id: None, span: 1, columns: None }
+    }
+}
+
+impl CompositeBlockBuilder {
+    /// Sets the number of columns spanned by the composite block.
+    ///
+    /// # Errors
+    ///
+    /// * If the span is zero.
+    pub fn span(mut self, span: u8) -> Result<Self, BlockDiagramError> {
+        if span == 0 {
+            return Err(BlockDiagramError::ZeroSpan);
+        }
+        self.span = span;
+        Ok(self)
+    }
+
+    /// Sets the number of columns in which the nested blocks are laid out.
+    ///
+    /// # Errors
+    ///
+    /// * If the number of columns is zero.
+    pub fn columns(mut self, columns: u8) -> Result<Self, BlockDiagramError> {
+        if columns == 0 {
+            return Err(BlockDiagramError::ZeroColumns);
+        }
+        self.columns = Some(columns);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the unique identifier of the composite block.
+    pub fn id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[must_use]
+    /// Returns the identifier of the composite block, if set.
+    pub fn get_id(&self) -> Option<u64> {
+        self.id
+    }
+
+    /// Builds the composite block.
+    ///
+    /// # Errors
+    ///
+    /// * If the ID is missing.
+    pub fn build(self) -> Result<CompositeBlock, BlockDiagramError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<CompositeBlockBuilder> for CompositeBlock {
+    type Error = BlockDiagramError;
+
+    fn try_from(builder: CompositeBlockBuilder) -> Result<Self, Self::Error> {
+        Ok(CompositeBlock {
+            id: builder.id.ok_or(BlockDiagramError::MissingId)?,
+            span: builder.span,
+            columns: builder.columns,
+        })
+    }
+}
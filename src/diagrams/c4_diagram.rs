@@ -0,0 +1,357 @@
+//! Submodule providing structs for creating C4 diagrams in Mermaid syntax.
+
+mod builder;
+mod c4_boundary;
+mod c4_diagram_kind;
+mod c4_element;
+mod c4_relationship;
+use alloc::{collections::BTreeMap, rc::Rc, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::C4DiagramBuilder;
+pub use c4_boundary::{C4Boundary, C4BoundaryBuilder, C4BoundaryKind};
+pub use c4_diagram_kind::C4DiagramKind;
+pub use c4_element::{C4Element, C4ElementBuilder, C4ElementKind, C4ElementStorage};
+pub use c4_relationship::{C4Relationship, C4RelationshipBuilder};
+
+use crate::{
+    errors::C4DiagramError,
+    shared::generic_configuration::{GenericConfiguration, GenericConfigurationBuilder},
+    traits::{Configuration, TabbedDisplay},
+};
+
+/// Represents the configuration for a C4 diagram.
+pub type C4DiagramConfiguration = GenericConfiguration;
+/// Represents the configuration builder for a C4 diagram.
+pub type C4DiagramConfigurationBuilder = GenericConfigurationBuilder;
+
+/// Validates a text of a C4 diagram, which is always written between double
+/// quotes.
+pub(crate) fn validate_text(text: impl Into<String>) -> Result<String, C4DiagramError> {
+    let text = text.into();
+    if text.trim().is_empty() {
+        return Err(C4DiagramError::EmptyText);
+    }
+    if text.contains(['"', '\n', '\r']) {
+        return Err(C4DiagramError::InvalidText(text));
+    }
+    Ok(text)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a member of a scope of a C4 diagram, kept in declaration order.
+pub(crate) enum C4Member {
+    /// An element.
+    Element(Rc<C4Element>),
+    /// A boundary, whose own members are declared within it.
+    Boundary(Rc<C4Boundary>),
+}
+
+impl C4Member {
+    /// Returns the unique identifier of the member.
+    pub(crate) fn id(&self) -> u64 {
+        match self {
+            C4Member::Element(element) => element.id(),
+            C4Member::Boundary(boundary) => boundary.id(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a C4 diagram in Mermaid syntax.
+///
+/// Elements and boundaries are declared within the boundary enclosing them,
+/// followed by the relationships and finally by the style updates.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = C4DiagramBuilder::default();
+///     let user = builder.element(
+///         None,
+///         C4ElementBuilder::default().kind(C4ElementKind::Person).label("User")?,
+///     )?;
+///     let platform = builder.boundary(None, C4BoundaryBuilder::default().label("Platform")?)?;
+///     let api = builder.element(Some(&platform), C4ElementBuilder::default().label("API")?)?;
+///     builder.relationship(
+///         C4RelationshipBuilder::default().source(user).destination(api).label("Uses")?,
+///     )?;
+///
+///     let diagram = C4Diagram::from(builder);
+///     assert_eq!(
+///         diagram.to_string(),
+///         concat!(
+///             "C4Context\n",
+///             "  Person(v0, \"User\")\n",
+///             "  Boundary(v1, \"Platform\") {\n",
+///             "    System(v2, \"API\")\n",
+///             "  }\n",
+///             "  Rel(v0, v2, \"Uses\")\n",
+///         )
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct C4Diagram {
+    /// Configuration of the diagram.
+    configuration: C4DiagramConfiguration,
+    /// Flavour of the diagram.
+    kind: C4DiagramKind,
+    /// Elements and boundaries of the diagram, in declaration order.
+    members: Vec<C4Member>,
+    /// Identifier of the boundary enclosing each member, indexed by member
+    /// identifier.
+    parents: BTreeMap<u64, u64>,
+    /// Relationships of the diagram.
+    relationships: Vec<C4Relationship>,
+}
+
+impl C4Diagram {
+    #[must_use]
+    /// Returns the configuration of the diagram.
+    pub fn configuration(&self) -> &C4DiagramConfiguration {
+        &self.configuration
+    }
+
+    #[must_use]
+    /// Returns the flavour of the diagram.
+    pub fn kind(&self) -> C4DiagramKind {
+        self.kind
+    }
+
+    /// Returns an iterator over the elements of the diagram.
+    pub fn elements(&self) -> impl Iterator<Item = &Rc<C4Element>> {
+        self.members.iter().filter_map(|member| match member {
+            C4Member::Element(element) => Some(element),
+            C4Member::Boundary(_) => None,
+        })
+    }
+
+    /// Returns an iterator over the boundaries of the diagram.
+    pub fn boundaries(&self) -> impl Iterator<Item = &Rc<C4Boundary>> {
+        self.members.iter().filter_map(|member| match member {
+            C4Member::Element(_) => None,
+            C4Member::Boundary(boundary) => Some(boundary),
+        })
+    }
+
+    /// Returns an iterator over the relationships of the diagram.
+    pub fn relationships(&self) -> impl Iterator<Item = &C4Relationship> {
+        self.relationships.iter()
+    }
+
+    #[must_use]
+    /// Returns the boundary enclosing the element or boundary with the
+    /// provided ID, if any.
+    pub fn parent(&self, id: u64) -> Option<&Rc<C4Boundary>> {
+        let parent_id = self.parents.get(&id)?;
+        self.boundaries().find(|boundary| boundary.id() == *parent_id)
+    }
+
+    /// Writes the members of the provided scope, recursing into boundaries.
+    fn fmt_scope(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        scope: Option<u64>,
+        tab_count: usize,
+    ) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        for member in &self.members {
+            if self.parents.get(&member.id()).copied() != scope {
+                continue;
+            }
+            match member {
+                C4Member::Element(element) => writeln!(f, "{indent}{element}")?,
+                C4Member::Boundary(boundary) => {
+                    writeln!(f, "{indent}{boundary} {{")?;
+                    self.fmt_scope(f, Some(boundary.id()), tab_count + 1)?;
+                    writeln!(f, "{indent}}}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for C4Diagram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for C4Diagram {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat((tab_count + 1) * 2);
+        self.configuration.fmt_frontmatter(f, false, &[])?;
+        writeln!(f, "{}{}", " ".repeat(tab_count * 2), self.kind)?;
+        if let Some(title) = self.configuration.title() {
+            writeln!(f, "{indent}title {title}")?;
+        }
+        self.fmt_scope(f, None, tab_count + 1)?;
+        for relationship in &self.relationships {
+            writeln!(f, "{indent}{relationship}")?;
+        }
+        for element in self.elements() {
+            element.fmt_style(f, tab_count + 1)?;
+        }
+        for relationship in &self.relationships {
+            relationship.fmt_style(f, tab_count + 1)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::{shared::Color, traits::ConfigurationBuilder};
+
+    #[test]
+    fn test_c4_diagram_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = C4DiagramBuilder::default()
+            .kind(C4DiagramKind::Container)
+            .configuration(C4DiagramConfigurationBuilder::default().title("Data platform")?)?;
+        let analyst = builder.element(
+            None,
+            C4ElementBuilder::default()
+                .kind(C4ElementKind::Person)
+                .label("Analyst")?
+                .description("Queries the results.")?,
+        )?;
+        let enterprise = builder.boundary(
+            None,
+            C4BoundaryBuilder::default().kind(C4BoundaryKind::Enterprise).label("Lab")?,
+        )?;
+        let platform = builder.boundary(
+            Some(&enterprise),
+            C4BoundaryBuilder::default().kind(C4BoundaryKind::System).label("Platform")?,
+        )?;
+        let api = builder.element(
+            Some(&platform),
+            C4ElementBuilder::default()
+                .kind(C4ElementKind::Container)
+                .label("API")?
+                .technology("Rust")?
+                .background_color(Color::from((0, 0, 255)))
+                .font_color(Color::from((255, 255, 255))),
+        )?;
+        let registry = builder.element(
+            Some(&platform),
+            C4ElementBuilder::default()
+                .kind(C4ElementKind::Container)
+                .storage(C4ElementStorage::Database)?
+                .label("Registry")?
+                .description("Service metadata")?,
+        )?;
+        let mailer = builder.element(
+            None,
+            C4ElementBuilder::default()
+                .kind(C4ElementKind::System)
+                .storage(C4ElementStorage::Queue)?
+                .external(true)
+                .label("Mailer")?,
+        )?;
+        builder.relationship(
+            C4RelationshipBuilder::default()
+                .source(analyst)
+                .destination(api.clone())
+                .label("Uses")?
+                .technology("HTTPS")?,
+        )?;
+        builder.relationship(
+            C4RelationshipBuilder::default()
+                .source(api.clone())
+                .destination(registry)
+                .bidirectional(true)
+                .label("Reads and writes")?
+                .description("Connection pool")?
+                .line_color(Color::from((255, 0, 0))),
+        )?;
+        builder.relationship(
+            C4RelationshipBuilder::default()
+                .source(api.clone())
+                .destination(mailer)
+                .label("Notifies")?,
+        )?;
+
+        let diagram = C4Diagram::from(builder);
+        assert_eq!(diagram.elements().count(), 4);
+        assert_eq!(diagram.boundaries().count(), 2);
+        assert_eq!(diagram.parent(api.id()), Some(&platform));
+        assert_eq!(diagram.parent(enterprise.id()), None);
+        assert_eq!(
+            diagram.to_string(),
+            concat!(
+                "C4Container\n",
+                "  title Data platform\n",
+                "  Person(v0, \"Analyst\", \"Queries the results.\")\n",
+                "  Enterprise_Boundary(v1, \"Lab\") {\n",
+                "    System_Boundary(v2, \"Platform\") {\n",
+                "      Container(v3, \"API\", \"Rust\")\n",
+                "      ContainerDb(v4, \"Registry\", \"\", \"Service metadata\")\n",
+                "    }\n",
+                "  }\n",
+                "  SystemQueue_Ext(v5, \"Mailer\")\n",
+                "  Rel(v0, v3, \"Uses\", \"HTTPS\")\n",
+                "  BiRel(v3, v4, \"Reads and writes\", \"\", \"Connection pool\")\n",
+                "  Rel(v3, v5, \"Notifies\")\n",
+                "  UpdateElementStyle(v3, $fontColor=\"#ffffff\", $bgColor=\"#0000ff\")\n",
+                "  UpdateRelStyle(v3, v4, $lineColor=\"#ff0000\")\n",
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_c4_diagram_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = C4DiagramBuilder::default();
+        let api = builder.element(None, C4ElementBuilder::default().label("API")?)?;
+        assert_eq!(
+            builder.boundary(None, C4BoundaryBuilder::default().label("Cloud")?.id(0)),
+            Err(C4DiagramError::DuplicateId("v0".to_owned()))
+        );
+
+        let foreign_boundary = Rc::new(C4BoundaryBuilder::default().label("Cloud")?.id(7).build()?);
+        assert_eq!(
+            builder.element(Some(&foreign_boundary), C4ElementBuilder::default().label("DB")?),
+            Err(C4DiagramError::BoundaryNotFound("v7".to_owned()))
+        );
+
+        let foreign_element = Rc::new(C4ElementBuilder::default().label("DB")?.id(9).build()?);
+        assert_eq!(
+            builder.relationship(
+                C4RelationshipBuilder::default()
+                    .source(api)
+                    .destination(foreign_element)
+                    .label("Reads")?
+            ),
+            Err(C4DiagramError::ElementNotFound("v9".to_owned()))
+        );
+        assert_eq!(
+            C4BoundaryBuilder::default().kind(C4BoundaryKind::System).boundary_type("Cloud"),
+            Err(C4DiagramError::UnsupportedBoundaryType(C4BoundaryKind::System))
+        );
+        assert_eq!(
+            C4BoundaryBuilder::default().description("Cloud"),
+            Err(C4DiagramError::UnsupportedBoundaryDescription(C4BoundaryKind::Generic))
+        );
+        assert_eq!(C4Diagram::from(builder).to_string(), "C4Context\n  System(v0, \"API\")\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_c4_diagram_builder_skips_taken_ids() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = C4DiagramBuilder::default();
+        let api = builder.element(None, C4ElementBuilder::default().label("API")?.id(1))?;
+        let database = builder.element(None, C4ElementBuilder::default().label("DB")?)?;
+        assert_eq!((api.id(), database.id()), (1, 2));
+        Ok(())
+    }
+}
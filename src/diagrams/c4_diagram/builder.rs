@@ -0,0 +1,222 @@
+//! Submodule providing a builder for C4 diagrams.
+
+use alloc::{collections::BTreeMap, format, rc::Rc, vec::Vec};
+
+use crate::{
+    diagrams::c4_diagram::{
+        C4Diagram, C4DiagramConfiguration, C4DiagramConfigurationBuilder, C4Member,
+        c4_boundary::{C4Boundary, C4BoundaryBuilder},
+        c4_diagram_kind::C4DiagramKind,
+        c4_element::{C4Element, C4ElementBuilder},
+        c4_relationship::{C4Relationship, C4RelationshipBuilder},
+    },
+    errors::{C4DiagramError, ConfigError},
+    shared::NODE_LETTER,
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a C4 diagram.
+///
+/// Elements and boundaries share the same IDs, and are inserted either at the
+/// top level of the diagram or within a boundary which is already part of it.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = C4DiagramBuilder::default().kind(C4DiagramKind::Deployment);
+///     let cluster = builder.boundary(
+///         None,
+///         C4BoundaryBuilder::default()
+///             .kind(C4BoundaryKind::DeploymentNode)
+///             .label("Cluster")?
+///             .boundary_type("Kubernetes")?,
+///     )?;
+///     builder.element(
+///         Some(&cluster),
+///         C4ElementBuilder::default().kind(C4ElementKind::Container).label("API")?,
+///     )?;
+///
+///     let diagram = C4Diagram::from(builder);
+///     assert_eq!(diagram.elements().count(), 1);
+///     Ok(())
+/// }
+/// ```
+pub struct C4DiagramBuilder {
+    /// Configuration of the diagram.
+    configuration: C4DiagramConfiguration,
+    /// Flavour of the diagram.
+    kind: C4DiagramKind,
+    /// Elements and boundaries of the diagram, in declaration order.
+    members: Vec<C4Member>,
+    /// Identifier of the boundary enclosing each member, indexed by member
+    /// identifier.
+    parents: BTreeMap<u64, u64>,
+    /// Relationships of the diagram.
+    relationships: Vec<C4Relationship>,
+}
+
+impl C4DiagramBuilder {
+    #[must_use]
+    /// Sets the flavour of the diagram.
+    pub fn kind(mut self, kind: C4DiagramKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the configuration of the diagram.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: C4DiagramConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Adds a boundary to the diagram.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The boundary enclosing the new boundary, if any.
+    /// * `boundary` - The builder of the boundary to add.
+    ///
+    /// # Errors
+    ///
+    /// * If the parent boundary is not part of the diagram.
+    /// * If an element or boundary with the same ID already exists in the
+    ///   diagram.
+    /// * If the boundary builder fails to build.
+    pub fn boundary(
+        &mut self,
+        parent: Option<&Rc<C4Boundary>>,
+        boundary: C4BoundaryBuilder,
+    ) -> Result<Rc<C4Boundary>, C4DiagramError> {
+        self.check_parent(parent)?;
+        let id = self.claim_id(boundary.get_id())?;
+        let boundary = Rc::new(boundary.id(id).build()?);
+        self.insert(parent, C4Member::Boundary(boundary.clone()));
+        Ok(boundary)
+    }
+
+    /// Adds an element to the diagram.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The boundary enclosing the new element, if any.
+    /// * `element` - The builder of the element to add.
+    ///
+    /// # Errors
+    ///
+    /// * If the parent boundary is not part of the diagram.
+    /// * If an element or boundary with the same ID already exists in the
+    ///   diagram.
+    /// * If the element builder fails to build.
+    pub fn element(
+        &mut self,
+        parent: Option<&Rc<C4Boundary>>,
+        element: C4ElementBuilder,
+    ) -> Result<Rc<C4Element>, C4DiagramError> {
+        self.check_parent(parent)?;
+        let id = self.claim_id(element.get_id())?;
+        let element = Rc::new(element.id(id).build()?);
+        self.insert(parent, C4Member::Element(element.clone()));
+        Ok(element)
+    }
+
+    /// Adds a relationship between two elements of the diagram.
+    ///
+    /// # Errors
+    ///
+    /// * If the relationship builder fails to build.
+    /// * If the source or the destination is not part of the diagram.
+    pub fn relationship(
+        &mut self,
+        relationship: C4RelationshipBuilder,
+    ) -> Result<(), C4DiagramError> {
+        let relationship = relationship.build()?;
+        for element in [relationship.source(), relationship.destination()] {
+            if self.get_element_by_id(element.id()).as_ref() != Some(element) {
+                return Err(C4DiagramError::ElementNotFound(format!(
+                    "{NODE_LETTER}{}",
+                    element.id()
+                )));
+            }
+        }
+        self.relationships.push(relationship);
+        Ok(())
+    }
+
+    #[must_use]
+    /// Returns the element with the provided ID, if any.
+    pub fn get_element_by_id(&self, id: u64) -> Option<Rc<C4Element>> {
+        self.members.iter().find_map(|member| match member {
+            C4Member::Element(element) if element.id() == id => Some(element.clone()),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    /// Returns the boundary with the provided ID, if any.
+    pub fn get_boundary_by_id(&self, id: u64) -> Option<Rc<C4Boundary>> {
+        self.members.iter().find_map(|member| match member {
+            C4Member::Boundary(boundary) if boundary.id() == id => Some(boundary.clone()),
+            _ => None,
+        })
+    }
+
+    /// Checks that the provided parent boundary, if any, is part of the
+    /// diagram.
+    fn check_parent(&self, parent: Option<&Rc<C4Boundary>>) -> Result<(), C4DiagramError> {
+        if let Some(parent) = parent
+            && self.get_boundary_by_id(parent.id()).as_ref() != Some(parent)
+        {
+            return Err(C4DiagramError::BoundaryNotFound(format!("{NODE_LETTER}{}", parent.id())));
+        }
+        Ok(())
+    }
+
+    /// Returns the provided ID after checking that no member of the diagram
+    /// uses it, or else the first unused ID from the number of members.
+    fn claim_id(&self, id: Option<u64>) -> Result<u64, C4DiagramError> {
+        let taken = |id: u64| self.members.iter().any(|member| member.id() == id);
+        let Some(id) = id else {
+            let mut id = self.members.len() as u64;
+            while taken(id) {
+                id += 1;
+            }
+            return Ok(id);
+        };
+        if taken(id) {
+            return Err(C4DiagramError::DuplicateId(format!("{NODE_LETTER}{id}")));
+        }
+        Ok(id)
+    }
+
+    /// Adds the provided member to the diagram, within the provided parent.
+    fn insert(&mut self, parent: Option<&Rc<C4Boundary>>, member: C4Member) {
+        if let Some(parent) = parent {
+            self.parents.insert(member.id(), parent.id());
+        }
+        self.members.push(member);
+    }
+}
+
+impl From<C4DiagramBuilder> for C4Diagram {
+    fn from(builder: C4DiagramBuilder) -> Self {
+        C4Diagram {
+            configuration: builder.configuration,
+            kind: builder.kind,
+            members: builder.members,
+            parents: builder.parents,
+            relationships: builder.relationships,
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Submodule defining the boundaries of C4 diagrams in Mermaid syntax, i.e.
+//! the scopes grouping elements and nested boundaries.
+
+mod builder;
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+pub use builder::C4BoundaryBuilder;
+
+use crate::shared::NODE_LETTER;
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the kind of a boundary of a C4 diagram.
+pub enum C4BoundaryKind {
+    /// A generic boundary, optionally with a type.
+    #[default]
+    Generic,
+    /// The boundary of an enterprise.
+    Enterprise,
+    /// The boundary of a software system.
+    System,
+    /// The boundary of a container.
+    Container,
+    /// A deployment node, optionally with a type and a description.
+    DeploymentNode,
+}
+
+impl C4BoundaryKind {
+    #[must_use]
+    /// Returns whether boundaries of this kind may have a type.
+    pub fn has_type(self) -> bool {
+        matches!(self, C4BoundaryKind::Generic | C4BoundaryKind::DeploymentNode)
+    }
+
+    #[must_use]
+    /// Returns whether boundaries of this kind may have a description.
+    pub fn has_description(self) -> bool {
+        matches!(self, C4BoundaryKind::DeploymentNode)
+    }
+}
+
+impl Display for C4BoundaryKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            C4BoundaryKind::Generic => write!(f, "Boundary"),
+            C4BoundaryKind::Enterprise => write!(f, "Enterprise_Boundary"),
+            C4BoundaryKind::System => write!(f, "System_Boundary"),
+            C4BoundaryKind::Container => write!(f, "Container_Boundary"),
+            C4BoundaryKind::DeploymentNode => write!(f, "Deployment_Node"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a boundary of a C4 diagram.
+///
+/// The boundary is displayed as the header of its scope, without the braces
+/// enclosing its content.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let node = C4BoundaryBuilder::default()
+///         .kind(C4BoundaryKind::DeploymentNode)
+///         .label("Cluster")?
+///         .boundary_type("Kubernetes")?
+///         .id(0)
+///         .build()?;
+///
+///     assert_eq!(node.to_string(), "Deployment_Node(v0, \"Cluster\", \"Kubernetes\")");
+///     Ok(())
+/// }
+/// ```
+pub struct C4Boundary {
+    /// Unique identifier of the boundary.
+    id: u64,
+    /// Kind of the boundary.
+    kind: C4BoundaryKind,
+    /// Label of the boundary.
+    label: String,
+    /// Type of the boundary.
+    boundary_type: Option<String>,
+    /// Description of the boundary.
+    description: Option<String>,
+}
+
+impl C4Boundary {
+    #[must_use]
+    /// Returns the unique identifier of the boundary.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[must_use]
+    /// Returns the kind of the boundary.
+    pub fn kind(&self) -> C4BoundaryKind {
+        self.kind
+    }
+
+    #[must_use]
+    /// Returns the label of the boundary.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the type of the boundary, if any.
+    pub fn boundary_type(&self) -> Option<&str> {
+        self.boundary_type.as_deref()
+    }
+
+    #[must_use]
+    /// Returns the description of the boundary, if any.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+}
+
+impl Display for C4Boundary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({NODE_LETTER}{}, \"{}\"", self.kind, self.id, self.label)?;
+        match (&self.boundary_type, &self.description) {
+            (Some(boundary_type), Some(description)) => {
+                write!(f, ", \"{boundary_type}\", \"{description}\"")?;
+            }
+            (Some(boundary_type), None) => write!(f, ", \"{boundary_type}\"")?,
+            (None, Some(description)) => write!(f, ", \"\", \"{description}\"")?,
+            (None, None) => {}
+        }
+        write!(f, ")")
+    }
+}
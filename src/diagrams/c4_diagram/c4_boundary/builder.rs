@@ -0,0 +1,118 @@
+//! Submodule defining the struct for building a boundary of a C4 diagram.
+
+use alloc::string::String;
+
+use crate::{
+    diagrams::c4_diagram::{
+        c4_boundary::{C4Boundary, C4BoundaryKind},
+        validate_text,
+    },
+    errors::C4DiagramError,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `C4Boundary`.
+///
+/// The kind of the boundary should be set first, as the type and the
+/// description are only accepted by the kinds supporting them. When added to
+/// a `C4DiagramBuilder`, a boundary without an ID receives the next available
+/// one.
+pub struct C4BoundaryBuilder {
+    /// Unique identifier of the boundary.
+    id: Option<u64>,
+    /// Kind of the boundary.
+    kind: C4BoundaryKind,
+    /// Label of the boundary.
+    label: Option<String>,
+    /// Type of the boundary.
+    boundary_type: Option<String>,
+    /// Description of the boundary.
+    description: Option<String>,
+}
+
+impl C4BoundaryBuilder {
+    #[must_use]
+    /// Sets the kind of the boundary.
+    pub fn kind(mut self, kind: C4BoundaryKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the label of the boundary.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains double quotes or line breaks.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, C4DiagramError> {
+        self.label = Some(validate_text(label)?);
+        Ok(self)
+    }
+
+    /// Sets the type of the boundary.
+    ///
+    /// # Errors
+    ///
+    /// * If the boundary is neither generic nor a deployment node.
+    /// * If the type is empty or contains double quotes or line breaks.
+    pub fn boundary_type(
+        mut self,
+        boundary_type: impl Into<String>,
+    ) -> Result<Self, C4DiagramError> {
+        if !self.kind.has_type() {
+            return Err(C4DiagramError::UnsupportedBoundaryType(self.kind));
+        }
+        self.boundary_type = Some(validate_text(boundary_type)?);
+        Ok(self)
+    }
+
+    /// Sets the description of the boundary.
+    ///
+    /// # Errors
+    ///
+    /// * If the boundary is not a deployment node.
+    /// * If the description is empty or contains double quotes or line breaks.
+    pub fn description(mut self, description: impl Into<String>) -> Result<Self, C4DiagramError> {
+        if !self.kind.has_description() {
+            return Err(C4DiagramError::UnsupportedBoundaryDescription(self.kind));
+        }
+        self.description = Some(validate_text(description)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the unique identifier of the boundary.
+    pub fn id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[must_use]
+    /// Returns the identifier of the boundary, if set.
+    pub fn get_id(&self) -> Option<u64> {
+        self.id
+    }
+
+    /// Builds the boundary.
+    ///
+    /// # Errors
+    ///
+    /// * If the label or the ID of the boundary is missing.
+    pub fn build(self) -> Result<C4Boundary, C4DiagramError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<C4BoundaryBuilder> for C4Boundary {
+    type Error = C4DiagramError;
+
+    fn try_from(builder: C4BoundaryBuilder) -> Result<Self, Self::Error> {
+        Ok(C4Boundary {
+            id: builder.id.ok_or(C4DiagramError::MissingId)?,
+            kind: builder.kind,
+            label: builder.label.ok_or(C4DiagramError::MissingLabel)?,
+            boundary_type: builder.boundary_type,
+            description: builder.description,
+        })
+    }
+}
@@ -0,0 +1,34 @@
+//! Submodule defining the flavours of C4 diagrams.
+
+use core::fmt::{self, Display};
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the flavour of a C4 diagram, i.e. the level of detail at which
+/// the software systems are described.
+pub enum C4DiagramKind {
+    /// A system context diagram, i.e. `C4Context`.
+    #[default]
+    Context,
+    /// A container diagram, i.e. `C4Container`.
+    Container,
+    /// A component diagram, i.e. `C4Component`.
+    Component,
+    /// A dynamic diagram, whose relationships are numbered in order, i.e.
+    /// `C4Dynamic`.
+    Dynamic,
+    /// A deployment diagram, i.e. `C4Deployment`.
+    Deployment,
+}
+
+impl Display for C4DiagramKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            C4DiagramKind::Context => write!(f, "C4Context"),
+            C4DiagramKind::Container => write!(f, "C4Container"),
+            C4DiagramKind::Component => write!(f, "C4Component"),
+            C4DiagramKind::Dynamic => write!(f, "C4Dynamic"),
+            C4DiagramKind::Deployment => write!(f, "C4Deployment"),
+        }
+    }
+}
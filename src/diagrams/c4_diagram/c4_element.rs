@@ -0,0 +1,217 @@
+//! Submodule defining the elements of C4 diagrams in Mermaid syntax, i.e.
+//! people, software systems, containers and components.
+
+mod builder;
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+pub use builder::C4ElementBuilder;
+
+use crate::shared::{Color, NODE_LETTER};
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the kind of an element of a C4 diagram.
+pub enum C4ElementKind {
+    /// A person using the software systems.
+    Person,
+    /// A software system.
+    #[default]
+    System,
+    /// A deployable unit of a software system, such as an application or a
+    /// data store.
+    Container,
+    /// A grouping of related functionality within a container.
+    Component,
+}
+
+impl C4ElementKind {
+    #[must_use]
+    /// Returns whether elements of this kind may have a technology.
+    pub fn has_technology(self) -> bool {
+        matches!(self, C4ElementKind::Container | C4ElementKind::Component)
+    }
+
+    #[must_use]
+    /// Returns whether elements of this kind may be databases or queues.
+    pub fn has_storage(self) -> bool {
+        !matches!(self, C4ElementKind::Person)
+    }
+}
+
+impl Display for C4ElementKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            C4ElementKind::Person => write!(f, "Person"),
+            C4ElementKind::System => write!(f, "System"),
+            C4ElementKind::Container => write!(f, "Container"),
+            C4ElementKind::Component => write!(f, "Component"),
+        }
+    }
+}
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the shape of a system, container or component, depending on
+/// whether it stores data.
+pub enum C4ElementStorage {
+    /// A regular element.
+    #[default]
+    None,
+    /// An element storing data in a database, suffixed with `Db`.
+    Database,
+    /// An element storing data in a queue, suffixed with `Queue`.
+    Queue,
+}
+
+impl Display for C4ElementStorage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            C4ElementStorage::None => Ok(()),
+            C4ElementStorage::Database => write!(f, "Db"),
+            C4ElementStorage::Queue => write!(f, "Queue"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents an element of a C4 diagram.
+///
+/// The macro used to declare the element combines its kind, its storage and
+/// whether it is external, e.g. `ContainerDb_Ext`.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let element = C4ElementBuilder::default()
+///         .kind(C4ElementKind::Container)
+///         .storage(C4ElementStorage::Database)?
+///         .external(true)
+///         .label("Registry")?
+///         .description("Stores the services.")?
+///         .id(3)
+///         .build()?;
+///
+///     assert_eq!(
+///         element.to_string(),
+///         "ContainerDb_Ext(v3, \"Registry\", \"\", \"Stores the services.\")"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct C4Element {
+    /// Unique identifier of the element.
+    id: u64,
+    /// Kind of the element.
+    kind: C4ElementKind,
+    /// Storage of the element.
+    storage: C4ElementStorage,
+    /// Whether the element is external to the modelled scope.
+    external: bool,
+    /// Label of the element.
+    label: String,
+    /// Technology of the element.
+    technology: Option<String>,
+    /// Description of the element.
+    description: Option<String>,
+    /// Color of the text of the element.
+    font_color: Option<Color>,
+    /// Background color of the element.
+    background_color: Option<Color>,
+    /// Border color of the element.
+    border_color: Option<Color>,
+}
+
+impl C4Element {
+    #[must_use]
+    /// Returns the unique identifier of the element.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[must_use]
+    /// Returns the kind of the element.
+    pub fn kind(&self) -> C4ElementKind {
+        self.kind
+    }
+
+    #[must_use]
+    /// Returns the storage of the element.
+    pub fn storage(&self) -> C4ElementStorage {
+        self.storage
+    }
+
+    #[must_use]
+    /// Returns whether the element is external to the modelled scope.
+    pub fn is_external(&self) -> bool {
+        self.external
+    }
+
+    #[must_use]
+    /// Returns the label of the element.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the technology of the element, if any.
+    pub fn technology(&self) -> Option<&str> {
+        self.technology.as_deref()
+    }
+
+    #[must_use]
+    /// Returns the description of the element, if any.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    #[must_use]
+    /// Returns whether the style of the element is updated.
+    pub fn has_styles(&self) -> bool {
+        self.font_color.is_some() || self.background_color.is_some() || self.border_color.is_some()
+    }
+
+    /// Writes the `UpdateElementStyle` statement of the element, if needed.
+    pub(crate) fn fmt_style(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        if !self.has_styles() {
+            return Ok(());
+        }
+        write!(f, "{}UpdateElementStyle({NODE_LETTER}{}", " ".repeat(tab_count * 2), self.id)?;
+        for (name, color) in [
+            ("fontColor", self.font_color),
+            ("bgColor", self.background_color),
+            ("borderColor", self.border_color),
+        ] {
+            if let Some(color) = color {
+                write!(f, ", ${name}=\"{}\"", color.to_hex())?;
+            }
+        }
+        writeln!(f, ")")
+    }
+}
+
+impl Display for C4Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.kind, self.storage)?;
+        if self.external {
+            write!(f, "_Ext")?;
+        }
+        write!(f, "({NODE_LETTER}{}, \"{}\"", self.id, self.label)?;
+        match (&self.technology, &self.description) {
+            (Some(technology), Some(description)) => {
+                write!(f, ", \"{technology}\", \"{description}\"")?;
+            }
+            (Some(technology), None) => write!(f, ", \"{technology}\"")?,
+            (None, Some(description)) if self.kind.has_technology() => {
+                write!(f, ", \"\", \"{description}\"")?;
+            }
+            (None, Some(description)) => write!(f, ", \"{description}\"")?,
+            (None, None) => {}
+        }
+        write!(f, ")")
+    }
+}
@@ -0,0 +1,211 @@
+//! Submodule defining the struct for building an element of a C4 diagram.
+
+use alloc::string::String;
+
+use crate::{
+    diagrams::c4_diagram::{
+        c4_element::{C4Element, C4ElementKind, C4ElementStorage},
+        validate_text,
+    },
+    errors::C4DiagramError,
+    shared::Color,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `C4Element`.
+///
+/// The kind of the element should be set first, as the storage and the
+/// technology are only accepted by the kinds supporting them. When added to a
+/// `C4DiagramBuilder`, an element without an ID receives the next available
+/// one.
+pub struct C4ElementBuilder {
+    /// Unique identifier of the element.
+    id: Option<u64>,
+    /// Kind of the element.
+    kind: C4ElementKind,
+    /// Storage of the element.
+    storage: C4ElementStorage,
+    /// Whether the element is external to the modelled scope.
+    external: bool,
+    /// Label of the element.
+    label: Option<String>,
+    /// Technology of the element.
+    technology: Option<String>,
+    /// Description of the element.
+    description: Option<String>,
+    /// Color of the text of the element.
+    font_color: Option<Color>,
+    /// Background color of the element.
+    background_color: Option<Color>,
+    /// Border color of the element.
+    border_color: Option<Color>,
+}
+
+impl C4ElementBuilder {
+    #[must_use]
+    /// Sets the kind of the element.
+    pub fn kind(mut self, kind: C4ElementKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the storage of the element.
+    ///
+    /// # Errors
+    ///
+    /// * If the element is a person and the storage is not `None`.
+    pub fn storage(mut self, storage: C4ElementStorage) -> Result<Self, C4DiagramError> {
+        if storage != C4ElementStorage::None && !self.kind.has_storage() {
+            return Err(C4DiagramError::UnsupportedStorage(self.kind));
+        }
+        self.storage = storage;
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets whether the element is external to the modelled scope.
+    pub fn external(mut self, external: bool) -> Self {
+        self.external = external;
+        self
+    }
+
+    /// Sets the label of the element.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains double quotes or line breaks.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, C4DiagramError> {
+        self.label = Some(validate_text(label)?);
+        Ok(self)
+    }
+
+    /// Sets the technology of the element.
+    ///
+    /// # Errors
+    ///
+    /// * If the element is neither a container nor a component.
+    /// * If the technology is empty or contains double quotes or line breaks.
+    pub fn technology(mut self, technology: impl Into<String>) -> Result<Self, C4DiagramError> {
+        if !self.kind.has_technology() {
+            return Err(C4DiagramError::UnsupportedTechnology(self.kind));
+        }
+        self.technology = Some(validate_text(technology)?);
+        Ok(self)
+    }
+
+    /// Sets the description of the element.
+    ///
+    /// # Errors
+    ///
+    /// * If the description is empty or contains double quotes or line breaks.
+    pub fn description(mut self, description: impl Into<String>) -> Result<Self, C4DiagramError> {
+        self.description = Some(validate_text(description)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the color of the text of the element.
+    pub fn font_color(mut self, color: Color) -> Self {
+        self.font_color = Some(color);
+        self
+    }
+
+    #[must_use]
+    /// Sets the background color of the element.
+    pub fn background_color(mut self, color: Color) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+
+    #[must_use]
+    /// Sets the border color of the element.
+    pub fn border_color(mut self, color: Color) -> Self {
+        self.border_color = Some(color);
+        self
+    }
+
+    #[must_use]
+    /// Sets the unique identifier of the element.
+    pub fn id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[must_use]
+    /// Returns the identifier of the element, if set.
+    pub fn get_id(&self) -> Option<u64> {
+        self.id
+    }
+
+    /// Builds the element.
+    ///
+    /// # Errors
+    ///
+    /// * If the label or the ID of the element is missing.
+    pub fn build(self) -> Result<C4Element, C4DiagramError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<C4ElementBuilder> for C4Element {
+    type Error = C4DiagramError;
+
+    fn try_from(builder: C4ElementBuilder) -> Result<Self, Self::Error> {
+        Ok(C4Element {
+            id: builder.id.ok_or(C4DiagramError::MissingId)?,
+            kind: builder.kind,
+            storage: builder.storage,
+            external: builder.external,
+            label: builder.label.ok_or(C4DiagramError::MissingLabel)?,
+            technology: builder.technology,
+            description: builder.description,
+            font_color: builder.font_color,
+            background_color: builder.background_color,
+            border_color: builder.border_color,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+
+    #[test]
+    fn test_c4_element_builder() -> Result<(), Box<dyn core::error::Error>> {
+        assert_eq!(
+            C4ElementBuilder::default()
+                .kind(C4ElementKind::Person)
+                .storage(C4ElementStorage::Queue),
+            Err(C4DiagramError::UnsupportedStorage(C4ElementKind::Person))
+        );
+        assert_eq!(
+            C4ElementBuilder::default().technology("Rust"),
+            Err(C4DiagramError::UnsupportedTechnology(C4ElementKind::System))
+        );
+        assert_eq!(
+            C4ElementBuilder::default().label("Registry")?.build(),
+            Err(C4DiagramError::MissingId)
+        );
+
+        let person = C4ElementBuilder::default()
+            .kind(C4ElementKind::Person)
+            .label("Analyst")?
+            .description("Runs the pipelines.")?
+            .id(0)
+            .build()?;
+        assert_eq!(person.to_string(), "Person(v0, \"Analyst\", \"Runs the pipelines.\")");
+
+        let queue = C4ElementBuilder::default()
+            .kind(C4ElementKind::Component)
+            .storage(C4ElementStorage::Queue)?
+            .label("Jobs")?
+            .technology("Kafka")?
+            .id(1)
+            .build()?;
+        assert_eq!(queue.to_string(), "ComponentQueue(v1, \"Jobs\", \"Kafka\")");
+        Ok(())
+    }
+}
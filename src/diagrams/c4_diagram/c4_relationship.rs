@@ -0,0 +1,147 @@
+//! Submodule defining the relationships between the elements of C4 diagrams
+//! in Mermaid syntax.
+
+mod builder;
+use alloc::{rc::Rc, string::String};
+use core::fmt::{self, Display};
+
+pub use builder::C4RelationshipBuilder;
+
+use crate::{
+    diagrams::c4_diagram::c4_element::C4Element,
+    shared::{Color, NODE_LETTER},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a relationship between two elements of a C4 diagram, declared
+/// with `Rel` or, when bidirectional, with `BiRel`.
+///
+/// # Example
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let user = Rc::new(
+///         C4ElementBuilder::default().kind(C4ElementKind::Person).label("User")?.id(0).build()?,
+///     );
+///     let api = Rc::new(C4ElementBuilder::default().label("API")?.id(1).build()?);
+///
+///     let relationship = C4RelationshipBuilder::default()
+///         .source(user)
+///         .destination(api)
+///         .label("Calls")?
+///         .technology("HTTPS")?
+///         .build()?;
+///
+///     assert_eq!(relationship.to_string(), "Rel(v0, v1, \"Calls\", \"HTTPS\")");
+///     Ok(())
+/// }
+/// ```
+pub struct C4Relationship {
+    /// Element from which the relationship starts.
+    source: Rc<C4Element>,
+    /// Element at which the relationship ends.
+    destination: Rc<C4Element>,
+    /// Whether the relationship goes both ways.
+    bidirectional: bool,
+    /// Label of the relationship.
+    label: String,
+    /// Technology of the relationship.
+    technology: Option<String>,
+    /// Description of the relationship.
+    description: Option<String>,
+    /// Color of the text of the relationship.
+    text_color: Option<Color>,
+    /// Color of the line of the relationship.
+    line_color: Option<Color>,
+}
+
+impl C4Relationship {
+    #[must_use]
+    /// Returns the element from which the relationship starts.
+    pub fn source(&self) -> &Rc<C4Element> {
+        &self.source
+    }
+
+    #[must_use]
+    /// Returns the element at which the relationship ends.
+    pub fn destination(&self) -> &Rc<C4Element> {
+        &self.destination
+    }
+
+    #[must_use]
+    /// Returns whether the relationship goes both ways.
+    pub fn is_bidirectional(&self) -> bool {
+        self.bidirectional
+    }
+
+    #[must_use]
+    /// Returns the label of the relationship.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the technology of the relationship, if any.
+    pub fn technology(&self) -> Option<&str> {
+        self.technology.as_deref()
+    }
+
+    #[must_use]
+    /// Returns the description of the relationship, if any.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    #[must_use]
+    /// Returns whether the style of the relationship is updated.
+    pub fn has_styles(&self) -> bool {
+        self.text_color.is_some() || self.line_color.is_some()
+    }
+
+    /// Writes the `UpdateRelStyle` statement of the relationship, if needed.
+    pub(crate) fn fmt_style(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        if !self.has_styles() {
+            return Ok(());
+        }
+        write!(
+            f,
+            "{}UpdateRelStyle({NODE_LETTER}{}, {NODE_LETTER}{}",
+            " ".repeat(tab_count * 2),
+            self.source.id(),
+            self.destination.id()
+        )?;
+        for (name, color) in [("textColor", self.text_color), ("lineColor", self.line_color)] {
+            if let Some(color) = color {
+                write!(f, ", ${name}=\"{}\"", color.to_hex())?;
+            }
+        }
+        writeln!(f, ")")
+    }
+}
+
+impl Display for C4Relationship {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}({NODE_LETTER}{}, {NODE_LETTER}{}, \"{}\"",
+            if self.bidirectional { "BiRel" } else { "Rel" },
+            self.source.id(),
+            self.destination.id(),
+            self.label
+        )?;
+        match (&self.technology, &self.description) {
+            (Some(technology), Some(description)) => {
+                write!(f, ", \"{technology}\", \"{description}\"")?;
+            }
+            (Some(technology), None) => write!(f, ", \"{technology}\"")?,
+            (None, Some(description)) => write!(f, ", \"\", \"{description}\"")?,
+            (None, None) => {}
+        }
+        write!(f, ")")
+    }
+}
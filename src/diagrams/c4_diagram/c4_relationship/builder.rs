@@ -0,0 +1,125 @@
+//! Submodule defining the struct for building a relationship of a C4
+//! diagram.
+
+use alloc::{rc::Rc, string::String};
+
+use crate::{
+    diagrams::c4_diagram::{c4_element::C4Element, c4_relationship::C4Relationship, validate_text},
+    errors::C4DiagramError,
+    shared::Color,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `C4Relationship`.
+pub struct C4RelationshipBuilder {
+    /// Element from which the relationship starts.
+    source: Option<Rc<C4Element>>,
+    /// Element at which the relationship ends.
+    destination: Option<Rc<C4Element>>,
+    /// Whether the relationship goes both ways.
+    bidirectional: bool,
+    /// Label of the relationship.
+    label: Option<String>,
+    /// Technology of the relationship.
+    technology: Option<String>,
+    /// Description of the relationship.
+    description: Option<String>,
+    /// Color of the text of the relationship.
+    text_color: Option<Color>,
+    /// Color of the line of the relationship.
+    line_color: Option<Color>,
+}
+
+impl C4RelationshipBuilder {
+    #[must_use]
+    /// Sets the element from which the relationship starts.
+    pub fn source(mut self, source: Rc<C4Element>) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    #[must_use]
+    /// Sets the element at which the relationship ends.
+    pub fn destination(mut self, destination: Rc<C4Element>) -> Self {
+        self.destination = Some(destination);
+        self
+    }
+
+    #[must_use]
+    /// Sets whether the relationship goes both ways.
+    pub fn bidirectional(mut self, bidirectional: bool) -> Self {
+        self.bidirectional = bidirectional;
+        self
+    }
+
+    /// Sets the label of the relationship.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains double quotes or line breaks.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, C4DiagramError> {
+        self.label = Some(validate_text(label)?);
+        Ok(self)
+    }
+
+    /// Sets the technology of the relationship, such as a protocol.
+    ///
+    /// # Errors
+    ///
+    /// * If the technology is empty or contains double quotes or line breaks.
+    pub fn technology(mut self, technology: impl Into<String>) -> Result<Self, C4DiagramError> {
+        self.technology = Some(validate_text(technology)?);
+        Ok(self)
+    }
+
+    /// Sets the description of the relationship.
+    ///
+    /// # Errors
+    ///
+    /// * If the description is empty or contains double quotes or line breaks.
+    pub fn description(mut self, description: impl Into<String>) -> Result<Self, C4DiagramError> {
+        self.description = Some(validate_text(description)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the color of the text of the relationship.
+    pub fn text_color(mut self, color: Color) -> Self {
+        self.text_color = Some(color);
+        self
+    }
+
+    #[must_use]
+    /// Sets the color of the line of the relationship.
+    pub fn line_color(mut self, color: Color) -> Self {
+        self.line_color = Some(color);
+        self
+    }
+
+    /// Builds the relationship.
+    ///
+    /// # Errors
+    ///
+    /// * If the source, the destination or the label is missing.
+    pub fn build(self) -> Result<C4Relationship, C4DiagramError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<C4RelationshipBuilder> for C4Relationship {
+    type Error = C4DiagramError;
+
+    fn try_from(builder: C4RelationshipBuilder) -> Result<Self, Self::Error> {
+        Ok(C4Relationship {
+            source: builder.source.ok_or(C4DiagramError::MissingSource)?,
+            destination: builder.destination.ok_or(C4DiagramError::MissingDestination)?,
+            bidirectional: builder.bidirectional,
+            label: builder.label.ok_or(C4DiagramError::MissingLabel)?,
+            technology: builder.technology,
+            description: builder.description,
+            text_color: builder.text_color,
+            line_color: builder.line_color,
+        })
+    }
+}
@@ -2,17 +2,21 @@
 
 pub mod class_edge;
 pub mod class_node;
+pub mod classifier;
 mod configuration;
+mod parser;
 pub mod visibility;
+use alloc::{collections::BTreeMap, string::String};
 use core::fmt::Display;
 
 use class_edge::ClassEdge;
 pub use class_edge::ClassEdgeBuilder;
 use class_node::ClassNode;
 pub use class_node::ClassNodeBuilder;
-pub use configuration::ClassDiagramConfiguration;
+pub use configuration::{ClassDiagramConfiguration, ClassDiagramConfigurationBuilder};
 
 use crate::{
+    errors::ParseError,
     shared::generic_diagram::{GenericDiagram, GenericDiagramBuilder},
     traits::{configuration::Configuration, diagram::Diagram},
 };
@@ -23,6 +27,57 @@ pub type ClassDiagram = GenericDiagram<ClassNode, ClassEdge, ClassDiagramConfigu
 pub type ClassDiagramBuilder =
     GenericDiagramBuilder<ClassNode, ClassEdge, ClassDiagramConfiguration>;
 
+impl ClassDiagramBuilder {
+    /// Parses a class diagram written by hand in the Mermaid syntax,
+    /// returning its builder together with the numeric identifiers assigned
+    /// to the class identifiers of the source, in order of first appearance.
+    ///
+    /// The `classDiagram` header, class bodies with attributes and methods,
+    /// `Class : member` statements, `<<annotation>>`, relationships with
+    /// their arrow heads, line styles, multiplicities and labels, and the
+    /// `classDef`, `cssClass`, `style`, `click`, `link`, `callback` and
+    /// `direction` statements are supported, as are the static (`$`) and
+    /// abstract (`*`) classifiers of members. Notes, namespaces, generics and
+    /// lollipop interfaces are reported as unsupported syntax. The configuration is read as by
+    /// [`ClassDiagramConfigurationBuilder::parse`].
+    ///
+    /// Unlike Mermaid, which ignores them, style classes applied with
+    /// `cssClass` or `:::` must be defined by a `classDef` statement
+    /// somewhere in the source, as a [`StyleClass`](crate::shared::StyleClass)
+    /// cannot be built without properties.
+    ///
+    /// # Errors
+    ///
+    /// * If a class is given a style class which no `classDef` statement
+    ///   defines.
+    /// * If the source is not a valid class diagram, with the line and column
+    ///   at which the error occurred.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate alloc;
+    /// use alloc::boxed::Box;
+    ///
+    /// use mermaid_builder::prelude::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn core::error::Error>> {
+    ///     let (builder, identifiers) = ClassDiagramBuilder::parse(
+    ///         "classDiagram\n  class Animal {\n    +String name\n    +eat() bool\n  }\n  Animal <|-- Duck",
+    ///     )?;
+    ///     assert_eq!(identifiers["Duck"], 1);
+    ///
+    ///     let diagram = ClassDiagram::from(builder);
+    ///     assert_eq!(diagram.nodes().count(), 2);
+    ///     assert_eq!(diagram.edges().count(), 1);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn parse(source: &str) -> Result<(Self, BTreeMap<String, u64>), ParseError> {
+        parser::parse(source)
+    }
+}
+
 impl Display for ClassDiagram {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use crate::traits::TabbedDisplay;
@@ -48,6 +48,23 @@ impl Display for Multiplicity {
     }
 }
 
+impl core::str::FromStr for Multiplicity {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Multiplicity::One),
+            "0..1" => Ok(Multiplicity::ZeroOrOne),
+            "1..*" => Ok(Multiplicity::OneOrMore),
+            "*" => Ok(Multiplicity::Many),
+            "n" => Ok(Multiplicity::N),
+            "0..n" => Ok(Multiplicity::ZeroToN),
+            "1..n" => Ok(Multiplicity::OneToN),
+            _ => Err(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::ToString;
@@ -64,4 +81,20 @@ mod tests {
         assert_eq!(Multiplicity::ZeroToN.to_string(), "0..n");
         assert_eq!(Multiplicity::OneToN.to_string(), "1..n");
     }
+
+    #[test]
+    fn test_multiplicity_from_str() {
+        for multiplicity in [
+            Multiplicity::One,
+            Multiplicity::ZeroOrOne,
+            Multiplicity::OneOrMore,
+            Multiplicity::Many,
+            Multiplicity::N,
+            Multiplicity::ZeroToN,
+            Multiplicity::OneToN,
+        ] {
+            assert_eq!(multiplicity.to_string().parse(), Ok(multiplicity));
+        }
+        assert_eq!("many".parse::<Multiplicity>(), Err(()));
+    }
 }
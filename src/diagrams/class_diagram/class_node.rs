@@ -10,7 +10,7 @@ use core::fmt::{self, Display};
 
 pub use builder::ClassNodeBuilder;
 pub use class_attribute::ClassAttribute;
-pub use class_method::ClassMethod;
+pub use class_method::{Argument, ClassMethod};
 
 use crate::{
     shared::{ClickEvent, GenericNode, NODE_LETTER, StyleClass, StyleProperty},
@@ -4,7 +4,7 @@
 use alloc::string::String;
 use core::fmt::{self, Display};
 
-use crate::diagrams::class_diagram::visibility::Visibility;
+use crate::diagrams::class_diagram::{classifier::Classifier, visibility::Visibility};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -16,6 +16,8 @@ pub struct ClassAttribute {
     attribute_type: String,
     /// The visibility of the class attribute (e.g., public, private).
     visibility: Visibility,
+    /// The classifier of the class attribute (e.g., static), if any.
+    classifier: Option<Classifier>,
 }
 
 impl ClassAttribute {
@@ -25,13 +27,35 @@ impl ClassAttribute {
             name: name.into(),
             attribute_type: attribute_type.into(),
             visibility: Visibility::Public,
+            classifier: None,
         }
     }
+
+    #[must_use]
+    /// Sets the visibility of the attribute.
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    #[must_use]
+    /// Sets the classifier of the attribute.
+    pub fn classifier(mut self, classifier: Classifier) -> Self {
+        self.classifier = Some(classifier);
+        self
+    }
 }
 
 impl Display for ClassAttribute {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}: {}", self.visibility, self.name, self.attribute_type)
+        write!(f, "{} {}", self.visibility, self.name)?;
+        if !self.attribute_type.is_empty() {
+            write!(f, ": {}", self.attribute_type)?;
+        }
+        if let Some(classifier) = self.classifier {
+            write!(f, "{classifier}")?;
+        }
+        Ok(())
     }
 }
 
@@ -47,7 +71,17 @@ mod tests {
             name: "attr1".to_string(),
             attribute_type: "int".to_string(),
             visibility: Visibility::Public,
+            classifier: None,
         };
         assert_eq!(attr.to_string(), "+ attr1: int");
+        assert_eq!(
+            ClassAttribute::new("int", "attr2").visibility(Visibility::Package).to_string(),
+            "~ attr2: int"
+        );
+        assert_eq!(
+            ClassAttribute::new("int", "attr3").classifier(Classifier::Static).to_string(),
+            "+ attr3: int$"
+        );
+        assert_eq!(ClassAttribute::new("", "attr4").to_string(), "+ attr4");
     }
 }
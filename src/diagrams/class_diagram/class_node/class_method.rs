@@ -4,7 +4,7 @@
 use alloc::{string::String, vec::Vec};
 use core::fmt::{self, Display};
 
-use crate::diagrams::class_diagram::visibility::Visibility;
+use crate::diagrams::class_diagram::{classifier::Classifier, visibility::Visibility};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -16,9 +16,20 @@ pub struct Argument {
     arg_type: String,
 }
 
+impl Argument {
+    /// Creates a new method argument.
+    pub fn new(name: impl Into<String>, arg_type: impl Into<String>) -> Self {
+        Self { name: name.into(), arg_type: arg_type.into() }
+    }
+}
+
 impl Display for Argument {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}: {}", self.name, self.arg_type)
+        if self.arg_type.is_empty() {
+            write!(f, "{}", self.name)
+        } else {
+            write!(f, "{}: {}", self.name, self.arg_type)
+        }
     }
 }
 
@@ -35,6 +46,8 @@ pub struct ClassMethod {
     return_type: Option<String>,
     /// The visibility of the method (e.g., public, private, protected).
     visibility: Visibility,
+    /// The classifier of the method (e.g., static, abstract), if any.
+    classifier: Option<Classifier>,
 }
 
 impl ClassMethod {
@@ -49,8 +62,23 @@ impl ClassMethod {
             arguments,
             return_type: Some(return_type.into()),
             visibility: Visibility::Public,
+            classifier: None,
         }
     }
+
+    #[must_use]
+    /// Sets the visibility of the method.
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    #[must_use]
+    /// Sets the classifier of the method.
+    pub fn classifier(mut self, classifier: Classifier) -> Self {
+        self.classifier = Some(classifier);
+        self
+    }
 }
 
 impl Display for ClassMethod {
@@ -77,6 +105,10 @@ impl Display for ClassMethod {
             write!(f, ": void")?;
         }
 
+        if let Some(classifier) = self.classifier {
+            write!(f, "{classifier}")?;
+        }
+
         Ok(())
     }
 }
@@ -91,6 +123,18 @@ mod tests {
     fn test_argument_display() {
         let arg = Argument { name: "arg1".to_string(), arg_type: "int".to_string() };
         assert_eq!(arg.to_string(), "arg1: int");
+        assert_eq!(Argument::new("arg1", "").to_string(), "arg1");
+    }
+
+    #[test]
+    fn test_class_method_constructors() {
+        let method = ClassMethod::new("bool", "method1", vec![Argument::new("arg1", "int")])
+            .visibility(Visibility::Protected);
+        assert_eq!(method.to_string(), "#method1(arg1: int): bool");
+        assert_eq!(
+            method.classifier(Classifier::Abstract).to_string(),
+            "#method1(arg1: int): bool*"
+        );
     }
 
     #[test]
@@ -103,6 +147,7 @@ mod tests {
             ],
             return_type: Some("bool".to_string()),
             visibility: Visibility::Public,
+            classifier: None,
         };
         assert_eq!(method.to_string(), "+method1(arg1: int, arg2: String): bool");
 
@@ -111,6 +156,7 @@ mod tests {
             arguments: vec![],
             return_type: None,
             visibility: Visibility::Private,
+            classifier: None,
         };
         assert_eq!(method_void.to_string(), "-method2(): void");
     }
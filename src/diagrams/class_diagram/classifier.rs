@@ -0,0 +1,59 @@
+//! Submodule providing an enumeration for the classifiers employable in
+//! class entries of Mermaid class diagrams.
+//!
+//! These include: Static (`$`) and Abstract (`*`).
+
+use core::fmt::{self, Display};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An enumeration representing the classifier of class members in Mermaid
+/// class diagrams.
+pub enum Classifier {
+    /// Static member, denoted by `$`.
+    Static,
+    /// Abstract member, denoted by `*`.
+    Abstract,
+}
+
+impl Display for Classifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Classifier::Static => write!(f, "$"),
+            Classifier::Abstract => write!(f, "*"),
+        }
+    }
+}
+
+impl core::str::FromStr for Classifier {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "$" => Ok(Classifier::Static),
+            "*" => Ok(Classifier::Abstract),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_classifier_display() {
+        assert_eq!(Classifier::Static.to_string(), "$");
+        assert_eq!(Classifier::Abstract.to_string(), "*");
+    }
+
+    #[test]
+    fn test_classifier_from_str() {
+        for classifier in [Classifier::Static, Classifier::Abstract] {
+            assert_eq!(classifier.to_string().parse(), Ok(classifier));
+        }
+        assert_eq!("+".parse::<Classifier>(), Err(()));
+    }
+}
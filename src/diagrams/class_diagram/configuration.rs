@@ -2,6 +2,7 @@
 //! Mermaid.
 
 mod builder;
+use alloc::{collections::BTreeMap, string::String};
 use core::fmt::{self, Display};
 
 pub use builder::ClassDiagramConfigurationBuilder;
@@ -24,6 +25,22 @@ pub struct ClassDiagramConfiguration {
     hide_empty_members_box: bool,
 }
 
+impl ClassDiagramConfiguration {
+    #[must_use]
+    /// Returns whether empty members boxes are hidden in the class diagram.
+    pub fn hide_empty_members_box(&self) -> bool {
+        self.hide_empty_members_box
+    }
+
+    #[must_use]
+    /// Returns the options which are not modelled, such as `logLevel` or
+    /// `class.padding`, keyed by their dotted path within the `config` section,
+    /// with their values written as in an init directive.
+    pub fn extra_options(&self) -> &BTreeMap<String, String> {
+        self.generic.extra_options()
+    }
+}
+
 impl Display for ClassDiagramConfiguration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "---")?;
@@ -35,7 +52,7 @@ impl Display for ClassDiagramConfiguration {
         writeln!(f, "    hideEmptyMembersBox: \"{}\"", self.hide_empty_members_box)?;
         writeln!(f, "---")?;
 
-        Ok(())
+        self.generic.fmt_extra_options(f)
     }
 }
 
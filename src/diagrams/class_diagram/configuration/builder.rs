@@ -4,12 +4,18 @@
 use alloc::string::ToString;
 
 use crate::{
-    diagrams::class_diagram::configuration::ClassDiagramConfiguration, errors::ConfigError,
-    shared::generic_configuration::GenericConfigurationBuilder, traits::ConfigurationBuilder,
+    diagrams::class_diagram::configuration::ClassDiagramConfiguration,
+    errors::{ConfigError, ParseError},
+    shared::{
+        generic_configuration::GenericConfigurationBuilder,
+        parsing::{ConfigurationEntry, ParsableConfiguration, parse_configuration},
+    },
+    traits::ConfigurationBuilder,
 };
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating class diagram configurations with various properties.
 pub struct ClassDiagramConfigurationBuilder {
     /// Generic configuration options which apply to all Mermaid diagrams.
     generic: GenericConfigurationBuilder,
@@ -18,19 +24,42 @@ pub struct ClassDiagramConfigurationBuilder {
 }
 
 impl ClassDiagramConfigurationBuilder {
+    /// Parses the configuration of a class diagram written in the Mermaid
+    /// syntax from its `---` frontmatter and its `%%{init: {...}}%%`
+    /// directives, ignoring its statements.
+    ///
+    /// The `title`, `theme`, `look` and `layout` keys are supported, as are
+    /// the `defaultRenderer` and `hideEmptyMembersBox` keys of the `class`
+    /// section. Other keys of the `config` section, such as `themeVariables`,
+    /// are kept as
+    /// [`extra_options`](ClassDiagramConfiguration::extra_options), which
+    /// are written back as an init directive.
+    ///
+    /// # Errors
+    ///
+    /// * If the frontmatter or a directive is malformed, sets an invalid value
+    ///   or has a key outside of the `config` section other than `title`,
+    ///   with the line and column at which the error occurred.
+    pub fn parse(source: &str) -> Result<Self, ParseError> {
+        parse_configuration(source).map(|(configuration, _)| configuration)
+    }
+
     /// Sets whether to hide empty members in the class diagram.
+    #[must_use]
     pub fn hide_empty_members_box(mut self, hide: bool) -> Self {
         self.hide_empty_members_box = hide;
         self
     }
 
     /// Sets the theme to use for the diagram.
+    #[must_use]
     pub fn theme(mut self, theme: crate::shared::generic_configuration::Theme) -> Self {
         self.generic = self.generic.theme(theme);
         self
     }
 
     /// Sets the look to use for the diagram.
+    #[must_use]
     pub fn look(mut self, look: crate::shared::generic_configuration::Look) -> Self {
         self.generic = self.generic.look(look);
         self
@@ -48,6 +77,25 @@ impl TryFrom<ClassDiagramConfigurationBuilder> for ClassDiagramConfiguration {
     }
 }
 
+impl ParsableConfiguration for ClassDiagramConfigurationBuilder {
+    const SECTION: Option<&'static str> = Some("class");
+
+    fn shared(
+        mut self,
+        update: impl FnOnce(GenericConfigurationBuilder) -> GenericConfigurationBuilder,
+    ) -> Self {
+        self.generic = update(self.generic);
+        self
+    }
+
+    fn specific(self, key: &str, entry: &ConfigurationEntry<'_>) -> Result<Self, ParseError> {
+        Ok(match key {
+            "hideEmptyMembersBox" => self.hide_empty_members_box(entry.value()?),
+            _ => return self.preserve(entry),
+        })
+    }
+}
+
 impl ConfigurationBuilder for ClassDiagramConfigurationBuilder {
     type Configuration = ClassDiagramConfiguration;
     type Error = ConfigError;
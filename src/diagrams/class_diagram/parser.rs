@@ -0,0 +1,762 @@
+//! Submodule providing a parser for class diagrams written in the Mermaid
+//! syntax, such as `Animal <|-- Duck : extends`.
+
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    diagrams::class_diagram::{
+        ClassDiagramBuilder, ClassEdgeBuilder, ClassNodeBuilder,
+        class_edge::multiplicity::Multiplicity,
+        class_node::{Argument, ClassAttribute, ClassMethod},
+        classifier::Classifier,
+        configuration::ClassDiagramConfigurationBuilder,
+        visibility::Visibility,
+    },
+    errors::{ParseError, ParseErrorKind},
+    shared::{
+        ArrowShape, ClickEvent, Direction, LineStyle, StyleProperty,
+        parsing::{
+            Cursor, Position, define_style_classes, is_identifier_char, label_text, merge_property,
+            parse_click_event, parse_configuration, parse_style_properties, parse_value,
+        },
+    },
+    traits::{ConfigurationBuilder, DiagramBuilder, EdgeBuilder, NodeBuilder},
+};
+
+/// Parses a method argument, written either as `name: Type`, as
+/// `Type name` or as a bare `name`.
+fn parse_argument(text: &str) -> Argument {
+    if let Some((name, argument_type)) = text.split_once(':') {
+        Argument::new(name.trim(), argument_type.trim())
+    } else if let Some((argument_type, name)) = text.rsplit_once(char::is_whitespace) {
+        Argument::new(name.trim(), argument_type.trim())
+    } else {
+        Argument::new(text, "")
+    }
+}
+
+/// A class whose statements are still being parsed.
+struct PendingClass {
+    /// The identifier of the class in the source.
+    identifier: String,
+    /// The label of the class, when it differs from its identifier.
+    label: Option<String>,
+    /// The annotation of the class, such as `interface`.
+    annotation: Option<String>,
+    /// The attributes of the class.
+    attributes: Vec<ClassAttribute>,
+    /// The methods of the class.
+    methods: Vec<ClassMethod>,
+    /// The names of the style classes of the class, with their positions.
+    classes: Vec<(String, Position)>,
+    /// The style properties of the class.
+    properties: Vec<StyleProperty>,
+    /// The click event of the class.
+    click_event: Option<ClickEvent>,
+    /// The position at which the class is first mentioned.
+    position: Position,
+}
+
+/// A relationship whose classes are still being parsed.
+struct PendingRelationship {
+    /// The index of the source class.
+    source: usize,
+    /// The index of the destination class.
+    destination: usize,
+    /// The builder of the edge, without its source and destination.
+    builder: ClassEdgeBuilder,
+    /// The position of the statement declaring the relationship.
+    position: Position,
+}
+
+#[derive(Default)]
+/// Parser of the statements following the class diagram header.
+struct ClassDiagramParser {
+    /// The builder receiving the style classes, the classes and the edges.
+    builder: ClassDiagramBuilder,
+    /// The configuration read from the frontmatter and the init directives.
+    configuration: ClassDiagramConfigurationBuilder,
+    /// The direction of the diagram, if any was written.
+    direction: Option<Direction>,
+    /// The identifiers of the classes in the source, mapped to their indices.
+    identifiers: BTreeMap<String, usize>,
+    /// The classes, in order of first mention, whose indices are their
+    /// numeric identifiers.
+    nodes: Vec<PendingClass>,
+    /// The relationships, in order of declaration.
+    relationships: Vec<PendingRelationship>,
+    /// The class whose body is open, with the position of its declaration.
+    body: Option<(usize, Position)>,
+}
+
+impl ClassDiagramParser {
+    /// Returns the index of the class written as `identifier`, declaring the
+    /// class when it is first mentioned.
+    fn mention(&mut self, identifier: &str, position: Position) -> usize {
+        if let Some(&id) = self.identifiers.get(identifier) {
+            return id;
+        }
+        let id = self.nodes.len();
+        self.identifiers.insert(identifier.to_string(), id);
+        self.nodes.push(PendingClass {
+            identifier: identifier.to_string(),
+            label: None,
+            annotation: None,
+            attributes: Vec::new(),
+            methods: Vec::new(),
+            classes: Vec::new(),
+            properties: Vec::new(),
+            click_event: None,
+            position,
+        });
+        id
+    }
+
+    /// Returns the class written as `identifier`, which must have been
+    /// mentioned before.
+    fn mentioned(
+        &mut self,
+        cursor: &Cursor<'_>,
+        identifier: &str,
+    ) -> Result<&mut PendingClass, ParseError> {
+        let id = *self.identifiers.get(identifier).ok_or_else(|| {
+            cursor
+                .position_of(identifier)
+                .error(ParseErrorKind::UnknownIdentifier(identifier.to_string()))
+        })?;
+        Ok(&mut self.nodes[id])
+    }
+
+    /// Consumes the identifier of a class at the cursor, and returns its
+    /// index.
+    fn class(&mut self, cursor: &mut Cursor<'_>) -> Result<usize, ParseError> {
+        let position = cursor.position();
+        let identifier = cursor.identifier().ok_or_else(|| cursor.unexpected())?;
+        if cursor.rest.starts_with('~') {
+            return Err(cursor
+                .position()
+                .error(ParseErrorKind::UnsupportedSyntax("generic class".to_string())));
+        }
+        Ok(self.mention(identifier, position))
+    }
+
+    /// Parses the statement at the cursor.
+    fn statement(&mut self, mut cursor: Cursor<'_>) -> Result<(), ParseError> {
+        if let Some((id, _)) = self.body {
+            return self.body_statement(cursor, id);
+        }
+
+        let statement = cursor.rest;
+        let (keyword, rest) = statement.split_once(char::is_whitespace).unwrap_or((statement, ""));
+        let rest = rest.trim();
+        let unexpected = || cursor.unexpected();
+
+        match keyword {
+            "class" => {
+                cursor.rest = rest;
+                self.declaration(cursor)?;
+            }
+            "classDef" => define_style_classes(&mut self.builder, &cursor, rest)?,
+            "cssClass" => {
+                let (identifiers, name) =
+                    rest.rsplit_once(char::is_whitespace).ok_or_else(unexpected)?;
+                let position = cursor.position_of(name);
+                for identifier in label_text(identifiers).split(',') {
+                    let node = self.mentioned(&cursor, identifier.trim())?;
+                    if !node.classes.iter().any(|(existing, _)| existing == name) {
+                        node.classes.push((name.to_string(), position));
+                    }
+                }
+            }
+            "style" => {
+                let (identifier, properties) =
+                    rest.split_once(char::is_whitespace).ok_or_else(unexpected)?;
+                let properties =
+                    parse_style_properties(cursor.number, cursor.line, properties.trim())?;
+                let node = self.mentioned(&cursor, identifier)?;
+                for property in properties {
+                    merge_property(&mut node.properties, property);
+                }
+            }
+            "click" | "link" | "callback" => {
+                let (identifier, event) =
+                    rest.split_once(char::is_whitespace).ok_or_else(unexpected)?;
+                let event = event.trim();
+                let click_event = match keyword {
+                    "link" => parse_click_event(&format!("href {event}")),
+                    "callback" => parse_click_event(label_text(event)),
+                    _ => {
+                        let normalized = event.strip_prefix("call ").unwrap_or(event).trim();
+                        parse_click_event(normalized.strip_suffix("()").unwrap_or(normalized))
+                    }
+                }
+                .ok_or_else(|| cursor.position_of(event).invalid(event))?;
+                self.mentioned(&cursor, identifier)?.click_event = Some(click_event);
+            }
+            "direction" => {
+                self.direction = Some(parse_value(cursor.number, cursor.line, rest)?);
+            }
+            "note" | "namespace" => {
+                return Err(cursor
+                    .position()
+                    .error(ParseErrorKind::UnsupportedSyntax(keyword.to_string())));
+            }
+            _ if let Some(annotated) = statement.strip_prefix("<<") => {
+                let (annotation, identifier) = annotated.split_once(">>").ok_or_else(unexpected)?;
+                let mut class = Cursor { rest: identifier.trim(), ..cursor };
+                let id = self.class(&mut class)?;
+                if !class.rest.is_empty() {
+                    return Err(class.unexpected());
+                }
+                self.nodes[id].annotation = Some(annotation.trim().to_string());
+            }
+            _ => {
+                let source = self.class(&mut cursor)?;
+                cursor.skip_whitespace();
+                if cursor.eat(":") {
+                    let member = cursor.rest.trim();
+                    return self.member(&cursor, source, member);
+                }
+                self.relationship(cursor, source)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses a `class` statement, whose arguments start at the cursor, such
+    /// as `Animal["Label"]:::style {`.
+    fn declaration(&mut self, mut cursor: Cursor<'_>) -> Result<(), ParseError> {
+        let position = cursor.position();
+        let id = self.class(&mut cursor)?;
+
+        if cursor.eat("[") {
+            let opening = cursor.position();
+            let label = cursor
+                .until("]")
+                .ok_or_else(|| opening.error(ParseErrorKind::UnclosedBlock("[".to_string())))?;
+            self.nodes[id].label = Some(label_text(label).to_string());
+        }
+        if cursor.eat(":::") {
+            let name_position = cursor.position();
+            let name = cursor.identifier().ok_or_else(|| cursor.unexpected())?;
+            let classes = &mut self.nodes[id].classes;
+            if !classes.iter().any(|(existing, _)| existing == name) {
+                classes.push((name.to_string(), name_position));
+            }
+        }
+
+        cursor.skip_whitespace();
+        if cursor.eat("{") {
+            cursor.skip_whitespace();
+            if !cursor.eat("}") {
+                self.body = Some((id, position));
+            }
+            cursor.skip_whitespace();
+        }
+        if cursor.rest.is_empty() { Ok(()) } else { Err(cursor.unexpected()) }
+    }
+
+    /// Parses a statement within the body of the class with the provided
+    /// index.
+    fn body_statement(&mut self, cursor: Cursor<'_>, id: usize) -> Result<(), ParseError> {
+        let statement = cursor.rest;
+        if statement == "}" {
+            self.body = None;
+        } else if let Some(annotation) =
+            statement.strip_prefix("<<").and_then(|annotation| annotation.strip_suffix(">>"))
+        {
+            self.nodes[id].annotation = Some(annotation.trim().to_string());
+        } else {
+            self.member(&cursor, id, statement)?;
+        }
+        Ok(())
+    }
+
+    /// Parses a member of the class with the provided index, such as
+    /// `+String name` or `-isValid(int value) bool`.
+    fn member(&mut self, cursor: &Cursor<'_>, id: usize, text: &str) -> Result<(), ParseError> {
+        let position = cursor.position_of(text);
+        if text.is_empty() {
+            return Err(cursor.unexpected());
+        }
+        // The classifier closes the member, or directly follows the
+        // arguments of a method, as in `count()$ int`.
+        let (mut classifier, text) = match text.get(text.len() - 1..).map(str::parse::<Classifier>)
+        {
+            Some(Ok(classifier)) => (Some(classifier), text[..text.len() - 1].trim_end()),
+            _ => (None, text),
+        };
+
+        let (visibility, body) = match text.get(..1).map(str::parse::<Visibility>) {
+            Some(Ok(visibility)) => (visibility, text[1..].trim()),
+            _ => (Visibility::Public, text),
+        };
+
+        let node = &mut self.nodes[id];
+        if let Some((name, remainder)) = body.split_once('(') {
+            let (arguments, return_type) = remainder
+                .rsplit_once(')')
+                .ok_or_else(|| position.error(ParseErrorKind::UnclosedBlock("(".to_string())))?;
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(position.invalid(text));
+            }
+            let arguments = arguments
+                .split(',')
+                .map(str::trim)
+                .filter(|argument| !argument.is_empty())
+                .map(parse_argument)
+                .collect();
+            let mut return_type = return_type.trim();
+            if let Some(Ok(inner)) = return_type.get(..1).map(str::parse::<Classifier>) {
+                classifier = Some(inner);
+                return_type = return_type[1..].trim();
+            }
+            let return_type = match return_type.strip_prefix(':').unwrap_or(return_type).trim() {
+                "" => "void",
+                return_type => return_type,
+            };
+            let mut method = ClassMethod::new(return_type, name, arguments).visibility(visibility);
+            if let Some(classifier) = classifier {
+                method = method.classifier(classifier);
+            }
+            node.methods.push(method);
+        } else {
+            let (name, attribute_type) = if let Some((name, attribute_type)) = body.split_once(':')
+            {
+                (name.trim(), attribute_type.trim())
+            } else if let Some((attribute_type, name)) = body.rsplit_once(char::is_whitespace) {
+                (name.trim(), attribute_type.trim())
+            } else {
+                (body, "")
+            };
+            if name.is_empty() {
+                return Err(position.invalid(text));
+            }
+            let mut attribute = ClassAttribute::new(attribute_type, name).visibility(visibility);
+            if let Some(classifier) = classifier {
+                attribute = attribute.classifier(classifier);
+            }
+            node.attributes.push(attribute);
+        }
+        Ok(())
+    }
+
+    /// Parses a relationship, such as `A "1" *-- "*" B : contains`, whose
+    /// source class has been parsed and which continues at the cursor.
+    fn relationship(&mut self, mut cursor: Cursor<'_>, source: usize) -> Result<(), ParseError> {
+        let position = cursor.position();
+        let left_multiplicity = multiplicity(&mut cursor)?;
+        cursor.skip_whitespace();
+
+        let link = cursor;
+        let invalid = |end: &Cursor<'_>| {
+            let token = link.rest.split_whitespace().next().unwrap_or_default();
+            if token.contains("()") {
+                link.position().error(ParseErrorKind::UnsupportedSyntax("()".to_string()))
+            } else {
+                end.position_of(token).invalid(token)
+            }
+        };
+        let left_arrow = if cursor.eat("<|") {
+            Some(ArrowShape::Triangle)
+        } else if cursor.eat("<") {
+            Some(ArrowShape::Normal)
+        } else if cursor.rest.starts_with(['*', 'o']) && cursor.rest[1..].starts_with(['-', '.']) {
+            let shape =
+                if cursor.rest.starts_with('*') { ArrowShape::Star } else { ArrowShape::Circle };
+            cursor.rest = &cursor.rest[1..];
+            Some(shape)
+        } else {
+            None
+        };
+        let line_style = if cursor.eat("--") {
+            LineStyle::Solid
+        } else if cursor.eat("..") {
+            LineStyle::Dashed
+        } else {
+            return Err(invalid(&cursor));
+        };
+        let right_arrow = if cursor.eat("|>") {
+            Some(ArrowShape::Triangle)
+        } else if cursor.eat(">") {
+            Some(ArrowShape::Normal)
+        } else if cursor.eat("*") {
+            Some(ArrowShape::Star)
+        } else if cursor.eat("o") {
+            Some(ArrowShape::Circle)
+        } else {
+            None
+        };
+        if cursor.rest.starts_with(|character: char| {
+            !character.is_whitespace() && character != '"' && !is_identifier_char(character)
+        }) {
+            return Err(invalid(&cursor));
+        }
+
+        cursor.skip_whitespace();
+        let right_multiplicity = multiplicity(&mut cursor)?;
+        cursor.skip_whitespace();
+        let destination = self.class(&mut cursor)?;
+        cursor.skip_whitespace();
+        let label = if cursor.eat(":") { Some(label_text(cursor.rest)) } else { None };
+        if label.is_none() && !cursor.rest.is_empty() {
+            return Err(cursor.unexpected());
+        }
+
+        let mut builder = ClassEdgeBuilder::default().line_style(line_style);
+        if let Some(shape) = left_arrow {
+            builder = builder.left_arrow_shape(shape).map_err(position.diagram())?;
+        }
+        if let Some(shape) = right_arrow {
+            builder = builder.right_arrow_shape(shape).map_err(position.diagram())?;
+        }
+        if let Some(multiplicity) = left_multiplicity {
+            builder = builder.left_multiplicity(multiplicity);
+        }
+        if let Some(multiplicity) = right_multiplicity {
+            builder = builder.right_multiplicity(multiplicity);
+        }
+        if let Some(label) = label.filter(|label| !label.is_empty()) {
+            builder = builder.label(label).map_err(position.diagram())?;
+        }
+
+        self.relationships.push(PendingRelationship { source, destination, builder, position });
+        Ok(())
+    }
+
+    /// Builds the class diagram builder from the parsed statements.
+    fn finish(self) -> Result<(ClassDiagramBuilder, BTreeMap<String, u64>), ParseError> {
+        if let Some((_, position)) = self.body {
+            return Err(position.error(ParseErrorKind::UnclosedBlock("{".to_string())));
+        }
+
+        // Mermaid lays class diagrams out from top to bottom unless told
+        // otherwise.
+        let configuration =
+            self.configuration.direction(self.direction.unwrap_or(Direction::TopToBottom));
+        let mut builder = self
+            .builder
+            .configuration(configuration)
+            .map_err(|error| ParseError::new(1, 1, ParseErrorKind::Diagram(error)))?;
+
+        let mut nodes = Vec::with_capacity(self.nodes.len());
+        for (id, node) in self.nodes.into_iter().enumerate() {
+            let position = node.position;
+            let mut node_builder = ClassNodeBuilder::default()
+                .label(node.label.as_ref().unwrap_or(&node.identifier))
+                .map_err(position.diagram())?
+                .id(id as u64);
+            if let Some(annotation) = &node.annotation {
+                node_builder = node_builder.annotation(annotation);
+            }
+            for attribute in node.attributes {
+                node_builder = node_builder.attribute(attribute);
+            }
+            for method in node.methods {
+                node_builder = node_builder.method(method);
+            }
+            if let Some(click_event) = node.click_event {
+                node_builder = node_builder.click_event(click_event);
+            }
+            for (name, class_position) in node.classes {
+                let style_class = builder.get_style_class_by_name(&name).ok_or_else(|| {
+                    class_position.error(ParseErrorKind::UnknownIdentifier(name.clone()))
+                })?;
+                node_builder = node_builder.style_class(style_class).map_err(position.diagram())?;
+            }
+            for property in node.properties {
+                node_builder = node_builder.style_property(property).map_err(position.diagram())?;
+            }
+            nodes.push(builder.node(node_builder).map_err(position.diagram())?);
+        }
+
+        for relationship in self.relationships {
+            let position = relationship.position;
+            let edge = relationship
+                .builder
+                .source(nodes[relationship.source].clone())
+                .and_then(|edge| edge.destination(nodes[relationship.destination].clone()))
+                .map_err(position.diagram())?;
+            builder.edge(edge).map_err(position.diagram())?;
+        }
+
+        let identifiers =
+            self.identifiers.into_iter().map(|(identifier, id)| (identifier, id as u64)).collect();
+        Ok((builder, identifiers))
+    }
+}
+
+/// Consumes the quoted multiplicity at the cursor, such as `"1..*"`, if any.
+fn multiplicity(cursor: &mut Cursor<'_>) -> Result<Option<Multiplicity>, ParseError> {
+    let opening = cursor.position();
+    if !cursor.eat("\"") {
+        return Ok(None);
+    }
+    let text = cursor
+        .until("\"")
+        .ok_or_else(|| opening.error(ParseErrorKind::UnclosedBlock("\"".to_string())))?;
+    parse_value(cursor.number, cursor.line, text).map(Some)
+}
+
+/// Parses a class diagram written in the Mermaid syntax.
+pub(super) fn parse(
+    source: &str,
+) -> Result<(ClassDiagramBuilder, BTreeMap<String, u64>), ParseError> {
+    let (configuration, lines) = parse_configuration(source)?;
+    let mut lines = lines.into_iter();
+    let Some((number, line)) = lines.next() else {
+        return Err(ParseError::new(
+            source.lines().count() + 1,
+            1,
+            ParseErrorKind::MissingHeader("classDiagram".to_string()),
+        ));
+    };
+    let header = line.trim();
+    if header != "classDiagram" && header != "classDiagram-v2" {
+        return Err(Position::of(number, line, header)
+            .error(ParseErrorKind::MissingHeader("classDiagram".to_string())));
+    }
+
+    let mut parser = ClassDiagramParser { configuration, ..ClassDiagramParser::default() };
+    for (number, line) in lines {
+        parser.statement(Cursor { number, line, rest: line.trim() })?;
+    }
+    parser.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString, vec::Vec};
+
+    use super::*;
+    use crate::{
+        diagrams::class_diagram::ClassDiagram,
+        shared::{StyleClass, generic_configuration::Theme},
+        traits::{Configuration, Diagram, Node},
+    };
+
+    /// Parses the source and returns the lines of the displayed diagram,
+    /// after its frontmatter.
+    fn displayed(source: &str) -> Result<Vec<String>, ParseError> {
+        let (builder, _) = parse(source)?;
+        Ok(ClassDiagram::from(builder).to_string().lines().skip(5).map(str::to_string).collect())
+    }
+
+    #[test]
+    fn test_parse_members() -> Result<(), Box<dyn core::error::Error>> {
+        let (builder, identifiers) = parse(
+            "classDiagram\n  class Animal[\"An animal\"]:::hot {\n    <<interface>>\n    \
+             +String name\n    -int age\n    #List~int~ ids\n    ~count: int\n    \
+             +eat(food: Food, int amount) bool\n    +sleep()\n    -run(speed): void\n    \
+             +String kingdom$\n    +move()*\n    +count()$ int\n  }\n  \
+             classDef hot fill:#ff0000\n  Animal : +swim(depth)\n  Duck : beak\n  \
+             <<abstract>> Duck\n  class Empty {}\n  class Bare",
+        )?;
+        assert_eq!(
+            identifiers.into_iter().collect::<Vec<_>>(),
+            [
+                ("Animal".to_string(), 0),
+                ("Bare".to_string(), 3),
+                ("Duck".to_string(), 1),
+                ("Empty".to_string(), 2)
+            ]
+        );
+        let diagram = ClassDiagram::from(builder);
+        let lines: Vec<_> = diagram.to_string().lines().skip(5).map(str::to_string).collect();
+        assert_eq!(
+            lines,
+            [
+                "classDiagram",
+                "  direction TB",
+                "  classDef hot fill: #ff0000",
+                "  class v0[\"An animal\"] {",
+                "      <<interface>>",
+                "      + name: String",
+                "      - age: int",
+                "      # ids: List~int~",
+                "      ~ count: int",
+                "      + kingdom: String$",
+                "      +eat(food: Food, amount: int): bool",
+                "      +sleep(): void",
+                "      -run(speed): void",
+                "      +move(): void*",
+                "      +count(): int$",
+                "      +swim(depth): void",
+                "  }",
+                "  cssClass v0 hot",
+                "  class v1[\"Duck\"] {",
+                "      <<abstract>>",
+                "      + beak",
+                "  }",
+                "  class v2[\"Empty\"] {",
+                "  }",
+                "  class v3[\"Bare\"] {",
+                "  }",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_relationships() -> Result<(), Box<dyn core::error::Error>> {
+        let lines = displayed(
+            "classDiagram-v2\n  direction LR\n  A <|-- B\n  C*--D\n  E o-- F\n  G --> H\n  \
+             I -- J\n  K ..> L\n  M ..|> N\n  O .. P\n  A \"1\" --> \"*\" B : has many\n  \
+             A \"0..1\" o--o \"1..n\" C\n  A<-->D : `**md**`",
+        )?;
+        assert_eq!(lines[1], "  direction LR");
+        assert_eq!(
+            &lines[34..],
+            [
+                "  v0 <|-- v1",
+                "  v2 *-- v3",
+                "  v4 o-- v5",
+                "  v6 --> v7",
+                "  v8 -- v9",
+                "  v10 ..> v11",
+                "  v12 ..|> v13",
+                "  v14 .. v15",
+                "  v0 1 --> * v1 : \"`has many`\"",
+                "  v0 0..1 o--o 1..n v2",
+                "  v0 <--> v3 : \"`**md**`\"",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_statements() -> Result<(), Box<dyn core::error::Error>> {
+        let (builder, identifiers) = parse(
+            "classDiagram\n  %% A comment\n  class A\n  class B\n  classDef hot,warm fill:#ff0000\n  \
+             classDef cold stroke:#0000ff\n  cssClass \"A,B\" hot\n  cssClass A cold\n  \
+             style B fill:#00ff00,stroke-width:2px\n  click A href \"https://example.com\" \"Tip\"\n  \
+             link B \"https://example.org\"\n  callback A \"showInfo\"\n  click B call showInfo()",
+        )?;
+        let diagram = ClassDiagram::from(builder);
+        assert_eq!(diagram.style_classes().count(), 3);
+        let a = diagram.get_node_by_id(identifiers["A"]).ok_or("A")?;
+        let b = diagram.get_node_by_id(identifiers["B"]).ok_or("B")?;
+        assert_eq!(a.classes().map(StyleClass::name).collect::<Vec<_>>(), ["hot", "cold"]);
+        assert_eq!(b.classes().map(StyleClass::name).collect::<Vec<_>>(), ["hot"]);
+        assert_eq!(b.styles().count(), 2);
+        let lines: Vec<_> = diagram.to_string().lines().map(str::to_string).collect();
+        assert!(lines.contains(&"  click v0 showInfo".to_string()));
+        assert!(lines.contains(&"  click v1 showInfo".to_string()));
+
+        let (builder, _) =
+            parse("classDiagram\n  class A\n  link A \"https://example.com\" \"Tip\" _blank")?;
+        let lines = ClassDiagram::from(builder).to_string();
+        assert!(lines.contains("  click v0 href \"https://example.com\" \"Tip\" _blank\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_configuration() -> Result<(), Box<dyn core::error::Error>> {
+        let (builder, _) = parse(
+            "---\ntitle: Animals\nconfig:\n  class:\n    hideEmptyMembersBox: true\n---\n\
+             %%{init: {'theme': 'dark'}}%%\nclassDiagram\n  direction RL\n  class Animal",
+        )?;
+        let diagram = ClassDiagram::from(builder);
+        let configuration = diagram.configuration();
+        assert_eq!(configuration.title(), Some("Animals"));
+        assert_eq!(configuration.theme(), Theme::Dark);
+        assert_eq!(configuration.direction(), Direction::RightToLeft);
+        assert!(configuration.hide_empty_members_box());
+
+        // The displayed frontmatter only holds the title and the class
+        // section.
+        let (builder, _) = parse(&diagram.to_string())?;
+        let reparsed = ClassDiagram::from(builder);
+        assert_eq!(reparsed.configuration().title(), Some("Animals"));
+        assert!(reparsed.configuration().hide_empty_members_box());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for (source, line, column, kind) in [
+            ("", 1, 1, ParseErrorKind::MissingHeader("classDiagram".to_string())),
+            ("graph LR", 1, 1, ParseErrorKind::MissingHeader("classDiagram".to_string())),
+            ("classDiagram\n  direction XY", 2, 13, ParseErrorKind::InvalidValue("XY".to_string())),
+            ("classDiagram\n  A -> B", 2, 5, ParseErrorKind::InvalidValue("->".to_string())),
+            ("classDiagram\n  A --", 2, 7, ParseErrorKind::UnexpectedStatement(String::new())),
+            ("classDiagram\n  A \"2\" --> B", 2, 6, ParseErrorKind::InvalidValue("2".to_string())),
+            ("classDiagram\n  A \"1 --> B", 2, 5, ParseErrorKind::UnclosedBlock("\"".to_string())),
+            ("classDiagram\n  A ()-- B", 2, 5, ParseErrorKind::UnsupportedSyntax("()".to_string())),
+            (
+                "classDiagram\n  class A {\n    +name",
+                2,
+                9,
+                ParseErrorKind::UnclosedBlock("{".to_string()),
+            ),
+            (
+                "classDiagram\n  class A[Label",
+                2,
+                11,
+                ParseErrorKind::UnclosedBlock("[".to_string()),
+            ),
+            (
+                "classDiagram\n  class A {\n    +eat(food\n  }",
+                3,
+                5,
+                ParseErrorKind::UnclosedBlock("(".to_string()),
+            ),
+            (
+                "classDiagram\n  class Square~Shape~",
+                2,
+                15,
+                ParseErrorKind::UnsupportedSyntax("generic class".to_string()),
+            ),
+            (
+                "classDiagram\n  note for A \"text\"",
+                2,
+                3,
+                ParseErrorKind::UnsupportedSyntax("note".to_string()),
+            ),
+            (
+                "classDiagram\n  class A:::missing",
+                2,
+                13,
+                ParseErrorKind::UnknownIdentifier("missing".to_string()),
+            ),
+            (
+                "classDiagram\n  class A\n  cssClass A missing",
+                3,
+                14,
+                ParseErrorKind::UnknownIdentifier("missing".to_string()),
+            ),
+            (
+                "classDiagram\n  class A\n  cssClass \"A,Z\" hot",
+                3,
+                15,
+                ParseErrorKind::UnknownIdentifier("Z".to_string()),
+            ),
+            (
+                "classDiagram\n  style Z fill:#ff0000",
+                2,
+                9,
+                ParseErrorKind::UnknownIdentifier("Z".to_string()),
+            ),
+            (
+                "classDiagram\n  class A\n  click A \"https://example.com",
+                3,
+                11,
+                ParseErrorKind::InvalidValue("\"https://example.com".to_string()),
+            ),
+        ] {
+            let error = parse(source).err().unwrap_or_else(|| panic!("{source}"));
+            assert_eq!(
+                (error.line(), error.column(), error.kind()),
+                (line, column, &kind),
+                "{source}"
+            );
+        }
+    }
+}
@@ -32,6 +32,20 @@ impl Display for Visibility {
     }
 }
 
+impl core::str::FromStr for Visibility {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Visibility::Public),
+            "-" => Ok(Visibility::Private),
+            "#" => Ok(Visibility::Protected),
+            "~" => Ok(Visibility::Package),
+            _ => Err(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::ToString;
@@ -45,4 +59,14 @@ mod tests {
         assert_eq!(Visibility::Protected.to_string(), "#");
         assert_eq!(Visibility::Package.to_string(), "~");
     }
+
+    #[test]
+    fn test_visibility_from_str() {
+        for visibility in
+            [Visibility::Public, Visibility::Private, Visibility::Protected, Visibility::Package]
+        {
+            assert_eq!(visibility.to_string().parse(), Ok(visibility));
+        }
+        assert_eq!("*".parse::<Visibility>(), Err(()));
+    }
 }
@@ -3,14 +3,20 @@
 
 pub mod entity_relationship_edge;
 pub mod entity_relationship_node;
+mod parser;
+use alloc::{collections::BTreeMap, string::String};
 use core::fmt::Display;
 
 use entity_relationship_edge::EREdge;
 pub use entity_relationship_edge::EREdgeBuilder;
 use entity_relationship_node::ERNode;
-pub use entity_relationship_node::{ERNodeBuilder, attribute::EntityRelationshipAttribute};
+pub use entity_relationship_node::{
+    ERNodeBuilder,
+    attribute::{AttributeKey, EntityRelationshipAttribute},
+};
 
 use crate::{
+    errors::ParseError,
     shared::{
         generic_configuration::{GenericConfiguration, GenericConfigurationBuilder},
         generic_diagram::{GenericDiagram, GenericDiagramBuilder},
@@ -27,6 +33,52 @@ pub type ERDiagram = GenericDiagram<ERNode, EREdge, GenericConfiguration>;
 /// Represents a builder for an entity-relationship diagram in Mermaid syntax.
 pub type ERDiagramBuilder = GenericDiagramBuilder<ERNode, EREdge, GenericConfiguration>;
 
+impl ERDiagramBuilder {
+    /// Parses an entity-relationship diagram written by hand in the Mermaid
+    /// syntax, returning its builder together with the numeric identifiers
+    /// assigned to the entity identifiers of the source, in order of first
+    /// appearance.
+    ///
+    /// Entity blocks with typed attributes, their keys and comments, quoted
+    /// and aliased entities, relationships with crow's-foot markers or their
+    /// word aliases, such as `one or more`, identifying (`--`, `to`) and
+    /// non-identifying (`..`, `optionally to`) lines, relationship labels and
+    /// the `classDef`, `class`, `style` and `direction` statements are
+    /// supported. The configuration is read as by
+    /// [`ERDiagramConfigurationBuilder::parse`].
+    ///
+    /// # Errors
+    ///
+    /// * If the source is not a valid entity-relationship diagram, with the
+    ///   line and column at which the error occurred.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate alloc;
+    /// use alloc::boxed::Box;
+    ///
+    /// use mermaid_builder::prelude::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn core::error::Error>> {
+    ///     let (builder, identifiers) = ERDiagramBuilder::parse(
+    ///         "erDiagram\n  CUSTOMER ||--o{ ORDER : places\n  ORDER {\n    int id\n  }",
+    ///     )?;
+    ///     assert_eq!(identifiers["ORDER"], 1);
+    ///
+    ///     let diagram = ERDiagram::from(builder);
+    ///     let edge = diagram.edges().next().unwrap();
+    ///     assert_eq!(edge.left_arrow_shape(), Some(ArrowShape::ExactlyOne));
+    ///     assert_eq!(edge.right_arrow_shape(), Some(ArrowShape::ZeroOrMore));
+    ///     assert_eq!(edge.label(), Some("places"));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn parse(source: &str) -> Result<(Self, BTreeMap<String, u64>), ParseError> {
+        parser::parse(source)
+    }
+}
+
 use core::fmt;
 
 impl Display for ERDiagram {
@@ -1,8 +1,46 @@
 //! Submodule defining an attribute of an Entity-Relationship (ER) node
 //! for the entity-relationship diagram in Mermaid syntax.
 
-use alloc::string::String;
-use core::fmt::Display;
+use alloc::{string::String, vec::Vec};
+use core::{fmt::Display, str::FromStr};
+
+use crate::errors::NodeError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Enum representing the keys which an entity-relationship attribute may be
+/// part of.
+pub enum AttributeKey {
+    /// A primary key, written as `PK`.
+    PrimaryKey,
+    /// A foreign key, written as `FK`.
+    ForeignKey,
+    /// A unique key, written as `UK`.
+    UniqueKey,
+}
+
+impl Display for AttributeKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AttributeKey::PrimaryKey => write!(f, "PK"),
+            AttributeKey::ForeignKey => write!(f, "FK"),
+            AttributeKey::UniqueKey => write!(f, "UK"),
+        }
+    }
+}
+
+impl FromStr for AttributeKey {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "PK" => Ok(AttributeKey::PrimaryKey),
+            "FK" => Ok(AttributeKey::ForeignKey),
+            "UK" => Ok(AttributeKey::UniqueKey),
+            _ => Err(()),
+        }
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -12,13 +50,77 @@ pub struct EntityRelationshipAttribute {
     name: String,
     /// The type of the class attribute.
     attribute_type: String,
+    /// The keys the attribute is part of.
+    keys: Vec<AttributeKey>,
+    /// The comment of the attribute, if any.
+    comment: Option<String>,
 }
 
 impl EntityRelationshipAttribute {
     /// Creates a new entity-relationship attribute.
     #[must_use]
     pub fn new(attribute_type: String, name: String) -> Self {
-        Self { name, attribute_type }
+        Self { name, attribute_type, keys: Vec::new(), comment: None }
+    }
+
+    /// Marks the attribute as part of the provided key. Marking it twice as
+    /// part of the same key has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mermaid_builder::diagrams::entity_relationship::{
+    ///     AttributeKey, EntityRelationshipAttribute,
+    /// };
+    ///
+    /// let attribute = EntityRelationshipAttribute::new("int".to_string(), "id".to_string())
+    ///     .with_key(AttributeKey::PrimaryKey)
+    ///     .with_key(AttributeKey::ForeignKey);
+    /// assert_eq!(attribute.to_string(), "int id PK, FK");
+    /// ```
+    #[must_use]
+    pub fn with_key(mut self, key: AttributeKey) -> Self {
+        if !self.keys.contains(&key) {
+            self.keys.push(key);
+        }
+        self
+    }
+
+    /// Sets the comment of the attribute.
+    ///
+    /// # Errors
+    ///
+    /// * If the comment contains a double quote or a line break, which
+    ///   cannot be written within the quoted comment.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mermaid_builder::diagrams::entity_relationship::EntityRelationshipAttribute;
+    ///
+    /// let attribute = EntityRelationshipAttribute::new("string".to_string(), "name".to_string())
+    ///     .with_comment("The full name")
+    ///     .unwrap();
+    /// assert_eq!(attribute.to_string(), "string name \"The full name\"");
+    /// ```
+    pub fn with_comment(mut self, comment: impl Into<String>) -> Result<Self, NodeError> {
+        let comment = comment.into();
+        if comment.contains(['"', '\n', '\r']) {
+            return Err(NodeError::InvalidAttributeComment(comment));
+        }
+        self.comment = Some(comment);
+        Ok(self)
+    }
+
+    /// Returns the keys the attribute is part of.
+    pub fn keys(&self) -> impl Iterator<Item = AttributeKey> + '_ {
+        self.keys.iter().copied()
+    }
+
+    /// Returns the comment of the attribute, if any.
+    #[must_use]
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
     }
 
     /// Returns the name of the attribute.
@@ -54,6 +156,48 @@ impl EntityRelationshipAttribute {
 
 impl Display for EntityRelationshipAttribute {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{} {}", self.name, self.attribute_type)
+        write!(f, "{} {}", self.attribute_type, self.name)?;
+        for (index, key) in self.keys.iter().enumerate() {
+            write!(f, "{}{key}", if index == 0 { " " } else { ", " })?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " \"{comment}\"")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_entity_relationship_attribute_display() {
+        let attribute =
+            EntityRelationshipAttribute::new("string".to_string(), "username".to_string());
+        assert_eq!(attribute.to_string(), "string username");
+    }
+
+    #[test]
+    fn test_entity_relationship_attribute_keys_and_comment() -> Result<(), NodeError> {
+        let attribute = EntityRelationshipAttribute::new("int".to_string(), "id".to_string())
+            .with_key(AttributeKey::PrimaryKey)
+            .with_key(AttributeKey::UniqueKey)
+            .with_key(AttributeKey::PrimaryKey)
+            .with_comment("Surrogate key")?;
+        assert_eq!(
+            attribute.keys().collect::<Vec<_>>(),
+            [AttributeKey::PrimaryKey, AttributeKey::UniqueKey]
+        );
+        assert_eq!(attribute.comment(), Some("Surrogate key"));
+        assert_eq!(attribute.to_string(), "int id PK, UK \"Surrogate key\"");
+
+        assert_eq!(
+            attribute.with_comment("a \"quoted\" word"),
+            Err(NodeError::InvalidAttributeComment("a \"quoted\" word".to_string()))
+        );
+        Ok(())
     }
 }
@@ -48,6 +48,14 @@ impl ERNodeBuilder {
             .push(EntityRelationshipAttribute::new(attribute_type.to_string(), name.to_string()));
         self
     }
+
+    /// Adds an attribute, which may carry keys and a comment, to the
+    /// entity-relationship node.
+    #[must_use]
+    pub fn entity_attribute(mut self, attribute: EntityRelationshipAttribute) -> Self {
+        self.class_attributes.push(attribute);
+        self
+    }
 }
 
 impl TryFrom<ERNodeBuilder> for ERNode {
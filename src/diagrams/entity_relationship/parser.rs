@@ -0,0 +1,636 @@
+//! Submodule providing a parser for entity-relationship diagrams written in
+//! the Mermaid syntax, such as `CUSTOMER ||--o{ ORDER : places`.
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    diagrams::entity_relationship::{
+        ERDiagramBuilder, ERDiagramConfigurationBuilder, EREdgeBuilder, ERNodeBuilder,
+        EntityRelationshipAttribute,
+    },
+    errors::{ParseError, ParseErrorKind},
+    shared::{
+        ArrowShape, Direction, LineStyle, StyleProperty,
+        parsing::{
+            Cursor, Position, define_style_classes, label_text, merge_property,
+            parse_configuration, parse_style_properties, parse_value,
+        },
+    },
+    traits::{ConfigurationBuilder, DiagramBuilder, EdgeBuilder, NodeBuilder},
+};
+
+/// The cardinality markers written on the left of a relationship, such as
+/// `||` in `||--o{`, with their arrow shapes.
+const LEFT_MARKERS: [(&str, ArrowShape); 4] = [
+    ("|o", ArrowShape::ZeroOrOne),
+    ("||", ArrowShape::ExactlyOne),
+    ("}o", ArrowShape::ZeroOrMore),
+    ("}|", ArrowShape::OneOrMore),
+];
+
+/// The cardinality markers written on the right of a relationship, such as
+/// `o{` in `||--o{`, with their arrow shapes.
+const RIGHT_MARKERS: [(&str, ArrowShape); 4] = [
+    ("o|", ArrowShape::ZeroOrOne),
+    ("||", ArrowShape::ExactlyOne),
+    ("o{", ArrowShape::ZeroOrMore),
+    ("|{", ArrowShape::OneOrMore),
+];
+
+/// The aliases which may be written instead of the cardinality markers, on
+/// either side of a relationship, with their arrow shapes. Longer aliases
+/// come first, so that `one or more` is not read as `one`.
+const ALIASES: [(&str, ArrowShape); 14] = [
+    ("one or zero", ArrowShape::ZeroOrOne),
+    ("zero or one", ArrowShape::ZeroOrOne),
+    ("one or more", ArrowShape::OneOrMore),
+    ("one or many", ArrowShape::OneOrMore),
+    ("zero or more", ArrowShape::ZeroOrMore),
+    ("zero or many", ArrowShape::ZeroOrMore),
+    ("many(1)", ArrowShape::OneOrMore),
+    ("many(0)", ArrowShape::ZeroOrMore),
+    ("many", ArrowShape::ZeroOrMore),
+    ("1+", ArrowShape::OneOrMore),
+    ("0+", ArrowShape::ZeroOrMore),
+    ("only one", ArrowShape::ExactlyOne),
+    ("one", ArrowShape::ExactlyOne),
+    ("1", ArrowShape::ExactlyOne),
+];
+
+/// The lines of a relationship, with their line styles: identifying
+/// relationships are solid and non-identifying ones are dashed.
+const LINES: [(&str, LineStyle); 6] = [
+    ("--", LineStyle::Solid),
+    ("..", LineStyle::Dashed),
+    (".-", LineStyle::Dashed),
+    ("-.", LineStyle::Dashed),
+    ("optionally to", LineStyle::Dashed),
+    ("to", LineStyle::Solid),
+];
+
+/// Consumes the first of the tokens at the cursor, ignoring case as Mermaid
+/// does, and returns its value. Tokens made of words, such as `one or more`,
+/// must not be directly followed by a letter or a digit, but may be followed
+/// by a line such as `--`.
+fn token<T: Copy>(cursor: &mut Cursor<'_>, tokens: &[(&str, T)]) -> Option<T> {
+    tokens.iter().find_map(|&(token, value)| {
+        let rest = cursor
+            .rest
+            .get(..token.len())
+            .filter(|prefix| prefix.eq_ignore_ascii_case(token))
+            .map(|_| &cursor.rest[token.len()..])?;
+        let word = token.chars().all(|character| character.is_alphanumeric() || character == ' ');
+        if word && rest.starts_with(char::is_alphanumeric) {
+            return None;
+        }
+        cursor.rest = rest;
+        Some(value)
+    })
+}
+
+/// An entity whose statements are still being parsed.
+struct PendingEntity {
+    /// The identifier of the entity in the source.
+    identifier: String,
+    /// The label of the entity, when it differs from its identifier.
+    label: Option<String>,
+    /// The attributes of the entity.
+    attributes: Vec<EntityRelationshipAttribute>,
+    /// The names of the style classes of the entity, with their positions.
+    classes: Vec<(String, Position)>,
+    /// The style properties of the entity.
+    properties: Vec<StyleProperty>,
+    /// The position at which the entity is first mentioned.
+    position: Position,
+}
+
+/// A relationship whose entities are still being parsed.
+struct PendingRelationship {
+    /// The index of the source entity.
+    source: usize,
+    /// The index of the destination entity.
+    destination: usize,
+    /// The builder of the edge, without its source and destination.
+    builder: EREdgeBuilder,
+    /// The position of the statement declaring the relationship.
+    position: Position,
+}
+
+#[derive(Default)]
+/// Parser of the statements following the entity-relationship diagram
+/// header.
+struct ERDiagramParser {
+    /// The builder receiving the style classes, the entities and the edges.
+    builder: ERDiagramBuilder,
+    /// The configuration read from the frontmatter and the init directives.
+    configuration: ERDiagramConfigurationBuilder,
+    /// The direction of the diagram, if any was written.
+    direction: Option<Direction>,
+    /// The identifiers of the entities in the source, mapped to their indices.
+    identifiers: BTreeMap<String, usize>,
+    /// The entities, in order of first mention, whose indices are their
+    /// numeric identifiers.
+    nodes: Vec<PendingEntity>,
+    /// The relationships, in order of declaration.
+    relationships: Vec<PendingRelationship>,
+    /// The entity whose block is open, with the position of its declaration.
+    block: Option<(usize, Position)>,
+}
+
+impl ERDiagramParser {
+    /// Returns the index of the entity written as `identifier`, declaring the
+    /// entity when it is first mentioned.
+    fn mention(&mut self, identifier: &str, position: Position) -> usize {
+        if let Some(&id) = self.identifiers.get(identifier) {
+            return id;
+        }
+        let id = self.nodes.len();
+        self.identifiers.insert(identifier.to_string(), id);
+        self.nodes.push(PendingEntity {
+            identifier: identifier.to_string(),
+            label: None,
+            attributes: Vec::new(),
+            classes: Vec::new(),
+            properties: Vec::new(),
+            position,
+        });
+        id
+    }
+
+    /// Returns the entity written as `identifier`, which must have been
+    /// mentioned before.
+    fn mentioned(
+        &mut self,
+        cursor: &Cursor<'_>,
+        identifier: &str,
+    ) -> Result<&mut PendingEntity, ParseError> {
+        let id = *self.identifiers.get(identifier).ok_or_else(|| {
+            cursor
+                .position_of(identifier)
+                .error(ParseErrorKind::UnknownIdentifier(identifier.to_string()))
+        })?;
+        Ok(&mut self.nodes[id])
+    }
+
+    /// Consumes an entity at the cursor, such as `CUSTOMER`, `"Line item"`,
+    /// `p[Person]` or `CUSTOMER:::important`, and returns its index.
+    fn entity(&mut self, cursor: &mut Cursor<'_>) -> Result<usize, ParseError> {
+        let position = cursor.position();
+        let identifier = if cursor.eat("\"") {
+            cursor
+                .until("\"")
+                .ok_or_else(|| position.error(ParseErrorKind::UnclosedBlock("\"".to_string())))?
+        } else {
+            cursor.identifier().ok_or_else(|| cursor.unexpected())?
+        };
+        let id = self.mention(identifier, position);
+
+        if cursor.eat("[") {
+            let opening = cursor.position();
+            let label = cursor
+                .until("]")
+                .ok_or_else(|| opening.error(ParseErrorKind::UnclosedBlock("[".to_string())))?;
+            self.nodes[id].label = Some(label_text(label).to_string());
+        }
+        if cursor.eat(":::") {
+            let name_position = cursor.position();
+            let name = cursor.identifier().ok_or_else(|| cursor.unexpected())?;
+            let classes = &mut self.nodes[id].classes;
+            if !classes.iter().any(|(existing, _)| existing == name) {
+                classes.push((name.to_string(), name_position));
+            }
+        }
+        Ok(id)
+    }
+
+    /// Parses the statement at the cursor.
+    fn statement(&mut self, mut cursor: Cursor<'_>) -> Result<(), ParseError> {
+        if let Some((id, _)) = self.block {
+            return self.block_statement(cursor, id);
+        }
+
+        let statement = cursor.rest;
+        let (keyword, rest) = statement.split_once(char::is_whitespace).unwrap_or((statement, ""));
+        let rest = rest.trim();
+        let unexpected = || cursor.unexpected();
+
+        match keyword {
+            "classDef" => define_style_classes(&mut self.builder, &cursor, rest)?,
+            "class" => {
+                let (identifiers, name) =
+                    rest.rsplit_once(char::is_whitespace).ok_or_else(unexpected)?;
+                let position = cursor.position_of(name);
+                for identifier in identifiers.split(',') {
+                    let node = self.mentioned(&cursor, identifier.trim())?;
+                    if !node.classes.iter().any(|(existing, _)| existing == name) {
+                        node.classes.push((name.to_string(), position));
+                    }
+                }
+            }
+            "style" => {
+                let (identifier, properties) =
+                    rest.split_once(char::is_whitespace).ok_or_else(unexpected)?;
+                let properties =
+                    parse_style_properties(cursor.number, cursor.line, properties.trim())?;
+                let node = self.mentioned(&cursor, identifier)?;
+                for property in properties {
+                    merge_property(&mut node.properties, property);
+                }
+            }
+            "direction" => {
+                self.direction = Some(parse_value(cursor.number, cursor.line, rest)?);
+            }
+            _ => {
+                let position = cursor.position();
+                let source = self.entity(&mut cursor)?;
+                cursor.skip_whitespace();
+                if cursor.eat("{") {
+                    cursor.skip_whitespace();
+                    if !cursor.eat("}") {
+                        self.block = Some((source, position));
+                    }
+                    cursor.skip_whitespace();
+                    if !cursor.rest.is_empty() {
+                        return Err(cursor.unexpected());
+                    }
+                } else if !cursor.rest.is_empty() {
+                    self.relationship(cursor, source)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses a statement within the block of the entity with the provided
+    /// index, such as `string name PK, FK "The name"`.
+    fn block_statement(&mut self, mut cursor: Cursor<'_>, id: usize) -> Result<(), ParseError> {
+        let statement = cursor;
+        if cursor.rest == "}" {
+            self.block = None;
+            return Ok(());
+        }
+
+        let attribute_type = cursor.take_while(|character| !character.is_whitespace());
+        cursor.skip_whitespace();
+        let name = cursor.take_while(|character| !character.is_whitespace() && character != '"');
+        if name.is_empty() {
+            return Err(statement.unexpected());
+        }
+        let mut attribute =
+            EntityRelationshipAttribute::new(attribute_type.to_string(), name.to_string());
+
+        cursor.skip_whitespace();
+        while !cursor.rest.is_empty() && !cursor.rest.starts_with('"') {
+            let key = cursor.take_while(|character| character.is_ascii_alphanumeric());
+            if key.is_empty() {
+                return Err(cursor.unexpected());
+            }
+            attribute = attribute.with_key(parse_value(cursor.number, cursor.line, key)?);
+            cursor.skip_whitespace();
+            if cursor.eat(",") {
+                cursor.skip_whitespace();
+            } else {
+                break;
+            }
+        }
+        let position = cursor.position();
+        if cursor.eat("\"") {
+            let comment = cursor
+                .until("\"")
+                .ok_or_else(|| position.error(ParseErrorKind::UnclosedBlock("\"".to_string())))?;
+            attribute = attribute.with_comment(comment).map_err(position.diagram())?;
+            cursor.skip_whitespace();
+        }
+        if !cursor.rest.is_empty() {
+            return Err(cursor.unexpected());
+        }
+
+        self.nodes[id].attributes.push(attribute);
+        Ok(())
+    }
+
+    /// Parses a relationship, such as `||--o{ ORDER : places`, whose source
+    /// entity has been parsed and which continues at the cursor.
+    fn relationship(&mut self, mut cursor: Cursor<'_>, source: usize) -> Result<(), ParseError> {
+        let position = cursor.position();
+        let invalid = |cursor: &Cursor<'_>| {
+            let token = cursor.rest.split_whitespace().next().unwrap_or_default();
+            cursor.position_of(token).invalid(token)
+        };
+
+        let left_shape = token(&mut cursor, &LEFT_MARKERS)
+            .or_else(|| token(&mut cursor, &ALIASES))
+            .ok_or_else(|| invalid(&cursor))?;
+        cursor.skip_whitespace();
+        let line_style = token(&mut cursor, &LINES).ok_or_else(|| invalid(&cursor))?;
+        cursor.skip_whitespace();
+        let right_shape = token(&mut cursor, &RIGHT_MARKERS)
+            .or_else(|| token(&mut cursor, &ALIASES))
+            .ok_or_else(|| invalid(&cursor))?;
+        cursor.skip_whitespace();
+
+        let destination = self.entity(&mut cursor)?;
+        cursor.skip_whitespace();
+        let label = if cursor.eat(":") { Some(label_text(cursor.rest)) } else { None };
+        if label.is_none() && !cursor.rest.is_empty() {
+            return Err(cursor.unexpected());
+        }
+
+        let mut builder = EREdgeBuilder::default()
+            .line_style(line_style)
+            .left_arrow_shape(left_shape)
+            .and_then(|builder| builder.right_arrow_shape(right_shape))
+            .map_err(position.diagram())?;
+        if let Some(label) = label.filter(|label| !label.is_empty()) {
+            builder = builder.label(label).map_err(position.diagram())?;
+        }
+
+        self.relationships.push(PendingRelationship { source, destination, builder, position });
+        Ok(())
+    }
+
+    /// Builds the entity-relationship diagram builder from the parsed
+    /// statements.
+    fn finish(self) -> Result<(ERDiagramBuilder, BTreeMap<String, u64>), ParseError> {
+        if let Some((_, position)) = self.block {
+            return Err(position.error(ParseErrorKind::UnclosedBlock("{".to_string())));
+        }
+
+        // Mermaid lays entity-relationship diagrams out from top to bottom
+        // unless told otherwise.
+        let configuration =
+            self.configuration.direction(self.direction.unwrap_or(Direction::TopToBottom));
+        let mut builder = self
+            .builder
+            .configuration(configuration)
+            .map_err(|error| ParseError::new(1, 1, ParseErrorKind::Diagram(error)))?;
+
+        let mut nodes = Vec::with_capacity(self.nodes.len());
+        for (id, node) in self.nodes.into_iter().enumerate() {
+            let position = node.position;
+            let mut node_builder = ERNodeBuilder::default()
+                .label(node.label.as_ref().unwrap_or(&node.identifier))
+                .map_err(position.diagram())?
+                .id(id as u64);
+            for attribute in node.attributes {
+                node_builder = node_builder.entity_attribute(attribute);
+            }
+            for (name, class_position) in node.classes {
+                let style_class = builder.get_style_class_by_name(&name).ok_or_else(|| {
+                    class_position.error(ParseErrorKind::UnknownIdentifier(name.clone()))
+                })?;
+                node_builder = node_builder.style_class(style_class).map_err(position.diagram())?;
+            }
+            for property in node.properties {
+                node_builder = node_builder.style_property(property).map_err(position.diagram())?;
+            }
+            nodes.push(builder.node(node_builder).map_err(position.diagram())?);
+        }
+
+        for relationship in self.relationships {
+            let position = relationship.position;
+            let edge = relationship
+                .builder
+                .source(nodes[relationship.source].clone())
+                .and_then(|edge| edge.destination(nodes[relationship.destination].clone()))
+                .map_err(position.diagram())?;
+            builder.edge(edge).map_err(position.diagram())?;
+        }
+
+        let identifiers =
+            self.identifiers.into_iter().map(|(identifier, id)| (identifier, id as u64)).collect();
+        Ok((builder, identifiers))
+    }
+}
+
+/// Parses an entity-relationship diagram written in the Mermaid syntax.
+pub(super) fn parse(source: &str) -> Result<(ERDiagramBuilder, BTreeMap<String, u64>), ParseError> {
+    let (configuration, lines) = parse_configuration(source)?;
+    let mut lines = lines.into_iter();
+    let Some((number, line)) = lines.next() else {
+        return Err(ParseError::new(
+            source.lines().count() + 1,
+            1,
+            ParseErrorKind::MissingHeader("erDiagram".to_string()),
+        ));
+    };
+    let header = line.trim();
+    if header != "erDiagram" {
+        return Err(Position::of(number, line, header)
+            .error(ParseErrorKind::MissingHeader("erDiagram".to_string())));
+    }
+
+    let mut parser = ERDiagramParser { configuration, ..ERDiagramParser::default() };
+    for (number, line) in lines {
+        parser.statement(Cursor { number, line, rest: line.trim() })?;
+    }
+    parser.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString, vec::Vec};
+
+    use super::*;
+    use crate::{
+        diagrams::entity_relationship::ERDiagram,
+        shared::{Renderer, StyleClass, generic_configuration::Look},
+        traits::{Configuration, Diagram, Edge, Node},
+    };
+
+    /// Parses the source and returns the lines of the displayed diagram,
+    /// after its frontmatter.
+    fn displayed(source: &str) -> Result<Vec<String>, ParseError> {
+        let (builder, _) = parse(source)?;
+        Ok(ERDiagram::from(builder).to_string().lines().skip(6).map(str::to_string).collect())
+    }
+
+    #[test]
+    fn test_parse_entities() -> Result<(), Box<dyn core::error::Error>> {
+        let (builder, identifiers) = parse(
+            "erDiagram\n  %% Orders\n  CUSTOMER {\n    string name\n    varchar(255) address\n  }\n  \
+             \"Line item\" {}\n  p[\"Person\"] {\n    int age\n    int id PK,FK\n    \
+             string email UK \"Work address\"\n    string nick \"\"\n  }\n  CUSTOMER {\n    \
+             string email\n  }\n  ORDER",
+        )?;
+        assert_eq!(
+            identifiers.into_iter().collect::<Vec<_>>(),
+            [
+                ("CUSTOMER".to_string(), 0),
+                ("Line item".to_string(), 1),
+                ("ORDER".to_string(), 3),
+                ("p".to_string(), 2)
+            ]
+        );
+        let lines: Vec<_> =
+            ERDiagram::from(builder).to_string().lines().skip(6).map(str::to_string).collect();
+        assert_eq!(
+            lines,
+            [
+                "erDiagram",
+                "  direction TB",
+                "  v0[\"CUSTOMER\"] {",
+                "      string name",
+                "      varchar(255) address",
+                "      string email",
+                "  }",
+                "  v1[\"Line item\"]",
+                "  v2[\"Person\"] {",
+                "      int age",
+                "      int id PK, FK",
+                "      string email UK \"Work address\"",
+                "      string nick \"\"",
+                "  }",
+                "  v3[\"ORDER\"]",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_relationships() -> Result<(), Box<dyn core::error::Error>> {
+        let lines = displayed(
+            "erDiagram\n  direction LR\n  CUSTOMER ||--o{ ORDER : places\n  \
+             ORDER ||--|{ LINE-ITEM : contains\n  CUSTOMER }|..|{ DELIVERY-ADDRESS : uses\n  \
+             A|o--o|B : \"two words\"\n  A }o.-o{ B\n  A }|-.o| B : `md`\n  \
+             A one or more to zero or many B : x\n  A only one optionally to 1+ B : y\n  \
+             A 1 to many(1) B : z\n  A zero or one to many B : w\n  \
+             A one or more--zero or one B : v\n  A 0+..Only One B : u",
+        )?;
+        assert_eq!(lines[1], "  direction LR");
+        assert_eq!(
+            &lines[8..],
+            [
+                "  v0 ||--o{ v1 : \"places\"",
+                "  v1 ||--|{ v2 : \"contains\"",
+                "  v0 }|..|{ v3 : \"uses\"",
+                "  v4 |o--o| v5 : \"two words\"",
+                "  v4 }o..o{ v5 : \"\"",
+                "  v4 }|..o| v5 : \"md\"",
+                "  v4 }|--o{ v5 : \"x\"",
+                "  v4 ||..|{ v5 : \"y\"",
+                "  v4 ||--|{ v5 : \"z\"",
+                "  v4 |o--o{ v5 : \"w\"",
+                "  v4 }|--o| v5 : \"v\"",
+                "  v4 }o..|| v5 : \"u\"",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_statements() -> Result<(), Box<dyn core::error::Error>> {
+        let (builder, identifiers) = parse(
+            "erDiagram\n  A:::hot ||--o{ B : has\n  classDef hot,warm fill:#ff0000\n  \
+             classDef cold stroke:#0000ff\n  class A,B cold\n  style B fill:#00ff00,stroke-width:2px",
+        )?;
+        let diagram = ERDiagram::from(builder);
+        assert_eq!(diagram.style_classes().count(), 3);
+        let a = diagram.get_node_by_id(identifiers["A"]).ok_or("A")?;
+        let b = diagram.get_node_by_id(identifiers["B"]).ok_or("B")?;
+        assert_eq!(a.classes().map(StyleClass::name).collect::<Vec<_>>(), ["hot", "cold"]);
+        assert_eq!(b.classes().map(StyleClass::name).collect::<Vec<_>>(), ["cold"]);
+        assert_eq!(b.styles().count(), 2);
+        let edge = diagram.edges().next().ok_or("edge")?;
+        assert_eq!(edge.line_style(), LineStyle::Solid);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_configuration() -> Result<(), Box<dyn core::error::Error>> {
+        let (builder, _) = parse(
+            "%%{init: {'layout': 'elk', 'look': 'handDrawn'}}%%\nerDiagram\n  CUSTOMER ||--o{ ORDER : places",
+        )?;
+        let diagram = ERDiagram::from(builder);
+        let configuration = diagram.configuration();
+        assert_eq!(configuration.renderer(), Renderer::EclipseLayoutKernel);
+        assert_eq!(configuration.look(), Look::HandDrawn);
+        assert_eq!(configuration.direction(), Direction::TopToBottom);
+
+        let (builder, _) = parse(&diagram.to_string())?;
+        assert_eq!(ERDiagram::from(builder).configuration(), configuration);
+
+        let (builder, _) = parse("%%{init: {'er': {'layoutDirection': 'LR'}}}%%\nerDiagram")?;
+        assert_eq!(ERDiagram::from(builder).configuration().direction(), Direction::TopToBottom);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for (source, line, column, kind) in [
+            ("", 1, 1, ParseErrorKind::MissingHeader("erDiagram".to_string())),
+            ("classDiagram", 1, 1, ParseErrorKind::MissingHeader("erDiagram".to_string())),
+            ("erDiagram\n  direction XY", 2, 13, ParseErrorKind::InvalidValue("XY".to_string())),
+            ("erDiagram\n  A -- B", 2, 5, ParseErrorKind::InvalidValue("--".to_string())),
+            ("erDiagram\n  A ||-> B", 2, 7, ParseErrorKind::InvalidValue("->".to_string())),
+            ("erDiagram\n  A ||--o B", 2, 9, ParseErrorKind::InvalidValue("o".to_string())),
+            ("erDiagram\n  A ||--||", 2, 11, ParseErrorKind::UnexpectedStatement(String::new())),
+            (
+                "erDiagram\n  A ||--|| B places",
+                2,
+                14,
+                ParseErrorKind::UnexpectedStatement("places".to_string()),
+            ),
+            (
+                "erDiagram\n  \"Line item ||--|| B",
+                2,
+                3,
+                ParseErrorKind::UnclosedBlock("\"".to_string()),
+            ),
+            (
+                "erDiagram\n  A {\n    string name",
+                2,
+                3,
+                ParseErrorKind::UnclosedBlock("{".to_string()),
+            ),
+            (
+                "erDiagram\n  A {\n    string\n  }",
+                3,
+                5,
+                ParseErrorKind::UnexpectedStatement("string".to_string()),
+            ),
+            (
+                "erDiagram\n  A {\n    string name PK FK\n  }",
+                3,
+                20,
+                ParseErrorKind::UnexpectedStatement("FK".to_string()),
+            ),
+            (
+                "erDiagram\n  A {\n    string name XK\n  }",
+                3,
+                17,
+                ParseErrorKind::InvalidValue("XK".to_string()),
+            ),
+            (
+                "erDiagram\n  A {\n    string name \"The name\n  }",
+                3,
+                17,
+                ParseErrorKind::UnclosedBlock("\"".to_string()),
+            ),
+            (
+                "erDiagram\n  A:::missing",
+                2,
+                7,
+                ParseErrorKind::UnknownIdentifier("missing".to_string()),
+            ),
+            (
+                "erDiagram\n  style Z fill:#ff0000",
+                2,
+                9,
+                ParseErrorKind::UnknownIdentifier("Z".to_string()),
+            ),
+        ] {
+            let error = parse(source).err().unwrap_or_else(|| panic!("{source}"));
+            assert_eq!(
+                (error.line(), error.column(), error.kind()),
+                (line, column, &kind),
+                "{source}"
+            );
+        }
+    }
+}
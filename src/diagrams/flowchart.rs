@@ -5,8 +5,13 @@ mod configuration;
 mod curve_styles;
 mod flowchart_edge;
 mod flowchart_node;
+mod general_parser;
+mod parser;
 use alloc::{rc::Rc, vec::Vec};
-use core::fmt::{self, Display};
+use core::{
+    fmt::{self, Display},
+    str::FromStr,
+};
 
 pub use builder::FlowchartBuilder;
 pub use configuration::{FlowchartConfiguration, FlowchartConfigurationBuilder};
@@ -15,6 +20,7 @@ pub use flowchart_edge::{FlowchartEdge, FlowchartEdgeBuilder};
 pub use flowchart_node::{FlowchartNode, FlowchartNodeBuilder, FlowchartNodeShape};
 
 use crate::{
+    errors::ParseError,
     shared::generic_diagram::GenericDiagram,
     traits::{Configuration, Diagram, Node, edge::Edge},
 };
@@ -73,6 +79,46 @@ impl Diagram for Flowchart {
     }
 }
 
+/// Parses the Mermaid syntax produced when displaying a [`Flowchart`], so that
+/// displaying a flowchart and parsing it back yields the same flowchart,
+/// provided that every style class of the flowchart is used by a node or an
+/// edge: style classes which no node or edge uses are not displayed, and are
+/// therefore missing from the parsed flowchart.
+///
+/// Nodes are added to the flowchart in ascending order of their identifiers,
+/// which is the order in which they were originally added whenever their
+/// identifiers were assigned by the [`FlowchartBuilder`].
+///
+/// # Example
+///
+/// ```
+/// extern crate alloc;
+/// use alloc::{boxed::Box, string::ToString};
+///
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn core::error::Error>> {
+///     let mut builder = FlowchartBuilder::default();
+///     let start = builder.node(FlowchartNodeBuilder::default().label("Start")?)?;
+///     let end = builder.node(
+///         FlowchartNodeBuilder::default().label("End")?.shape(FlowchartNodeShape::Circle),
+///     )?;
+///     builder.edge(FlowchartEdgeBuilder::default().source(start)?.destination(end)?)?;
+///     let flowchart = Flowchart::from(builder);
+///
+///     let parsed: Flowchart = flowchart.to_string().parse()?;
+///     assert_eq!(parsed, flowchart);
+///     Ok(())
+/// }
+/// ```
+impl FromStr for Flowchart {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parser::parse(s)
+    }
+}
+
 impl Display for Flowchart {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use crate::traits::TabbedDisplay;
@@ -95,14 +141,15 @@ impl crate::traits::TabbedDisplay for Flowchart {
             style_class.fmt_tabbed(f, tab_count + 1)?;
         }
 
+        // Nodes within subgraphs, however deeply nested, are displayed by
+        // their subgraphs.
         let mut subgraph_nodes = Vec::new();
-        for node in self.nodes() {
-            if subgraph_nodes.contains(&node) {
-                continue;
-            }
-            subgraph_nodes.extend(node.subnodes());
+        let mut pending: Vec<&FlowchartNode> =
+            self.nodes().flat_map(FlowchartNode::subnodes).collect();
+        while let Some(node) = pending.pop() {
+            pending.extend(node.subnodes());
+            subgraph_nodes.push(node);
         }
-        subgraph_nodes.sort_unstable();
 
         for node in self.nodes() {
             if subgraph_nodes.contains(&node) {
@@ -1,6 +1,9 @@
 //! Submodule providing a builder for flowchart diagrams in Mermaid syntax.
 
+use alloc::{collections::BTreeMap, string::String};
+
 use crate::{
+    errors::ParseError,
     prelude::{
         Flowchart, FlowchartConfiguration, FlowchartConfigurationBuilder, FlowchartEdge,
         FlowchartEdgeBuilder, FlowchartNode, FlowchartNodeBuilder,
@@ -17,6 +20,49 @@ pub struct FlowchartBuilder {
     generic: GenericDiagramBuilder<FlowchartNode, FlowchartEdge, FlowchartConfiguration>,
 }
 
+impl FlowchartBuilder {
+    /// Parses a flowchart written by hand in the Mermaid syntax, returning
+    /// its builder together with the numeric identifiers assigned to the node
+    /// identifiers of the source, in order of first appearance.
+    ///
+    /// The `graph` and `flowchart` headers, the bracket shape syntax, chained
+    /// links, `&` groups, `:::` classes, subgraphs and the `classDef`,
+    /// `class`, `style`, `linkStyle` and `click` statements are supported.
+    /// Lengths follow Mermaid, in which `-->` and `---` have length one. The
+    /// configuration is read as by [`FlowchartConfigurationBuilder::parse`].
+    ///
+    /// # Errors
+    ///
+    /// * If the source is not a valid flowchart, with the line and column at
+    ///   which the error occurred.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate alloc;
+    /// use alloc::boxed::Box;
+    ///
+    /// use mermaid_builder::prelude::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn core::error::Error>> {
+    ///     let (mut builder, identifiers) =
+    ///         FlowchartBuilder::parse("graph LR\n  A[Start] -->|yes| B{Decide} & C")?;
+    ///     assert_eq!(identifiers["B"], 1);
+    ///     assert_eq!(builder.number_of_edges(), 2);
+    ///
+    ///     let end = builder.node(FlowchartNodeBuilder::default().label("End")?)?;
+    ///     let decide = builder.get_node_by_id(identifiers["B"]).unwrap();
+    ///     builder.edge(FlowchartEdgeBuilder::default().source(decide)?.destination(end)?)?;
+    ///     let flowchart = Flowchart::from(builder);
+    ///     assert_eq!(flowchart.nodes().count(), 4);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn parse(source: &str) -> Result<(Self, BTreeMap<String, u64>), ParseError> {
+        super::general_parser::parse(source)
+    }
+}
+
 impl From<FlowchartBuilder> for Flowchart {
     fn from(builder: FlowchartBuilder) -> Self {
         let generic = builder.generic.into();
@@ -3,6 +3,7 @@
 
 mod builder;
 
+use alloc::{collections::BTreeMap, string::String};
 use core::fmt::Display;
 
 pub use builder::FlowchartConfigurationBuilder;
@@ -16,7 +17,7 @@ use crate::{
     traits::Configuration,
 };
 
-#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Represents the configuration for a flowchart diagram in Mermaid syntax.
 pub struct FlowchartConfiguration {
@@ -30,10 +31,60 @@ pub struct FlowchartConfiguration {
     curve_style: CurveStyle,
 }
 
+impl Default for FlowchartConfiguration {
+    fn default() -> Self {
+        // Mermaid enables both html labels and markdown auto wrapping by
+        // default.
+        Self {
+            generic: GenericConfiguration::default(),
+            markdown_auto_wrap: true,
+            html_labels: true,
+            curve_style: CurveStyle::default(),
+        }
+    }
+}
+
+impl FlowchartConfiguration {
+    #[must_use]
+    /// Returns whether markdown labels are automatically wrapped.
+    pub fn markdown_auto_wrap(&self) -> bool {
+        self.markdown_auto_wrap
+    }
+
+    #[must_use]
+    /// Returns whether html labels are enabled in the flowchart.
+    pub fn html_labels(&self) -> bool {
+        self.html_labels
+    }
+
+    #[must_use]
+    /// Returns the curve style used for edges in the flowchart.
+    pub fn curve_style(&self) -> &CurveStyle {
+        &self.curve_style
+    }
+
+    #[must_use]
+    /// Returns the options which are not modelled, such as `logLevel` or
+    /// `flowchart.nodeSpacing`, keyed by their dotted path within the `config` section,
+    /// with their values written as in an init directive.
+    pub fn extra_options(&self) -> &BTreeMap<String, String> {
+        self.generic.extra_options()
+    }
+}
+
 impl Display for FlowchartConfiguration {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if self.generic.title().is_none() && self.renderer() == Renderer::default() {
-            return Ok(());
+        // The direction is written in the flowchart header, not in the
+        // frontmatter.
+        if self.title().is_none()
+            && self.renderer() == Renderer::default()
+            && self.theme() == Theme::default()
+            && self.look() == Look::default()
+            && self.html_labels
+            && self.markdown_auto_wrap
+            && self.curve_style == CurveStyle::default()
+        {
+            return self.generic.fmt_extra_options(f);
         }
         writeln!(f, "---")?;
         writeln!(f, "config:")?;
@@ -41,12 +92,22 @@ impl Display for FlowchartConfiguration {
         writeln!(f, "  look: {}", self.look())?;
         writeln!(f, "  flowchart:")?;
         writeln!(f, "    defaultRenderer: \"{}\"", self.renderer())?;
+        // Options are only written when they differ from Mermaid's defaults.
+        if !self.html_labels {
+            writeln!(f, "    htmlLabels: false")?;
+        }
+        if !self.markdown_auto_wrap {
+            writeln!(f, "    markdownAutoWrap: false")?;
+        }
+        if self.curve_style != CurveStyle::default() {
+            writeln!(f, "    curve: {}", self.curve_style)?;
+        }
         if let Some(title) = &self.generic.title() {
             writeln!(f, "title: {title}")?;
         }
         writeln!(f, "---")?;
 
-        Ok(())
+        self.generic.fmt_extra_options(f)
     }
 }
 
@@ -104,6 +165,49 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_flowchart_configuration_display_options() -> Result<(), Box<dyn core::error::Error>> {
+        let config = FlowchartConfigurationBuilder::default()
+            .html_labels(false)
+            .curve_style(CurveStyle::StepAfter)
+            .build()?;
+
+        assert_eq!(
+            format!("{config}"),
+            concat!(
+                "---\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "  flowchart:\n",
+                "    defaultRenderer: \"dagre\"\n",
+                "    htmlLabels: false\n",
+                "    curve: stepAfter\n",
+                "---\n",
+            )
+        );
+
+        let config = FlowchartConfigurationBuilder::default().title("Only a title")?.build()?;
+        assert_eq!(
+            format!("{config}"),
+            concat!(
+                "---\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "  flowchart:\n",
+                "    defaultRenderer: \"dagre\"\n",
+                "title: Only a title\n",
+                "---\n",
+            )
+        );
+
+        let config =
+            FlowchartConfigurationBuilder::default().direction(Direction::TopToBottom).build()?;
+        assert_eq!(format!("{config}"), "");
+        Ok(())
+    }
+
     #[test]
     fn test_flowchart_configuration_traits() -> Result<(), Box<dyn core::error::Error>> {
         let config = FlowchartConfigurationBuilder::default()
@@ -5,12 +5,15 @@ use alloc::string::ToString;
 
 use crate::{
     diagrams::flowchart::{configuration::FlowchartConfiguration, curve_styles::CurveStyle},
-    errors::ConfigError,
-    shared::generic_configuration::GenericConfigurationBuilder,
+    errors::{ConfigError, ParseError},
+    shared::{
+        generic_configuration::GenericConfigurationBuilder,
+        parsing::{ConfigurationEntry, ParsableConfiguration, parse_configuration},
+    },
     traits::ConfigurationBuilder,
 };
 
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Builder for creating flowchart configurations with various properties.
 pub struct FlowchartConfigurationBuilder {
@@ -24,7 +27,60 @@ pub struct FlowchartConfigurationBuilder {
     curve_style: CurveStyle,
 }
 
+impl Default for FlowchartConfigurationBuilder {
+    fn default() -> Self {
+        // Mermaid enables both html labels and markdown auto wrapping by
+        // default.
+        Self {
+            generic: GenericConfigurationBuilder::default(),
+            html_labels: true,
+            markdown_auto_wrap: true,
+            curve_style: CurveStyle::default(),
+        }
+    }
+}
+
 impl FlowchartConfigurationBuilder {
+    /// Parses the configuration of a flowchart written in the Mermaid syntax
+    /// from its `---` frontmatter and its `%%{init: {...}}%%` directives,
+    /// ignoring its statements.
+    ///
+    /// The `title`, `theme`, `look` and `layout` keys are supported, as are
+    /// the `defaultRenderer`, `htmlLabels`, `markdownAutoWrap` and `curve`
+    /// keys of the `flowchart` section. Other keys of the `config` section,
+    /// such as `themeVariables` or `nodeSpacing`, are kept as
+    /// [`extra_options`](FlowchartConfiguration::extra_options), which are
+    /// written back as an init directive.
+    ///
+    /// # Errors
+    ///
+    /// * If the frontmatter or a directive is malformed, sets an invalid value
+    ///   or has a key outside of the `config` section other than `title`,
+    ///   with the line and column at which the error occurred.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate alloc;
+    /// use alloc::boxed::Box;
+    ///
+    /// use mermaid_builder::prelude::*;
+    ///
+    /// fn main() -> Result<(), Box<dyn core::error::Error>> {
+    ///     let configuration = FlowchartConfigurationBuilder::parse(
+    ///         "---\ntitle: Pipeline\n---\n%%{init: {'theme': 'forest', 'flowchart': {'curve': 'basis'}}}%%\ngraph LR",
+    ///     )?
+    ///     .build()?;
+    ///     assert_eq!(configuration.title(), Some("Pipeline"));
+    ///     assert_eq!(configuration.theme().to_string(), "forest");
+    ///     assert_eq!(configuration.curve_style(), &CurveStyle::Basis);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, ParseError> {
+        parse_configuration(source).map(|(configuration, _)| configuration)
+    }
+
     #[must_use]
     /// Sets whether to enable html labels in the flowchart.
     pub fn html_labels(mut self, enable: bool) -> Self {
@@ -74,6 +130,27 @@ impl TryFrom<FlowchartConfigurationBuilder> for FlowchartConfiguration {
     }
 }
 
+impl ParsableConfiguration for FlowchartConfigurationBuilder {
+    const SECTION: Option<&'static str> = Some("flowchart");
+
+    fn shared(
+        mut self,
+        update: impl FnOnce(GenericConfigurationBuilder) -> GenericConfigurationBuilder,
+    ) -> Self {
+        self.generic = update(self.generic);
+        self
+    }
+
+    fn specific(self, key: &str, entry: &ConfigurationEntry<'_>) -> Result<Self, ParseError> {
+        Ok(match key {
+            "htmlLabels" => self.html_labels(entry.value()?),
+            "markdownAutoWrap" => self.markdown_auto_wrap(entry.value()?),
+            "curve" => self.curve_style(entry.value()?),
+            _ => return self.preserve(entry),
+        })
+    }
+}
+
 impl ConfigurationBuilder for FlowchartConfigurationBuilder {
     type Configuration = FlowchartConfiguration;
     type Error = ConfigError;
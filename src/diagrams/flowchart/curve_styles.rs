@@ -51,6 +51,28 @@ impl core::fmt::Display for CurveStyle {
     }
 }
 
+impl core::str::FromStr for CurveStyle {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "basis" => Ok(CurveStyle::Basis),
+            "bumpX" => Ok(CurveStyle::BumpX),
+            "bumpY" => Ok(CurveStyle::BumpY),
+            "cardinal" => Ok(CurveStyle::Cardinal),
+            "catmullRom" => Ok(CurveStyle::CatmullRom),
+            "linear" => Ok(CurveStyle::Linear),
+            "monotoneX" => Ok(CurveStyle::MonotoneX),
+            "monotoneY" => Ok(CurveStyle::MonotoneY),
+            "natural" => Ok(CurveStyle::Natural),
+            "step" => Ok(CurveStyle::Step),
+            "stepAfter" => Ok(CurveStyle::StepAfter),
+            "stepBefore" => Ok(CurveStyle::StepBefore),
+            _ => Err(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::ToString;
@@ -72,4 +94,25 @@ mod tests {
         assert_eq!(CurveStyle::StepAfter.to_string(), "stepAfter");
         assert_eq!(CurveStyle::StepBefore.to_string(), "stepBefore");
     }
+
+    #[test]
+    fn test_curve_style_from_str() {
+        for style in [
+            CurveStyle::Basis,
+            CurveStyle::BumpX,
+            CurveStyle::BumpY,
+            CurveStyle::Cardinal,
+            CurveStyle::CatmullRom,
+            CurveStyle::Linear,
+            CurveStyle::MonotoneX,
+            CurveStyle::MonotoneY,
+            CurveStyle::Natural,
+            CurveStyle::Step,
+            CurveStyle::StepAfter,
+            CurveStyle::StepBefore,
+        ] {
+            assert_eq!(style.to_string().parse(), Ok(style));
+        }
+        assert_eq!("bumpx".parse::<CurveStyle>(), Err(()));
+    }
 }
@@ -159,6 +159,12 @@ impl EdgeBuilder for FlowchartEdgeBuilder {
     }
 
     fn label<S: ToString>(mut self, label: S) -> Result<Self, Self::Error> {
+        // Flowchart statements end at line breaks, so the label could not be
+        // parsed back.
+        let label = label.to_string();
+        if label.contains(['\n', '\r']) {
+            return Err(EdgeError::InvalidLabel(label));
+        }
         self.edge_builder = self.edge_builder.label(label)?;
         Ok(self)
     }
@@ -104,14 +104,6 @@ impl crate::traits::TabbedDisplay for FlowchartNode {
                 self.shape,
                 self.label()
             )?;
-
-            if let Some(click_event) = &self.click_event {
-                writeln!(f, "{indent}click {NODE_LETTER}{} {click_event}", self.id(),)?;
-            }
-
-            for class in self.classes() {
-                writeln!(f, "{indent}class {NODE_LETTER}{} {}", self.id(), class.name())?;
-            }
         } else {
             writeln!(f, "{indent}subgraph {NODE_LETTER}{} [\"`{}`\"]", self.id(), self.label())?;
             if let Some(direction) = &self.direction {
@@ -123,6 +115,14 @@ impl crate::traits::TabbedDisplay for FlowchartNode {
             }
             writeln!(f, "{indent}end")?;
         }
+
+        if let Some(click_event) = &self.click_event {
+            writeln!(f, "{indent}click {NODE_LETTER}{} {click_event}", self.id())?;
+        }
+
+        for class in self.classes() {
+            writeln!(f, "{indent}class {NODE_LETTER}{} {}", self.id(), class.name())?;
+        }
         if self.has_styles() {
             write!(f, "{indent}style {NODE_LETTER}{} ", self.id())?;
             for (style_number, style) in self.styles().enumerate() {
@@ -152,6 +152,12 @@ impl NodeBuilder for FlowchartNodeBuilder {
     }
 
     fn label<S: ToString>(mut self, label: S) -> Result<Self, Self::Error> {
+        // Flowchart statements end at line breaks, so the label could not be
+        // parsed back.
+        let label = label.to_string();
+        if label.contains(['\n', '\r']) {
+            return Err(NodeError::InvalidLabel(label));
+        }
         self.builder = self.builder.label(label)?;
         Ok(self)
     }
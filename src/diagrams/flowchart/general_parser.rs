@@ -0,0 +1,904 @@
+//! Submodule providing a parser for flowcharts written by hand in the Mermaid
+//! syntax, such as `A[Start] -->|yes| B{Decide}`.
+
+use alloc::{
+    collections::BTreeMap,
+    rc::Rc,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::cmp::Reverse;
+
+use crate::{
+    diagrams::flowchart::{
+        CurveStyle, FlowchartBuilder, FlowchartConfigurationBuilder, FlowchartEdgeBuilder,
+        FlowchartNode, FlowchartNodeBuilder, FlowchartNodeShape,
+    },
+    errors::{ParseError, ParseErrorKind},
+    shared::{
+        ArrowShape, ClickEvent, Direction, LineStyle, StyleProperty,
+        parsing::{
+            Cursor, Position, define_style_classes, is_identifier_char, label_text, merge_property,
+            parse_click_event, parse_configuration, parse_style_properties, parse_value,
+            split_unquoted,
+        },
+    },
+    traits::{ConfigurationBuilder, DiagramBuilder, EdgeBuilder, NodeBuilder},
+};
+
+/// The opening delimiters of the bracket shape syntax, longest first, each
+/// with its closing delimiters and the shapes they denote.
+const SHAPES: &[(&str, &[(&str, FlowchartNodeShape)])] = &[
+    ("(((", &[(")))", FlowchartNodeShape::DoubleCircle)]),
+    ("((", &[("))", FlowchartNodeShape::Circle)]),
+    ("([", &[("])", FlowchartNodeShape::StadiumShape)]),
+    ("[[", &[("]]", FlowchartNodeShape::Subprocess)]),
+    ("[(", &[(")]", FlowchartNodeShape::Cylinder)]),
+    ("{{", &[("}}", FlowchartNodeShape::Hexagon)]),
+    ("[/", &[("/]", FlowchartNodeShape::LRParallelogram), ("\\]", FlowchartNodeShape::Trapezoid)]),
+    (
+        "[\\",
+        &[
+            ("\\]", FlowchartNodeShape::LLParallelogram),
+            ("/]", FlowchartNodeShape::ReverseTrapezoid),
+        ],
+    ),
+    ("(", &[(")", FlowchartNodeShape::RoundEdges)]),
+    ("[", &[("]", FlowchartNodeShape::Rectangle)]),
+    ("{", &[("}", FlowchartNodeShape::Diamond)]),
+    (">", &[("]", FlowchartNodeShape::Odd)]),
+];
+
+/// Returns the line style and the length of an edge drawn with `run`, the
+/// characters between its arrow heads.
+///
+/// Lengths follow Mermaid, in which `-->`, `---` and `-.-` all have length
+/// one, and each additional dash, equal sign or dot adds one.
+fn line_style(run: &str, headed: bool) -> Option<(LineStyle, u8)> {
+    let minimum = if headed { 2 } else { 3 };
+    let (line_style, length) = if run.bytes().all(|byte| byte == b'-') && run.len() >= minimum {
+        (LineStyle::Solid, run.len() + 1 - minimum)
+    } else if run.bytes().all(|byte| byte == b'=') && run.len() >= minimum {
+        (LineStyle::Thick, run.len() + 1 - minimum)
+    } else if let Some(dots) = run.strip_prefix('-').and_then(|run| run.strip_suffix('-'))
+        && !dots.is_empty()
+        && dots.bytes().all(|byte| byte == b'.')
+    {
+        (LineStyle::Dashed, dots.len())
+    } else {
+        return None;
+    };
+    Some((line_style, u8::try_from(length).ok()?))
+}
+
+/// A node whose statements are still being parsed.
+struct PendingNode {
+    /// The identifier of the node in the source.
+    identifier: String,
+    /// The label of the node, when it differs from its identifier.
+    label: Option<String>,
+    /// The shape of the node, if any was written.
+    shape: Option<FlowchartNodeShape>,
+    /// The names of the style classes of the node, with their positions.
+    classes: Vec<(String, Position)>,
+    /// The style properties of the node.
+    properties: Vec<StyleProperty>,
+    /// The click event of the node.
+    click_event: Option<ClickEvent>,
+    /// The direction of the node, when it is a subgraph.
+    direction: Option<Direction>,
+    /// Whether the node was declared with a `subgraph` statement.
+    subgraph: bool,
+    /// The index of the subgraph containing the node.
+    parent: Option<usize>,
+    /// The position at which the node is first mentioned.
+    position: Position,
+}
+
+/// An edge whose statements are still being parsed.
+struct PendingEdge {
+    /// The index of the source node.
+    source: usize,
+    /// The index of the destination node.
+    destination: usize,
+    /// The builder of the edge, without its source and destination.
+    builder: FlowchartEdgeBuilder,
+    /// The style properties of the edge.
+    properties: Vec<StyleProperty>,
+    /// The position of the link declaring the edge.
+    position: Position,
+}
+
+/// A `linkStyle` statement, applied once all edges are declared.
+struct LinkStyle {
+    /// The indices of the targeted edges, with their positions.
+    indices: Vec<(usize, Position)>,
+    /// The curve style of the edges, if any.
+    curve: Option<CurveStyle>,
+    /// The style properties of the edges.
+    properties: Vec<StyleProperty>,
+}
+
+#[derive(Default)]
+/// Parser of the statements following the flowchart header.
+struct FlowchartParser {
+    /// The builder receiving the style classes, the nodes and the edges.
+    builder: FlowchartBuilder,
+    /// The identifiers of the nodes in the source, mapped to their indices.
+    identifiers: BTreeMap<String, usize>,
+    /// The nodes, in order of first mention, whose indices are their numeric
+    /// identifiers.
+    nodes: Vec<PendingNode>,
+    /// The subgraphs whose `end` statement has not been reached yet, from the
+    /// outermost one, with the positions of their `subgraph` statements.
+    subgraphs: Vec<(usize, Position)>,
+    /// The edges, in order of declaration.
+    edges: Vec<PendingEdge>,
+    /// The `linkStyle` statements.
+    link_styles: Vec<LinkStyle>,
+}
+
+impl FlowchartParser {
+    /// Returns the index of the node written as `identifier`, declaring the
+    /// node when it is first mentioned.
+    ///
+    /// Nodes mentioned within a subgraph belong to the innermost open one,
+    /// unless they already belong to another subgraph.
+    fn mention(&mut self, identifier: &str, position: Position) -> usize {
+        let id = if let Some(&id) = self.identifiers.get(identifier) {
+            id
+        } else {
+            let id = self.nodes.len();
+            self.identifiers.insert(identifier.to_string(), id);
+            self.nodes.push(PendingNode {
+                identifier: identifier.to_string(),
+                label: None,
+                shape: None,
+                classes: Vec::new(),
+                properties: Vec::new(),
+                click_event: None,
+                direction: None,
+                subgraph: false,
+                parent: None,
+                position,
+            });
+            id
+        };
+
+        if let Some(&(subgraph, _)) = self.subgraphs.last()
+            && self.nodes[id].parent.is_none()
+            && !self.is_ancestor(id, subgraph)
+        {
+            self.nodes[id].parent = Some(subgraph);
+        }
+        id
+    }
+
+    /// Returns whether the node `ancestor` is `node` or contains it.
+    fn is_ancestor(&self, ancestor: usize, mut node: usize) -> bool {
+        loop {
+            if node == ancestor {
+                return true;
+            }
+            match self.nodes[node].parent {
+                Some(parent) => node = parent,
+                None => return false,
+            }
+        }
+    }
+
+    /// Returns the node written as `identifier`, which must have been
+    /// mentioned before.
+    fn mentioned(
+        &mut self,
+        cursor: &Cursor<'_>,
+        identifier: &str,
+    ) -> Result<&mut PendingNode, ParseError> {
+        let id = *self.identifiers.get(identifier).ok_or_else(|| {
+            cursor
+                .position_of(identifier)
+                .error(ParseErrorKind::UnknownIdentifier(identifier.to_string()))
+        })?;
+        Ok(&mut self.nodes[id])
+    }
+
+    /// Parses the statement at the cursor.
+    fn statement(&mut self, mut cursor: Cursor<'_>) -> Result<(), ParseError> {
+        let statement = cursor.rest;
+        let (keyword, rest) = statement.split_once(char::is_whitespace).unwrap_or((statement, ""));
+        let rest = rest.trim();
+        let unexpected = || cursor.unexpected();
+
+        match keyword {
+            "classDef" => define_style_classes(&mut self.builder, &cursor, rest)?,
+            "class" => {
+                let (identifiers, name) =
+                    rest.rsplit_once(char::is_whitespace).ok_or_else(unexpected)?;
+                let position = cursor.position_of(name);
+                for identifier in identifiers.split(',') {
+                    let node = self.mentioned(&cursor, identifier.trim())?;
+                    if !node.classes.iter().any(|(existing, _)| existing == name) {
+                        node.classes.push((name.to_string(), position));
+                    }
+                }
+            }
+            "style" => {
+                let (identifier, properties) =
+                    rest.split_once(char::is_whitespace).ok_or_else(unexpected)?;
+                let properties =
+                    parse_style_properties(cursor.number, cursor.line, properties.trim())?;
+                let node = self.mentioned(&cursor, identifier)?;
+                for property in properties {
+                    merge_property(&mut node.properties, property);
+                }
+            }
+            "linkStyle" => self.link_style(&cursor, rest)?,
+            "click" => {
+                let (identifier, event) =
+                    rest.split_once(char::is_whitespace).ok_or_else(unexpected)?;
+                let event = event.trim();
+                let normalized = event.strip_prefix("call ").unwrap_or(event).trim();
+                let normalized = normalized.strip_suffix(" _self").unwrap_or(normalized);
+                let normalized = normalized.strip_suffix("()").unwrap_or(normalized);
+                let click_event = parse_click_event(normalized)
+                    .ok_or_else(|| cursor.position_of(event).invalid(event))?;
+                self.mentioned(&cursor, identifier)?.click_event = Some(click_event);
+            }
+            "subgraph" => self.subgraph(&cursor, rest)?,
+            "end" if rest.is_empty() => {
+                self.subgraphs.pop().ok_or_else(unexpected)?;
+            }
+            "direction" => {
+                let direction = parse_value(cursor.number, cursor.line, rest)?;
+                let &(subgraph, _) = self.subgraphs.last().ok_or_else(unexpected)?;
+                self.nodes[subgraph].direction = Some(direction);
+            }
+            _ => {
+                let mut sources = self.group(&mut cursor)?;
+                loop {
+                    cursor.skip_whitespace();
+                    if cursor.rest.is_empty() {
+                        break;
+                    }
+                    let position = cursor.position();
+                    let builder = link(&mut cursor)?;
+                    cursor.skip_whitespace();
+                    let destinations = self.group(&mut cursor)?;
+                    for &source in &sources {
+                        for &destination in &destinations {
+                            self.edges.push(PendingEdge {
+                                source,
+                                destination,
+                                builder: builder.clone(),
+                                properties: Vec::new(),
+                                position,
+                            });
+                        }
+                    }
+                    sources = destinations;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses a `subgraph` statement, whose arguments are `rest`.
+    fn subgraph(&mut self, cursor: &Cursor<'_>, rest: &str) -> Result<(), ParseError> {
+        let position = cursor.position();
+        let mut arguments = Cursor { rest, ..*cursor };
+        let (identifier, title) = match arguments.identifier() {
+            Some(identifier) if arguments.rest.is_empty() => (identifier, None),
+            Some(identifier) if arguments.rest.trim_start().starts_with('[') => {
+                arguments.skip_whitespace();
+                let title = arguments
+                    .rest
+                    .strip_prefix('[')
+                    .and_then(|title| title.strip_suffix(']'))
+                    .ok_or_else(|| arguments.position().invalid(arguments.rest))?;
+                (identifier, Some(label_text(title)))
+            }
+            _ if !rest.is_empty() => (label_text(rest), None),
+            _ => return Err(cursor.unexpected()),
+        };
+
+        let id = self.mention(identifier, cursor.position_of(identifier));
+        let node = &mut self.nodes[id];
+        if node.subgraph {
+            return Err(cursor
+                .position_of(identifier)
+                .error(ParseErrorKind::DuplicateIdentifier(identifier.to_string())));
+        }
+        node.subgraph = true;
+        if let Some(title) = title {
+            node.label = Some(title.to_string());
+        }
+        self.subgraphs.push((id, position));
+        Ok(())
+    }
+
+    /// Parses a `linkStyle` statement, whose arguments are `rest`.
+    fn link_style(&mut self, cursor: &Cursor<'_>, rest: &str) -> Result<(), ParseError> {
+        let (indices, mut declarations) =
+            rest.split_once(char::is_whitespace).ok_or_else(|| cursor.unexpected())?;
+        if indices == "default" {
+            return Err(cursor
+                .position_of(indices)
+                .error(ParseErrorKind::UnsupportedSyntax("linkStyle default".to_string())));
+        }
+        let indices = indices
+            .split(',')
+            .map(|index| {
+                Ok((parse_value(cursor.number, cursor.line, index)?, cursor.position_of(index)))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        declarations = declarations.trim();
+        let mut curve = None;
+        if let Some(interpolation) = declarations.strip_prefix("interpolate ") {
+            let interpolation = interpolation.trim_start();
+            let (name, remainder) =
+                interpolation.split_once(char::is_whitespace).unwrap_or((interpolation, ""));
+            curve = Some(parse_value(cursor.number, cursor.line, name)?);
+            declarations = remainder.trim();
+        }
+        let properties = if declarations.is_empty() {
+            Vec::new()
+        } else {
+            parse_style_properties(cursor.number, cursor.line, declarations)?
+        };
+
+        self.link_styles.push(LinkStyle { indices, curve, properties });
+        Ok(())
+    }
+
+    /// Parses a group of nodes joined by `&`, such as `A & B[Text]`.
+    fn group(&mut self, cursor: &mut Cursor<'_>) -> Result<Vec<usize>, ParseError> {
+        let mut ids = vec![self.node(cursor)?];
+        loop {
+            let mut lookahead = *cursor;
+            lookahead.skip_whitespace();
+            if !lookahead.eat("&") {
+                return Ok(ids);
+            }
+            lookahead.skip_whitespace();
+            *cursor = lookahead;
+            ids.push(self.node(cursor)?);
+        }
+    }
+
+    /// Parses a node, such as `A`, `A[Text]:::class` or `A@{ shape: hex }`.
+    fn node(&mut self, cursor: &mut Cursor<'_>) -> Result<usize, ParseError> {
+        let position = cursor.position();
+        let identifier = cursor.identifier().ok_or_else(|| cursor.unexpected())?;
+        let id = self.mention(identifier, position);
+
+        let opening = *cursor;
+        if cursor.eat("@{") {
+            let body = cursor.until("}").ok_or_else(|| {
+                opening.position().error(ParseErrorKind::UnclosedBlock("@{".to_string()))
+            })?;
+            for entry in split_unquoted(body, ',') {
+                let (key, value) = entry
+                    .split_once(':')
+                    .ok_or_else(|| cursor.position_of(entry).invalid(entry))?;
+                let node = &mut self.nodes[id];
+                match key.trim() {
+                    "shape" => {
+                        node.shape = Some(parse_value(cursor.number, cursor.line, value.trim())?);
+                    }
+                    "label" => node.label = Some(label_text(value).to_string()),
+                    key => {
+                        return Err(cursor
+                            .position_of(key)
+                            .error(ParseErrorKind::UnsupportedKey(key.to_string())));
+                    }
+                }
+            }
+        } else if let Some((opening_delimiter, closings)) =
+            SHAPES.iter().find(|(opening_delimiter, _)| cursor.eat(opening_delimiter))
+        {
+            let unclosed = || {
+                opening
+                    .position()
+                    .error(ParseErrorKind::UnclosedBlock(opening_delimiter.to_string()))
+            };
+            let (text, shape) = if let Some(quoted) = cursor.rest.strip_prefix('"') {
+                let (text, rest) = quoted.split_once('"').ok_or_else(unclosed)?;
+                let mut after = Cursor { rest, ..*cursor };
+                after.skip_whitespace();
+                let &(_, shape) =
+                    closings.iter().find(|(closing, _)| after.eat(closing)).ok_or_else(unclosed)?;
+                *cursor = after;
+                (text, shape)
+            } else {
+                let (index, closing, shape) = closings
+                    .iter()
+                    .filter_map(|&(closing, shape)| {
+                        cursor.rest.find(closing).map(|index| (index, closing, shape))
+                    })
+                    .min_by_key(|&(index, _, _)| index)
+                    .ok_or_else(unclosed)?;
+                let text = &cursor.rest[..index];
+                cursor.rest = &cursor.rest[index + closing.len()..];
+                (text, shape)
+            };
+            let node = &mut self.nodes[id];
+            node.label = Some(label_text(text).to_string());
+            node.shape = Some(shape);
+        }
+
+        if cursor.eat(":::") {
+            let position = cursor.position();
+            let name =
+                cursor.take_while(|character| is_identifier_char(character) || character == '-');
+            if name.is_empty() {
+                return Err(cursor.unexpected());
+            }
+            let classes = &mut self.nodes[id].classes;
+            if !classes.iter().any(|(existing, _)| existing == name) {
+                classes.push((name.to_string(), position));
+            }
+        }
+
+        Ok(id)
+    }
+
+    /// Builds the flowchart builder from the parsed statements.
+    fn finish(mut self) -> Result<(FlowchartBuilder, BTreeMap<String, u64>), ParseError> {
+        if let Some(&(_, position)) = self.subgraphs.last() {
+            return Err(position.error(ParseErrorKind::UnclosedBlock("subgraph".to_string())));
+        }
+
+        for LinkStyle { indices, curve, properties } in self.link_styles {
+            for (index, position) in indices {
+                let edge = self.edges.get_mut(index).ok_or_else(|| {
+                    position.error(ParseErrorKind::UnknownIdentifier(index.to_string()))
+                })?;
+                if let Some(curve) = &curve {
+                    edge.builder = core::mem::take(&mut edge.builder).curve_style(curve.clone());
+                }
+                for &property in &properties {
+                    merge_property(&mut edge.properties, property);
+                }
+            }
+        }
+
+        // Subgraphs can only be built once their subnodes are, hence nodes
+        // are built from the most deeply nested ones.
+        let depth = |mut id: usize| {
+            let mut depth = 0;
+            while let Some(parent) = self.nodes[id].parent {
+                depth += 1;
+                id = parent;
+            }
+            depth
+        };
+        let mut order: Vec<usize> = (0..self.nodes.len()).collect();
+        order.sort_by_key(|&id| (Reverse(depth(id)), id));
+
+        let mut built: BTreeMap<usize, (FlowchartNodeBuilder, Rc<FlowchartNode>)> = BTreeMap::new();
+        for id in order {
+            let node = &self.nodes[id];
+            let position = node.position;
+            let mut builder = FlowchartNodeBuilder::default()
+                .label(node.label.as_ref().unwrap_or(&node.identifier))
+                .map_err(position.diagram())?
+                .id(id as u64);
+            if let Some(shape) = node.shape {
+                builder = builder.shape(shape);
+            }
+            if let Some(click_event) = node.click_event.clone() {
+                builder = builder.click_event(click_event);
+            }
+            for (name, class_position) in &node.classes {
+                let style_class = self.builder.get_style_class_by_name(name).ok_or_else(|| {
+                    class_position.error(ParseErrorKind::UnknownIdentifier(name.clone()))
+                })?;
+                builder = builder.style_class(style_class).map_err(position.diagram())?;
+            }
+            for &property in &node.properties {
+                builder = builder.style_property(property).map_err(position.diagram())?;
+            }
+            for (subnode, _) in
+                self.nodes.iter().enumerate().filter(|(_, subnode)| subnode.parent == Some(id))
+            {
+                builder = builder.subnode(built[&subnode].1.clone()).map_err(position.diagram())?;
+            }
+            if let Some(direction) = node.direction {
+                builder = builder.direction(direction);
+            }
+            let rc = Rc::new(builder.clone().build().map_err(position.diagram())?);
+            built.insert(id, (builder, rc));
+        }
+
+        let mut nodes = Vec::with_capacity(built.len());
+        for (id, (builder, _)) in built {
+            let position = self.nodes[id].position;
+            nodes.push(self.builder.node(builder).map_err(position.diagram())?);
+        }
+
+        for edge in self.edges {
+            let mut builder = edge
+                .builder
+                .source(nodes[edge.source].clone())
+                .and_then(|builder| builder.destination(nodes[edge.destination].clone()))
+                .map_err(edge.position.diagram())?;
+            for property in edge.properties {
+                builder = builder.style_property(property).map_err(edge.position.diagram())?;
+            }
+            self.builder.edge(builder).map_err(edge.position.diagram())?;
+        }
+
+        let identifiers =
+            self.identifiers.into_iter().map(|(identifier, id)| (identifier, id as u64)).collect();
+        Ok((self.builder, identifiers))
+    }
+}
+
+/// Parses the link at the cursor, such as `-->`, `<-.->|label|` or
+/// `-- label ---`, into a builder lacking the source and destination.
+fn link(cursor: &mut Cursor<'_>) -> Result<FlowchartEdgeBuilder, ParseError> {
+    let start = *cursor;
+    let invalid = |end: &Cursor<'_>| {
+        let link = &start.rest[..start.rest.len() - end.rest.len()];
+        start.position().invalid(if link.is_empty() { start.rest } else { link })
+    };
+    if start.rest.starts_with("~~~") {
+        return Err(start.position().error(ParseErrorKind::UnsupportedSyntax("~~~".to_string())));
+    }
+
+    let left_arrow = match cursor.rest.as_bytes() {
+        [b'<', ..] => Some(ArrowShape::Normal),
+        [b'o', b'-' | b'=', ..] => Some(ArrowShape::Circle),
+        [b'x', b'-' | b'=', ..] => Some(ArrowShape::X),
+        _ => None,
+    };
+    if left_arrow.is_some() {
+        cursor.rest = &cursor.rest[1..];
+    }
+    let mut run = cursor.take_while(|character| matches!(character, '-' | '=' | '.'));
+    let mut right_arrow = right_arrow(cursor);
+    let mut label = None;
+
+    // Links with a label in their middle, such as `-- label -->`, open with
+    // two characters, and their closing part determines their style.
+    let mut dotted = false;
+    if right_arrow.is_none() && matches!(run, "--" | "==" | "-.") {
+        dotted = run == "-.";
+        let closing = if dotted { ".-" } else { run };
+        let index = cursor.rest.find(closing).ok_or_else(|| invalid(cursor))?;
+        let index = cursor.rest[..index].trim_end_matches(closing.as_bytes()[0] as char).len();
+        label = Some(label_text(&cursor.rest[..index]));
+        cursor.rest = &cursor.rest[index..];
+        run = cursor.take_while(|character| matches!(character, '-' | '=' | '.'));
+        right_arrow = self::right_arrow(cursor);
+    }
+
+    let headed = left_arrow.is_some() || right_arrow.is_some();
+    let (line_style, length) = if dotted {
+        // The closing part `.->` of a dotted link stands for `-.->`.
+        run.strip_suffix('-')
+            .filter(|dots| !dots.is_empty() && dots.bytes().all(|byte| byte == b'.'))
+            .and_then(|dots| Some((LineStyle::Dashed, u8::try_from(dots.len()).ok()?)))
+    } else {
+        line_style(run, headed)
+    }
+    .ok_or_else(|| invalid(cursor))?;
+    let position = start.position();
+    let mut builder = FlowchartEdgeBuilder::default().line_style(line_style).length(length);
+    if let Some(shape) = left_arrow {
+        builder = builder.left_arrow_shape(shape).map_err(position.diagram())?;
+    }
+    if let Some(shape) = right_arrow {
+        builder = builder.right_arrow_shape(shape).map_err(position.diagram())?;
+    }
+
+    let mut lookahead = *cursor;
+    lookahead.skip_whitespace();
+    if lookahead.eat("|") {
+        let opening = lookahead;
+        let text = lookahead.until_unquoted('|').ok_or_else(|| {
+            opening.position().error(ParseErrorKind::UnclosedBlock("|".to_string()))
+        })?;
+        label = Some(label_text(text));
+        *cursor = lookahead;
+    }
+    if let Some(label) = label {
+        builder = builder.label(label).map_err(position.diagram())?;
+    }
+    Ok(builder)
+}
+
+/// Consumes the arrow head closing a link, if any.
+fn right_arrow(cursor: &mut Cursor<'_>) -> Option<ArrowShape> {
+    let shape = match cursor.rest.as_bytes().first()? {
+        b'>' => ArrowShape::Normal,
+        b'o' => ArrowShape::Circle,
+        b'x' => ArrowShape::X,
+        _ => return None,
+    };
+    cursor.rest = &cursor.rest[1..];
+    Some(shape)
+}
+
+/// Parses a flowchart written by hand in the Mermaid syntax.
+pub(super) fn parse(source: &str) -> Result<(FlowchartBuilder, BTreeMap<String, u64>), ParseError> {
+    let (mut configuration, lines): (FlowchartConfigurationBuilder, _) =
+        parse_configuration(source)?;
+
+    let mut statements = lines.into_iter().flat_map(|(number, line)| {
+        split_unquoted(line, ';')
+            .map(str::trim)
+            .filter(|statement| !statement.is_empty())
+            .map(move |statement| Cursor { number, line, rest: statement })
+    });
+
+    let Some(mut header) = statements.next() else {
+        return Err(ParseError::new(
+            source.lines().count() + 1,
+            1,
+            ParseErrorKind::MissingHeader("flowchart".to_string()),
+        ));
+    };
+    let position = header.position();
+    if !header.eat("flowchart") && !header.eat("graph") {
+        return Err(position.error(ParseErrorKind::MissingHeader("flowchart".to_string())));
+    }
+    // Mermaid lays flowcharts out from top to bottom unless told otherwise.
+    let direction = header.rest.trim();
+    configuration = if direction.is_empty() {
+        configuration.direction(Direction::TopToBottom)
+    } else if header.rest.len() == direction.len() {
+        return Err(position.error(ParseErrorKind::MissingHeader("flowchart".to_string())));
+    } else {
+        configuration.direction(parse_value(header.number, header.line, direction)?)
+    };
+
+    let mut parser = FlowchartParser {
+        builder: FlowchartBuilder::default()
+            .configuration(configuration)
+            .map_err(position.diagram())?,
+        ..FlowchartParser::default()
+    };
+    for statement in statements {
+        parser.statement(statement)?;
+    }
+    parser.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString, vec::Vec};
+
+    use super::*;
+    use crate::{
+        diagrams::flowchart::Flowchart,
+        traits::{Configuration, Diagram, Edge, Node},
+    };
+
+    /// Parses the source and returns the lines of the displayed flowchart.
+    fn displayed(source: &str) -> Result<Vec<String>, ParseError> {
+        let (builder, _) = parse(source)?;
+        Ok(Flowchart::from(builder).to_string().lines().map(str::to_string).collect())
+    }
+
+    #[test]
+    fn test_parse_shapes() -> Result<(), Box<dyn core::error::Error>> {
+        let lines = displayed(
+            "graph LR\n  A[Rect] --- B(Round) --- C([Stadium]) --- D[[Sub]] --- E[(DB)]\n  \
+             F((Circle)) --- G(((Double))) --- H>Odd] --- I{Diamond} --- J{{Hex}}\n  \
+             K[/Right/] --- L[\\Left\\] --- M[/Base\\] --- N[\\Top/] --- O\n  \
+             P[\"Quoted ] text\"] --- Q@{ shape: cyl, label: \"Hi, there\" } --- R[\"`**md**`\"]",
+        )?;
+        assert_eq!(
+            &lines[1..19],
+            [
+                "  v0@{shape: rect, label: \"Rect\"}",
+                "  v1@{shape: rounded, label: \"Round\"}",
+                "  v2@{shape: stadium, label: \"Stadium\"}",
+                "  v3@{shape: subproc, label: \"Sub\"}",
+                "  v4@{shape: cyl, label: \"DB\"}",
+                "  v5@{shape: circle, label: \"Circle\"}",
+                "  v6@{shape: dbl-circ, label: \"Double\"}",
+                "  v7@{shape: odd, label: \"Odd\"}",
+                "  v8@{shape: diamond, label: \"Diamond\"}",
+                "  v9@{shape: hex, label: \"Hex\"}",
+                "  v10@{shape: lean-r, label: \"Right\"}",
+                "  v11@{shape: lean-l, label: \"Left\"}",
+                "  v12@{shape: trap-b, label: \"Base\"}",
+                "  v13@{shape: trap-t, label: \"Top\"}",
+                "  v14@{shape: rect, label: \"O\"}",
+                "  v15@{shape: rect, label: \"Quoted ] text\"}",
+                "  v16@{shape: cyl, label: \"Hi, there\"}",
+                "  v17@{shape: rect, label: \"**md**\"}",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_links() -> Result<(), Box<dyn core::error::Error>> {
+        let lines = displayed(
+            "flowchart TD\n  A --> B --- C ---> D ---- E\n  A -.-> B -..- C ==> D === E\n  \
+             A <--> B o--o C x--x D --o E --x A\n  A -->|yes| B -- text --> C -- text ---- D\n  \
+             A -. dotted ..-> B == thick ==> C -->|\"`**md**`\"| D -->|\"a|b\"| E",
+        )?;
+        assert_eq!(
+            &lines[6..],
+            [
+                "  v0 ---> v1",
+                "  v1 --- v2",
+                "  v2 ----> v3",
+                "  v3 ---- v4",
+                "  v0 -.-> v1",
+                "  v1 -..- v2",
+                "  v2 ===> v3",
+                "  v3 === v4",
+                "  v0 <---> v1",
+                "  v1 o---o v2",
+                "  v2 x---x v3",
+                "  v3 ---o v4",
+                "  v4 ---x v0",
+                "  v0 --->|\"`yes`\"| v1",
+                "  v1 --->|\"`text`\"| v2",
+                "  v2 ----|\"`text`\"| v3",
+                "  v0 -..->|\"`dotted`\"| v1",
+                "  v1 ===>|\"`thick`\"| v2",
+                "  v2 --->|\"`**md**`\"| v3",
+                "  v3 --->|\"`a|b`\"| v4",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_chains_and_groups() -> Result<(), Box<dyn core::error::Error>> {
+        let (builder, identifiers) =
+            parse("graph\n  A & B --> C & D --> E;F-->G\n  node-1.a --> node_2")?;
+        assert_eq!(
+            identifiers
+                .iter()
+                .map(|(identifier, id)| (identifier.as_str(), *id))
+                .collect::<Vec<_>>(),
+            [
+                ("A", 0),
+                ("B", 1),
+                ("C", 2),
+                ("D", 3),
+                ("E", 4),
+                ("F", 5),
+                ("G", 6),
+                ("node-1.a", 7),
+                ("node_2", 8)
+            ]
+        );
+        let flowchart = Flowchart::from(builder);
+        assert_eq!(flowchart.configuration().direction(), Direction::TopToBottom);
+        assert_eq!(
+            flowchart
+                .edges()
+                .map(|edge| (edge.source().label(), edge.destination().label()))
+                .collect::<Vec<_>>(),
+            [
+                ("A", "C"),
+                ("A", "D"),
+                ("B", "C"),
+                ("B", "D"),
+                ("C", "E"),
+                ("D", "E"),
+                ("F", "G"),
+                ("node-1.a", "node_2")
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_statements() -> Result<(), Box<dyn core::error::Error>> {
+        let lines = displayed(
+            "---\ntitle: Hand-written\n---\n%% A comment\ngraph LR\n  \
+             A:::hot --> B[Label]:::cold\n  classDef hot,cold fill:#f9f,stroke:#333\n  \
+             style B fill:#ff0000,stroke-dasharray: 5 5,opacity:0.5\n  \
+             linkStyle 0 interpolate linear stroke:#00ff00\n  \
+             click A href \"https://example.com\" \"Tip\" _blank\n  click B call callback()\n  \
+             subgraph outer [Outer]\n    direction TB\n    C --> D\n    subgraph inner\n      \
+             E\n    end\n  end\n  A --> C",
+        )?;
+        assert_eq!(
+            lines,
+            [
+                "---",
+                "config:",
+                "  theme: default",
+                "  look: classic",
+                "  flowchart:",
+                "    defaultRenderer: \"dagre\"",
+                "title: Hand-written",
+                "---",
+                "flowchart LR",
+                "  classDef hot fill: #ff99ff,stroke: #333333",
+                "  classDef cold fill: #ff99ff,stroke: #333333",
+                "  v0@{shape: rect, label: \"A\"}",
+                "  click v0 href \"https://example.com\" \"Tip\" _blank",
+                "  class v0 hot",
+                "  v1@{shape: rect, label: \"Label\"}",
+                "  click v1 callback",
+                "  class v1 cold",
+                "  style v1 fill: #ff0000 , stroke-dasharray: 5, 5 , opacity: 0.50 ",
+                "  subgraph v2 [\"`Outer`\"]",
+                "      direction TB",
+                "    v3@{shape: rect, label: \"C\"}",
+                "    v4@{shape: rect, label: \"D\"}",
+                "    subgraph v5 [\"`inner`\"]",
+                "      v6@{shape: rect, label: \"E\"}",
+                "    end",
+                "  end",
+                "  v0 e0@---> v1",
+                "  e0@{curve: linear}",
+                "  linkStyle e0 stroke: #00ff00 ",
+                "  v3 ---> v4",
+                "  v0 ---> v3",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for (source, line, column, kind) in [
+            ("", 1, 1, ParseErrorKind::MissingHeader("flowchart".to_string())),
+            ("graphLR", 1, 1, ParseErrorKind::MissingHeader("flowchart".to_string())),
+            ("flowchart XY", 1, 11, ParseErrorKind::InvalidValue("XY".to_string())),
+            ("graph LR\n  A -->", 2, 8, ParseErrorKind::UnexpectedStatement(String::new())),
+            ("graph LR\n  A -> B", 2, 5, ParseErrorKind::InvalidValue("->".to_string())),
+            ("graph LR\n  A[Start --> B", 2, 4, ParseErrorKind::UnclosedBlock("[".to_string())),
+            ("graph LR\n  A ~~~ B", 2, 5, ParseErrorKind::UnsupportedSyntax("~~~".to_string())),
+            ("graph LR\n  A; end", 2, 6, ParseErrorKind::UnexpectedStatement("end".to_string())),
+            (
+                "graph LR\n  subgraph one\n    A",
+                2,
+                3,
+                ParseErrorKind::UnclosedBlock("subgraph".to_string()),
+            ),
+            (
+                "graph LR\n  A:::missing",
+                2,
+                7,
+                ParseErrorKind::UnknownIdentifier("missing".to_string()),
+            ),
+            (
+                "graph LR\n  style Z fill:#ff0000",
+                2,
+                9,
+                ParseErrorKind::UnknownIdentifier("Z".to_string()),
+            ),
+            (
+                "graph LR\n  A --> B\n  linkStyle 0,3 stroke:#ff0000",
+                3,
+                15,
+                ParseErrorKind::UnknownIdentifier("3".to_string()),
+            ),
+            (
+                "graph LR\n  A --> B\n  linkStyle default stroke:#ff0000",
+                3,
+                13,
+                ParseErrorKind::UnsupportedSyntax("linkStyle default".to_string()),
+            ),
+            (
+                "graph LR\n  A@{ shape: hex, size: 2 }",
+                2,
+                19,
+                ParseErrorKind::UnsupportedKey("size".to_string()),
+            ),
+        ] {
+            let error = parse(source).err().unwrap_or_else(|| panic!("{source}"));
+            assert_eq!(
+                (error.line(), error.column(), error.kind()),
+                (line, column, &kind),
+                "{source}"
+            );
+        }
+    }
+}
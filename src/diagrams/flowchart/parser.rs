@@ -0,0 +1,803 @@
+//! Submodule providing a parser which reads back the Mermaid syntax written
+//! when displaying a flowchart.
+
+use alloc::{collections::BTreeMap, format, rc::Rc, string::ToString, vec::Vec};
+
+use crate::{
+    diagrams::flowchart::{
+        Flowchart, FlowchartBuilder, FlowchartConfigurationBuilder, FlowchartEdgeBuilder,
+        FlowchartNode, FlowchartNodeBuilder, FlowchartNodeShape,
+    },
+    errors::{ParseError, ParseErrorKind},
+    shared::{
+        ArrowShape, EDGE_LETTER, LineStyle, NODE_LETTER, StyleClassBuilder,
+        parsing::{
+            Position, parse_click_event, parse_configuration, parse_style_properties, parse_value,
+        },
+    },
+    traits::{ConfigurationBuilder, DiagramBuilder, EdgeBuilder, NodeBuilder},
+};
+
+/// Returns the identifier of the node written as `token`, if any.
+fn node_id(token: &str) -> Option<u64> {
+    token
+        .strip_prefix(NODE_LETTER)
+        .filter(|digits| !digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_digit()))?
+        .parse()
+        .ok()
+}
+
+/// Returns the identifier of the edge written as `token`, if any.
+fn edge_id(token: &str) -> Option<usize> {
+    token
+        .strip_prefix(EDGE_LETTER)
+        .filter(|digits| !digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_digit()))?
+        .parse()
+        .ok()
+}
+
+/// Parses the link between the two nodes of an edge, such as `<-.->`,
+/// returning the arrow shapes, the line style and the length of the edge.
+fn parse_link(link: &str) -> Option<(Option<ArrowShape>, LineStyle, u8, Option<ArrowShape>)> {
+    let left_arrow = match link.chars().next()? {
+        '<' => Some(ArrowShape::Normal),
+        'o' => Some(ArrowShape::Circle),
+        'x' => Some(ArrowShape::X),
+        _ => None,
+    };
+    let segment = if left_arrow.is_some() { &link[1..] } else { link };
+    let right_arrow = match segment.chars().last()? {
+        '>' => Some(ArrowShape::Normal),
+        'o' => Some(ArrowShape::Circle),
+        'x' => Some(ArrowShape::X),
+        _ => None,
+    };
+    let segment = if right_arrow.is_some() { &segment[..segment.len() - 1] } else { segment };
+
+    let line_style = if segment.bytes().all(|byte| byte == b'-') {
+        LineStyle::Solid
+    } else if segment.bytes().all(|byte| byte == b'=') {
+        LineStyle::Thick
+    } else if segment
+        .strip_prefix('-')
+        .and_then(|dots| dots.strip_suffix('-'))
+        .is_some_and(|dots| dots.bytes().all(|byte| byte == b'.'))
+    {
+        LineStyle::Dashed
+    } else {
+        return None;
+    };
+
+    let length = u8::try_from(segment.len().checked_sub(2)?).ok()?;
+    Some((left_arrow, line_style, length, right_arrow))
+}
+
+/// A node whose statements are still being parsed.
+struct PendingNode {
+    /// The identifier of the node.
+    id: u64,
+    /// The builder of the node, without its subnodes.
+    builder: FlowchartNodeBuilder,
+    /// The identifiers of the subnodes, when the node is a subgraph.
+    subnodes: Vec<u64>,
+    /// The position of the statement declaring the node.
+    position: Position,
+}
+
+/// An edge whose statements are still being parsed.
+struct PendingEdge {
+    /// The identifier of the edge.
+    id: usize,
+    /// The identifier of the source node.
+    source: u64,
+    /// The identifier of the destination node.
+    destination: u64,
+    /// The builder of the edge, without its source and destination.
+    builder: FlowchartEdgeBuilder,
+    /// The position of the statement declaring the edge.
+    position: Position,
+}
+
+#[derive(Default)]
+/// Parser of the statements following the flowchart header.
+struct FlowchartParser {
+    /// The builder receiving the style classes, the nodes and the edges.
+    builder: FlowchartBuilder,
+    /// The nodes, in the order in which their declaration ends.
+    nodes: Vec<PendingNode>,
+    /// The subgraphs whose `end` statement has not been reached yet, from the
+    /// outermost one.
+    subgraphs: Vec<PendingNode>,
+    /// The edges, in order of declaration.
+    edges: Vec<PendingEdge>,
+}
+
+impl FlowchartParser {
+    /// Returns whether a node with the provided identifier was declared.
+    fn is_declared(&self, id: u64) -> bool {
+        self.nodes.iter().any(|node| node.id == id)
+            || self.subgraphs.iter().any(|subgraph| subgraph.id == id)
+    }
+
+    /// Returns the node with the provided identifier, if any.
+    fn node_mut(&mut self, id: u64) -> Option<&mut PendingNode> {
+        self.nodes.iter_mut().find(|node| node.id == id)
+    }
+
+    /// Returns the edge with the provided identifier, if any.
+    fn edge_mut(&mut self, id: usize) -> Option<&mut PendingEdge> {
+        self.edges.iter_mut().find(|edge| edge.id == id)
+    }
+
+    /// Adds the node to the parsed nodes and to the innermost open subgraph.
+    fn declare(&mut self, node: PendingNode) {
+        if let Some(subgraph) = self.subgraphs.last_mut() {
+            subgraph.subnodes.push(node.id);
+        }
+        self.nodes.push(node);
+    }
+
+    /// Parses the statement written on the provided line.
+    fn statement(&mut self, number: usize, line: &str) -> Result<(), ParseError> {
+        let statement = line.trim();
+        let position = Position::of(number, line, statement);
+        let unexpected =
+            || position.error(ParseErrorKind::UnexpectedStatement(statement.to_string()));
+        let (keyword, rest) = statement.split_once(' ').unwrap_or((statement, ""));
+
+        match keyword {
+            "classDef" => {
+                let (name, properties) = rest.split_once(' ').ok_or_else(unexpected)?;
+                let mut style_class =
+                    StyleClassBuilder::default().name(name).map_err(position.diagram())?;
+                for property in parse_style_properties(number, line, properties)? {
+                    style_class = style_class.property(property).map_err(position.diagram())?;
+                }
+                self.builder.style_class(style_class).map_err(position.diagram())?;
+            }
+            "subgraph" => {
+                let (id, label) = rest.split_once(' ').ok_or_else(unexpected)?;
+                let id = self.new_node_id(number, line, id)?;
+                let label = label
+                    .strip_prefix("[\"`")
+                    .and_then(|label| label.strip_suffix("`\"]"))
+                    .ok_or_else(|| Position::of(number, line, label).invalid(label))?;
+                let builder = FlowchartNodeBuilder::default()
+                    .label(label)
+                    .map_err(position.diagram())?
+                    .id(id);
+                self.subgraphs.push(PendingNode { id, builder, subnodes: Vec::new(), position });
+            }
+            "direction" => {
+                let direction = parse_value(number, line, rest)?;
+                let subgraph = self.subgraphs.last_mut().ok_or_else(unexpected)?;
+                subgraph.builder = core::mem::take(&mut subgraph.builder).direction(direction);
+            }
+            "end" if rest.is_empty() => {
+                let subgraph = self.subgraphs.pop().ok_or_else(unexpected)?;
+                self.declare(subgraph);
+            }
+            "click" => {
+                let (id, event) = rest.split_once(' ').ok_or_else(unexpected)?;
+                let click_event = parse_click_event(event)
+                    .ok_or_else(|| Position::of(number, line, event).invalid(event))?;
+                let node = self.declared_node(number, line, id)?;
+                node.builder = core::mem::take(&mut node.builder).click_event(click_event);
+            }
+            "class" => {
+                let (target, name) = rest.split_once(' ').ok_or_else(unexpected)?;
+                let style_class = self.builder.get_style_class_by_name(name).ok_or_else(|| {
+                    Position::of(number, line, name)
+                        .error(ParseErrorKind::UnknownIdentifier(name.to_string()))
+                })?;
+                if target.starts_with(EDGE_LETTER) {
+                    let edge = self.declared_edge(number, line, target)?;
+                    edge.builder = core::mem::take(&mut edge.builder)
+                        .style_class(style_class)
+                        .map_err(position.diagram())?;
+                } else {
+                    let node = self.declared_node(number, line, target)?;
+                    node.builder = core::mem::take(&mut node.builder)
+                        .style_class(style_class)
+                        .map_err(position.diagram())?;
+                }
+            }
+            "style" => {
+                let (id, properties) = rest.split_once(' ').ok_or_else(unexpected)?;
+                let properties = parse_style_properties(number, line, properties)?;
+                let node = self.declared_node(number, line, id)?;
+                for property in properties {
+                    node.builder = core::mem::take(&mut node.builder)
+                        .style_property(property)
+                        .map_err(position.diagram())?;
+                }
+            }
+            "linkStyle" => {
+                let (id, properties) = rest.split_once(' ').ok_or_else(unexpected)?;
+                let properties = parse_style_properties(number, line, properties)?;
+                let edge = self.declared_edge(number, line, id)?;
+                for property in properties {
+                    edge.builder = core::mem::take(&mut edge.builder)
+                        .style_property(property)
+                        .map_err(position.diagram())?;
+                }
+            }
+            _ => {
+                if let Some((target, body)) = statement.split_once("@{")
+                    && !target.contains(' ')
+                {
+                    self.definition(number, line, target, body)?;
+                } else {
+                    self.edge(number, line, keyword, rest)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the identifier of a node which is about to be declared.
+    fn new_node_id(&self, number: usize, line: &str, token: &str) -> Result<u64, ParseError> {
+        let position = Position::of(number, line, token);
+        let id = node_id(token).ok_or_else(|| position.invalid(token))?;
+        if self.is_declared(id) {
+            return Err(position.error(ParseErrorKind::DuplicateIdentifier(token.to_string())));
+        }
+        Ok(id)
+    }
+
+    /// Returns the node written as `token`, which must have been declared.
+    fn declared_node(
+        &mut self,
+        number: usize,
+        line: &str,
+        token: &str,
+    ) -> Result<&mut PendingNode, ParseError> {
+        let position = Position::of(number, line, token);
+        let id = node_id(token).ok_or_else(|| position.invalid(token))?;
+        self.node_mut(id)
+            .ok_or_else(|| position.error(ParseErrorKind::UnknownIdentifier(token.to_string())))
+    }
+
+    /// Returns the edge written as `token`, which must have been declared.
+    fn declared_edge(
+        &mut self,
+        number: usize,
+        line: &str,
+        token: &str,
+    ) -> Result<&mut PendingEdge, ParseError> {
+        let position = Position::of(number, line, token);
+        let id = edge_id(token).ok_or_else(|| position.invalid(token))?;
+        self.edge_mut(id)
+            .ok_or_else(|| position.error(ParseErrorKind::UnknownIdentifier(token.to_string())))
+    }
+
+    /// Parses a node definition, such as `v0@{shape: rect, label: "A"}`, or
+    /// an edge curve definition, such as `e0@{curve: linear}`.
+    fn definition(
+        &mut self,
+        number: usize,
+        line: &str,
+        target: &str,
+        body: &str,
+    ) -> Result<(), ParseError> {
+        let position = Position::of(number, line, target);
+        let body =
+            body.strip_suffix('}').ok_or_else(|| Position::of(number, line, body).invalid(body))?;
+
+        if target.starts_with(EDGE_LETTER) {
+            let curve = body
+                .strip_prefix("curve: ")
+                .ok_or_else(|| Position::of(number, line, body).invalid(body))?;
+            let curve = parse_value(number, line, curve)?;
+            let edge = self.declared_edge(number, line, target)?;
+            edge.builder = core::mem::take(&mut edge.builder).curve_style(curve);
+            return Ok(());
+        }
+
+        let id = self.new_node_id(number, line, target)?;
+        let (shape, label) = body
+            .strip_prefix("shape: ")
+            .and_then(|body| body.split_once(", label: "))
+            .ok_or_else(|| Position::of(number, line, body).invalid(body))?;
+        let shape: FlowchartNodeShape = parse_value(number, line, shape)?;
+        let label = label
+            .strip_prefix('"')
+            .and_then(|label| label.strip_suffix('"'))
+            .ok_or_else(|| Position::of(number, line, label).invalid(label))?;
+        let builder = FlowchartNodeBuilder::default()
+            .label(label)
+            .map_err(position.diagram())?
+            .id(id)
+            .shape(shape);
+
+        self.declare(PendingNode { id, builder, subnodes: Vec::new(), position });
+        Ok(())
+    }
+
+    /// Parses an edge, such as `v0 e1@<-.->|"`label`"| v1`, whose source
+    /// node is written as `source` and which continues with `rest`.
+    fn edge(
+        &mut self,
+        number: usize,
+        line: &str,
+        source: &str,
+        rest: &str,
+    ) -> Result<(), ParseError> {
+        let position = Position::of(number, line, source);
+        let statement = line.trim();
+        let (Some((connection, destination)), Some(_)) = (rest.rsplit_once(' '), node_id(source))
+        else {
+            return Err(position.error(ParseErrorKind::UnexpectedStatement(statement.to_string())));
+        };
+
+        let mut ids = Vec::with_capacity(2);
+        for token in [source, destination] {
+            let token_position = Position::of(number, line, token);
+            let id = node_id(token).ok_or_else(|| token_position.invalid(token))?;
+            if !self.is_declared(id) {
+                return Err(
+                    token_position.error(ParseErrorKind::UnknownIdentifier(token.to_string()))
+                );
+            }
+            ids.push(id);
+        }
+
+        // The edge identifier is only written when the edge has further
+        // statements, and otherwise matches the position of the edge.
+        let (id, connection) = match connection.split_once('@') {
+            Some((token, connection)) if let Some(id) = edge_id(token) => {
+                if self.edges.iter().any(|edge| edge.id == id) {
+                    return Err(Position::of(number, line, token)
+                        .error(ParseErrorKind::DuplicateIdentifier(token.to_string())));
+                }
+                (id, connection)
+            }
+            _ => (self.edges.len(), connection),
+        };
+
+        let (arrow, label) = match connection.find('|') {
+            Some(index) => {
+                let label = &connection[index..];
+                let text = label
+                    .strip_prefix("|\"`")
+                    .and_then(|label| label.strip_suffix("`\"|"))
+                    .ok_or_else(|| Position::of(number, line, label).invalid(label))?;
+                (&connection[..index], Some(text))
+            }
+            None => (connection, None),
+        };
+
+        let (left_arrow, line_style, length, right_arrow) =
+            parse_link(arrow).ok_or_else(|| Position::of(number, line, arrow).invalid(arrow))?;
+        let mut builder = FlowchartEdgeBuilder::default().line_style(line_style).length(length);
+        if let Some(shape) = left_arrow {
+            builder = builder.left_arrow_shape(shape).map_err(position.diagram())?;
+        }
+        if let Some(shape) = right_arrow {
+            builder = builder.right_arrow_shape(shape).map_err(position.diagram())?;
+        }
+        if let Some(label) = label {
+            builder = builder.label(label).map_err(position.diagram())?;
+        }
+
+        self.edges.push(PendingEdge { id, source: ids[0], destination: ids[1], builder, position });
+        Ok(())
+    }
+
+    /// Builds the flowchart from the parsed statements.
+    fn finish(mut self) -> Result<Flowchart, ParseError> {
+        if let Some(subgraph) = self.subgraphs.last() {
+            return Err(subgraph
+                .position
+                .error(ParseErrorKind::UnclosedBlock("subgraph".to_string())));
+        }
+
+        // Subgraphs are declared when they are closed, hence after all of
+        // their subnodes have been built.
+        let mut built: BTreeMap<u64, Rc<FlowchartNode>> = BTreeMap::new();
+        let mut nodes = Vec::with_capacity(self.nodes.len());
+        for PendingNode { id, mut builder, subnodes, position } in self.nodes {
+            for subnode in subnodes {
+                builder = builder.subnode(built[&subnode].clone()).map_err(position.diagram())?;
+            }
+            built.insert(id, Rc::new(builder.clone().build().map_err(position.diagram())?));
+            nodes.push((id, builder, position));
+        }
+
+        // Nodes are added in ascending order of their identifiers, which is
+        // the order in which they were added to the diagram whenever their
+        // identifiers were assigned by the builder.
+        nodes.sort_unstable_by_key(|(id, _, _)| *id);
+        for (_, builder, position) in nodes {
+            self.builder.node(builder).map_err(position.diagram())?;
+        }
+
+        for edge in self.edges {
+            let node = |id| {
+                self.builder.get_node_by_id(id).ok_or_else(|| {
+                    edge.position
+                        .error(ParseErrorKind::UnknownIdentifier(format!("{NODE_LETTER}{id}")))
+                })
+            };
+            let (source, destination) = (node(edge.source)?, node(edge.destination)?);
+            let builder = edge
+                .builder
+                .source(source)
+                .and_then(|builder| builder.destination(destination))
+                .map_err(edge.position.diagram())?;
+            self.builder.edge(builder).map_err(edge.position.diagram())?;
+        }
+
+        Ok(self.builder.into())
+    }
+}
+
+/// Parses a flowchart written in the Mermaid syntax produced when displaying
+/// a [`Flowchart`].
+pub(super) fn parse(source: &str) -> Result<Flowchart, ParseError> {
+    let (configuration, lines): (FlowchartConfigurationBuilder, _) = parse_configuration(source)?;
+    let mut lines = lines.into_iter();
+
+    let Some((number, line)) = lines.next() else {
+        return Err(ParseError::new(
+            source.lines().count() + 1,
+            1,
+            ParseErrorKind::MissingHeader("flowchart".to_string()),
+        ));
+    };
+    let statement = line.trim();
+    let Some(direction) = statement.strip_prefix("flowchart ") else {
+        return Err(Position::of(number, line, statement)
+            .error(ParseErrorKind::MissingHeader("flowchart".to_string())));
+    };
+    let configuration = configuration.direction(parse_value(number, line, direction.trim())?);
+
+    let mut parser = FlowchartParser {
+        builder: FlowchartBuilder::default()
+            .configuration(configuration)
+            .map_err(Position::of(number, line, statement).diagram())?,
+        ..FlowchartParser::default()
+    };
+    for (number, line) in lines {
+        parser.statement(number, line)?;
+    }
+    parser.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString, vec};
+
+    use super::*;
+    use crate::{
+        diagrams::flowchart::{CurveStyle, FlowchartConfigurationBuilder},
+        errors::{EdgeError, NodeError},
+        shared::{
+            ClickEvent, Color, Direction, FontWeight, Renderer, StyleClassBuilder, StyleProperty,
+            Unit,
+            click_event::{JsFunctionCall, Navigation},
+            generic_configuration::{Look, Theme},
+        },
+        traits::Diagram,
+    };
+
+    /// Asserts that parsing the displayed flowchart yields the flowchart.
+    fn assert_round_trip(flowchart: &Flowchart) -> Result<(), ParseError> {
+        let source = flowchart.to_string();
+        let parsed: Flowchart = source.parse()?;
+        assert_eq!(&parsed, flowchart, "{source}");
+        assert_eq!(parsed.to_string(), source);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_empty() -> Result<(), Box<dyn core::error::Error>> {
+        assert_round_trip(&Flowchart::from(FlowchartBuilder::default()))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_edges() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = FlowchartBuilder::default();
+        let a = builder.node(FlowchartNodeBuilder::default().label("Start: here")?)?;
+        let b = builder.node(
+            FlowchartNodeBuilder::default()
+                .label("Decide \"now\"")?
+                .shape(FlowchartNodeShape::Diamond),
+        )?;
+        let c = builder.node(FlowchartNodeBuilder::default().label("x")?)?;
+
+        builder.edge(FlowchartEdgeBuilder::default().source(a.clone())?.destination(b.clone())?)?;
+        builder.edge(
+            FlowchartEdgeBuilder::default()
+                .source(b.clone())?
+                .destination(c.clone())?
+                .label("yes | no")?
+                .line_style(LineStyle::Dashed)
+                .length(3)
+                .left_arrow_shape(ArrowShape::Circle)?
+                .right_arrow_shape(ArrowShape::X)?,
+        )?;
+        builder.edge(
+            FlowchartEdgeBuilder::default()
+                .source(c.clone())?
+                .destination(a.clone())?
+                .line_style(LineStyle::Thick)
+                .left_arrow_shape(ArrowShape::Normal)?
+                .right_arrow_shape(ArrowShape::Normal)?,
+        )?;
+        builder.edge(
+            FlowchartEdgeBuilder::default()
+                .source(c)?
+                .destination(b)?
+                .length(2)
+                .right_arrow_shape(ArrowShape::Circle)?
+                .curve_style(CurveStyle::StepAfter),
+        )?;
+
+        assert_round_trip(&Flowchart::from(builder))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_full() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = FlowchartBuilder::default().configuration(
+            FlowchartConfigurationBuilder::default()
+                .title("Checkout: overview")?
+                .direction(Direction::TopToBottom)
+                .renderer(Renderer::EclipseLayoutKernel)
+                .theme(Theme::Forest)
+                .look(Look::HandDrawn)
+                .html_labels(false)
+                .markdown_auto_wrap(false)
+                .curve_style(CurveStyle::Linear),
+        )?;
+        let important = builder.style_class(
+            StyleClassBuilder::default()
+                .name("important")?
+                .property(StyleProperty::Fill(Color::from((255, 0, 0))))?
+                .property(StyleProperty::StrokeDasharray(5, 2))?
+                .property(StyleProperty::BorderRadius(Unit::Pixel(4)))?,
+        )?;
+        let muted = builder.style_class(
+            StyleClassBuilder::default()
+                .name("muted")?
+                .property(StyleProperty::Opacity(40))?
+                .property(StyleProperty::FontWeight(FontWeight::Lighter))?,
+        )?;
+        let cart = builder.node(
+            FlowchartNodeBuilder::default()
+                .label("Cart")?
+                .shape(FlowchartNodeShape::StackedDocument)
+                .style_class(important.clone())?
+                .style_class(muted.clone())?
+                .style_property(StyleProperty::Stroke(Color::from((0, 0, 255))))?
+                .style_property(StyleProperty::StrokeDasharray(3, 1))?
+                .click_event(ClickEvent::Navigation(
+                    Navigation::new("https://example.com/cart").anchor(true).tooltip("Open"),
+                )),
+        )?;
+        let pay = builder.node(
+            FlowchartNodeBuilder::default()
+                .label("Pay")?
+                .shape(FlowchartNodeShape::Hexagon)
+                .click_event(ClickEvent::Navigation(
+                    Navigation::new("https://example.com/pay").new_tab(true),
+                )),
+        )?;
+        let outside =
+            builder.node(FlowchartNodeBuilder::default().label("Outside")?.click_event(
+                ClickEvent::JsFunctionCall(JsFunctionCall::new(
+                    "notify",
+                    vec!["a".to_string(), "b".to_string()],
+                )),
+            ))?;
+        let ship = builder.node(
+            FlowchartNodeBuilder::default()
+                .label("Ship")?
+                .click_event(ClickEvent::JsFunctionCall(JsFunctionCall::new("ship", vec![]))),
+        )?;
+        let payment = builder.node(
+            FlowchartNodeBuilder::default()
+                .label("Payment")?
+                .subnode(pay.clone())?
+                .direction(Direction::BottomToTop)
+                .style_class(muted.clone())?
+                .style_property(StyleProperty::Fill(Color::from((0, 255, 0))))?,
+        )?;
+        let checkout = builder.node(
+            FlowchartNodeBuilder::default()
+                .label("Checkout")?
+                .subnode(payment)?
+                .subnode(cart.clone())?
+                .subnode(ship.clone())?,
+        )?;
+
+        builder.edge(
+            FlowchartEdgeBuilder::default()
+                .source(cart.clone())?
+                .destination(pay.clone())?
+                .label("checkout")?
+                .style_class(important)?
+                .style_property(StyleProperty::StrokeWidth(Unit::Point(3)))?
+                .style_property(StyleProperty::BorderRadius(Unit::Pixel(2)))?,
+        )?;
+        builder.edge(
+            FlowchartEdgeBuilder::default()
+                .source(pay)?
+                .destination(ship)?
+                .curve_style(CurveStyle::BumpY)
+                .style_class(muted)?,
+        )?;
+        builder.edge(FlowchartEdgeBuilder::default().source(outside)?.destination(checkout)?)?;
+
+        assert_round_trip(&Flowchart::from(builder))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_drops_unused_style_classes() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = FlowchartBuilder::default();
+        builder.style_class(
+            StyleClassBuilder::default()
+                .name("unused")?
+                .property(StyleProperty::Color(Color::from((0, 0, 0))))?,
+        )?;
+        builder.node(FlowchartNodeBuilder::default().label("A")?)?;
+        let flowchart = Flowchart::from(builder);
+
+        let parsed: Flowchart = flowchart.to_string().parse()?;
+        assert_eq!(parsed.style_classes().count(), 0);
+        assert_ne!(parsed, flowchart);
+        assert_eq!(parsed.to_string(), flowchart.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_multiline_labels() -> Result<(), Box<dyn core::error::Error>> {
+        assert_eq!(
+            FlowchartNodeBuilder::default().label("first\nsecond"),
+            Err(NodeError::InvalidLabel("first\nsecond".to_string()))
+        );
+        assert_eq!(
+            FlowchartEdgeBuilder::default().label("first\r\nsecond"),
+            Err(EdgeError::InvalidLabel("first\r\nsecond".to_string()))
+        );
+
+        let mut builder = FlowchartBuilder::default();
+        let a = builder.node(FlowchartNodeBuilder::default().label("first<br>second")?)?;
+        let b = builder.node(FlowchartNodeBuilder::default().label("B")?)?;
+        builder.edge(
+            FlowchartEdgeBuilder::default().source(a)?.destination(b)?.label("one<br/>two")?,
+        )?;
+        assert_round_trip(&Flowchart::from(builder))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_shapes() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = FlowchartBuilder::default();
+        for shape in [
+            FlowchartNodeShape::Rectangle,
+            FlowchartNodeShape::RoundEdges,
+            FlowchartNodeShape::StadiumShape,
+            FlowchartNodeShape::Subprocess,
+            FlowchartNodeShape::Cylinder,
+            FlowchartNodeShape::Circle,
+            FlowchartNodeShape::Odd,
+            FlowchartNodeShape::Diamond,
+            FlowchartNodeShape::Hexagon,
+            FlowchartNodeShape::LRParallelogram,
+            FlowchartNodeShape::LLParallelogram,
+            FlowchartNodeShape::Trapezoid,
+            FlowchartNodeShape::ReverseTrapezoid,
+            FlowchartNodeShape::DoubleCircle,
+            FlowchartNodeShape::NotchedRectangle,
+            FlowchartNodeShape::Linedrectangle,
+            FlowchartNodeShape::SmallCircle,
+            FlowchartNodeShape::FramedCircle,
+            FlowchartNodeShape::LongRectangle,
+            FlowchartNodeShape::Hourglass,
+            FlowchartNodeShape::LeftCurlyBrace,
+            FlowchartNodeShape::RightCurlyBrace,
+            FlowchartNodeShape::CurlyBraces,
+            FlowchartNodeShape::LightningBolt,
+            FlowchartNodeShape::Document,
+            FlowchartNodeShape::HalfRoundedRectangle,
+            FlowchartNodeShape::HorizontalCylinder,
+            FlowchartNodeShape::LinedCylinder,
+            FlowchartNodeShape::CurvedTrapezoid,
+            FlowchartNodeShape::DividedRectangle,
+            FlowchartNodeShape::SmallTriangle,
+            FlowchartNodeShape::WindowPane,
+            FlowchartNodeShape::FilledCircle,
+            FlowchartNodeShape::LinedDocument,
+            FlowchartNodeShape::NotchedPentagon,
+            FlowchartNodeShape::FlippedTriangle,
+            FlowchartNodeShape::SlopedRectangle,
+            FlowchartNodeShape::StackedDocument,
+            FlowchartNodeShape::StackedRectangle,
+            FlowchartNodeShape::Flag,
+            FlowchartNodeShape::BowTieRectangle,
+            FlowchartNodeShape::CrossedCircle,
+            FlowchartNodeShape::TaggedDocument,
+            FlowchartNodeShape::TaggedRectangle,
+            FlowchartNodeShape::FramedRectangle,
+            FlowchartNodeShape::TextBlock,
+        ] {
+            builder.node(FlowchartNodeBuilder::default().label("Node")?.shape(shape))?;
+        }
+
+        assert_round_trip(&Flowchart::from(builder))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |source: &str| {
+            source
+                .parse::<Flowchart>()
+                .map(|_| ())
+                .map_err(|error| (error.line(), error.column(), error.kind().clone()))
+        };
+
+        assert_eq!(error(""), Err((1, 1, ParseErrorKind::MissingHeader("flowchart".to_string()))));
+        assert_eq!(
+            error("graph LR\n"),
+            Err((1, 1, ParseErrorKind::MissingHeader("flowchart".to_string())))
+        );
+        assert_eq!(
+            error("---\ntitle: Open\n"),
+            Err((1, 1, ParseErrorKind::UnclosedBlock("---".to_string())))
+        );
+        assert_eq!(
+            error("---\nconfig:\n  theme: pink\n---\nflowchart LR\n"),
+            Err((3, 10, ParseErrorKind::InvalidValue("pink".to_string())))
+        );
+        assert_eq!(
+            error("flowchart XY\n"),
+            Err((1, 11, ParseErrorKind::InvalidValue("XY".to_string())))
+        );
+        assert_eq!(
+            error("flowchart LR\n  v0@{shape: blob, label: \"A\"}\n"),
+            Err((2, 14, ParseErrorKind::InvalidValue("blob".to_string())))
+        );
+        assert_eq!(
+            error("flowchart LR\n  v0@{shape: rect, label: \"A\"}\n  v0 --> v1\n"),
+            Err((3, 10, ParseErrorKind::UnknownIdentifier("v1".to_string())))
+        );
+        assert_eq!(
+            error(
+                "flowchart LR\n  v0@{shape: rect, label: \"A\"}\n  v0@{shape: rect, label: \"B\"}\n"
+            ),
+            Err((3, 3, ParseErrorKind::DuplicateIdentifier("v0".to_string())))
+        );
+        assert_eq!(
+            error("flowchart LR\n  v0@{shape: rect, label: \"A\"}\n  v0 -~> v0\n"),
+            Err((3, 6, ParseErrorKind::InvalidValue("-~>".to_string())))
+        );
+        assert_eq!(
+            error(
+                "flowchart LR\n  subgraph v1 [\"`Group`\"]\n    v0@{shape: rect, label: \"A\"}\n"
+            ),
+            Err((2, 3, ParseErrorKind::UnclosedBlock("subgraph".to_string())))
+        );
+        assert_eq!(
+            error("flowchart LR\n  v0@{shape: rect, label: \"A\"}\n  class v0 missing\n"),
+            Err((3, 12, ParseErrorKind::UnknownIdentifier("missing".to_string())))
+        );
+        assert_eq!(
+            error("flowchart LR\n  end\n"),
+            Err((2, 3, ParseErrorKind::UnexpectedStatement("end".to_string())))
+        );
+        assert_eq!(
+            error("flowchart LR\n  classDef hot fill: red\n"),
+            Err((2, 16, ParseErrorKind::InvalidValue("fill: red".to_string())))
+        );
+        assert!(matches!(
+            error("flowchart LR\n  v0@{shape: rect, label: \"\"}\n"),
+            Err((2, 3, ParseErrorKind::Diagram(_)))
+        ));
+    }
+}
@@ -0,0 +1,268 @@
+//! Submodule providing structs for creating Gantt charts in Mermaid syntax.
+
+mod builder;
+mod configuration;
+mod date;
+mod duration;
+mod section;
+mod task;
+use alloc::{rc::Rc, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::GanttBuilder;
+pub use configuration::{
+    GanttConfiguration, GanttConfigurationBuilder, GanttExclusion, GanttTickInterval,
+    GanttTickUnit, GanttTodayMarker, GanttWeekday,
+};
+pub use date::{GanttDate, GanttDateFormat};
+pub use duration::GanttDuration;
+pub use section::GanttSection;
+pub use task::{GanttTask, GanttTaskBuilder, GanttTaskEnd, GanttTaskStart, GanttTaskStatus};
+
+use crate::traits::TabbedDisplay;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a Gantt chart in Mermaid syntax.
+///
+/// The `dateFormat` of the chart is the least precise format which represents
+/// all of its dates exactly, and every task is written with an explicit ID
+/// and start, so that the chart does not depend on the implicit sequencing
+/// rules of Mermaid.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = GanttBuilder::default();
+///     builder.section("Lab")?;
+///     builder.task(
+///         GanttTaskBuilder::default()
+///             .label("Prepare")?
+///             .start(GanttDate::new(2024, 1, 8)?)
+///             .duration(GanttDuration::Days(2)),
+///     )?;
+///     builder
+///         .task(GanttTaskBuilder::default().label("Measure")?.duration(GanttDuration::Days(1)))?;
+///
+///     let gantt = Gantt::from(builder);
+///     assert_eq!(
+///         gantt.to_string(),
+///         concat!(
+///             "gantt\n",
+///             "  dateFormat YYYY-MM-DD\n",
+///             "  section Lab\n",
+///             "    Prepare :v0, 2024-01-08, 2d\n",
+///             "    Measure :v1, after v0, 1d\n",
+///         )
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct Gantt {
+    /// Configuration of the chart.
+    configuration: GanttConfiguration,
+    /// Sections of the chart.
+    sections: Vec<GanttSection>,
+}
+
+impl Gantt {
+    #[must_use]
+    /// Returns the configuration of the chart.
+    pub fn configuration(&self) -> &GanttConfiguration {
+        &self.configuration
+    }
+
+    /// Returns an iterator over the sections of the chart.
+    pub fn sections(&self) -> impl Iterator<Item = &GanttSection> {
+        self.sections.iter()
+    }
+
+    /// Returns an iterator over the tasks of the chart.
+    pub fn tasks(&self) -> impl Iterator<Item = &Rc<GanttTask>> {
+        self.sections.iter().flat_map(GanttSection::tasks)
+    }
+
+    #[must_use]
+    /// Returns the task with the provided ID, if any.
+    pub fn get_task_by_id(&self, id: u64) -> Option<Rc<GanttTask>> {
+        self.tasks().find(|task| task.id() == id).cloned()
+    }
+
+    #[must_use]
+    /// Returns the date format set in the configuration, or else the least
+    /// precise date format which represents all the dates of the chart
+    /// exactly.
+    pub fn date_format(&self) -> GanttDateFormat {
+        if let Some(date_format) = self.configuration.date_format() {
+            return date_format;
+        }
+        let excluded_dates =
+            self.configuration.excludes().iter().filter_map(|exclusion| match exclusion {
+                GanttExclusion::Date(date) => Some(date.required_format()),
+                _ => None,
+            });
+        self.tasks()
+            .map(|task| task.required_format())
+            .chain(excluded_dates)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl Display for Gantt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for Gantt {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat((tab_count + 1) * 2);
+        let date_format = self.date_format();
+        write!(f, "{}", self.configuration)?;
+        writeln!(f, "{}gantt", " ".repeat(tab_count * 2))?;
+        writeln!(f, "{indent}dateFormat {date_format}")?;
+        if let Some(axis_format) = self.configuration.axis_format() {
+            writeln!(f, "{indent}axisFormat {axis_format}")?;
+        }
+        if let Some(tick_interval) = self.configuration.tick_interval() {
+            writeln!(f, "{indent}tickInterval {tick_interval}")?;
+        }
+        if !self.configuration.excludes().is_empty() {
+            write!(f, "{indent}excludes ")?;
+            for (exclusion_number, exclusion) in self.configuration.excludes().iter().enumerate() {
+                if exclusion_number > 0 {
+                    write!(f, ",")?;
+                }
+                // Mermaid compares excluded dates with the days of the chart
+                // formatted according to its `dateFormat`.
+                match exclusion {
+                    GanttExclusion::Date(date) => write!(f, "{}", date_format.render(*date))?,
+                    _ => write!(f, "{exclusion}")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        if self.configuration.today_marker() != &GanttTodayMarker::Default {
+            writeln!(f, "{indent}todayMarker {}", self.configuration.today_marker())?;
+        }
+
+        for section in &self.sections {
+            let task_tab_count = if let Some(label) = section.label() {
+                writeln!(f, "{indent}section {label}")?;
+                tab_count + 2
+            } else {
+                tab_count + 1
+            };
+            for task in section.tasks() {
+                task.fmt_scheduled(f, task_tab_count, date_format)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString, vec};
+    use core::num::NonZeroU32;
+
+    use super::*;
+    use crate::{
+        shared::{Color, StyleProperty, Unit},
+        traits::ConfigurationBuilder,
+    };
+
+    #[test]
+    fn test_gantt_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = GanttBuilder::default().configuration(
+            GanttConfigurationBuilder::default()
+                .title("Campaign")?
+                .axis_format("%d/%m")?
+                .tick_interval(GanttTickInterval::new(
+                    NonZeroU32::new(2).ok_or("zero")?,
+                    GanttTickUnit::Day,
+                ))
+                .exclude(GanttExclusion::Weekends)
+                .exclude(GanttExclusion::Date(GanttDate::new(2024, 1, 10)?))
+                .today_marker(GanttTodayMarker::Styled(vec![
+                    StyleProperty::StrokeWidth(Unit::Pixel(5)),
+                    StyleProperty::Stroke(Color::from((0, 255, 0))),
+                ])),
+        )?;
+
+        let kickoff = builder.task(
+            GanttTaskBuilder::default()
+                .label("Kickoff")?
+                .milestone()
+                .start(GanttDate::new(2024, 1, 8)?.with_time(9, 0, 0)?)
+                .duration(GanttDuration::Hours(1)),
+        )?;
+        builder.section("Preparation")?;
+        let order = builder.task(
+            GanttTaskBuilder::default()
+                .label("Order")?
+                .status(GanttTaskStatus::Done)
+                .duration(GanttDuration::Days(3)),
+        )?;
+        let setup = builder.task(
+            GanttTaskBuilder::default()
+                .label("Setup")?
+                .id(10)
+                .status(GanttTaskStatus::Active)
+                .after(kickoff)?
+                .duration(GanttDuration::Days(1)),
+        )?;
+        builder.section("Runs")?;
+        let report = builder.task(
+            GanttTaskBuilder::default()
+                .label("Report")?
+                .start(GanttDate::new(2024, 1, 26)?)
+                .end(GanttDate::new(2024, 1, 29)?),
+        )?;
+        builder.task(
+            GanttTaskBuilder::default()
+                .label("Run")?
+                .critical()
+                .after(order)?
+                .after(setup)?
+                .until(report),
+        )?;
+
+        let gantt = Gantt::from(builder);
+        assert_eq!(gantt.date_format(), GanttDateFormat::DateTime);
+        assert_eq!(gantt.sections().count(), 3);
+        assert_eq!(
+            gantt.get_task_by_id(10).map(|task| task.label().to_string()),
+            Some("Setup".into())
+        );
+        assert_eq!(
+            gantt.to_string(),
+            concat!(
+                "---\n",
+                "title: Campaign\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "---\n",
+                "gantt\n",
+                "  dateFormat YYYY-MM-DD HH:mm\n",
+                "  axisFormat %d/%m\n",
+                "  tickInterval 2day\n",
+                "  excludes weekends,2024-01-10 00:00\n",
+                "  todayMarker stroke-width: 5px,stroke: #00ff00\n",
+                "  Kickoff :milestone, v0, 2024-01-08 09:00, 1h\n",
+                "  section Preparation\n",
+                "    Order :done, v1, after v0, 3d\n",
+                "    Setup :active, v10, after v0, 1d\n",
+                "  section Runs\n",
+                "    Report :v3, 2024-01-26 00:00, 2024-01-29 00:00\n",
+                "    Run :crit, v4, after v1 v10, until v3\n",
+            )
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,281 @@
+//! Submodule providing a builder for Gantt charts.
+
+use alloc::{format, rc::Rc, string::String, vec::Vec};
+
+use crate::{
+    diagrams::gantt::{
+        Gantt, GanttDate,
+        configuration::{GanttConfiguration, GanttConfigurationBuilder},
+        section::GanttSection,
+        task::{GanttTask, GanttTaskBuilder},
+    },
+    errors::{ConfigError, GanttError},
+    shared::NODE_LETTER,
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a Gantt chart.
+///
+/// Tasks are added to the most recently opened section, and may only depend
+/// upon tasks which were already added to the chart.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = GanttBuilder::default();
+///     builder.section("Preparation")?;
+///     let order = builder.task(
+///         GanttTaskBuilder::default()
+///             .label("Order reagents")?
+///             .start(GanttDate::new(2024, 1, 8)?)
+///             .duration(GanttDuration::Days(5)),
+///     )?;
+///     builder.task(
+///         GanttTaskBuilder::default().label("Calibrate")?.duration(GanttDuration::Days(1)),
+///     )?;
+///     builder.section("Runs")?;
+///     builder.task(
+///         GanttTaskBuilder::default()
+///             .label("First run")?
+///             .critical()
+///             .after(order)?
+///             .duration(GanttDuration::Weeks(2)),
+///     )?;
+///
+///     let gantt = Gantt::from(builder);
+///     assert_eq!(gantt.tasks().count(), 3);
+///     Ok(())
+/// }
+/// ```
+pub struct GanttBuilder {
+    /// Configuration of the chart.
+    configuration: GanttConfiguration,
+    /// Sections of the chart.
+    sections: Vec<GanttSection>,
+}
+
+impl GanttBuilder {
+    /// Sets the configuration of the chart.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    /// * If the date format of the configuration cannot represent a date of
+    ///   the tasks already added to the chart.
+    pub fn configuration(
+        mut self,
+        configuration: GanttConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        let configuration = configuration.build()?;
+        if let Some(date) = self.tasks().find_map(|task| unrepresentable_date(&configuration, task))
+        {
+            return Err(ConfigError::UnrepresentableDate(date));
+        }
+        self.configuration = configuration;
+        Ok(self)
+    }
+
+    /// Opens a new section, to which the subsequently added tasks belong.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The label of the section.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty.
+    /// * If the label contains a colon, a semicolon, a hash or a line break,
+    ///   which are reserved by the syntax.
+    pub fn section(&mut self, label: impl Into<String>) -> Result<(), GanttError> {
+        let label = label.into();
+        if label.trim().is_empty() {
+            return Err(GanttError::EmptySectionLabel);
+        }
+        if label.contains([':', ';', '#', '\n', '\r']) {
+            return Err(GanttError::InvalidSectionLabel(label));
+        }
+        self.sections.push(GanttSection::new(Some(label)));
+        Ok(())
+    }
+
+    /// Adds a task to the current section of the chart.
+    ///
+    /// Tasks without an ID receive the number of tasks in the chart, or the
+    /// next ID which no task of the chart already uses.
+    ///
+    /// # Arguments
+    ///
+    /// * `task` - The builder of the task to add.
+    ///
+    /// # Errors
+    ///
+    /// * If a task with the same ID already exists in the chart.
+    /// * If the task depends upon a task which is not part of the chart.
+    /// * If the task has no start and is the first task of the chart.
+    /// * If the date format of the configuration cannot represent a date of
+    ///   the task.
+    /// * If the task builder fails to build.
+    pub fn task(&mut self, mut task: GanttTaskBuilder) -> Result<Rc<GanttTask>, GanttError> {
+        let id = task.get_id().unwrap_or_else(|| {
+            let mut id = self.tasks().count() as u64;
+            while self.get_task_by_id(id).is_some() {
+                id += 1;
+            }
+            id
+        });
+        if self.get_task_by_id(id).is_some() {
+            return Err(GanttError::DuplicateTask(format!("{NODE_LETTER}{id}")));
+        }
+        for dependency in task.dependencies() {
+            if self.get_task_by_id(dependency.id()).as_ref() != Some(dependency) {
+                return Err(GanttError::TaskNotFound(dependency.label().into()));
+            }
+        }
+        if !task.has_start()
+            && let Some(previous) = self.tasks().last()
+        {
+            task = task.after(previous.clone())?;
+        }
+
+        let task = Rc::new(task.id(id).build()?);
+        if let Some(date) = unrepresentable_date(&self.configuration, &task) {
+            return Err(GanttError::UnrepresentableDate(date));
+        }
+        if self.sections.is_empty() {
+            self.sections.push(GanttSection::new(None));
+        }
+        if let Some(section) = self.sections.last_mut() {
+            section.push(task.clone());
+        }
+        Ok(task)
+    }
+
+    /// Returns an iterator over the tasks of the chart.
+    pub fn tasks(&self) -> impl Iterator<Item = &Rc<GanttTask>> {
+        self.sections.iter().flat_map(GanttSection::tasks)
+    }
+
+    #[must_use]
+    /// Returns the task with the provided ID, if any.
+    pub fn get_task_by_id(&self, id: u64) -> Option<Rc<GanttTask>> {
+        self.tasks().find(|task| task.id() == id).cloned()
+    }
+}
+
+/// Returns the first date of the task which the date format of the
+/// configuration, if set, cannot represent.
+fn unrepresentable_date(configuration: &GanttConfiguration, task: &GanttTask) -> Option<GanttDate> {
+    let date_format = configuration.date_format()?;
+    task.dates().find(|date| date.required_format() > date_format)
+}
+
+impl From<GanttBuilder> for Gantt {
+    fn from(builder: GanttBuilder) -> Self {
+        Gantt { configuration: builder.configuration, sections: builder.sections }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::diagrams::gantt::{GanttDateFormat, GanttDuration, GanttTaskStart};
+
+    #[test]
+    fn test_gantt_builder() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = GanttBuilder::default();
+        let first = builder.task(
+            GanttTaskBuilder::default()
+                .label("First")?
+                .start(GanttDate::new(2024, 1, 1)?)
+                .duration(GanttDuration::Days(1)),
+        )?;
+        let second = builder
+            .task(GanttTaskBuilder::default().label("Second")?.duration(GanttDuration::Days(1)))?;
+
+        assert_eq!(first.id(), 0);
+        assert_eq!(second.id(), 1);
+        assert_eq!(second.start(), &GanttTaskStart::After(alloc::vec![first.clone()]));
+        assert_eq!(builder.get_task_by_id(1), Some(second));
+
+        let mut builder = GanttBuilder::default();
+        let task = GanttTaskBuilder::default()
+            .start(GanttDate::new(2024, 1, 1)?)
+            .duration(GanttDuration::Days(1));
+        let explicit = builder.task(task.clone().label("First")?.id(1))?;
+        let automatic = builder.task(task.label("Second")?)?;
+        assert_eq!(explicit.id(), 1);
+        assert_eq!(automatic.id(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_gantt_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = GanttBuilder::default();
+        assert_eq!(builder.section(""), Err(GanttError::EmptySectionLabel));
+        assert_eq!(builder.section("a;b"), Err(GanttError::InvalidSectionLabel("a;b".to_owned())));
+        assert_eq!(
+            builder
+                .task(GanttTaskBuilder::default().label("Lone")?.duration(GanttDuration::Days(1))),
+            Err(GanttError::MissingStart("Lone".to_owned()))
+        );
+
+        let date = GanttDate::new(2024, 1, 1)?;
+        let task = GanttTaskBuilder::default().label("Run")?.start(date).end(date);
+        builder.task(task.clone().id(3))?;
+        assert_eq!(
+            builder.task(task.clone().id(3)),
+            Err(GanttError::DuplicateTask("v3".to_owned()))
+        );
+
+        let foreign = Rc::new(task.clone().label("Foreign")?.id(7).build()?);
+        assert_eq!(
+            builder.task(task.clone().after(foreign.clone())?),
+            Err(GanttError::TaskNotFound("Foreign".to_owned()))
+        );
+        assert_eq!(
+            builder.task(task.until(foreign)),
+            Err(GanttError::TaskNotFound("Foreign".to_owned()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_gantt_builder_date_format() -> Result<(), Box<dyn core::error::Error>> {
+        let morning = GanttDate::new(2024, 1, 1)?.with_time(9, 30, 0)?;
+        let task = GanttTaskBuilder::default().label("Run")?.start(morning).end(morning);
+        let mut builder = GanttBuilder::default().configuration(
+            GanttConfigurationBuilder::default().date_format(GanttDateFormat::Date),
+        )?;
+        assert_eq!(builder.task(task.clone()), Err(GanttError::UnrepresentableDate(morning)));
+
+        let day = GanttDate::new(2024, 1, 2)?;
+        builder.task(GanttTaskBuilder::default().label("Review")?.start(day).end(day))?;
+        let gantt = Gantt::from(builder);
+        assert_eq!(gantt.date_format(), GanttDateFormat::Date);
+
+        let mut builder = GanttBuilder::default().configuration(
+            GanttConfigurationBuilder::default().date_format(GanttDateFormat::DateTime),
+        )?;
+        builder.task(GanttTaskBuilder::default().label("Review")?.start(day).end(day))?;
+        let displayed = Gantt::from(builder).to_string();
+        assert!(displayed.contains("dateFormat YYYY-MM-DD HH:mm\n"));
+        assert!(displayed.contains("v0, 2024-01-02 00:00, 2024-01-02 00:00\n"));
+
+        let mut builder = GanttBuilder::default();
+        builder.task(task)?;
+        assert_eq!(
+            builder.configuration(
+                GanttConfigurationBuilder::default().date_format(GanttDateFormat::Date)
+            ),
+            Err(ConfigError::UnrepresentableDate(morning))
+        );
+        Ok(())
+    }
+}
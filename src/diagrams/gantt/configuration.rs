@@ -0,0 +1,133 @@
+//! Submodule defining configuration specifically for Gantt charts in Mermaid.
+
+mod builder;
+mod exclusion;
+mod tick_interval;
+mod today_marker;
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Display;
+
+pub use builder::GanttConfigurationBuilder;
+pub use exclusion::{GanttExclusion, GanttWeekday};
+pub use tick_interval::{GanttTickInterval, GanttTickUnit};
+pub use today_marker::GanttTodayMarker;
+
+use crate::{
+    diagrams::gantt::GanttDateFormat,
+    shared::{
+        Direction, Renderer,
+        generic_configuration::{GenericConfiguration, Look, Theme},
+    },
+    traits::Configuration,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the configuration for a Gantt chart in Mermaid syntax.
+///
+/// Unless it is set, the `dateFormat` of the chart is derived from the dates
+/// of its tasks.
+pub struct GanttConfiguration {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfiguration,
+    /// The format in which the dates of the chart are written, if set.
+    date_format: Option<GanttDateFormat>,
+    /// The format of the dates displayed on the axis, e.g. `%Y-%m-%d`.
+    axis_format: Option<String>,
+    /// The interval between the ticks of the axis.
+    tick_interval: Option<GanttTickInterval>,
+    /// The days excluded from the schedule.
+    excludes: Vec<GanttExclusion>,
+    /// The marker of the current day.
+    today_marker: GanttTodayMarker,
+}
+
+impl GanttConfiguration {
+    #[must_use]
+    /// Returns the format in which the dates of the chart are written, if
+    /// set.
+    pub fn date_format(&self) -> Option<GanttDateFormat> {
+        self.date_format
+    }
+
+    #[must_use]
+    /// Returns the format of the dates displayed on the axis, if any.
+    pub fn axis_format(&self) -> Option<&str> {
+        self.axis_format.as_deref()
+    }
+
+    #[must_use]
+    /// Returns the interval between the ticks of the axis, if any.
+    pub fn tick_interval(&self) -> Option<GanttTickInterval> {
+        self.tick_interval
+    }
+
+    #[must_use]
+    /// Returns the days excluded from the schedule.
+    pub fn excludes(&self) -> &[GanttExclusion] {
+        &self.excludes
+    }
+
+    #[must_use]
+    /// Returns the marker of the current day.
+    pub fn today_marker(&self) -> &GanttTodayMarker {
+        &self.today_marker
+    }
+}
+
+impl Display for GanttConfiguration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.generic.fmt_frontmatter(f, true, &[])
+    }
+}
+
+impl Configuration for GanttConfiguration {
+    type Builder = GanttConfigurationBuilder;
+
+    fn title(&self) -> Option<&str> {
+        self.generic.title()
+    }
+
+    fn direction(&self) -> Direction {
+        self.generic.direction()
+    }
+
+    fn renderer(&self) -> Renderer {
+        self.generic.renderer()
+    }
+
+    fn theme(&self) -> Theme {
+        self.generic.theme()
+    }
+
+    fn look(&self) -> Look {
+        self.generic.look()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format};
+
+    use super::*;
+    use crate::traits::ConfigurationBuilder;
+
+    #[test]
+    fn test_gantt_configuration_display_default() {
+        let config = GanttConfiguration::default();
+        assert_eq!(format!("{config}"), "");
+    }
+
+    #[test]
+    fn test_gantt_configuration_display_full() -> Result<(), Box<dyn core::error::Error>> {
+        let config =
+            GanttConfigurationBuilder::default().title("Campaign")?.theme(Theme::Dark).build()?;
+
+        assert_eq!(
+            format!("{config}"),
+            "---\ntitle: Campaign\nconfig:\n  theme: dark\n  look: classic\n---\n"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,224 @@
+//! Submodule providing a builder struct for the configuration of Gantt charts
+//! in Mermaid syntax.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    diagrams::gantt::{
+        GanttDateFormat,
+        configuration::{GanttConfiguration, GanttExclusion, GanttTickInterval, GanttTodayMarker},
+    },
+    errors::ConfigError,
+    shared::generic_configuration::{
+        Direction, GenericConfigurationBuilder, Look, Renderer, Theme,
+    },
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating Gantt chart configurations.
+///
+/// # Example
+///
+/// ```
+/// use std::num::NonZeroU32;
+///
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let config = GanttConfigurationBuilder::default()
+///         .title("Campaign")?
+///         .axis_format("%d/%m")?
+///         .tick_interval(GanttTickInterval::new(NonZeroU32::new(1).unwrap(), GanttTickUnit::Week))
+///         .exclude(GanttExclusion::Weekends)
+///         .today_marker(GanttTodayMarker::Off)
+///         .build()?;
+///
+///     assert_eq!(config.axis_format(), Some("%d/%m"));
+///     assert_eq!(config.excludes(), &[GanttExclusion::Weekends]);
+///     Ok(())
+/// }
+/// ```
+pub struct GanttConfigurationBuilder {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfigurationBuilder,
+    /// The format in which the dates of the chart are written, if set.
+    date_format: Option<GanttDateFormat>,
+    /// The format of the dates displayed on the axis.
+    axis_format: Option<String>,
+    /// The interval between the ticks of the axis.
+    tick_interval: Option<GanttTickInterval>,
+    /// The days excluded from the schedule.
+    excludes: Vec<GanttExclusion>,
+    /// The marker of the current day.
+    today_marker: GanttTodayMarker,
+}
+
+impl GanttConfigurationBuilder {
+    #[must_use]
+    /// Sets the format in which the dates of the chart are written, instead
+    /// of the least precise format representing all of them exactly.
+    ///
+    /// Tasks and excluded dates which the format cannot represent, such as a
+    /// task starting at `09:30` with the [`GanttDateFormat::Date`] format,
+    /// are rejected.
+    pub fn date_format(mut self, date_format: GanttDateFormat) -> Self {
+        self.date_format = Some(date_format);
+        self
+    }
+
+    /// Sets the format of the dates displayed on the axis, using the `d3`
+    /// time format specifiers (e.g. `%Y-%m-%d`).
+    ///
+    /// # Arguments
+    ///
+    /// * `axis_format` - The format of the dates displayed on the axis.
+    ///
+    /// # Errors
+    ///
+    /// * If the provided format is empty.
+    pub fn axis_format(mut self, axis_format: impl Into<String>) -> Result<Self, ConfigError> {
+        let axis_format = axis_format.into();
+        if axis_format.trim().is_empty() {
+            return Err(ConfigError::EmptyAxisFormat);
+        }
+        self.axis_format = Some(axis_format);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the interval between the ticks of the axis.
+    pub fn tick_interval(mut self, tick_interval: GanttTickInterval) -> Self {
+        self.tick_interval = Some(tick_interval);
+        self
+    }
+
+    #[must_use]
+    /// Excludes the provided days from the schedule. Exclusions which were
+    /// already provided are ignored.
+    pub fn exclude(mut self, exclusion: GanttExclusion) -> Self {
+        if !self.excludes.contains(&exclusion) {
+            self.excludes.push(exclusion);
+        }
+        self
+    }
+
+    #[must_use]
+    /// Sets the marker of the current day.
+    pub fn today_marker(mut self, today_marker: GanttTodayMarker) -> Self {
+        self.today_marker = today_marker;
+        self
+    }
+
+    /// Sets the theme to use for the diagram.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.generic = self.generic.theme(theme);
+        self
+    }
+
+    /// Sets the look to use for the diagram.
+    #[must_use]
+    pub fn look(mut self, look: Look) -> Self {
+        self.generic = self.generic.look(look);
+        self
+    }
+}
+
+impl TryFrom<GanttConfigurationBuilder> for GanttConfiguration {
+    type Error = ConfigError;
+
+    fn try_from(builder: GanttConfigurationBuilder) -> Result<Self, Self::Error> {
+        if let Some(date_format) = builder.date_format {
+            for exclusion in &builder.excludes {
+                if let GanttExclusion::Date(date) = exclusion
+                    && date.required_format() > date_format
+                {
+                    return Err(ConfigError::UnrepresentableDate(*date));
+                }
+            }
+        }
+
+        Ok(GanttConfiguration {
+            generic: builder.generic.try_into()?,
+            date_format: builder.date_format,
+            axis_format: builder.axis_format,
+            tick_interval: builder.tick_interval,
+            excludes: builder.excludes,
+            today_marker: builder.today_marker,
+        })
+    }
+}
+
+impl ConfigurationBuilder for GanttConfigurationBuilder {
+    type Configuration = GanttConfiguration;
+    type Error = ConfigError;
+
+    fn build(self) -> Result<Self::Configuration, Self::Error> {
+        self.try_into()
+    }
+
+    fn title<S: ToString>(mut self, title: S) -> Result<Self, Self::Error> {
+        self.generic = self.generic.title(title)?;
+        Ok(self)
+    }
+
+    fn direction(mut self, direction: Direction) -> Self {
+        self.generic = self.generic.direction(direction);
+        self
+    }
+
+    fn renderer(mut self, renderer: Renderer) -> Self {
+        self.generic = self.generic.renderer(renderer);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+
+    use super::*;
+    use crate::{
+        diagrams::gantt::{GanttDate, GanttWeekday},
+        traits::Configuration,
+    };
+
+    #[test]
+    fn test_gantt_configuration_builder() -> Result<(), Box<dyn core::error::Error>> {
+        let config = GanttConfigurationBuilder::default()
+            .title("Campaign")?
+            .exclude(GanttExclusion::Weekday(GanttWeekday::Friday))
+            .exclude(GanttExclusion::Weekday(GanttWeekday::Friday))
+            .today_marker(GanttTodayMarker::Off)
+            .build()?;
+
+        assert_eq!(config.title(), Some("Campaign"));
+        assert_eq!(config.excludes().len(), 1);
+        assert_eq!(config.today_marker(), &GanttTodayMarker::Off);
+        assert_eq!(config.axis_format(), None);
+        assert_eq!(
+            GanttConfigurationBuilder::default().axis_format(" "),
+            Err(ConfigError::EmptyAxisFormat)
+        );
+
+        let date = GanttDate::new(2024, 1, 8)?.with_time(9, 30, 0)?;
+        let config = GanttConfigurationBuilder::default()
+            .date_format(GanttDateFormat::DateTime)
+            .exclude(GanttExclusion::Date(date))
+            .build()?;
+        assert_eq!(config.date_format(), Some(GanttDateFormat::DateTime));
+        assert_eq!(
+            GanttConfigurationBuilder::default()
+                .date_format(GanttDateFormat::Date)
+                .exclude(GanttExclusion::Date(date))
+                .build(),
+            Err(ConfigError::UnrepresentableDate(date))
+        );
+        Ok(())
+    }
+}
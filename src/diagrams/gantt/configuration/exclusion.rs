@@ -0,0 +1,64 @@
+//! Submodule defining the days which may be excluded from the schedule of
+//! Gantt charts.
+
+use core::fmt::{self, Display};
+
+use crate::diagrams::gantt::date::GanttDate;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a day of the week.
+pub enum GanttWeekday {
+    /// Monday.
+    Monday,
+    /// Tuesday.
+    Tuesday,
+    /// Wednesday.
+    Wednesday,
+    /// Thursday.
+    Thursday,
+    /// Friday.
+    Friday,
+    /// Saturday.
+    Saturday,
+    /// Sunday.
+    Sunday,
+}
+
+impl Display for GanttWeekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GanttWeekday::Monday => write!(f, "monday"),
+            GanttWeekday::Tuesday => write!(f, "tuesday"),
+            GanttWeekday::Wednesday => write!(f, "wednesday"),
+            GanttWeekday::Thursday => write!(f, "thursday"),
+            GanttWeekday::Friday => write!(f, "friday"),
+            GanttWeekday::Saturday => write!(f, "saturday"),
+            GanttWeekday::Sunday => write!(f, "sunday"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents days which are excluded from the schedule of a Gantt chart.
+///
+/// Tasks are extended by the excluded days falling within them.
+pub enum GanttExclusion {
+    /// Excludes Saturdays and Sundays.
+    Weekends,
+    /// Excludes every occurrence of a day of the week.
+    Weekday(GanttWeekday),
+    /// Excludes a specific day.
+    Date(GanttDate),
+}
+
+impl Display for GanttExclusion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GanttExclusion::Weekends => write!(f, "weekends"),
+            GanttExclusion::Weekday(weekday) => write!(f, "{weekday}"),
+            GanttExclusion::Date(date) => write!(f, "{date}"),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Submodule defining the interval between the ticks of the axis of Gantt
+//! charts.
+
+use core::{
+    fmt::{self, Display},
+    num::NonZeroU32,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the unit of time of a tick interval.
+pub enum GanttTickUnit {
+    /// Ticks every few milliseconds.
+    Millisecond,
+    /// Ticks every few seconds.
+    Second,
+    /// Ticks every few minutes.
+    Minute,
+    /// Ticks every few hours.
+    Hour,
+    /// Ticks every few days.
+    Day,
+    /// Ticks every few weeks.
+    Week,
+    /// Ticks every few months.
+    Month,
+}
+
+impl Display for GanttTickUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GanttTickUnit::Millisecond => write!(f, "millisecond"),
+            GanttTickUnit::Second => write!(f, "second"),
+            GanttTickUnit::Minute => write!(f, "minute"),
+            GanttTickUnit::Hour => write!(f, "hour"),
+            GanttTickUnit::Day => write!(f, "day"),
+            GanttTickUnit::Week => write!(f, "week"),
+            GanttTickUnit::Month => write!(f, "month"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the `tickInterval` of a Gantt chart, e.g. `1week`.
+pub struct GanttTickInterval {
+    /// The number of units between two ticks.
+    count: NonZeroU32,
+    /// The unit of time of the interval.
+    unit: GanttTickUnit,
+}
+
+impl GanttTickInterval {
+    #[must_use]
+    /// Creates a new tick interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of units between two ticks.
+    /// * `unit` - The unit of time of the interval.
+    pub fn new(count: NonZeroU32, unit: GanttTickUnit) -> Self {
+        Self { count, unit }
+    }
+
+    #[must_use]
+    /// Returns the number of units between two ticks.
+    pub fn count(self) -> NonZeroU32 {
+        self.count
+    }
+
+    #[must_use]
+    /// Returns the unit of time of the interval.
+    pub fn unit(self) -> GanttTickUnit {
+        self.unit
+    }
+}
+
+impl Display for GanttTickInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.count, self.unit)
+    }
+}
@@ -0,0 +1,37 @@
+//! Submodule defining the marker of the current day in Gantt charts.
+
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+use crate::shared::StyleProperty;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the `todayMarker` of a Gantt chart.
+pub enum GanttTodayMarker {
+    /// The current day is marked with the default style of the theme.
+    #[default]
+    Default,
+    /// The current day is not marked.
+    Off,
+    /// The current day is marked with the provided style properties.
+    Styled(Vec<StyleProperty>),
+}
+
+impl Display for GanttTodayMarker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GanttTodayMarker::Default => Ok(()),
+            GanttTodayMarker::Off => write!(f, "off"),
+            GanttTodayMarker::Styled(properties) => {
+                for (property_number, property) in properties.iter().enumerate() {
+                    if property_number > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{property}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Submodule defining a `no_std` calendar date type, optionally with a time of
+//! the day, used to schedule the tasks of Gantt charts.
+
+use alloc::{format, string::String};
+use core::fmt::{self, Display};
+
+use crate::errors::GanttError;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a validated calendar date in the proleptic Gregorian calendar,
+/// optionally with a time of the day.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let date = GanttDate::new(2024, 2, 29)?;
+///     assert_eq!(date.to_string(), "2024-02-29");
+///
+///     let date = date.with_time(9, 30, 0)?;
+///     assert_eq!(date.to_string(), "2024-02-29 09:30");
+///
+///     assert!(GanttDate::new(2023, 2, 29).is_err());
+///     Ok(())
+/// }
+/// ```
+pub struct GanttDate {
+    /// The year of the date.
+    year: u16,
+    /// The month of the date, from 1 to 12.
+    month: u8,
+    /// The day of the month, from 1.
+    day: u8,
+    /// The hour of the day, from 0 to 23.
+    hour: u8,
+    /// The minute of the hour, from 0 to 59.
+    minute: u8,
+    /// The second of the minute, from 0 to 59.
+    second: u8,
+}
+
+impl GanttDate {
+    /// Creates a new date at midnight.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The year of the date.
+    /// * `month` - The month of the date, from 1 to 12.
+    /// * `day` - The day of the month, from 1.
+    ///
+    /// # Errors
+    ///
+    /// * If the provided day does not exist in the provided month and year.
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self, GanttError> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return Err(GanttError::InvalidDate { year, month, day });
+        }
+        Ok(Self { year, month, day, hour: 0, minute: 0, second: 0 })
+    }
+
+    /// Sets the time of the day of the date.
+    ///
+    /// # Arguments
+    ///
+    /// * `hour` - The hour of the day, from 0 to 23.
+    /// * `minute` - The minute of the hour, from 0 to 59.
+    /// * `second` - The second of the minute, from 0 to 59.
+    ///
+    /// # Errors
+    ///
+    /// * If the provided time of the day does not exist.
+    pub fn with_time(mut self, hour: u8, minute: u8, second: u8) -> Result<Self, GanttError> {
+        if hour > 23 || minute > 59 || second > 59 {
+            return Err(GanttError::InvalidTime { hour, minute, second });
+        }
+        self.hour = hour;
+        self.minute = minute;
+        self.second = second;
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Returns the year of the date.
+    pub fn year(self) -> u16 {
+        self.year
+    }
+
+    #[must_use]
+    /// Returns the month of the date, from 1 to 12.
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    #[must_use]
+    /// Returns the day of the month.
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    #[must_use]
+    /// Returns the hour of the day.
+    pub fn hour(self) -> u8 {
+        self.hour
+    }
+
+    #[must_use]
+    /// Returns the minute of the hour.
+    pub fn minute(self) -> u8 {
+        self.minute
+    }
+
+    #[must_use]
+    /// Returns the second of the minute.
+    pub fn second(self) -> u8 {
+        self.second
+    }
+
+    #[must_use]
+    /// Returns the least precise format which represents the date exactly.
+    pub fn required_format(self) -> GanttDateFormat {
+        if self.second != 0 {
+            GanttDateFormat::DateTimeSeconds
+        } else if self.hour != 0 || self.minute != 0 {
+            GanttDateFormat::DateTime
+        } else {
+            GanttDateFormat::Date
+        }
+    }
+}
+
+impl Display for GanttDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.required_format().render(*self))
+    }
+}
+
+/// Returns the number of days in the provided month of the provided year.
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the `dateFormat` of a Gantt chart, i.e. how the dates of the
+/// tasks are written and parsed.
+///
+/// Variants are ordered by increasing precision, so that the format of a
+/// chart is the maximum of the formats required by its dates.
+pub enum GanttDateFormat {
+    /// Dates without a time of the day, i.e. `YYYY-MM-DD`.
+    #[default]
+    Date,
+    /// Dates with hours and minutes, i.e. `YYYY-MM-DD HH:mm`.
+    DateTime,
+    /// Dates with hours, minutes and seconds, i.e. `YYYY-MM-DD HH:mm:ss`.
+    DateTimeSeconds,
+}
+
+impl GanttDateFormat {
+    #[must_use]
+    /// Writes the provided date according to the format, truncating the
+    /// components which are not part of it.
+    pub fn render(self, date: GanttDate) -> String {
+        let GanttDate { year, month, day, hour, minute, second } = date;
+        match self {
+            GanttDateFormat::Date => format!("{year:04}-{month:02}-{day:02}"),
+            GanttDateFormat::DateTime => {
+                format!("{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}")
+            }
+            GanttDateFormat::DateTimeSeconds => {
+                format!("{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}:{second:02}")
+            }
+        }
+    }
+}
+
+impl Display for GanttDateFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GanttDateFormat::Date => write!(f, "YYYY-MM-DD"),
+            GanttDateFormat::DateTime => write!(f, "YYYY-MM-DD HH:mm"),
+            GanttDateFormat::DateTimeSeconds => write!(f, "YYYY-MM-DD HH:mm:ss"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+
+    #[test]
+    fn test_gantt_date_validation() {
+        assert!(GanttDate::new(2024, 2, 29).is_ok());
+        assert!(GanttDate::new(2000, 2, 29).is_ok());
+        assert_eq!(
+            GanttDate::new(1900, 2, 29),
+            Err(GanttError::InvalidDate { year: 1900, month: 2, day: 29 })
+        );
+        assert!(GanttDate::new(2024, 4, 31).is_err());
+        assert!(GanttDate::new(2024, 13, 1).is_err());
+        assert!(GanttDate::new(2024, 1, 0).is_err());
+        assert_eq!(
+            GanttDate::new(2024, 1, 1).and_then(|date| date.with_time(24, 0, 0)),
+            Err(GanttError::InvalidTime { hour: 24, minute: 0, second: 0 })
+        );
+    }
+
+    #[test]
+    fn test_gantt_date_formats() -> Result<(), Box<dyn core::error::Error>> {
+        let date = GanttDate::new(2024, 3, 5)?;
+        assert_eq!(date.required_format(), GanttDateFormat::Date);
+        assert_eq!(date.with_time(8, 0, 0)?.required_format(), GanttDateFormat::DateTime);
+
+        let date = date.with_time(8, 15, 30)?;
+        assert_eq!(date.required_format(), GanttDateFormat::DateTimeSeconds);
+        assert_eq!(date.to_string(), "2024-03-05 08:15:30");
+        assert_eq!(GanttDateFormat::Date.render(date), "2024-03-05");
+        assert_eq!(GanttDateFormat::DateTime.render(date), "2024-03-05 08:15");
+        assert_eq!(GanttDateFormat::DateTimeSeconds.to_string(), "YYYY-MM-DD HH:mm:ss");
+        assert!(GanttDate::new(2024, 3, 5)? < date);
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+//! Submodule defining the durations which may be assigned to the tasks of
+//! Gantt charts.
+
+use core::fmt::{self, Display};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the duration of a task in a Gantt chart.
+pub enum GanttDuration {
+    /// A duration in milliseconds, written as `ms`.
+    Milliseconds(u32),
+    /// A duration in seconds, written as `s`.
+    Seconds(u32),
+    /// A duration in minutes, written as `m`.
+    Minutes(u32),
+    /// A duration in hours, written as `h`.
+    Hours(u32),
+    /// A duration in days, written as `d`.
+    Days(u32),
+    /// A duration in weeks, written as `w`.
+    Weeks(u32),
+    /// A duration in months, written as `M`.
+    Months(u32),
+    /// A duration in years, written as `y`.
+    Years(u32),
+}
+
+impl Display for GanttDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GanttDuration::Milliseconds(amount) => write!(f, "{amount}ms"),
+            GanttDuration::Seconds(amount) => write!(f, "{amount}s"),
+            GanttDuration::Minutes(amount) => write!(f, "{amount}m"),
+            GanttDuration::Hours(amount) => write!(f, "{amount}h"),
+            GanttDuration::Days(amount) => write!(f, "{amount}d"),
+            GanttDuration::Weeks(amount) => write!(f, "{amount}w"),
+            GanttDuration::Months(amount) => write!(f, "{amount}M"),
+            GanttDuration::Years(amount) => write!(f, "{amount}y"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn test_gantt_duration_display() {
+        assert_eq!(format!("{}", GanttDuration::Milliseconds(500)), "500ms");
+        assert_eq!(format!("{}", GanttDuration::Minutes(30)), "30m");
+        assert_eq!(format!("{}", GanttDuration::Days(0)), "0d");
+        assert_eq!(format!("{}", GanttDuration::Months(2)), "2M");
+    }
+}
@@ -0,0 +1,40 @@
+//! Submodule defining a section grouping tasks of a Gantt chart.
+
+use alloc::{rc::Rc, string::String, vec::Vec};
+
+use crate::diagrams::gantt::task::GanttTask;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a section of a Gantt chart, i.e. a group of consecutive tasks.
+///
+/// Tasks added before any section is opened belong to an unlabelled section.
+pub struct GanttSection {
+    /// Label of the section, if any.
+    label: Option<String>,
+    /// Tasks of the section, in the order in which they were added.
+    tasks: Vec<Rc<GanttTask>>,
+}
+
+impl GanttSection {
+    /// Creates a new empty section.
+    pub(crate) fn new(label: Option<String>) -> Self {
+        Self { label, tasks: Vec::new() }
+    }
+
+    /// Adds a task to the section.
+    pub(crate) fn push(&mut self, task: Rc<GanttTask>) {
+        self.tasks.push(task);
+    }
+
+    #[must_use]
+    /// Returns the label of the section, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Returns an iterator over the tasks of the section.
+    pub fn tasks(&self) -> impl Iterator<Item = &Rc<GanttTask>> {
+        self.tasks.iter()
+    }
+}
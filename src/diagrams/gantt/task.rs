@@ -0,0 +1,264 @@
+//! Submodule defining a task of a Gantt chart in Mermaid syntax.
+
+mod builder;
+use alloc::{rc::Rc, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::GanttTaskBuilder;
+
+use crate::{
+    diagrams::gantt::{
+        date::{GanttDate, GanttDateFormat},
+        duration::GanttDuration,
+    },
+    shared::NODE_LETTER,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the progress status of a task in a Gantt chart.
+pub enum GanttTaskStatus {
+    /// The task is completed, rendered with the `done` tag.
+    Done,
+    /// The task is in progress, rendered with the `active` tag.
+    Active,
+}
+
+impl Display for GanttTaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GanttTaskStatus::Done => write!(f, "done"),
+            GanttTaskStatus::Active => write!(f, "active"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents when a task of a Gantt chart starts.
+pub enum GanttTaskStart {
+    /// The task starts at the provided date.
+    Date(GanttDate),
+    /// The task starts after the latest end of the provided tasks.
+    After(Vec<Rc<GanttTask>>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents when a task of a Gantt chart ends.
+pub enum GanttTaskEnd {
+    /// The task ends at the provided date.
+    Date(GanttDate),
+    /// The task lasts for the provided duration.
+    Duration(GanttDuration),
+    /// The task runs until the provided task starts.
+    Until(Rc<GanttTask>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a task of a Gantt chart.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let task = GanttTaskBuilder::default()
+///         .label("Calibrate")?
+///         .id(0)
+///         .status(GanttTaskStatus::Done)
+///         .critical()
+///         .start(GanttDate::new(2024, 1, 8)?)
+///         .duration(GanttDuration::Days(2))
+///         .build()?;
+///
+///     assert_eq!(task.to_string(), "Calibrate :crit, done, v0, 2024-01-08, 2d\n");
+///     Ok(())
+/// }
+/// ```
+pub struct GanttTask {
+    /// Unique identifier of the task within its chart.
+    id: u64,
+    /// Label displayed for the task.
+    label: String,
+    /// Progress status of the task, if any.
+    status: Option<GanttTaskStatus>,
+    /// Whether the task is on the critical path.
+    critical: bool,
+    /// Whether the task is a milestone.
+    milestone: bool,
+    /// When the task starts.
+    start: GanttTaskStart,
+    /// When the task ends.
+    end: GanttTaskEnd,
+}
+
+impl GanttTask {
+    #[must_use]
+    /// Returns the unique identifier of the task.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[must_use]
+    /// Returns the label of the task.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the progress status of the task, if any.
+    pub fn status(&self) -> Option<GanttTaskStatus> {
+        self.status
+    }
+
+    #[must_use]
+    /// Returns whether the task is on the critical path.
+    pub fn is_critical(&self) -> bool {
+        self.critical
+    }
+
+    #[must_use]
+    /// Returns whether the task is a milestone.
+    pub fn is_milestone(&self) -> bool {
+        self.milestone
+    }
+
+    #[must_use]
+    /// Returns when the task starts.
+    pub fn start(&self) -> &GanttTaskStart {
+        &self.start
+    }
+
+    #[must_use]
+    /// Returns when the task ends.
+    pub fn end(&self) -> &GanttTaskEnd {
+        &self.end
+    }
+
+    /// Returns an iterator over the tasks this task depends upon, either to
+    /// start after or to run until.
+    pub fn dependencies(&self) -> impl Iterator<Item = &Rc<GanttTask>> {
+        let after = match &self.start {
+            GanttTaskStart::After(tasks) => tasks.as_slice(),
+            GanttTaskStart::Date(_) => &[],
+        };
+        let until = match &self.end {
+            GanttTaskEnd::Until(task) => Some(task),
+            _ => None,
+        };
+        after.iter().chain(until)
+    }
+
+    /// Returns an iterator over the dates at which the task starts or ends.
+    pub(crate) fn dates(&self) -> impl Iterator<Item = GanttDate> {
+        let start = match &self.start {
+            GanttTaskStart::Date(date) => Some(*date),
+            GanttTaskStart::After(_) => None,
+        };
+        let end = match &self.end {
+            GanttTaskEnd::Date(date) => Some(*date),
+            _ => None,
+        };
+        start.into_iter().chain(end)
+    }
+
+    #[must_use]
+    /// Returns the least precise date format which represents the dates of
+    /// the task exactly.
+    pub fn required_format(&self) -> GanttDateFormat {
+        let start = match &self.start {
+            GanttTaskStart::Date(date) => date.required_format(),
+            GanttTaskStart::After(_) => GanttDateFormat::default(),
+        };
+        let end = match &self.end {
+            GanttTaskEnd::Date(date) => date.required_format(),
+            _ => GanttDateFormat::default(),
+        };
+        start.max(end)
+    }
+
+    /// Writes the task with its dates in the provided format.
+    pub(crate) fn fmt_scheduled(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        tab_count: usize,
+        format: GanttDateFormat,
+    ) -> fmt::Result {
+        write!(f, "{}{} :", " ".repeat(tab_count * 2), self.label)?;
+        if self.critical {
+            write!(f, "crit, ")?;
+        }
+        if let Some(status) = self.status {
+            write!(f, "{status}, ")?;
+        }
+        if self.milestone {
+            write!(f, "milestone, ")?;
+        }
+        write!(f, "{NODE_LETTER}{}, ", self.id)?;
+        match &self.start {
+            GanttTaskStart::Date(date) => write!(f, "{}", format.render(*date))?,
+            GanttTaskStart::After(tasks) => {
+                write!(f, "after")?;
+                for task in tasks {
+                    write!(f, " {NODE_LETTER}{}", task.id)?;
+                }
+            }
+        }
+        match &self.end {
+            GanttTaskEnd::Date(date) => writeln!(f, ", {}", format.render(*date)),
+            GanttTaskEnd::Duration(duration) => writeln!(f, ", {duration}"),
+            GanttTaskEnd::Until(task) => writeln!(f, ", until {NODE_LETTER}{}", task.id),
+        }
+    }
+}
+
+impl Display for GanttTask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_scheduled(f, 0, self.required_format())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+
+    #[test]
+    fn test_gantt_task_display() -> Result<(), Box<dyn core::error::Error>> {
+        let setup = Rc::new(
+            GanttTaskBuilder::default()
+                .label("Setup")?
+                .id(0)
+                .start(GanttDate::new(2024, 1, 8)?.with_time(9, 0, 0)?)
+                .end(GanttDate::new(2024, 1, 8)?.with_time(17, 30, 0)?)
+                .build()?,
+        );
+        assert_eq!(setup.required_format(), GanttDateFormat::DateTime);
+        assert_eq!(setup.to_string(), "Setup :v0, 2024-01-08 09:00, 2024-01-08 17:30\n");
+
+        let review = Rc::new(
+            GanttTaskBuilder::default()
+                .label("Review")?
+                .id(1)
+                .milestone()
+                .after(setup.clone())?
+                .duration(GanttDuration::Days(0))
+                .build()?,
+        );
+        let run = GanttTaskBuilder::default()
+            .label("Run")?
+            .id(2)
+            .status(GanttTaskStatus::Active)
+            .after(setup)?
+            .after(review.clone())?
+            .until(review)
+            .build()?;
+        assert_eq!(run.to_string(), "Run :active, v2, after v0 v1, until v1\n");
+        assert_eq!(run.dependencies().map(|task| task.id()).collect::<Vec<_>>(), [0, 1, 1]);
+        Ok(())
+    }
+}
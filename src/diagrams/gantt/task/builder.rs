@@ -0,0 +1,271 @@
+//! Submodule defining the struct for building a task of a Gantt chart.
+
+use alloc::{rc::Rc, string::String, vec};
+
+use crate::{
+    diagrams::gantt::{
+        date::GanttDate,
+        duration::GanttDuration,
+        task::{GanttTask, GanttTaskEnd, GanttTaskStart, GanttTaskStatus},
+    },
+    errors::GanttError,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `GanttTask`.
+///
+/// When added to a `GanttBuilder`, a task without an ID receives the next
+/// available one, and a task without a start begins after the previously
+/// added task.
+///
+/// # Example
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let design = Rc::new(
+///         GanttTaskBuilder::default()
+///             .label("Design")?
+///             .id(0)
+///             .start(GanttDate::new(2024, 1, 1)?)
+///             .duration(GanttDuration::Weeks(1))
+///             .build()?,
+///     );
+///     let build = GanttTaskBuilder::default()
+///         .label("Build")?
+///         .id(1)
+///         .after(design)?
+///         .duration(GanttDuration::Days(3))
+///         .build()?;
+///
+///     assert_eq!(build.dependencies().count(), 1);
+///     Ok(())
+/// }
+/// ```
+pub struct GanttTaskBuilder {
+    /// Unique identifier of the task.
+    id: Option<u64>,
+    /// Label displayed for the task.
+    label: Option<String>,
+    /// Progress status of the task.
+    status: Option<GanttTaskStatus>,
+    /// Whether the task is on the critical path.
+    critical: bool,
+    /// Whether the task is a milestone.
+    milestone: bool,
+    /// When the task starts.
+    start: Option<GanttTaskStart>,
+    /// When the task ends.
+    end: Option<GanttTaskEnd>,
+}
+
+impl GanttTaskBuilder {
+    /// Sets the label of the task.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The label displayed for the task.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty.
+    /// * If the label contains a colon, a hash or a line break, which are
+    ///   reserved by the syntax.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, GanttError> {
+        let label = label.into();
+        if label.trim().is_empty() {
+            return Err(GanttError::EmptyTaskLabel);
+        }
+        if label.contains([':', '#', '\n', '\r']) {
+            return Err(GanttError::InvalidTaskLabel(label));
+        }
+        self.label = Some(label);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the unique identifier of the task.
+    pub fn id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[must_use]
+    /// Returns the identifier of the task, if set.
+    pub fn get_id(&self) -> Option<u64> {
+        self.id
+    }
+
+    #[must_use]
+    /// Sets the progress status of the task.
+    pub fn status(mut self, status: GanttTaskStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    #[must_use]
+    /// Marks the task as being on the critical path.
+    pub fn critical(mut self) -> Self {
+        self.critical = true;
+        self
+    }
+
+    #[must_use]
+    /// Marks the task as a milestone.
+    pub fn milestone(mut self) -> Self {
+        self.milestone = true;
+        self
+    }
+
+    #[must_use]
+    /// Sets the start date of the task, replacing any dependency it was
+    /// meant to start after.
+    pub fn start(mut self, date: GanttDate) -> Self {
+        self.start = Some(GanttTaskStart::Date(date));
+        self
+    }
+
+    /// Makes the task start after the provided task. When called multiple
+    /// times, the task starts after the latest end of all the provided tasks.
+    ///
+    /// # Arguments
+    ///
+    /// * `task` - The task to start after.
+    ///
+    /// # Errors
+    ///
+    /// * If the task already starts after the provided task.
+    pub fn after(mut self, task: Rc<GanttTask>) -> Result<Self, GanttError> {
+        match &mut self.start {
+            Some(GanttTaskStart::After(tasks)) => {
+                if tasks.contains(&task) {
+                    return Err(GanttError::DuplicateDependency(task.label().into()));
+                }
+                tasks.push(task);
+            }
+            _ => self.start = Some(GanttTaskStart::After(vec![task])),
+        }
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Returns whether a start was provided for the task.
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    /// Returns an iterator over the tasks this task depends upon.
+    pub fn dependencies(&self) -> impl Iterator<Item = &Rc<GanttTask>> {
+        let after = match &self.start {
+            Some(GanttTaskStart::After(tasks)) => tasks.as_slice(),
+            _ => &[],
+        };
+        let until = match &self.end {
+            Some(GanttTaskEnd::Until(task)) => Some(task),
+            _ => None,
+        };
+        after.iter().chain(until)
+    }
+
+    #[must_use]
+    /// Sets the end date of the task.
+    pub fn end(mut self, date: GanttDate) -> Self {
+        self.end = Some(GanttTaskEnd::Date(date));
+        self
+    }
+
+    #[must_use]
+    /// Sets the duration of the task.
+    pub fn duration(mut self, duration: GanttDuration) -> Self {
+        self.end = Some(GanttTaskEnd::Duration(duration));
+        self
+    }
+
+    #[must_use]
+    /// Makes the task run until the provided task starts.
+    pub fn until(mut self, task: Rc<GanttTask>) -> Self {
+        self.end = Some(GanttTaskEnd::Until(task));
+        self
+    }
+
+    /// Builds the task.
+    ///
+    /// # Errors
+    ///
+    /// * If the label, the ID, the start or the end of the task is missing.
+    /// * If the task ends at a date before its start date.
+    pub fn build(self) -> Result<GanttTask, GanttError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<GanttTaskBuilder> for GanttTask {
+    type Error = GanttError;
+
+    fn try_from(builder: GanttTaskBuilder) -> Result<Self, Self::Error> {
+        let label = builder.label.ok_or(GanttError::MissingTaskLabel)?;
+        let id = builder.id.ok_or(GanttError::MissingTaskId)?;
+        let Some(start) = builder.start else {
+            return Err(GanttError::MissingStart(label));
+        };
+        let Some(end) = builder.end else {
+            return Err(GanttError::MissingEnd(label));
+        };
+        if let (GanttTaskStart::Date(start), GanttTaskEnd::Date(end)) = (&start, &end)
+            && end < start
+        {
+            return Err(GanttError::EndBeforeStart(label));
+        }
+
+        Ok(GanttTask {
+            id,
+            label,
+            status: builder.status,
+            critical: builder.critical,
+            milestone: builder.milestone,
+            start,
+            end,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box};
+
+    use super::*;
+
+    #[test]
+    fn test_gantt_task_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        assert_eq!(GanttTaskBuilder::default().label(" "), Err(GanttError::EmptyTaskLabel));
+        assert_eq!(
+            GanttTaskBuilder::default().label("a: b"),
+            Err(GanttError::InvalidTaskLabel("a: b".to_owned()))
+        );
+        assert_eq!(GanttTaskBuilder::default().id(0).build(), Err(GanttError::MissingTaskLabel));
+        assert_eq!(
+            GanttTaskBuilder::default().label("Run")?.build(),
+            Err(GanttError::MissingTaskId)
+        );
+
+        let date = GanttDate::new(2024, 1, 8)?;
+        let base = GanttTaskBuilder::default().label("Run")?.id(0);
+        assert_eq!(base.clone().build(), Err(GanttError::MissingStart("Run".to_owned())));
+        assert_eq!(base.clone().start(date).build(), Err(GanttError::MissingEnd("Run".to_owned())));
+        assert_eq!(
+            base.clone().start(date).end(GanttDate::new(2024, 1, 7)?).build(),
+            Err(GanttError::EndBeforeStart("Run".to_owned()))
+        );
+
+        let setup = Rc::new(base.start(date).end(date).build()?);
+        assert_eq!(
+            GanttTaskBuilder::default().after(setup.clone())?.after(setup),
+            Err(GanttError::DuplicateDependency("Run".to_owned()))
+        );
+        Ok(())
+    }
+}
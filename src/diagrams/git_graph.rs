@@ -0,0 +1,156 @@
+//! Submodule providing structs for creating git graphs in Mermaid syntax.
+
+mod builder;
+mod configuration;
+mod git_commit;
+mod git_statement;
+use alloc::{rc::Rc, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::GitGraphBuilder;
+pub use configuration::{GitGraphConfiguration, GitGraphConfigurationBuilder};
+pub use git_commit::{GitCommit, GitCommitBuilder, GitCommitType};
+pub use git_statement::GitStatement;
+
+use crate::{
+    shared::Direction,
+    traits::{Configuration, TabbedDisplay},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a git graph in Mermaid syntax.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = GitGraphBuilder::default();
+///     builder.commit(GitCommitBuilder::default());
+///     builder.branch("develop", Some(1))?;
+///     builder.commit(GitCommitBuilder::default().kind(GitCommitType::Highlight));
+///     builder.checkout("main")?;
+///     builder.merge("develop", GitCommitBuilder::default().tag("v1.0")?)?;
+///
+///     let graph = builder.build()?;
+///     assert_eq!(
+///         graph.to_string(),
+///         concat!(
+///             "gitGraph\n",
+///             "  commit id: \"v0\"\n",
+///             "  branch develop order: 1\n",
+///             "  commit id: \"v1\" type: HIGHLIGHT\n",
+///             "  checkout main\n",
+///             "  merge develop id: \"v2\" tag: \"v1.0\"\n",
+///         )
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct GitGraph {
+    /// Configuration of the graph.
+    configuration: GitGraphConfiguration,
+    /// Statements of the graph.
+    statements: Vec<GitStatement>,
+}
+
+impl GitGraph {
+    #[must_use]
+    /// Returns the configuration of the graph.
+    pub fn configuration(&self) -> &GitGraphConfiguration {
+        &self.configuration
+    }
+
+    /// Returns an iterator over the statements of the graph.
+    pub fn statements(&self) -> impl Iterator<Item = &GitStatement> {
+        self.statements.iter()
+    }
+
+    /// Returns an iterator over the commits and merge commits of the graph,
+    /// excluding cherry-picked copies.
+    pub fn commits(&self) -> impl Iterator<Item = &Rc<GitCommit>> {
+        self.statements.iter().filter_map(|statement| match statement {
+            GitStatement::Commit(commit) | GitStatement::Merge { commit, .. } => Some(commit),
+            _ => None,
+        })
+    }
+}
+
+impl Display for GitGraph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for GitGraph {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        write!(f, "{}", self.configuration)?;
+        write!(f, "{}gitGraph", " ".repeat(tab_count * 2))?;
+        let direction = self.configuration.direction();
+        if direction != Direction::LeftToRight {
+            write!(f, " {direction}:")?;
+        }
+        writeln!(f)?;
+        for statement in &self.statements {
+            statement.fmt_tabbed(f, tab_count + 1)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::traits::ConfigurationBuilder;
+
+    #[test]
+    fn test_git_graph_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = GitGraphBuilder::default().configuration(
+            GitGraphConfigurationBuilder::default()
+                .direction(Direction::TopToBottom)
+                .main_branch_name("trunk")?
+                .show_branches(false),
+        )?;
+        builder.commit(GitCommitBuilder::default().id("init")?);
+        builder.branch("hotfix", None)?;
+        let fix = builder.commit(GitCommitBuilder::default().kind(GitCommitType::Reverse));
+        builder.switch("trunk")?;
+        builder.commit(GitCommitBuilder::default());
+        builder.cherry_pick(&fix, Some("v1.0.1"))?;
+        builder.merge("hotfix", GitCommitBuilder::default().id("merge")?)?;
+
+        let graph = builder.build()?;
+        assert_eq!(graph.statements().count(), 7);
+        assert_eq!(graph.commits().count(), 4);
+        assert_eq!(
+            graph.to_string(),
+            concat!(
+                "---\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "  gitGraph:\n",
+                "    showBranches: false\n",
+                "    showCommitLabel: true\n",
+                "    rotateCommitLabel: true\n",
+                "    parallelCommits: false\n",
+                "    mainBranchName: trunk\n",
+                "    mainBranchOrder: 0\n",
+                "---\n",
+                "gitGraph TB:\n",
+                "  commit id: \"init\"\n",
+                "  branch hotfix\n",
+                "  commit id: \"v1\" type: REVERSE\n",
+                "  switch trunk\n",
+                "  commit id: \"v2\"\n",
+                "  cherry-pick id: \"v1\" tag: \"v1.0.1\"\n",
+                "  merge hotfix id: \"merge\"\n",
+            )
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,416 @@
+//! Submodule providing a builder for git graphs.
+
+use alloc::{borrow::ToOwned, collections::BTreeMap, format, rc::Rc, string::String, vec::Vec};
+
+use crate::{
+    diagrams::git_graph::{
+        GitGraph,
+        configuration::{GitGraphConfiguration, GitGraphConfigurationBuilder},
+        git_commit::{GitCommit, GitCommitBuilder, validate_tag},
+        git_statement::{GitStatement, is_valid_branch_name},
+    },
+    errors::{ConfigError, GitGraphError},
+    shared::NODE_LETTER,
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a git graph.
+///
+/// Statements are recorded in the order in which they are added, and the
+/// consistency of the branches is validated when the graph is built, by
+/// replaying them as Mermaid does.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = GitGraphBuilder::default();
+///     builder.commit(GitCommitBuilder::default());
+///     builder.branch("develop", None)?;
+///     let feature = builder.commit(GitCommitBuilder::default().id("feature")?);
+///     builder.checkout("main")?;
+///     builder.cherry_pick(&feature, Some("v0.1.1"))?;
+///     builder.merge("develop", GitCommitBuilder::default().tag("v0.2")?)?;
+///
+///     let graph = builder.build()?;
+///     assert_eq!(graph.commits().count(), 3);
+///
+///     let mut builder = GitGraphBuilder::default();
+///     builder.checkout("develop")?;
+///     assert!(builder.build().is_err());
+///     Ok(())
+/// }
+/// ```
+pub struct GitGraphBuilder {
+    /// Configuration of the graph.
+    configuration: GitGraphConfiguration,
+    /// Statements of the graph.
+    statements: Vec<GitStatement>,
+    /// Number of commits created by the statements.
+    commit_count: usize,
+}
+
+impl GitGraphBuilder {
+    /// Sets the configuration of the graph.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: GitGraphConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Adds a commit to the current branch.
+    pub fn commit(&mut self, commit: GitCommitBuilder) -> Rc<GitCommit> {
+        let commit = self.next_commit(commit);
+        self.statements.push(GitStatement::Commit(commit.clone()));
+        commit
+    }
+
+    /// Creates a branch from the current branch and checks it out.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the branch.
+    /// * `order` - The position of the branch in the graph, if any.
+    ///
+    /// # Errors
+    ///
+    /// * If the name is not a valid branch name.
+    pub fn branch(
+        &mut self,
+        name: impl Into<String>,
+        order: Option<u32>,
+    ) -> Result<(), GitGraphError> {
+        let name = branch_name(name)?;
+        self.statements.push(GitStatement::Branch { name, order });
+        Ok(())
+    }
+
+    /// Checks out a branch, using the `checkout` keyword.
+    ///
+    /// # Errors
+    ///
+    /// * If the name is not a valid branch name.
+    pub fn checkout(&mut self, name: impl Into<String>) -> Result<(), GitGraphError> {
+        let name = branch_name(name)?;
+        self.statements.push(GitStatement::Checkout(name));
+        Ok(())
+    }
+
+    /// Checks out a branch, using the `switch` keyword, which Mermaid treats
+    /// as an alias of `checkout`.
+    ///
+    /// # Errors
+    ///
+    /// * If the name is not a valid branch name.
+    pub fn switch(&mut self, name: impl Into<String>) -> Result<(), GitGraphError> {
+        let name = branch_name(name)?;
+        self.statements.push(GitStatement::Switch(name));
+        Ok(())
+    }
+
+    /// Merges a branch into the current branch.
+    ///
+    /// # Arguments
+    ///
+    /// * `branch` - The name of the merged branch.
+    /// * `commit` - The attributes of the merge commit.
+    ///
+    /// # Errors
+    ///
+    /// * If the name is not a valid branch name.
+    pub fn merge(
+        &mut self,
+        branch: impl Into<String>,
+        commit: GitCommitBuilder,
+    ) -> Result<Rc<GitCommit>, GitGraphError> {
+        let branch = branch_name(branch)?;
+        let commit = self.next_commit(commit);
+        self.statements.push(GitStatement::Merge { branch, commit: commit.clone() });
+        Ok(commit)
+    }
+
+    /// Copies a commit from another branch onto the current branch.
+    ///
+    /// # Arguments
+    ///
+    /// * `commit` - The cherry-picked commit.
+    /// * `tag` - The tag attached to the copied commit, if any.
+    ///
+    /// # Errors
+    ///
+    /// * If the tag is empty or contains double quotes or line breaks.
+    pub fn cherry_pick(
+        &mut self,
+        commit: &Rc<GitCommit>,
+        tag: Option<&str>,
+    ) -> Result<(), GitGraphError> {
+        if let Some(tag) = tag {
+            validate_tag(tag)?;
+        }
+        self.statements.push(GitStatement::CherryPick {
+            commit: commit.clone(),
+            tag: tag.map(ToOwned::to_owned),
+        });
+        Ok(())
+    }
+
+    /// Builds the git graph.
+    ///
+    /// # Errors
+    ///
+    /// * If a branch is created twice.
+    /// * If a checked out or merged branch does not exist.
+    /// * If two commits share the same ID.
+    /// * If a branch is merged into itself, or either branch of a merge has no
+    ///   commits, or the merged branch has nothing to merge.
+    /// * If a cherry-picked commit does not exist, is on the current branch or
+    ///   is a merge commit.
+    pub fn build(self) -> Result<GitGraph, GitGraphError> {
+        self.try_into()
+    }
+
+    /// Builds the next commit, assigning it an ID which no commit of the
+    /// graph already uses if needed.
+    fn next_commit(&mut self, commit: GitCommitBuilder) -> Rc<GitCommit> {
+        let mut default_id = format!("{NODE_LETTER}{}", self.commit_count);
+        while self.statements.iter().any(|statement| {
+            matches!(
+                statement,
+                GitStatement::Commit(commit) | GitStatement::Merge { commit, .. }
+                    if commit.id() == default_id
+            )
+        }) {
+            self.commit_count += 1;
+            default_id = format!("{NODE_LETTER}{}", self.commit_count);
+        }
+        self.commit_count += 1;
+        Rc::new(commit.build_with_default_id(default_id))
+    }
+}
+
+/// Validates the provided branch name.
+fn branch_name(name: impl Into<String>) -> Result<String, GitGraphError> {
+    let name = name.into();
+    if is_valid_branch_name(&name) { Ok(name) } else { Err(GitGraphError::InvalidBranchName(name)) }
+}
+
+/// Where a commit was created while replaying the statements of a graph.
+struct ReplayedCommit {
+    /// The commit as created by its statement.
+    commit: Rc<GitCommit>,
+    /// The branch on which the commit was created.
+    branch: String,
+    /// Whether the commit was created by a merge.
+    is_merge: bool,
+}
+
+/// State of the branches of a graph while replaying its statements.
+struct Replay {
+    /// Index of the statement which created the latest commit of each branch,
+    /// as cherry-picked copies have no ID of their own.
+    heads: BTreeMap<String, Option<usize>>,
+    /// Commits created so far, indexed by ID.
+    commits: BTreeMap<String, ReplayedCommit>,
+    /// Name of the checked out branch.
+    current: String,
+}
+
+impl Replay {
+    /// Creates the state of a graph which only has an empty main branch.
+    fn new(main_branch_name: &str) -> Self {
+        let mut heads = BTreeMap::new();
+        heads.insert(main_branch_name.to_owned(), None);
+        Self { heads, commits: BTreeMap::new(), current: main_branch_name.to_owned() }
+    }
+
+    /// Returns the head of the provided branch.
+    fn head(&self, branch: &str) -> Result<Option<usize>, GitGraphError> {
+        self.heads.get(branch).copied().ok_or_else(|| GitGraphError::BranchNotFound(branch.into()))
+    }
+
+    /// Records a commit created on the current branch by the statement at
+    /// the provided index.
+    fn add_commit(
+        &mut self,
+        index: usize,
+        commit: &Rc<GitCommit>,
+        is_merge: bool,
+    ) -> Result<(), GitGraphError> {
+        if self.commits.contains_key(commit.id()) {
+            return Err(GitGraphError::DuplicateCommit(commit.id().to_owned()));
+        }
+        let replayed =
+            ReplayedCommit { commit: commit.clone(), branch: self.current.clone(), is_merge };
+        self.commits.insert(commit.id().to_owned(), replayed);
+        self.heads.insert(self.current.clone(), Some(index));
+        Ok(())
+    }
+
+    /// Applies the statement at the provided index.
+    fn apply(&mut self, index: usize, statement: &GitStatement) -> Result<(), GitGraphError> {
+        match statement {
+            GitStatement::Commit(commit) => self.add_commit(index, commit, false),
+            GitStatement::Branch { name, .. } => {
+                if self.heads.contains_key(name) {
+                    return Err(GitGraphError::DuplicateBranch(name.clone()));
+                }
+                let head = self.head(&self.current)?;
+                self.heads.insert(name.clone(), head);
+                self.current.clone_from(name);
+                Ok(())
+            }
+            GitStatement::Checkout(name) | GitStatement::Switch(name) => {
+                self.head(name)?;
+                self.current.clone_from(name);
+                Ok(())
+            }
+            GitStatement::Merge { branch, commit } => {
+                let merged_head = self.head(branch)?;
+                if *branch == self.current {
+                    return Err(GitGraphError::SelfMerge(branch.clone()));
+                }
+                let current_head = self.head(&self.current)?;
+                if current_head.is_none() {
+                    return Err(GitGraphError::EmptyBranch(self.current.clone()));
+                }
+                if merged_head.is_none() {
+                    return Err(GitGraphError::EmptyBranch(branch.clone()));
+                }
+                if merged_head == current_head {
+                    return Err(GitGraphError::NothingToMerge(branch.clone()));
+                }
+                self.add_commit(index, commit, true)
+            }
+            GitStatement::CherryPick { commit, .. } => {
+                let Some(replayed) =
+                    self.commits.get(commit.id()).filter(|replayed| replayed.commit == *commit)
+                else {
+                    return Err(GitGraphError::CommitNotFound(commit.id().to_owned()));
+                };
+                if replayed.branch == self.current {
+                    return Err(GitGraphError::CherryPickFromSameBranch(commit.id().to_owned()));
+                }
+                if replayed.is_merge {
+                    return Err(GitGraphError::CherryPickMergeCommit(commit.id().to_owned()));
+                }
+                self.heads.insert(self.current.clone(), Some(index));
+                Ok(())
+            }
+        }
+    }
+}
+
+impl TryFrom<GitGraphBuilder> for GitGraph {
+    type Error = GitGraphError;
+
+    fn try_from(builder: GitGraphBuilder) -> Result<Self, Self::Error> {
+        let mut replay = Replay::new(builder.configuration.main_branch_name());
+        for (index, statement) in builder.statements.iter().enumerate() {
+            replay.apply(index, statement)?;
+        }
+
+        Ok(GitGraph { configuration: builder.configuration, statements: builder.statements })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box};
+
+    use super::*;
+
+    #[test]
+    fn test_git_graph_builder_branch_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = GitGraphBuilder::default();
+        assert_eq!(
+            builder.branch("new feature", None),
+            Err(GitGraphError::InvalidBranchName("new feature".to_owned()))
+        );
+
+        builder.branch("main", None)?;
+        assert_eq!(builder.build(), Err(GitGraphError::DuplicateBranch("main".to_owned())));
+
+        let mut builder = GitGraphBuilder::default();
+        builder.commit(GitCommitBuilder::default());
+        builder.switch("develop")?;
+        assert_eq!(builder.build(), Err(GitGraphError::BranchNotFound("develop".to_owned())));
+        Ok(())
+    }
+
+    #[test]
+    fn test_git_graph_builder_merge_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = GitGraphBuilder::default();
+        builder.branch("develop", None)?;
+        builder.commit(GitCommitBuilder::default());
+        builder.checkout("main")?;
+        builder.merge("develop", GitCommitBuilder::default())?;
+        assert_eq!(builder.build(), Err(GitGraphError::EmptyBranch("main".to_owned())));
+
+        let mut builder = GitGraphBuilder::default();
+        builder.commit(GitCommitBuilder::default());
+        builder.merge("main", GitCommitBuilder::default())?;
+        assert_eq!(builder.build(), Err(GitGraphError::SelfMerge("main".to_owned())));
+
+        let mut builder = GitGraphBuilder::default();
+        builder.commit(GitCommitBuilder::default());
+        builder.branch("develop", None)?;
+        builder.checkout("main")?;
+        builder.merge("develop", GitCommitBuilder::default())?;
+        assert_eq!(builder.build(), Err(GitGraphError::NothingToMerge("develop".to_owned())));
+
+        let mut builder = GitGraphBuilder::default();
+        builder.commit(GitCommitBuilder::default().id("a")?);
+        builder.commit(GitCommitBuilder::default().id("a")?);
+        assert_eq!(builder.build(), Err(GitGraphError::DuplicateCommit("a".to_owned())));
+        Ok(())
+    }
+
+    #[test]
+    fn test_git_graph_builder_skips_taken_ids() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = GitGraphBuilder::default();
+        let explicit = builder.commit(GitCommitBuilder::default().id("v1")?);
+        let automatic = builder.commit(GitCommitBuilder::default());
+        assert_eq!((explicit.id(), automatic.id()), ("v1", "v2"));
+        assert!(builder.build().is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_git_graph_builder_cherry_pick_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = GitGraphBuilder::default();
+        let first = builder.commit(GitCommitBuilder::default());
+        builder.cherry_pick(&first, None)?;
+        assert_eq!(builder.build(), Err(GitGraphError::CherryPickFromSameBranch("v0".to_owned())));
+
+        let mut builder = GitGraphBuilder::default();
+        builder.commit(GitCommitBuilder::default());
+        builder.branch("develop", None)?;
+        builder.commit(GitCommitBuilder::default());
+        builder.checkout("main")?;
+        builder.commit(GitCommitBuilder::default());
+        let merge = builder.merge("develop", GitCommitBuilder::default())?;
+        builder.checkout("develop")?;
+        builder.cherry_pick(&merge, None)?;
+        assert_eq!(builder.build(), Err(GitGraphError::CherryPickMergeCommit("v3".to_owned())));
+
+        let mut builder = GitGraphBuilder::default();
+        let foreign = Rc::new(GitCommitBuilder::default().build_with_default_id("x".to_owned()));
+        assert_eq!(
+            builder.cherry_pick(&foreign, Some("")),
+            Err(GitGraphError::InvalidTag(String::new()))
+        );
+        builder.cherry_pick(&foreign, None)?;
+        assert_eq!(builder.build(), Err(GitGraphError::CommitNotFound("x".to_owned())));
+        Ok(())
+    }
+}
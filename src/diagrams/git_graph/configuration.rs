@@ -0,0 +1,192 @@
+//! Submodule defining configuration specifically for git graphs in Mermaid.
+
+mod builder;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt::Display;
+
+pub use builder::GitGraphConfigurationBuilder;
+
+use crate::{
+    shared::{
+        Direction, Renderer,
+        generic_configuration::{GenericConfiguration, Look, Theme},
+    },
+    traits::Configuration,
+};
+
+/// Name of the branch on which git graphs start by default.
+pub(crate) const DEFAULT_MAIN_BRANCH_NAME: &str = "main";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the configuration for a git graph in Mermaid syntax.
+///
+/// Git graphs may only be drawn left to right, top to bottom or bottom to
+/// top.
+pub struct GitGraphConfiguration {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfiguration,
+    /// Whether to display the names and lines of the branches.
+    show_branches: bool,
+    /// Whether to display the IDs of the commits.
+    show_commit_label: bool,
+    /// Whether to rotate the IDs of the commits.
+    rotate_commit_label: bool,
+    /// Whether commits are aligned by their distance from their parents
+    /// rather than by their chronological order.
+    parallel_commits: bool,
+    /// Name of the branch on which the graph starts.
+    main_branch_name: String,
+    /// Position of the main branch in the graph.
+    main_branch_order: u32,
+}
+
+impl Default for GitGraphConfiguration {
+    fn default() -> Self {
+        Self {
+            generic: GenericConfiguration::default(),
+            show_branches: true,
+            show_commit_label: true,
+            rotate_commit_label: true,
+            parallel_commits: false,
+            main_branch_name: DEFAULT_MAIN_BRANCH_NAME.into(),
+            main_branch_order: 0,
+        }
+    }
+}
+
+impl GitGraphConfiguration {
+    #[must_use]
+    /// Returns whether the names and lines of the branches are displayed.
+    pub fn show_branches(&self) -> bool {
+        self.show_branches
+    }
+
+    #[must_use]
+    /// Returns whether the IDs of the commits are displayed.
+    pub fn show_commit_label(&self) -> bool {
+        self.show_commit_label
+    }
+
+    #[must_use]
+    /// Returns whether the IDs of the commits are rotated.
+    pub fn rotate_commit_label(&self) -> bool {
+        self.rotate_commit_label
+    }
+
+    #[must_use]
+    /// Returns whether commits are aligned by their distance from their
+    /// parents.
+    pub fn parallel_commits(&self) -> bool {
+        self.parallel_commits
+    }
+
+    #[must_use]
+    /// Returns the name of the branch on which the graph starts.
+    pub fn main_branch_name(&self) -> &str {
+        &self.main_branch_name
+    }
+
+    #[must_use]
+    /// Returns the position of the main branch in the graph.
+    pub fn main_branch_order(&self) -> u32 {
+        self.main_branch_order
+    }
+}
+
+impl Display for GitGraphConfiguration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let default = GitGraphConfiguration::default();
+        let has_git_graph_options = self.show_branches != default.show_branches
+            || self.show_commit_label != default.show_commit_label
+            || self.rotate_commit_label != default.rotate_commit_label
+            || self.parallel_commits != default.parallel_commits
+            || self.main_branch_name != default.main_branch_name
+            || self.main_branch_order != default.main_branch_order;
+        let options = if has_git_graph_options {
+            vec![
+                ("gitGraph.showBranches".to_string(), self.show_branches.to_string()),
+                ("gitGraph.showCommitLabel".to_string(), self.show_commit_label.to_string()),
+                ("gitGraph.rotateCommitLabel".to_string(), self.rotate_commit_label.to_string()),
+                ("gitGraph.parallelCommits".to_string(), self.parallel_commits.to_string()),
+                ("gitGraph.mainBranchName".to_string(), self.main_branch_name.clone()),
+                ("gitGraph.mainBranchOrder".to_string(), self.main_branch_order.to_string()),
+            ]
+        } else {
+            Vec::new()
+        };
+        self.generic.fmt_frontmatter(f, true, &options)
+    }
+}
+
+impl Configuration for GitGraphConfiguration {
+    type Builder = GitGraphConfigurationBuilder;
+
+    fn title(&self) -> Option<&str> {
+        self.generic.title()
+    }
+
+    fn direction(&self) -> Direction {
+        self.generic.direction()
+    }
+
+    fn renderer(&self) -> Renderer {
+        self.generic.renderer()
+    }
+
+    fn theme(&self) -> Theme {
+        self.generic.theme()
+    }
+
+    fn look(&self) -> Look {
+        self.generic.look()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format};
+
+    use super::*;
+    use crate::traits::ConfigurationBuilder;
+
+    #[test]
+    fn test_git_graph_configuration_display_default() {
+        let config = GitGraphConfiguration::default();
+        assert_eq!(format!("{config}"), "");
+    }
+
+    #[test]
+    fn test_git_graph_configuration_display_full() -> Result<(), Box<dyn core::error::Error>> {
+        let config = GitGraphConfigurationBuilder::default()
+            .title("Releases")?
+            .show_commit_label(false)
+            .main_branch_name("trunk")?
+            .build()?;
+
+        assert_eq!(
+            format!("{config}"),
+            concat!(
+                "---\n",
+                "title: Releases\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "  gitGraph:\n",
+                "    showBranches: true\n",
+                "    showCommitLabel: false\n",
+                "    rotateCommitLabel: true\n",
+                "    parallelCommits: false\n",
+                "    mainBranchName: trunk\n",
+                "    mainBranchOrder: 0\n",
+                "---\n",
+            )
+        );
+        Ok(())
+    }
+}
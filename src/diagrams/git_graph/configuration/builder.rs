@@ -0,0 +1,177 @@
+//! Submodule providing a builder struct for the configuration of git graphs
+//! in Mermaid syntax.
+
+use alloc::string::{String, ToString};
+
+use crate::{
+    diagrams::git_graph::{
+        configuration::{DEFAULT_MAIN_BRANCH_NAME, GitGraphConfiguration},
+        git_statement::is_valid_branch_name,
+    },
+    errors::ConfigError,
+    shared::generic_configuration::{
+        Direction, GenericConfiguration, GenericConfigurationBuilder, Look, Renderer, Theme,
+    },
+    traits::{Configuration, ConfigurationBuilder},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating git graph configurations.
+pub struct GitGraphConfigurationBuilder {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfigurationBuilder,
+    /// Whether to display the names and lines of the branches.
+    show_branches: bool,
+    /// Whether to display the IDs of the commits.
+    show_commit_label: bool,
+    /// Whether to rotate the IDs of the commits.
+    rotate_commit_label: bool,
+    /// Whether to align commits by their distance from their parents.
+    parallel_commits: bool,
+    /// Name of the branch on which the graph starts.
+    main_branch_name: String,
+    /// Position of the main branch in the graph.
+    main_branch_order: u32,
+}
+
+impl Default for GitGraphConfigurationBuilder {
+    fn default() -> Self {
+        Self {
+            generic: GenericConfigurationBuilder::default(),
+            show_branches: true,
+            show_commit_label: true,
+            rotate_commit_label: true,
+            parallel_commits: false,
+            main_branch_name: DEFAULT_MAIN_BRANCH_NAME.into(),
+            main_branch_order: 0,
+        }
+    }
+}
+
+impl GitGraphConfigurationBuilder {
+    #[must_use]
+    /// Sets whether to display the names and lines of the branches.
+    pub fn show_branches(mut self, show_branches: bool) -> Self {
+        self.show_branches = show_branches;
+        self
+    }
+
+    #[must_use]
+    /// Sets whether to display the IDs of the commits.
+    pub fn show_commit_label(mut self, show_commit_label: bool) -> Self {
+        self.show_commit_label = show_commit_label;
+        self
+    }
+
+    #[must_use]
+    /// Sets whether to rotate the IDs of the commits.
+    pub fn rotate_commit_label(mut self, rotate_commit_label: bool) -> Self {
+        self.rotate_commit_label = rotate_commit_label;
+        self
+    }
+
+    #[must_use]
+    /// Sets whether to align commits by their distance from their parents
+    /// rather than by their chronological order.
+    pub fn parallel_commits(mut self, parallel_commits: bool) -> Self {
+        self.parallel_commits = parallel_commits;
+        self
+    }
+
+    /// Sets the name of the branch on which the graph starts.
+    ///
+    /// # Errors
+    ///
+    /// * If the name is not a valid branch name.
+    pub fn main_branch_name(mut self, name: impl Into<String>) -> Result<Self, ConfigError> {
+        let name = name.into();
+        if !is_valid_branch_name(&name) {
+            return Err(ConfigError::InvalidMainBranchName);
+        }
+        self.main_branch_name = name;
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the position of the main branch in the graph.
+    pub fn main_branch_order(mut self, order: u32) -> Self {
+        self.main_branch_order = order;
+        self
+    }
+
+    /// Sets the theme to use for the diagram.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.generic = self.generic.theme(theme);
+        self
+    }
+
+    /// Sets the look to use for the diagram.
+    #[must_use]
+    pub fn look(mut self, look: Look) -> Self {
+        self.generic = self.generic.look(look);
+        self
+    }
+}
+
+impl TryFrom<GitGraphConfigurationBuilder> for GitGraphConfiguration {
+    type Error = ConfigError;
+
+    fn try_from(builder: GitGraphConfigurationBuilder) -> Result<Self, Self::Error> {
+        let generic: GenericConfiguration = builder.generic.try_into()?;
+        if generic.direction() == Direction::RightToLeft {
+            return Err(ConfigError::UnsupportedDirection(generic.direction()));
+        }
+        Ok(GitGraphConfiguration {
+            generic,
+            show_branches: builder.show_branches,
+            show_commit_label: builder.show_commit_label,
+            rotate_commit_label: builder.rotate_commit_label,
+            parallel_commits: builder.parallel_commits,
+            main_branch_name: builder.main_branch_name,
+            main_branch_order: builder.main_branch_order,
+        })
+    }
+}
+
+impl ConfigurationBuilder for GitGraphConfigurationBuilder {
+    type Configuration = GitGraphConfiguration;
+    type Error = ConfigError;
+
+    fn build(self) -> Result<Self::Configuration, Self::Error> {
+        self.try_into()
+    }
+
+    fn title<S: ToString>(mut self, title: S) -> Result<Self, Self::Error> {
+        self.generic = self.generic.title(title)?;
+        Ok(self)
+    }
+
+    fn direction(mut self, direction: Direction) -> Self {
+        self.generic = self.generic.direction(direction);
+        self
+    }
+
+    fn renderer(mut self, renderer: Renderer) -> Self {
+        self.generic = self.generic.renderer(renderer);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_git_graph_configuration_builder_errors() {
+        assert_eq!(
+            GitGraphConfigurationBuilder::default().main_branch_name("my trunk"),
+            Err(ConfigError::InvalidMainBranchName)
+        );
+        assert_eq!(
+            GitGraphConfigurationBuilder::default().direction(Direction::RightToLeft).build(),
+            Err(ConfigError::UnsupportedDirection(Direction::RightToLeft))
+        );
+    }
+}
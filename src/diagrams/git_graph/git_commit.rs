@@ -0,0 +1,79 @@
+//! Submodule defining a commit of a git graph in Mermaid syntax.
+
+mod builder;
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+pub use builder::GitCommitBuilder;
+pub(crate) use builder::validate_tag;
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents how a commit is drawn in a git graph.
+pub enum GitCommitType {
+    /// A regular commit, drawn as a filled circle.
+    #[default]
+    Normal,
+    /// A reverting commit, drawn as a crossed circle.
+    Reverse,
+    /// A highlighted commit, drawn as a filled rectangle.
+    Highlight,
+}
+
+impl Display for GitCommitType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GitCommitType::Normal => write!(f, "NORMAL"),
+            GitCommitType::Reverse => write!(f, "REVERSE"),
+            GitCommitType::Highlight => write!(f, "HIGHLIGHT"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a commit of a git graph, either regular or created by a merge.
+///
+/// The commit is displayed as the attributes following the `commit` and
+/// `merge` keywords, e.g. `id: "a1" type: HIGHLIGHT tag: "v1.0"`.
+pub struct GitCommit {
+    /// Unique identifier of the commit within its graph.
+    id: String,
+    /// How the commit is drawn.
+    kind: GitCommitType,
+    /// Tag attached to the commit, if any.
+    tag: Option<String>,
+}
+
+impl GitCommit {
+    #[must_use]
+    /// Returns the unique identifier of the commit.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    #[must_use]
+    /// Returns how the commit is drawn.
+    pub fn kind(&self) -> GitCommitType {
+        self.kind
+    }
+
+    #[must_use]
+    /// Returns the tag attached to the commit, if any.
+    pub fn tag(&self) -> Option<&str> {
+        self.tag.as_deref()
+    }
+}
+
+impl Display for GitCommit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "id: \"{}\"", self.id)?;
+        if self.kind != GitCommitType::Normal {
+            write!(f, " type: {}", self.kind)?;
+        }
+        if let Some(tag) = &self.tag {
+            write!(f, " tag: \"{tag}\"")?;
+        }
+        Ok(())
+    }
+}
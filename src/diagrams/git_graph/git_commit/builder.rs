@@ -0,0 +1,113 @@
+//! Submodule defining the struct for building a commit of a git graph.
+
+use alloc::string::String;
+
+use crate::{
+    diagrams::git_graph::git_commit::{GitCommit, GitCommitType},
+    errors::GitGraphError,
+};
+
+/// Returns whether the provided commit ID or tag can be written as a quoted
+/// string.
+fn is_valid_quoted(value: &str) -> bool {
+    !value.trim().is_empty() && !value.contains(['"', '\n', '\r'])
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `GitCommit`.
+///
+/// When added to a `GitGraphBuilder`, a commit without an ID receives one
+/// derived from its position in the graph, so that the rendered graph does
+/// not depend on the random IDs generated by Mermaid.
+pub struct GitCommitBuilder {
+    /// Unique identifier of the commit.
+    id: Option<String>,
+    /// How the commit is drawn.
+    kind: GitCommitType,
+    /// Tag attached to the commit.
+    tag: Option<String>,
+}
+
+impl GitCommitBuilder {
+    /// Sets the unique identifier of the commit.
+    ///
+    /// # Errors
+    ///
+    /// * If the ID is empty or contains double quotes or line breaks.
+    pub fn id(mut self, id: impl Into<String>) -> Result<Self, GitGraphError> {
+        let id = id.into();
+        if !is_valid_quoted(&id) {
+            return Err(GitGraphError::InvalidCommitId(id));
+        }
+        self.id = Some(id);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Returns the identifier of the commit, if set.
+    pub fn get_id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    #[must_use]
+    /// Sets how the commit is drawn.
+    pub fn kind(mut self, kind: GitCommitType) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Attaches a tag to the commit.
+    ///
+    /// # Errors
+    ///
+    /// * If the tag is empty or contains double quotes or line breaks.
+    pub fn tag(mut self, tag: impl Into<String>) -> Result<Self, GitGraphError> {
+        let tag = tag.into();
+        if !is_valid_quoted(&tag) {
+            return Err(GitGraphError::InvalidTag(tag));
+        }
+        self.tag = Some(tag);
+        Ok(self)
+    }
+
+    /// Builds the commit, using the provided ID if none was set.
+    pub(crate) fn build_with_default_id(self, default_id: String) -> GitCommit {
+        GitCommit { id: self.id.unwrap_or(default_id), kind: self.kind, tag: self.tag }
+    }
+}
+
+/// Validates a tag attached to a cherry-picked commit.
+pub(crate) fn validate_tag(tag: &str) -> Result<(), GitGraphError> {
+    if is_valid_quoted(tag) { Ok(()) } else { Err(GitGraphError::InvalidTag(tag.into())) }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box, format};
+
+    use super::*;
+
+    #[test]
+    fn test_git_commit_builder() -> Result<(), Box<dyn core::error::Error>> {
+        let commit = GitCommitBuilder::default()
+            .id("fix")?
+            .kind(GitCommitType::Highlight)
+            .tag("v1.0")?
+            .build_with_default_id("v0".to_owned());
+        assert_eq!(format!("{commit}"), "id: \"fix\" type: HIGHLIGHT tag: \"v1.0\"");
+
+        let commit = GitCommitBuilder::default().build_with_default_id("v0".to_owned());
+        assert_eq!(format!("{commit}"), "id: \"v0\"");
+
+        assert_eq!(
+            GitCommitBuilder::default().id("a\"b"),
+            Err(GitGraphError::InvalidCommitId("a\"b".to_owned()))
+        );
+        assert_eq!(
+            GitCommitBuilder::default().tag(" "),
+            Err(GitGraphError::InvalidTag(" ".to_owned()))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+//! Submodule defining the statements of a git graph in Mermaid syntax.
+
+use alloc::{rc::Rc, string::String};
+use core::fmt::{self, Display};
+
+use crate::{diagrams::git_graph::git_commit::GitCommit, traits::TabbedDisplay};
+
+/// Returns whether the provided name is accepted by Mermaid as a branch name.
+pub(crate) fn is_valid_branch_name(name: &str) -> bool {
+    name.chars().next().is_some_and(|first| first.is_ascii_alphanumeric() || first == '_')
+        && !name.ends_with(['.', '/'])
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '/'))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a statement of a git graph, in the order in which Mermaid
+/// replays it.
+pub enum GitStatement {
+    /// Adds a commit to the current branch.
+    Commit(Rc<GitCommit>),
+    /// Creates a branch from the current branch and checks it out, optionally
+    /// with the position of the branch in the graph.
+    Branch {
+        /// Name of the branch.
+        name: String,
+        /// Position of the branch in the graph, if any.
+        order: Option<u32>,
+    },
+    /// Checks out a branch, written with the `checkout` keyword.
+    Checkout(String),
+    /// Checks out a branch, written with the `switch` keyword.
+    Switch(String),
+    /// Merges a branch into the current branch, creating a merge commit.
+    Merge {
+        /// Name of the merged branch.
+        branch: String,
+        /// Merge commit created on the current branch.
+        commit: Rc<GitCommit>,
+    },
+    /// Copies a commit from another branch onto the current branch.
+    CherryPick {
+        /// The cherry-picked commit.
+        commit: Rc<GitCommit>,
+        /// Tag attached to the copied commit, if any.
+        tag: Option<String>,
+    },
+}
+
+impl Display for GitStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for GitStatement {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        write!(f, "{}", " ".repeat(tab_count * 2))?;
+        match self {
+            GitStatement::Commit(commit) => writeln!(f, "commit {commit}"),
+            GitStatement::Branch { name, order } => {
+                write!(f, "branch {name}")?;
+                if let Some(order) = order {
+                    write!(f, " order: {order}")?;
+                }
+                writeln!(f)
+            }
+            GitStatement::Checkout(branch) => writeln!(f, "checkout {branch}"),
+            GitStatement::Switch(branch) => writeln!(f, "switch {branch}"),
+            GitStatement::Merge { branch, commit } => writeln!(f, "merge {branch} {commit}"),
+            GitStatement::CherryPick { commit, tag } => {
+                write!(f, "cherry-pick id: \"{}\"", commit.id())?;
+                if let Some(tag) = tag {
+                    write!(f, " tag: \"{tag}\"")?;
+                }
+                writeln!(f)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_branch_name() {
+        assert!(is_valid_branch_name("main"));
+        assert!(is_valid_branch_name("feature/parser-v2"));
+        assert!(is_valid_branch_name("_hotfix.1"));
+        assert!(!is_valid_branch_name(""));
+        assert!(!is_valid_branch_name("-x"));
+        assert!(!is_valid_branch_name("release/"));
+        assert!(!is_valid_branch_name("my branch"));
+    }
+}
@@ -0,0 +1,182 @@
+//! Submodule providing structs for creating user journeys in Mermaid syntax.
+
+mod builder;
+mod configuration;
+mod journey_section;
+mod journey_task;
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::JourneyBuilder;
+pub use configuration::{JourneyConfiguration, JourneyConfigurationBuilder};
+pub use journey_section::JourneySection;
+pub use journey_task::{JourneyTask, JourneyTaskBuilder};
+
+use crate::{
+    errors::JourneyError,
+    shared::Color,
+    traits::{Configuration, TabbedDisplay},
+};
+
+/// Validates a text of a user journey, which is written without quotes and
+/// delimited by colons.
+pub(crate) fn validate_text(text: impl Into<String>) -> Result<String, JourneyError> {
+    let text = text.into();
+    if text.trim().is_empty() {
+        return Err(JourneyError::EmptyText);
+    }
+    if text.contains([':', '#', ';', '\n', '\r']) {
+        return Err(JourneyError::InvalidText(text));
+    }
+    Ok(text)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a user journey in Mermaid syntax.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = JourneyBuilder::default()
+///         .configuration(JourneyConfigurationBuilder::default().title("My working day")?)?;
+///     builder.section("Go to work")?;
+///     builder.task(JourneyTaskBuilder::default().label("Make tea")?.score(5)?.actor("Me")?)?;
+///
+///     let journey = Journey::from(builder);
+///     assert_eq!(
+///         journey.to_string(),
+///         "journey\n  title My working day\n  section Go to work\n    Make tea: 5: Me\n"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct Journey {
+    /// Configuration of the journey.
+    configuration: JourneyConfiguration,
+    /// Sections of the journey.
+    sections: Vec<JourneySection>,
+}
+
+impl Journey {
+    #[must_use]
+    /// Returns the configuration of the journey.
+    pub fn configuration(&self) -> &JourneyConfiguration {
+        &self.configuration
+    }
+
+    /// Returns an iterator over the sections of the journey.
+    pub fn sections(&self) -> impl Iterator<Item = &JourneySection> {
+        self.sections.iter()
+    }
+
+    /// Returns an iterator over the tasks of the journey.
+    pub fn tasks(&self) -> impl Iterator<Item = &JourneyTask> {
+        self.sections.iter().flat_map(JourneySection::tasks)
+    }
+
+    /// Returns the fills of the sections which contain tasks, colored by
+    /// their mean score, or an empty vector if coloring by score is disabled.
+    fn section_fills(&self) -> Vec<Color> {
+        self.sections
+            .iter()
+            .filter_map(JourneySection::mean_score)
+            .map_while(|score| self.configuration.score_color(score))
+            .collect()
+    }
+}
+
+impl Display for Journey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for Journey {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat((tab_count + 1) * 2);
+        self.configuration.fmt_frontmatter(f, &self.section_fills())?;
+        writeln!(f, "{}journey", " ".repeat(tab_count * 2))?;
+        if let Some(title) = self.configuration.title() {
+            writeln!(f, "{indent}title {title}")?;
+        }
+        for section in &self.sections {
+            let task_indent = if let Some(label) = section.label() {
+                writeln!(f, "{indent}section {label}")?;
+                " ".repeat((tab_count + 2) * 2)
+            } else {
+                indent.clone()
+            };
+            for task in section.tasks() {
+                writeln!(f, "{task_indent}{task}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::traits::ConfigurationBuilder;
+
+    #[test]
+    fn test_journey_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = JourneyBuilder::default().configuration(
+            JourneyConfigurationBuilder::default()
+                .title("Downloading a dataset")?
+                .color_by_score(100, 50),
+        )?;
+        builder.task(JourneyTaskBuilder::default().label("Open portal")?.score(4)?)?;
+        builder.section("Search")?;
+        builder.task(
+            JourneyTaskBuilder::default()
+                .label("Filter by instrument")?
+                .score(2)?
+                .actor("Researcher")?,
+        )?;
+        builder.task(
+            JourneyTaskBuilder::default()
+                .label("Preview spectra")?
+                .score(1)?
+                .actor("Researcher")?
+                .actor("Curator")?,
+        )?;
+        builder.section("Empty")?;
+        builder.section("Download")?;
+        builder.task(
+            JourneyTaskBuilder::default().label("Get archive")?.score(5)?.actor("Researcher")?,
+        )?;
+
+        let journey = Journey::from(builder);
+        assert_eq!(journey.sections().count(), 4);
+        assert_eq!(journey.tasks().count(), 4);
+        assert_eq!(
+            journey.to_string(),
+            concat!(
+                "---\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "  journey:\n",
+                "    sectionFills: [\"#7fff00\", \"#ff7f00\", \"#00ff00\"]\n",
+                "---\n",
+                "journey\n",
+                "  title Downloading a dataset\n",
+                "  Open portal: 4\n",
+                "  section Search\n",
+                "    Filter by instrument: 2: Researcher\n",
+                "    Preview spectra: 1: Researcher, Curator\n",
+                "  section Empty\n",
+                "  section Download\n",
+                "    Get archive: 5: Researcher\n",
+            )
+        );
+        Ok(())
+    }
+}
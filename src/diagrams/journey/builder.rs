@@ -0,0 +1,92 @@
+//! Submodule providing a builder for user journeys.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    diagrams::journey::{
+        Journey,
+        configuration::{JourneyConfiguration, JourneyConfigurationBuilder},
+        journey_section::JourneySection,
+        journey_task::JourneyTaskBuilder,
+        validate_text,
+    },
+    errors::{ConfigError, JourneyError},
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a user journey.
+///
+/// Tasks are added to the most recently opened section.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = JourneyBuilder::default();
+///     builder.section("Sign up")?;
+///     builder.task(JourneyTaskBuilder::default().label("Fill the form")?.score(3)?)?;
+///     assert!(JourneyTaskBuilder::default().score(7).is_err());
+///
+///     let journey = Journey::from(builder);
+///     assert_eq!(journey.tasks().count(), 1);
+///     Ok(())
+/// }
+/// ```
+pub struct JourneyBuilder {
+    /// Configuration of the journey.
+    configuration: JourneyConfiguration,
+    /// Sections of the journey.
+    sections: Vec<JourneySection>,
+}
+
+impl JourneyBuilder {
+    /// Sets the configuration of the journey.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: JourneyConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Opens a new section, to which the subsequently added tasks belong.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty.
+    /// * If the label contains a colon, a hash, a semicolon or a line break.
+    pub fn section(&mut self, label: impl Into<String>) -> Result<(), JourneyError> {
+        self.sections.push(JourneySection::new(Some(validate_text(label)?)));
+        Ok(())
+    }
+
+    /// Adds a task to the current section of the journey.
+    ///
+    /// # Errors
+    ///
+    /// * If the task builder fails to build.
+    pub fn task(&mut self, task: JourneyTaskBuilder) -> Result<(), JourneyError> {
+        let task = task.build()?;
+        if self.sections.is_empty() {
+            self.sections.push(JourneySection::new(None));
+        }
+        if let Some(section) = self.sections.last_mut() {
+            section.push(task);
+        }
+        Ok(())
+    }
+}
+
+impl From<JourneyBuilder> for Journey {
+    fn from(builder: JourneyBuilder) -> Self {
+        Journey { configuration: builder.configuration, sections: builder.sections }
+    }
+}
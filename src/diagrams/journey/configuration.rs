@@ -0,0 +1,111 @@
+//! Submodule defining configuration specifically for user journeys in
+//! Mermaid.
+
+mod builder;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Display};
+
+pub use builder::JourneyConfigurationBuilder;
+use colorsys::Hsl;
+
+use crate::{
+    shared::{
+        Color, Direction, Renderer,
+        generic_configuration::{GenericConfiguration, Look, Theme},
+    },
+    traits::Configuration,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the configuration for a user journey in Mermaid syntax.
+///
+/// The title of a user journey is written within the diagram rather than in
+/// its frontmatter.
+pub struct JourneyConfiguration {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfiguration,
+    /// Saturation and lightness of the colors by score, if enabled.
+    score_palette: Option<(u8, u8)>,
+}
+
+impl JourneyConfiguration {
+    #[must_use]
+    /// Returns the color of the provided satisfaction score, ranging from red
+    /// for a score of 1 to green for a score of 5, or `None` if coloring by
+    /// score is disabled.
+    pub fn score_color(&self, score: u8) -> Option<Color> {
+        let (saturation, lightness) = self.score_palette?;
+        let hue = f64::from(score.clamp(1, 5) - 1) * 30.0;
+        Some(Hsl::new(hue, f64::from(saturation), f64::from(lightness), None).into())
+    }
+
+    /// Writes the frontmatter of the journey, including the provided section
+    /// fills.
+    pub(crate) fn fmt_frontmatter(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        section_fills: &[Color],
+    ) -> fmt::Result {
+        let mut options = Vec::new();
+        if !section_fills.is_empty() {
+            let fills: Vec<String> =
+                section_fills.iter().map(|fill| format!("\"{}\"", fill.to_hex())).collect();
+            options.push(("journey.sectionFills".to_string(), format!("[{}]", fills.join(", "))));
+        }
+        self.generic.fmt_frontmatter(f, false, &options)
+    }
+}
+
+impl Display for JourneyConfiguration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_frontmatter(f, &[])
+    }
+}
+
+impl Configuration for JourneyConfiguration {
+    type Builder = JourneyConfigurationBuilder;
+
+    fn title(&self) -> Option<&str> {
+        self.generic.title()
+    }
+
+    fn direction(&self) -> Direction {
+        self.generic.direction()
+    }
+
+    fn renderer(&self) -> Renderer {
+        self.generic.renderer()
+    }
+
+    fn theme(&self) -> Theme {
+        self.generic.theme()
+    }
+
+    fn look(&self) -> Look {
+        self.generic.look()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+
+    use super::*;
+    use crate::traits::ConfigurationBuilder;
+
+    #[test]
+    fn test_journey_configuration_score_color() -> Result<(), Box<dyn core::error::Error>> {
+        assert_eq!(JourneyConfiguration::default().score_color(3), None);
+
+        let config = JourneyConfigurationBuilder::default().color_by_score(100, 50).build()?;
+        assert_eq!(config.score_color(1), Some(Color::from((255, 0, 0))));
+        assert_eq!(config.score_color(5), Some(Color::from((0, 255, 0))));
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+//! Submodule providing a builder struct for the configuration of user
+//! journeys in Mermaid syntax.
+
+use alloc::string::ToString;
+
+use crate::{
+    diagrams::journey::configuration::JourneyConfiguration,
+    errors::ConfigError,
+    shared::generic_configuration::{
+        Direction, GenericConfigurationBuilder, Look, Renderer, Theme,
+    },
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating user journey configurations.
+pub struct JourneyConfigurationBuilder {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfigurationBuilder,
+    /// Saturation and lightness of the colors by score, if enabled.
+    score_palette: Option<(u8, u8)>,
+}
+
+impl JourneyConfigurationBuilder {
+    #[must_use]
+    /// Colors the tasks by satisfaction score, from red for a score of 1 to
+    /// green for a score of 5.
+    ///
+    /// Mermaid fills every task with the color of its section, so each
+    /// section is filled with the color of the rounded mean score of its
+    /// tasks.
+    ///
+    /// # Arguments
+    ///
+    /// * `saturation` - The saturation of the colors (0-100).
+    /// * `lightness` - The lightness of the colors (0-100).
+    pub fn color_by_score(mut self, saturation: u8, lightness: u8) -> Self {
+        self.score_palette = Some((saturation, lightness));
+        self
+    }
+
+    /// Sets the theme to use for the diagram.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.generic = self.generic.theme(theme);
+        self
+    }
+
+    /// Sets the look to use for the diagram.
+    #[must_use]
+    pub fn look(mut self, look: Look) -> Self {
+        self.generic = self.generic.look(look);
+        self
+    }
+}
+
+impl TryFrom<JourneyConfigurationBuilder> for JourneyConfiguration {
+    type Error = ConfigError;
+
+    fn try_from(builder: JourneyConfigurationBuilder) -> Result<Self, Self::Error> {
+        Ok(JourneyConfiguration {
+            generic: builder.generic.try_into()?,
+            score_palette: builder.score_palette,
+        })
+    }
+}
+
+impl ConfigurationBuilder for JourneyConfigurationBuilder {
+    type Configuration = JourneyConfiguration;
+    type Error = ConfigError;
+
+    fn build(self) -> Result<Self::Configuration, Self::Error> {
+        self.try_into()
+    }
+
+    fn title<S: ToString>(mut self, title: S) -> Result<Self, Self::Error> {
+        self.generic = self.generic.title(title)?;
+        Ok(self)
+    }
+
+    fn direction(mut self, direction: Direction) -> Self {
+        self.generic = self.generic.direction(direction);
+        self
+    }
+
+    fn renderer(mut self, renderer: Renderer) -> Self {
+        self.generic = self.generic.renderer(renderer);
+        self
+    }
+}
@@ -0,0 +1,50 @@
+//! Submodule defining a section grouping tasks of a user journey.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::diagrams::journey::journey_task::JourneyTask;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a section of a user journey, i.e. a group of consecutive
+/// tasks.
+///
+/// Tasks added before any section is opened belong to an unlabelled section.
+pub struct JourneySection {
+    /// Label of the section, if any.
+    label: Option<String>,
+    /// Tasks of the section, in the order in which they were added.
+    tasks: Vec<JourneyTask>,
+}
+
+impl JourneySection {
+    /// Creates a new empty section.
+    pub(crate) fn new(label: Option<String>) -> Self {
+        Self { label, tasks: Vec::new() }
+    }
+
+    /// Adds a task to the section.
+    pub(crate) fn push(&mut self, task: JourneyTask) {
+        self.tasks.push(task);
+    }
+
+    #[must_use]
+    /// Returns the label of the section, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Returns an iterator over the tasks of the section.
+    pub fn tasks(&self) -> impl Iterator<Item = &JourneyTask> {
+        self.tasks.iter()
+    }
+
+    #[must_use]
+    /// Returns the mean satisfaction score of the tasks of the section,
+    /// rounded to the nearest integer, or `None` if the section is empty.
+    pub fn mean_score(&self) -> Option<u8> {
+        let count = u32::try_from(self.tasks.len()).ok().filter(|count| *count > 0)?;
+        let total: u32 = self.tasks.iter().map(|task| u32::from(task.score())).sum();
+        u8::try_from((total + count / 2) / count).ok()
+    }
+}
@@ -0,0 +1,67 @@
+//! Submodule defining the tasks of user journeys in Mermaid syntax.
+
+mod builder;
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::JourneyTaskBuilder;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a task of a user journey, with the satisfaction score of the
+/// actors taking part in it.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let task = JourneyTaskBuilder::default()
+///         .label("Do work")?
+///         .score(1)?
+///         .actor("Me")?
+///         .actor("Cat")?
+///         .build()?;
+///
+///     assert_eq!(task.to_string(), "Do work: 1: Me, Cat");
+///     Ok(())
+/// }
+/// ```
+pub struct JourneyTask {
+    /// Label of the task.
+    label: String,
+    /// Satisfaction score of the task, between 1 and 5.
+    score: u8,
+    /// Actors taking part in the task.
+    actors: Vec<String>,
+}
+
+impl JourneyTask {
+    #[must_use]
+    /// Returns the label of the task.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the satisfaction score of the task, between 1 and 5.
+    pub fn score(&self) -> u8 {
+        self.score
+    }
+
+    /// Returns an iterator over the actors taking part in the task.
+    pub fn actors(&self) -> impl Iterator<Item = &str> {
+        self.actors.iter().map(String::as_str)
+    }
+}
+
+impl Display for JourneyTask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.label, self.score)?;
+        if !self.actors.is_empty() {
+            write!(f, ": {}", self.actors.join(", "))?;
+        }
+        Ok(())
+    }
+}
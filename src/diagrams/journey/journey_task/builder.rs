@@ -0,0 +1,113 @@
+//! Submodule defining the struct for building a task of a user journey.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    diagrams::journey::{journey_task::JourneyTask, validate_text},
+    errors::JourneyError,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `JourneyTask`.
+pub struct JourneyTaskBuilder {
+    /// Label of the task.
+    label: Option<String>,
+    /// Satisfaction score of the task, between 1 and 5.
+    score: Option<u8>,
+    /// Actors taking part in the task.
+    actors: Vec<String>,
+}
+
+impl JourneyTaskBuilder {
+    /// Sets the label of the task.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty.
+    /// * If the label contains a colon, a hash, a semicolon or a line break.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, JourneyError> {
+        self.label = Some(validate_text(label)?);
+        Ok(self)
+    }
+
+    /// Sets the satisfaction score of the task.
+    ///
+    /// # Errors
+    ///
+    /// * If the score is not between 1 and 5.
+    pub fn score(mut self, score: u8) -> Result<Self, JourneyError> {
+        if !(1..=5).contains(&score) {
+            return Err(JourneyError::InvalidScore(score));
+        }
+        self.score = Some(score);
+        Ok(self)
+    }
+
+    /// Adds an actor taking part in the task.
+    ///
+    /// # Errors
+    ///
+    /// * If the actor is empty.
+    /// * If the actor contains a comma, a colon, a hash, a semicolon or a line
+    ///   break.
+    pub fn actor(mut self, actor: impl Into<String>) -> Result<Self, JourneyError> {
+        let actor = validate_text(actor)?;
+        if actor.contains(',') {
+            return Err(JourneyError::InvalidText(actor));
+        }
+        self.actors.push(actor);
+        Ok(self)
+    }
+
+    /// Builds the task.
+    ///
+    /// # Errors
+    ///
+    /// * If the label or the score is missing.
+    pub fn build(self) -> Result<JourneyTask, JourneyError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<JourneyTaskBuilder> for JourneyTask {
+    type Error = JourneyError;
+
+    fn try_from(builder: JourneyTaskBuilder) -> Result<Self, Self::Error> {
+        let label = builder.label.ok_or(JourneyError::MissingTaskLabel)?;
+        let Some(score) = builder.score else {
+            return Err(JourneyError::MissingScore(label));
+        };
+        Ok(JourneyTask { label, score, actors: builder.actors })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::borrow::ToOwned;
+
+    use super::*;
+
+    #[test]
+    fn test_journey_task_builder_errors() -> Result<(), JourneyError> {
+        assert_eq!(JourneyTaskBuilder::default().score(0), Err(JourneyError::InvalidScore(0)));
+        assert_eq!(JourneyTaskBuilder::default().score(6), Err(JourneyError::InvalidScore(6)));
+        assert_eq!(
+            JourneyTaskBuilder::default().actor("Me, Cat"),
+            Err(JourneyError::InvalidText("Me, Cat".to_owned()))
+        );
+        assert_eq!(
+            JourneyTaskBuilder::default().label("Step 1: log in"),
+            Err(JourneyError::InvalidText("Step 1: log in".to_owned()))
+        );
+        assert_eq!(
+            JourneyTaskBuilder::default().label("Log in")?.build(),
+            Err(JourneyError::MissingScore("Log in".to_owned()))
+        );
+        assert_eq!(
+            JourneyTaskBuilder::default().score(3)?.build(),
+            Err(JourneyError::MissingTaskLabel)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+//! Submodule providing structs for creating kanban boards in Mermaid syntax.
+
+mod builder;
+mod configuration;
+mod kanban_column;
+mod kanban_item;
+use alloc::{rc::Rc, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::KanbanBuilder;
+pub use configuration::{KanbanConfiguration, KanbanConfigurationBuilder};
+pub use kanban_column::{KanbanColumn, KanbanColumnBuilder};
+pub use kanban_item::{KanbanItem, KanbanItemBuilder, KanbanPriority};
+
+use crate::{errors::KanbanError, traits::TabbedDisplay};
+
+/// Validates a label of a kanban board, which is always written between
+/// square brackets.
+pub(crate) fn validate_text(text: impl Into<String>) -> Result<String, KanbanError> {
+    let text = text.into();
+    if text.trim().is_empty() {
+        return Err(KanbanError::EmptyText);
+    }
+    if text.contains(['[', ']', '\n', '\r']) {
+        return Err(KanbanError::InvalidText(text));
+    }
+    Ok(text)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a kanban board in Mermaid syntax.
+///
+/// Each column is followed by its items, indented one level further.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = KanbanBuilder::default();
+///     let todo = builder.column(KanbanColumnBuilder::default().label("Todo")?)?;
+///     builder.item(&todo, KanbanItemBuilder::default().label("Write docs")?.assigned("ada")?)?;
+///     builder.column(KanbanColumnBuilder::default().label("Done")?)?;
+///
+///     let kanban = Kanban::from(builder);
+///     assert_eq!(
+///         kanban.to_string(),
+///         concat!(
+///             "kanban\n",
+///             "  v0[Todo]\n",
+///             "    v1[Write docs]@{ assigned: 'ada' }\n",
+///             "  v2[Done]\n",
+///         )
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct Kanban {
+    /// Configuration of the board.
+    configuration: KanbanConfiguration,
+    /// Columns of the board.
+    columns: Vec<Rc<KanbanColumn>>,
+    /// Items of the board, along with the identifier of their column.
+    items: Vec<(u64, Rc<KanbanItem>)>,
+}
+
+impl Kanban {
+    #[must_use]
+    /// Returns the configuration of the board.
+    pub fn configuration(&self) -> &KanbanConfiguration {
+        &self.configuration
+    }
+
+    /// Returns an iterator over the columns of the board.
+    pub fn columns(&self) -> impl Iterator<Item = &Rc<KanbanColumn>> {
+        self.columns.iter()
+    }
+
+    /// Returns an iterator over the items of the column with the provided ID.
+    pub fn items(&self, column_id: u64) -> impl Iterator<Item = &Rc<KanbanItem>> {
+        self.items.iter().filter(move |(column, _)| *column == column_id).map(|(_, item)| item)
+    }
+}
+
+impl Display for Kanban {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for Kanban {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat((tab_count + 1) * 2);
+        write!(f, "{}", self.configuration)?;
+        writeln!(f, "{}kanban", " ".repeat(tab_count * 2))?;
+        for column in &self.columns {
+            writeln!(f, "{indent}{column}")?;
+            for item in self.items(column.id()) {
+                writeln!(f, "{indent}  {item}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::traits::ConfigurationBuilder;
+
+    #[test]
+    fn test_kanban_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = KanbanBuilder::default().configuration(
+            KanbanConfigurationBuilder::default()
+                .title("Sprint 12")?
+                .ticket_base_url("https://tracker.example.com/browse/#TICKET#")?,
+        )?;
+        let todo = builder.column(KanbanColumnBuilder::default().label("Todo")?)?;
+        let doing = builder.column(KanbanColumnBuilder::default().label("In progress")?)?;
+        builder.item(
+            &doing,
+            KanbanItemBuilder::default()
+                .label("Calibrate detector")?
+                .ticket("DAQ-42")?
+                .priority(KanbanPriority::High),
+        )?;
+        builder.item(&todo, KanbanItemBuilder::default().label("Export spectra")?)?;
+        builder.item(
+            &doing,
+            KanbanItemBuilder::default().label("Review header format")?.assigned("Grace")?,
+        )?;
+
+        let kanban = Kanban::from(builder);
+        assert_eq!(kanban.columns().count(), 2);
+        assert_eq!(kanban.items(doing.id()).count(), 2);
+        assert_eq!(
+            kanban.to_string(),
+            concat!(
+                "---\n",
+                "title: Sprint 12\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "  kanban:\n",
+                "    ticketBaseUrl: 'https://tracker.example.com/browse/#TICKET#'\n",
+                "---\n",
+                "kanban\n",
+                "  v0[Todo]\n",
+                "    v3[Export spectra]\n",
+                "  v1[In progress]\n",
+                "    v2[Calibrate detector]@{ ticket: 'DAQ-42', priority: 'High' }\n",
+                "    v4[Review header format]@{ assigned: 'Grace' }\n",
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_kanban_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = KanbanBuilder::default();
+        let todo = builder.column(KanbanColumnBuilder::default().label("Todo")?)?;
+        assert_eq!(
+            builder.item(&todo, KanbanItemBuilder::default().label("Docs")?.id(0)),
+            Err(KanbanError::DuplicateId("v0".to_owned()))
+        );
+
+        let foreign_column = Rc::new(KanbanColumnBuilder::default().label("Done")?.id(3).build()?);
+        assert_eq!(
+            builder.item(&foreign_column, KanbanItemBuilder::default().label("Docs")?),
+            Err(KanbanError::ColumnNotFound("v3".to_owned()))
+        );
+        assert_eq!(Kanban::from(builder).to_string(), "kanban\n  v0[Todo]\n");
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+//! Submodule providing a builder for kanban boards.
+
+use alloc::{format, rc::Rc, vec::Vec};
+
+use crate::{
+    diagrams::kanban::{
+        Kanban,
+        configuration::{KanbanConfiguration, KanbanConfigurationBuilder},
+        kanban_column::{KanbanColumn, KanbanColumnBuilder},
+        kanban_item::{KanbanItem, KanbanItemBuilder},
+    },
+    errors::{ConfigError, KanbanError},
+    shared::NODE_LETTER,
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a kanban board.
+///
+/// Columns and items share the same IDs, and items may be added to any
+/// column which is already part of the board.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = KanbanBuilder::default();
+///     let done = builder.column(KanbanColumnBuilder::default().label("Done")?)?;
+///     let item = builder.item(&done, KanbanItemBuilder::default().label("Ship it")?)?;
+///
+///     assert_eq!(builder.get_item_by_id(item.id()), Some(item));
+///     Ok(())
+/// }
+/// ```
+pub struct KanbanBuilder {
+    /// Configuration of the board.
+    configuration: KanbanConfiguration,
+    /// Columns of the board.
+    columns: Vec<Rc<KanbanColumn>>,
+    /// Items of the board, along with the identifier of their column.
+    items: Vec<(u64, Rc<KanbanItem>)>,
+}
+
+impl KanbanBuilder {
+    /// Sets the configuration of the board.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: KanbanConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Adds a column to the board.
+    ///
+    /// # Errors
+    ///
+    /// * If a column or item with the same ID already exists in the board.
+    /// * If the column builder fails to build.
+    pub fn column(&mut self, column: KanbanColumnBuilder) -> Result<Rc<KanbanColumn>, KanbanError> {
+        let id = self.claim_id(column.get_id())?;
+        let column = Rc::new(column.id(id).build()?);
+        self.columns.push(column.clone());
+        Ok(column)
+    }
+
+    /// Adds an item to a column of the board.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The column to which the item is added.
+    /// * `item` - The builder of the item to add.
+    ///
+    /// # Errors
+    ///
+    /// * If the column is not part of the board.
+    /// * If a column or item with the same ID already exists in the board.
+    /// * If the item builder fails to build.
+    pub fn item(
+        &mut self,
+        column: &Rc<KanbanColumn>,
+        item: KanbanItemBuilder,
+    ) -> Result<Rc<KanbanItem>, KanbanError> {
+        if self.get_column_by_id(column.id()).as_ref() != Some(column) {
+            return Err(KanbanError::ColumnNotFound(format!("{NODE_LETTER}{}", column.id())));
+        }
+        let id = self.claim_id(item.get_id())?;
+        let item = Rc::new(item.id(id).build()?);
+        self.items.push((column.id(), item.clone()));
+        Ok(item)
+    }
+
+    #[must_use]
+    /// Returns the column with the provided ID, if any.
+    pub fn get_column_by_id(&self, id: u64) -> Option<Rc<KanbanColumn>> {
+        self.columns.iter().find(|column| column.id() == id).cloned()
+    }
+
+    #[must_use]
+    /// Returns the item with the provided ID, if any.
+    pub fn get_item_by_id(&self, id: u64) -> Option<Rc<KanbanItem>> {
+        self.items.iter().find(|(_, item)| item.id() == id).map(|(_, item)| item.clone())
+    }
+
+    /// Returns the provided ID, or the next available one, after checking
+    /// that no column or item of the board uses it.
+    fn claim_id(&self, id: Option<u64>) -> Result<u64, KanbanError> {
+        let id = id.unwrap_or((self.columns.len() + self.items.len()) as u64);
+        if self.get_column_by_id(id).is_some() || self.get_item_by_id(id).is_some() {
+            return Err(KanbanError::DuplicateId(format!("{NODE_LETTER}{id}")));
+        }
+        Ok(id)
+    }
+}
+
+impl From<KanbanBuilder> for Kanban {
+    fn from(builder: KanbanBuilder) -> Self {
+        Kanban {
+            configuration: builder.configuration,
+            columns: builder.columns,
+            items: builder.items,
+        }
+    }
+}
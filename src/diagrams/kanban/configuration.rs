@@ -0,0 +1,108 @@
+//! Submodule defining configuration specifically for kanban boards in
+//! Mermaid.
+
+mod builder;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Display;
+
+pub use builder::KanbanConfigurationBuilder;
+
+use crate::{
+    shared::{
+        Direction, Renderer,
+        generic_configuration::{GenericConfiguration, Look, Theme},
+    },
+    traits::Configuration,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the configuration for a kanban board in Mermaid syntax.
+pub struct KanbanConfiguration {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfiguration,
+    /// URL to which the tickets of the items link, in which `#TICKET#` is
+    /// replaced by the ticket of each item.
+    ticket_base_url: Option<String>,
+}
+
+impl KanbanConfiguration {
+    #[must_use]
+    /// Returns the URL to which the tickets of the items link, if any.
+    pub fn ticket_base_url(&self) -> Option<&str> {
+        self.ticket_base_url.as_deref()
+    }
+}
+
+impl Display for KanbanConfiguration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut options = Vec::new();
+        if let Some(ticket_base_url) = &self.ticket_base_url {
+            options.push(("kanban.ticketBaseUrl".to_string(), format!("'{ticket_base_url}'")));
+        }
+        self.generic.fmt_frontmatter(f, true, &options)
+    }
+}
+
+impl Configuration for KanbanConfiguration {
+    type Builder = KanbanConfigurationBuilder;
+
+    fn title(&self) -> Option<&str> {
+        self.generic.title()
+    }
+
+    fn direction(&self) -> Direction {
+        self.generic.direction()
+    }
+
+    fn renderer(&self) -> Renderer {
+        self.generic.renderer()
+    }
+
+    fn theme(&self) -> Theme {
+        self.generic.theme()
+    }
+
+    fn look(&self) -> Look {
+        self.generic.look()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format};
+
+    use super::*;
+    use crate::{errors::ConfigError, traits::ConfigurationBuilder};
+
+    #[test]
+    fn test_kanban_configuration_display() -> Result<(), Box<dyn core::error::Error>> {
+        assert_eq!(format!("{}", KanbanConfiguration::default()), "");
+
+        let config = KanbanConfigurationBuilder::default()
+            .ticket_base_url("https://tracker.example.com/browse/#TICKET#")?
+            .build()?;
+        assert_eq!(
+            format!("{config}"),
+            concat!(
+                "---\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "  kanban:\n",
+                "    ticketBaseUrl: 'https://tracker.example.com/browse/#TICKET#'\n",
+                "---\n",
+            )
+        );
+        assert_eq!(
+            KanbanConfigurationBuilder::default().ticket_base_url("https://it's.here/#TICKET#"),
+            Err(ConfigError::InvalidTicketBaseUrl)
+        );
+        Ok(())
+    }
+}
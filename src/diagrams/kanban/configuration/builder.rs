@@ -0,0 +1,89 @@
+//! Submodule providing a builder struct for the configuration of kanban
+//! boards in Mermaid syntax.
+
+use alloc::string::{String, ToString};
+
+use crate::{
+    diagrams::kanban::configuration::KanbanConfiguration,
+    errors::ConfigError,
+    shared::generic_configuration::{
+        Direction, GenericConfigurationBuilder, Look, Renderer, Theme,
+    },
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating kanban board configurations.
+pub struct KanbanConfigurationBuilder {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfigurationBuilder,
+    /// URL to which the tickets of the items link.
+    ticket_base_url: Option<String>,
+}
+
+impl KanbanConfigurationBuilder {
+    /// Sets the URL to which the tickets of the items link, in which
+    /// `#TICKET#` is replaced by the ticket of each item.
+    ///
+    /// # Errors
+    ///
+    /// * If the URL is empty or contains single quotes or whitespace.
+    pub fn ticket_base_url(mut self, url: impl Into<String>) -> Result<Self, ConfigError> {
+        let url = url.into();
+        if url.is_empty() || url.contains(|c: char| c == '\'' || c.is_whitespace()) {
+            return Err(ConfigError::InvalidTicketBaseUrl);
+        }
+        self.ticket_base_url = Some(url);
+        Ok(self)
+    }
+
+    /// Sets the theme to use for the diagram.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.generic = self.generic.theme(theme);
+        self
+    }
+
+    /// Sets the look to use for the diagram.
+    #[must_use]
+    pub fn look(mut self, look: Look) -> Self {
+        self.generic = self.generic.look(look);
+        self
+    }
+}
+
+impl TryFrom<KanbanConfigurationBuilder> for KanbanConfiguration {
+    type Error = ConfigError;
+
+    fn try_from(builder: KanbanConfigurationBuilder) -> Result<Self, Self::Error> {
+        Ok(KanbanConfiguration {
+            generic: builder.generic.try_into()?,
+            ticket_base_url: builder.ticket_base_url,
+        })
+    }
+}
+
+impl ConfigurationBuilder for KanbanConfigurationBuilder {
+    type Configuration = KanbanConfiguration;
+    type Error = ConfigError;
+
+    fn build(self) -> Result<Self::Configuration, Self::Error> {
+        self.try_into()
+    }
+
+    fn title<S: ToString>(mut self, title: S) -> Result<Self, Self::Error> {
+        self.generic = self.generic.title(title)?;
+        Ok(self)
+    }
+
+    fn direction(mut self, direction: Direction) -> Self {
+        self.generic = self.generic.direction(direction);
+        self
+    }
+
+    fn renderer(mut self, renderer: Renderer) -> Self {
+        self.generic = self.generic.renderer(renderer);
+        self
+    }
+}
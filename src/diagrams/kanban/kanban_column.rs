@@ -0,0 +1,52 @@
+//! Submodule defining the columns of kanban boards in Mermaid syntax.
+
+mod builder;
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+pub use builder::KanbanColumnBuilder;
+
+use crate::shared::NODE_LETTER;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a column of a kanban board, under which its items are
+/// declared.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let column = KanbanColumnBuilder::default().label("In progress")?.id(0).build()?;
+///     assert_eq!(column.to_string(), "v0[In progress]");
+///     Ok(())
+/// }
+/// ```
+pub struct KanbanColumn {
+    /// Unique identifier of the column.
+    id: u64,
+    /// Label of the column.
+    label: String,
+}
+
+impl KanbanColumn {
+    #[must_use]
+    /// Returns the unique identifier of the column.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[must_use]
+    /// Returns the label of the column.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+impl Display for KanbanColumn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{NODE_LETTER}{}[{}]", self.id, self.label)
+    }
+}
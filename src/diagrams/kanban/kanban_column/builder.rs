@@ -0,0 +1,63 @@
+//! Submodule defining the struct for building a column of a kanban board.
+
+use alloc::string::String;
+
+use crate::{
+    diagrams::kanban::{kanban_column::KanbanColumn, validate_text},
+    errors::KanbanError,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `KanbanColumn`.
+pub struct KanbanColumnBuilder {
+    /// Unique identifier of the column.
+    id: Option<u64>,
+    /// Label of the column.
+    label: Option<String>,
+}
+
+impl KanbanColumnBuilder {
+    /// Sets the label of the column.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains square brackets or line breaks.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, KanbanError> {
+        self.label = Some(validate_text(label)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the unique identifier of the column.
+    pub fn id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[must_use]
+    /// Returns the identifier of the column, if set.
+    pub fn get_id(&self) -> Option<u64> {
+        self.id
+    }
+
+    /// Builds the column.
+    ///
+    /// # Errors
+    ///
+    /// * If the ID or the label is missing.
+    pub fn build(self) -> Result<KanbanColumn, KanbanError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<KanbanColumnBuilder> for KanbanColumn {
+    type Error = KanbanError;
+
+    fn try_from(builder: KanbanColumnBuilder) -> Result<Self, Self::Error> {
+        Ok(KanbanColumn {
+            id: builder.id.ok_or(KanbanError::MissingId)?,
+            label: builder.label.ok_or(KanbanError::MissingLabel)?,
+        })
+    }
+}
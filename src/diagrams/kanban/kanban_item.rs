@@ -0,0 +1,122 @@
+//! Submodule defining the items of kanban boards in Mermaid syntax.
+
+mod builder;
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::KanbanItemBuilder;
+
+use crate::shared::NODE_LETTER;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the priority of an item of a kanban board.
+pub enum KanbanPriority {
+    /// The item has a very high priority.
+    VeryHigh,
+    /// The item has a high priority.
+    High,
+    /// The item has a low priority.
+    Low,
+    /// The item has a very low priority.
+    VeryLow,
+}
+
+impl Display for KanbanPriority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KanbanPriority::VeryHigh => write!(f, "Very High"),
+            KanbanPriority::High => write!(f, "High"),
+            KanbanPriority::Low => write!(f, "Low"),
+            KanbanPriority::VeryLow => write!(f, "Very Low"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents an item of a kanban board, with optional metadata.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let item = KanbanItemBuilder::default()
+///         .label("Design grammar")?
+///         .assigned("knsv")?
+///         .ticket("MC-2036")?
+///         .priority(KanbanPriority::VeryHigh)
+///         .id(3)
+///         .build()?;
+///
+///     assert_eq!(
+///         item.to_string(),
+///         "v3[Design grammar]@{ assigned: 'knsv', ticket: 'MC-2036', priority: 'Very High' }"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct KanbanItem {
+    /// Unique identifier of the item.
+    id: u64,
+    /// Label of the item.
+    label: String,
+    /// Person to whom the item is assigned.
+    assigned: Option<String>,
+    /// Ticket of the item in the issue tracker.
+    ticket: Option<String>,
+    /// Priority of the item.
+    priority: Option<KanbanPriority>,
+}
+
+impl KanbanItem {
+    #[must_use]
+    /// Returns the unique identifier of the item.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[must_use]
+    /// Returns the label of the item.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the person to whom the item is assigned, if any.
+    pub fn assigned(&self) -> Option<&str> {
+        self.assigned.as_deref()
+    }
+
+    #[must_use]
+    /// Returns the ticket of the item, if any.
+    pub fn ticket(&self) -> Option<&str> {
+        self.ticket.as_deref()
+    }
+
+    #[must_use]
+    /// Returns the priority of the item, if any.
+    pub fn priority(&self) -> Option<KanbanPriority> {
+        self.priority
+    }
+}
+
+impl Display for KanbanItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{NODE_LETTER}{}[{}]", self.id, self.label)?;
+        let metadata: Vec<String> = [
+            self.assigned.as_ref().map(|assigned| format!("assigned: '{assigned}'")),
+            self.ticket.as_ref().map(|ticket| format!("ticket: '{ticket}'")),
+            self.priority.map(|priority| format!("priority: '{priority}'")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !metadata.is_empty() {
+            write!(f, "@{{ {} }}", metadata.join(", "))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+//! Submodule defining the struct for building an item of a kanban board.
+
+use alloc::string::String;
+
+use crate::{
+    diagrams::kanban::{
+        kanban_item::{KanbanItem, KanbanPriority},
+        validate_text,
+    },
+    errors::KanbanError,
+};
+
+/// Validates a metadata value of an item, which is always written between
+/// single quotes.
+fn validate_metadata(value: impl Into<String>) -> Result<String, KanbanError> {
+    let value = value.into();
+    if value.trim().is_empty() {
+        return Err(KanbanError::EmptyText);
+    }
+    if value.contains(['\'', '\n', '\r']) {
+        return Err(KanbanError::InvalidText(value));
+    }
+    Ok(value)
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `KanbanItem`.
+pub struct KanbanItemBuilder {
+    /// Unique identifier of the item.
+    id: Option<u64>,
+    /// Label of the item.
+    label: Option<String>,
+    /// Person to whom the item is assigned.
+    assigned: Option<String>,
+    /// Ticket of the item in the issue tracker.
+    ticket: Option<String>,
+    /// Priority of the item.
+    priority: Option<KanbanPriority>,
+}
+
+impl KanbanItemBuilder {
+    /// Sets the label of the item.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains square brackets or line breaks.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, KanbanError> {
+        self.label = Some(validate_text(label)?);
+        Ok(self)
+    }
+
+    /// Sets the person to whom the item is assigned.
+    ///
+    /// # Errors
+    ///
+    /// * If the assignee is empty or contains single quotes or line breaks.
+    pub fn assigned(mut self, assigned: impl Into<String>) -> Result<Self, KanbanError> {
+        self.assigned = Some(validate_metadata(assigned)?);
+        Ok(self)
+    }
+
+    /// Sets the ticket of the item in the issue tracker.
+    ///
+    /// # Errors
+    ///
+    /// * If the ticket is empty or contains single quotes or line breaks.
+    pub fn ticket(mut self, ticket: impl Into<String>) -> Result<Self, KanbanError> {
+        self.ticket = Some(validate_metadata(ticket)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the priority of the item.
+    pub fn priority(mut self, priority: KanbanPriority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    #[must_use]
+    /// Sets the unique identifier of the item.
+    pub fn id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[must_use]
+    /// Returns the identifier of the item, if set.
+    pub fn get_id(&self) -> Option<u64> {
+        self.id
+    }
+
+    /// Builds the item.
+    ///
+    /// # Errors
+    ///
+    /// * If the ID or the label is missing.
+    pub fn build(self) -> Result<KanbanItem, KanbanError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<KanbanItemBuilder> for KanbanItem {
+    type Error = KanbanError;
+
+    fn try_from(builder: KanbanItemBuilder) -> Result<Self, Self::Error> {
+        Ok(KanbanItem {
+            id: builder.id.ok_or(KanbanError::MissingId)?,
+            label: builder.label.ok_or(KanbanError::MissingLabel)?,
+            assigned: builder.assigned,
+            ticket: builder.ticket,
+            priority: builder.priority,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::borrow::ToOwned;
+
+    use super::*;
+
+    #[test]
+    fn test_kanban_item_builder_errors() -> Result<(), KanbanError> {
+        assert_eq!(KanbanItemBuilder::default().assigned(" "), Err(KanbanError::EmptyText));
+        assert_eq!(
+            KanbanItemBuilder::default().ticket("O'Brien"),
+            Err(KanbanError::InvalidText("O'Brien".to_owned()))
+        );
+        assert_eq!(
+            KanbanItemBuilder::default().label("[Done]"),
+            Err(KanbanError::InvalidText("[Done]".to_owned()))
+        );
+        assert_eq!(
+            KanbanItemBuilder::default().label("Docs")?.build(),
+            Err(KanbanError::MissingId)
+        );
+        Ok(())
+    }
+}
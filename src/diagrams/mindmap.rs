@@ -0,0 +1,206 @@
+//! Submodule providing structs for creating mindmaps in Mermaid syntax.
+
+mod builder;
+mod mindmap_node;
+use alloc::{collections::BTreeMap, rc::Rc, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::MindmapBuilder;
+pub use mindmap_node::{MindmapNode, MindmapNodeBuilder, MindmapNodeShape};
+
+use crate::{
+    shared::generic_configuration::{GenericConfiguration, GenericConfigurationBuilder},
+    traits::TabbedDisplay,
+};
+
+/// Represents the configuration for a mindmap.
+pub type MindmapConfiguration = GenericConfiguration;
+/// Represents the configuration builder for a mindmap.
+pub type MindmapConfigurationBuilder = GenericConfigurationBuilder;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a mindmap in Mermaid syntax.
+///
+/// Mindmaps encode their hierarchy through indentation, so every node is
+/// written one level deeper than its parent, and siblings are written in the
+/// order in which they were added to the builder.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = MindmapBuilder::default();
+///     let root = builder.root(MindmapNodeBuilder::default().label("Ontology")?)?;
+///     let molecule = builder.child(&root, MindmapNodeBuilder::default().label("Molecule")?)?;
+///     builder.child(
+///         &molecule,
+///         MindmapNodeBuilder::default().label("Acid")?.shape(MindmapNodeShape::Rounded),
+///     )?;
+///     builder.child(&root, MindmapNodeBuilder::default().label("Role")?)?;
+///
+///     let mindmap = builder.build()?;
+///     assert_eq!(
+///         mindmap.to_string(),
+///         "mindmap\n  Ontology\n    Molecule\n      v2(\"Acid\")\n    Role\n"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct Mindmap {
+    /// Configuration of the mindmap.
+    configuration: MindmapConfiguration,
+    /// Nodes of the mindmap, starting with the root.
+    nodes: Vec<Rc<MindmapNode>>,
+    /// Identifier of the parent of each node, indexed by node identifier.
+    parents: BTreeMap<u64, u64>,
+}
+
+impl Mindmap {
+    #[must_use]
+    /// Returns the configuration of the mindmap.
+    pub fn configuration(&self) -> &MindmapConfiguration {
+        &self.configuration
+    }
+
+    #[must_use]
+    /// Returns the root node of the mindmap, or `None` if the mindmap has no
+    /// nodes, as may happen when it is deserialized.
+    pub fn root(&self) -> Option<&Rc<MindmapNode>> {
+        self.nodes.first()
+    }
+
+    /// Returns an iterator over the nodes of the mindmap, in insertion order.
+    pub fn nodes(&self) -> impl Iterator<Item = &Rc<MindmapNode>> {
+        self.nodes.iter()
+    }
+
+    #[must_use]
+    /// Returns the node with the provided ID, if any.
+    pub fn get_node_by_id(&self, id: u64) -> Option<Rc<MindmapNode>> {
+        self.nodes.iter().find(|node| node.id() == id).cloned()
+    }
+
+    #[must_use]
+    /// Returns the parent of the provided node, or `None` for the root.
+    pub fn parent(&self, node: &MindmapNode) -> Option<Rc<MindmapNode>> {
+        self.parents.get(&node.id()).and_then(|parent_id| self.get_node_by_id(*parent_id))
+    }
+
+    /// Returns an iterator over the children of the provided node.
+    pub fn children<'a>(
+        &'a self,
+        node: &'a MindmapNode,
+    ) -> impl Iterator<Item = &'a Rc<MindmapNode>> + 'a {
+        self.nodes.iter().filter(move |child| self.parents.get(&child.id()) == Some(&node.id()))
+    }
+
+    /// Writes the provided node and its descendants.
+    fn fmt_subtree(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        node: &MindmapNode,
+        tab_count: usize,
+    ) -> fmt::Result {
+        node.fmt_tabbed(f, tab_count)?;
+        for child in self.children(node) {
+            self.fmt_subtree(f, child, tab_count + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Mindmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for Mindmap {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        self.configuration.fmt_frontmatter(f, true, &[])?;
+        writeln!(f, "{}mindmap", " ".repeat(tab_count * 2))?;
+        match self.root() {
+            Some(root) => self.fmt_subtree(f, root, tab_count + 1),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::traits::ConfigurationBuilder;
+
+    #[test]
+    fn test_mindmap_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = MindmapBuilder::default()
+            .configuration(MindmapConfigurationBuilder::default().title("Ontology")?)?;
+        let root = builder.root(
+            MindmapNodeBuilder::default()
+                .label("Chemical entity")?
+                .shape(MindmapNodeShape::Circle)
+                .icon("fa fa-atom")?,
+        )?;
+        let molecule = builder.child(
+            &root,
+            MindmapNodeBuilder::default().label("Molecule")?.shape(MindmapNodeShape::Hexagon),
+        )?;
+        builder.child(
+            &molecule,
+            MindmapNodeBuilder::default()
+                .label("Acid (organic)")?
+                .shape(MindmapNodeShape::Square)
+                .class("urgent")?
+                .class("large")?,
+        )?;
+        builder.child(
+            &molecule,
+            MindmapNodeBuilder::default().label("Base")?.shape(MindmapNodeShape::Bang),
+        )?;
+        let role = builder.child(
+            &root,
+            MindmapNodeBuilder::default().label("Role")?.shape(MindmapNodeShape::Cloud),
+        )?;
+
+        let mindmap = builder.build()?;
+        assert_eq!(mindmap.root(), Some(&root));
+        assert_eq!(mindmap.parent(&role), Some(root.clone()));
+        assert_eq!(mindmap.parent(&root), None);
+        assert_eq!(mindmap.children(&molecule).count(), 2);
+        assert_eq!(
+            mindmap.to_string(),
+            concat!(
+                "---\n",
+                "title: Ontology\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "---\n",
+                "mindmap\n",
+                "  v0((\"Chemical entity\"))\n",
+                "  ::icon(fa fa-atom)\n",
+                "    v1{{\"Molecule\"}}\n",
+                "      v2[\"Acid (organic)\"]\n",
+                "      :::urgent large\n",
+                "      v3))\"Base\"((\n",
+                "    v4)\"Role\"(\n",
+            )
+        );
+        Ok(())
+    }
+    #[test]
+    fn test_mindmap_without_nodes() {
+        let mindmap = Mindmap {
+            configuration: MindmapConfiguration::default(),
+            nodes: Vec::new(),
+            parents: BTreeMap::new(),
+        };
+        assert_eq!(mindmap.root(), None);
+        assert!(mindmap.to_string().ends_with("mindmap\n"));
+    }
+}
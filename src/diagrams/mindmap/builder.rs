@@ -0,0 +1,186 @@
+//! Submodule providing a builder for mindmaps.
+
+use alloc::{collections::BTreeMap, format, rc::Rc, vec::Vec};
+
+use crate::{
+    diagrams::mindmap::{
+        Mindmap, MindmapConfiguration, MindmapConfigurationBuilder,
+        mindmap_node::{MindmapNode, MindmapNodeBuilder},
+    },
+    errors::{ConfigError, MindmapError},
+    shared::NODE_LETTER,
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a mindmap.
+///
+/// The first node must be added with [`MindmapBuilder::root`], and every other
+/// node is inserted under a parent which is already part of the mindmap.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = MindmapBuilder::default();
+///     let root = builder.root(
+///         MindmapNodeBuilder::default().label("Chemical entity")?.shape(MindmapNodeShape::Circle),
+///     )?;
+///     builder.child(&root, MindmapNodeBuilder::default().label("Molecule")?)?;
+///     assert!(builder.root(MindmapNodeBuilder::default().label("Role")?).is_err());
+///
+///     let mindmap = builder.build()?;
+///     assert_eq!(mindmap.children(mindmap.root().ok_or("missing root")?).count(), 1);
+///     Ok(())
+/// }
+/// ```
+pub struct MindmapBuilder {
+    /// Configuration of the mindmap.
+    configuration: MindmapConfiguration,
+    /// Nodes of the mindmap, in insertion order.
+    nodes: Vec<Rc<MindmapNode>>,
+    /// Identifier of the parent of each node, indexed by node identifier.
+    parents: BTreeMap<u64, u64>,
+}
+
+impl MindmapBuilder {
+    /// Sets the configuration of the mindmap.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: MindmapConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Adds the root node of the mindmap.
+    ///
+    /// # Errors
+    ///
+    /// * If the mindmap already has a root.
+    /// * If the node builder fails to build.
+    pub fn root(&mut self, node: MindmapNodeBuilder) -> Result<Rc<MindmapNode>, MindmapError> {
+        if let Some(root) = self.nodes.first() {
+            return Err(MindmapError::MultipleRoots(root.label().into()));
+        }
+        self.insert(node)
+    }
+
+    /// Adds a node under the provided parent node.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The node under which the new node is inserted.
+    /// * `node` - The builder of the node to add.
+    ///
+    /// # Errors
+    ///
+    /// * If the parent node is not part of the mindmap.
+    /// * If a node with the same ID already exists in the mindmap.
+    /// * If the node builder fails to build.
+    pub fn child(
+        &mut self,
+        parent: &Rc<MindmapNode>,
+        node: MindmapNodeBuilder,
+    ) -> Result<Rc<MindmapNode>, MindmapError> {
+        if self.get_node_by_id(parent.id()).as_ref() != Some(parent) {
+            return Err(MindmapError::ParentNotFound(parent.label().into()));
+        }
+        let node = self.insert(node)?;
+        self.parents.insert(node.id(), parent.id());
+        Ok(node)
+    }
+
+    #[must_use]
+    /// Returns the node with the provided ID, if any.
+    pub fn get_node_by_id(&self, id: u64) -> Option<Rc<MindmapNode>> {
+        self.nodes.iter().find(|node| node.id() == id).cloned()
+    }
+
+    /// Builds the mindmap.
+    ///
+    /// # Errors
+    ///
+    /// * If no root was added to the mindmap.
+    pub fn build(self) -> Result<Mindmap, MindmapError> {
+        self.try_into()
+    }
+
+    /// Assigns an unused ID to the node if needed and adds it to the mindmap.
+    fn insert(&mut self, node: MindmapNodeBuilder) -> Result<Rc<MindmapNode>, MindmapError> {
+        let id = node.get_id().unwrap_or_else(|| {
+            let mut id = self.nodes.len() as u64;
+            while self.get_node_by_id(id).is_some() {
+                id += 1;
+            }
+            id
+        });
+        if self.get_node_by_id(id).is_some() {
+            return Err(MindmapError::DuplicateNode(format!("{NODE_LETTER}{id}")));
+        }
+        let node = Rc::new(node.id(id).build()?);
+        self.nodes.push(node.clone());
+        Ok(node)
+    }
+}
+
+impl TryFrom<MindmapBuilder> for Mindmap {
+    type Error = MindmapError;
+
+    fn try_from(builder: MindmapBuilder) -> Result<Self, Self::Error> {
+        if builder.nodes.is_empty() {
+            return Err(MindmapError::MissingRoot);
+        }
+        Ok(Mindmap {
+            configuration: builder.configuration,
+            nodes: builder.nodes,
+            parents: builder.parents,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box};
+
+    use super::*;
+
+    #[test]
+    fn test_mindmap_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        assert_eq!(MindmapBuilder::default().build(), Err(MindmapError::MissingRoot));
+
+        let mut builder = MindmapBuilder::default();
+        let root = builder.root(MindmapNodeBuilder::default().label("Root")?)?;
+        assert_eq!(
+            builder.root(MindmapNodeBuilder::default().label("Other")?),
+            Err(MindmapError::MultipleRoots("Root".to_owned()))
+        );
+        assert_eq!(
+            builder.child(&root, MindmapNodeBuilder::default().label("Copy")?.id(0)),
+            Err(MindmapError::DuplicateNode("v0".to_owned()))
+        );
+
+        let foreign = Rc::new(MindmapNodeBuilder::default().label("Foreign")?.id(5).build()?);
+        assert_eq!(
+            builder.child(&foreign, MindmapNodeBuilder::default().label("Orphan")?),
+            Err(MindmapError::ParentNotFound("Foreign".to_owned()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_mindmap_builder_skips_taken_ids() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = MindmapBuilder::default();
+        let root = builder.root(MindmapNodeBuilder::default().label("Root")?.id(1))?;
+        let child = builder.child(&root, MindmapNodeBuilder::default().label("Child")?)?;
+        assert_eq!(child.id(), 2);
+        Ok(())
+    }
+}
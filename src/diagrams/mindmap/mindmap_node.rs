@@ -0,0 +1,106 @@
+//! Submodule defining a node of a mindmap in Mermaid syntax.
+
+mod builder;
+mod shape;
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::MindmapNodeBuilder;
+pub use shape::MindmapNodeShape;
+
+use crate::{shared::NODE_LETTER, traits::TabbedDisplay};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a node of a mindmap, optionally decorated with an icon and CSS
+/// classes.
+///
+/// Mindmaps do not support `classDef` statements, so the classes must be
+/// defined in the CSS of the page displaying the mindmap.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let node = MindmapNodeBuilder::default()
+///         .label("Lipids")?
+///         .id(3)
+///         .shape(MindmapNodeShape::Cloud)
+///         .icon("fa fa-flask")?
+///         .class("highlighted")?
+///         .build()?;
+///
+///     assert_eq!(node.to_string(), "v3)\"Lipids\"(\n::icon(fa fa-flask)\n:::highlighted\n");
+///     Ok(())
+/// }
+/// ```
+pub struct MindmapNode {
+    /// Unique identifier of the node within its mindmap.
+    id: u64,
+    /// Label displayed in the node.
+    label: String,
+    /// Shape of the node.
+    shape: MindmapNodeShape,
+    /// Icon displayed in the node, if any.
+    icon: Option<String>,
+    /// CSS classes applied to the node.
+    classes: Vec<String>,
+}
+
+impl MindmapNode {
+    #[must_use]
+    /// Returns the unique identifier of the node.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[must_use]
+    /// Returns the label of the node.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the shape of the node.
+    pub fn shape(&self) -> MindmapNodeShape {
+        self.shape
+    }
+
+    #[must_use]
+    /// Returns the icon of the node, if any.
+    pub fn icon(&self) -> Option<&str> {
+        self.icon.as_deref()
+    }
+
+    /// Returns an iterator over the CSS classes of the node.
+    pub fn classes(&self) -> impl Iterator<Item = &str> {
+        self.classes.iter().map(String::as_str)
+    }
+}
+
+impl Display for MindmapNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for MindmapNode {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        match self.shape.delimiters() {
+            Some((open, close)) => {
+                writeln!(f, "{indent}{NODE_LETTER}{}{open}\"{}\"{close}", self.id, self.label)?;
+            }
+            None => writeln!(f, "{indent}{}", self.label)?,
+        }
+        if let Some(icon) = &self.icon {
+            writeln!(f, "{indent}::icon({icon})")?;
+        }
+        if !self.classes.is_empty() {
+            writeln!(f, "{indent}:::{}", self.classes.join(" "))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+//! Submodule defining the struct for building a node of a mindmap.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    diagrams::mindmap::mindmap_node::{MindmapNode, MindmapNodeShape},
+    errors::MindmapError,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `MindmapNode`.
+///
+/// When added to a `MindmapBuilder`, a node without an ID receives the next
+/// available one.
+pub struct MindmapNodeBuilder {
+    /// Unique identifier of the node.
+    id: Option<u64>,
+    /// Label displayed in the node.
+    label: Option<String>,
+    /// Shape of the node.
+    shape: MindmapNodeShape,
+    /// Icon displayed in the node.
+    icon: Option<String>,
+    /// CSS classes applied to the node.
+    classes: Vec<String>,
+}
+
+impl MindmapNodeBuilder {
+    /// Sets the label of the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The label displayed in the node.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty.
+    /// * If the label contains double quotes or line breaks.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, MindmapError> {
+        let label = label.into();
+        if label.trim().is_empty() {
+            return Err(MindmapError::EmptyLabel);
+        }
+        if label.contains(['"', '\n', '\r']) {
+            return Err(MindmapError::InvalidLabel(label));
+        }
+        self.label = Some(label);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the unique identifier of the node.
+    pub fn id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[must_use]
+    /// Returns the identifier of the node, if set.
+    pub fn get_id(&self) -> Option<u64> {
+        self.id
+    }
+
+    #[must_use]
+    /// Sets the shape of the node.
+    pub fn shape(mut self, shape: MindmapNodeShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Sets the icon of the node, e.g. `fa fa-book`.
+    ///
+    /// # Errors
+    ///
+    /// * If the icon is empty or contains parentheses or line breaks.
+    pub fn icon(mut self, icon: impl Into<String>) -> Result<Self, MindmapError> {
+        let icon = icon.into();
+        if icon.trim().is_empty() || icon.contains(['(', ')', '\n', '\r']) {
+            return Err(MindmapError::InvalidIcon(icon));
+        }
+        self.icon = Some(icon);
+        Ok(self)
+    }
+
+    /// Adds a CSS class to the node.
+    ///
+    /// # Errors
+    ///
+    /// * If the class name is empty or contains whitespace.
+    /// * If the class was already assigned to the node.
+    pub fn class(mut self, class: impl Into<String>) -> Result<Self, MindmapError> {
+        let class = class.into();
+        if class.is_empty() || class.contains(char::is_whitespace) {
+            return Err(MindmapError::InvalidClassName(class));
+        }
+        if self.classes.contains(&class) {
+            return Err(MindmapError::DuplicateClass(class));
+        }
+        self.classes.push(class);
+        Ok(self)
+    }
+
+    /// Builds the node.
+    ///
+    /// # Errors
+    ///
+    /// * If the label or the ID of the node is missing.
+    /// * If the node has the default shape and its label contains brackets,
+    ///   parentheses or braces, which would be parsed as a shape.
+    pub fn build(self) -> Result<MindmapNode, MindmapError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<MindmapNodeBuilder> for MindmapNode {
+    type Error = MindmapError;
+
+    fn try_from(builder: MindmapNodeBuilder) -> Result<Self, Self::Error> {
+        let label = builder.label.ok_or(MindmapError::MissingLabel)?;
+        let id = builder.id.ok_or(MindmapError::MissingId)?;
+        if builder.shape == MindmapNodeShape::Default
+            && label.contains(['(', ')', '[', ']', '{', '}'])
+        {
+            return Err(MindmapError::InvalidLabel(label));
+        }
+
+        Ok(MindmapNode {
+            id,
+            label,
+            shape: builder.shape,
+            icon: builder.icon,
+            classes: builder.classes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box};
+
+    use super::*;
+
+    #[test]
+    fn test_mindmap_node_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        assert_eq!(MindmapNodeBuilder::default().label(""), Err(MindmapError::EmptyLabel));
+        assert_eq!(
+            MindmapNodeBuilder::default().label("a\nb"),
+            Err(MindmapError::InvalidLabel("a\nb".to_owned()))
+        );
+        assert_eq!(
+            MindmapNodeBuilder::default().icon("fa(x)"),
+            Err(MindmapError::InvalidIcon("fa(x)".to_owned()))
+        );
+        assert_eq!(
+            MindmapNodeBuilder::default().class("a b"),
+            Err(MindmapError::InvalidClassName("a b".to_owned()))
+        );
+        assert_eq!(
+            MindmapNodeBuilder::default().class("a")?.class("a"),
+            Err(MindmapError::DuplicateClass("a".to_owned()))
+        );
+        assert_eq!(MindmapNodeBuilder::default().id(0).build(), Err(MindmapError::MissingLabel));
+
+        let base = MindmapNodeBuilder::default().label("Acids (organic)")?;
+        assert_eq!(base.clone().build(), Err(MindmapError::MissingId));
+        assert_eq!(
+            base.clone().id(0).build(),
+            Err(MindmapError::InvalidLabel("Acids (organic)".to_owned()))
+        );
+        assert!(base.id(0).shape(MindmapNodeShape::Square).build().is_ok());
+        Ok(())
+    }
+}
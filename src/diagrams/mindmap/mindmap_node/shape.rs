@@ -0,0 +1,39 @@
+//! Submodule defining the shapes of the nodes of mindmaps.
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the shape of a node in a mindmap.
+pub enum MindmapNodeShape {
+    /// The default shape, written as plain text.
+    #[default]
+    Default,
+    /// A square, written as `id[label]`.
+    Square,
+    /// A rounded square, written as `id(label)`.
+    Rounded,
+    /// A circle, written as `id((label))`.
+    Circle,
+    /// A bang, written as `id))label((`.
+    Bang,
+    /// A cloud, written as `id)label(`.
+    Cloud,
+    /// A hexagon, written as `id{{label}}`.
+    Hexagon,
+}
+
+impl MindmapNodeShape {
+    #[must_use]
+    /// Returns the opening and closing delimiters of the shape, or `None` for
+    /// the default shape.
+    pub fn delimiters(self) -> Option<(&'static str, &'static str)> {
+        match self {
+            MindmapNodeShape::Default => None,
+            MindmapNodeShape::Square => Some(("[", "]")),
+            MindmapNodeShape::Rounded => Some(("(", ")")),
+            MindmapNodeShape::Circle => Some(("((", "))")),
+            MindmapNodeShape::Bang => Some(("))", "((")),
+            MindmapNodeShape::Cloud => Some((")", "(")),
+            MindmapNodeShape::Hexagon => Some(("{{", "}}")),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Submodule providing structs for creating packet diagrams in Mermaid
+//! syntax.
+
+mod builder;
+mod packet_field;
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+pub use builder::PacketDiagramBuilder;
+pub use packet_field::PacketField;
+
+use crate::{
+    shared::generic_configuration::{GenericConfiguration, GenericConfigurationBuilder},
+    traits::TabbedDisplay,
+};
+
+/// Represents the configuration for a packet diagram.
+pub type PacketDiagramConfiguration = GenericConfiguration;
+/// Represents the configuration builder for a packet diagram.
+pub type PacketDiagramConfigurationBuilder = GenericConfigurationBuilder;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a packet diagram in Mermaid syntax.
+///
+/// The fields of the diagram are contiguous and cover the bits of the packet
+/// from the first one onwards.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = PacketDiagramBuilder::default();
+///     builder.field(PacketField::new(0, 15, "Source Port")?)?;
+///     builder.field_with_width(16, "Destination Port")?;
+///
+///     let diagram = PacketDiagram::from(builder);
+///     assert_eq!(diagram.bit_count(), 32);
+///     assert_eq!(
+///         diagram.to_string(),
+///         "packet-beta\n  0-15: \"Source Port\"\n  16-31: \"Destination Port\"\n"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct PacketDiagram {
+    /// Configuration of the diagram.
+    configuration: PacketDiagramConfiguration,
+    /// Fields of the diagram, ordered by bit offset.
+    fields: Vec<PacketField>,
+}
+
+impl PacketDiagram {
+    #[must_use]
+    /// Returns the configuration of the diagram.
+    pub fn configuration(&self) -> &PacketDiagramConfiguration {
+        &self.configuration
+    }
+
+    /// Returns an iterator over the fields of the diagram.
+    pub fn fields(&self) -> impl Iterator<Item = &PacketField> {
+        self.fields.iter()
+    }
+
+    #[must_use]
+    /// Returns the number of bits covered by the fields of the diagram.
+    pub fn bit_count(&self) -> u64 {
+        self.fields.last().map_or(0, |field| u64::from(field.end()) + 1)
+    }
+}
+
+impl Display for PacketDiagram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for PacketDiagram {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat((tab_count + 1) * 2);
+        self.configuration.fmt_frontmatter(f, true, &[])?;
+        writeln!(f, "{}packet-beta", " ".repeat(tab_count * 2))?;
+        for field in &self.fields {
+            writeln!(f, "{indent}{field}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::{errors::PacketDiagramError, traits::ConfigurationBuilder};
+
+    #[test]
+    fn test_packet_diagram_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = PacketDiagramBuilder::default()
+            .configuration(PacketDiagramConfigurationBuilder::default().title("Frame header")?)?;
+        builder.field_with_width(8, "Magic")?;
+        builder.field(PacketField::new(8, 8, "Compressed")?)?;
+        let reserved = builder.field_with_width(7, "Reserved")?.clone();
+        builder.field_with_width(16, "Channel count")?;
+
+        assert_eq!((reserved.start(), reserved.end()), (9, 15));
+        let diagram = PacketDiagram::from(builder);
+        assert_eq!(diagram.fields().count(), 4);
+        assert_eq!(
+            diagram.to_string(),
+            concat!(
+                "---\n",
+                "title: Frame header\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "---\n",
+                "packet-beta\n",
+                "  0-7: \"Magic\"\n",
+                "  8: \"Compressed\"\n",
+                "  9-15: \"Reserved\"\n",
+                "  16-31: \"Channel count\"\n",
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_packet_diagram_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = PacketDiagramBuilder::default();
+        assert_eq!(
+            builder.field(PacketField::new(1, 7, "Version")?),
+            Err(PacketDiagramError::NonContiguousRange { start: 1, next: 0 })
+        );
+        builder.field(PacketField::new(0, 7, "Version")?)?;
+        assert_eq!(
+            builder.field(PacketField::new(4, 11, "Flags")?),
+            Err(PacketDiagramError::OverlappingRange { start: 4, next: 8 })
+        );
+        assert_eq!(
+            builder.field(PacketField::new(12, 15, "Flags")?),
+            Err(PacketDiagramError::NonContiguousRange { start: 12, next: 8 })
+        );
+        builder.field(PacketField::new(8, u32::MAX, "Payload")?)?;
+        assert_eq!(builder.field_with_width(1, "Trailer"), Err(PacketDiagramError::RangeOverflow));
+        assert_eq!(PacketDiagram::from(builder).bit_count(), 1 << 32);
+        Ok(())
+    }
+}
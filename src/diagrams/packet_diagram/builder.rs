@@ -0,0 +1,107 @@
+//! Submodule providing a builder for packet diagrams.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    diagrams::packet_diagram::{
+        PacketDiagram, PacketDiagramConfiguration, PacketDiagramConfigurationBuilder, PacketField,
+    },
+    errors::{ConfigError, PacketDiagramError},
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a packet diagram.
+///
+/// Fields are added in order, each starting right after the previous one.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = PacketDiagramBuilder::default();
+///     builder.field_with_width(4, "Version")?;
+///     builder.field_with_width(4, "IHL")?;
+///     assert!(builder.field(PacketField::new(4, 11, "Type of Service")?).is_err());
+///
+///     let diagram = PacketDiagram::from(builder);
+///     assert_eq!(diagram.bit_count(), 8);
+///     Ok(())
+/// }
+/// ```
+pub struct PacketDiagramBuilder {
+    /// Configuration of the diagram.
+    configuration: PacketDiagramConfiguration,
+    /// Fields of the diagram, ordered by bit offset.
+    fields: Vec<PacketField>,
+}
+
+impl PacketDiagramBuilder {
+    /// Sets the configuration of the diagram.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: PacketDiagramConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Adds a field to the diagram, which must start right after the last
+    /// field of the diagram, or at bit zero for the first field.
+    ///
+    /// # Errors
+    ///
+    /// * If the field starts within the last field of the diagram.
+    /// * If the field leaves a gap after the last field of the diagram.
+    /// * If the last field of the diagram already ends at `u32::MAX`.
+    pub fn field(&mut self, field: PacketField) -> Result<(), PacketDiagramError> {
+        let next = self.next_offset()?;
+        if field.start() < next {
+            return Err(PacketDiagramError::OverlappingRange { start: field.start(), next });
+        }
+        if field.start() > next {
+            return Err(PacketDiagramError::NonContiguousRange { start: field.start(), next });
+        }
+        self.fields.push(field);
+        Ok(())
+    }
+
+    /// Adds a field of the provided number of bits right after the last
+    /// field of the diagram, and returns it with its computed bit range.
+    ///
+    /// # Errors
+    ///
+    /// * If the width is zero.
+    /// * If the field would end beyond `u32::MAX`.
+    /// * If the label is empty or contains double quotes or line breaks.
+    pub fn field_with_width(
+        &mut self,
+        width: u32,
+        label: impl Into<String>,
+    ) -> Result<&PacketField, PacketDiagramError> {
+        let field = PacketField::with_width(self.next_offset()?, width, label)?;
+        self.fields.push(field);
+        Ok(&self.fields[self.fields.len() - 1])
+    }
+
+    /// Returns the first bit following the last field of the diagram.
+    fn next_offset(&self) -> Result<u32, PacketDiagramError> {
+        match self.fields.last() {
+            Some(field) => field.end().checked_add(1).ok_or(PacketDiagramError::RangeOverflow),
+            None => Ok(0),
+        }
+    }
+}
+
+impl From<PacketDiagramBuilder> for PacketDiagram {
+    fn from(builder: PacketDiagramBuilder) -> Self {
+        PacketDiagram { configuration: builder.configuration, fields: builder.fields }
+    }
+}
@@ -0,0 +1,136 @@
+//! Submodule defining the fields of packet diagrams in Mermaid syntax.
+
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+use crate::errors::PacketDiagramError;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a field of a packet diagram, covering an inclusive range of
+/// bits.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let port = PacketField::new(0, 15, "Source Port")?;
+///     assert_eq!(port.width(), 16);
+///     assert_eq!(port.to_string(), "0-15: \"Source Port\"");
+///
+///     let flag = PacketField::new(106, 106, "URG")?;
+///     assert_eq!(flag.to_string(), "106: \"URG\"");
+///     Ok(())
+/// }
+/// ```
+pub struct PacketField {
+    /// First bit of the field.
+    start: u32,
+    /// Last bit of the field.
+    end: u32,
+    /// Label of the field.
+    label: String,
+}
+
+impl PacketField {
+    /// Creates a field covering the bits from `start` to `end`, both
+    /// included.
+    ///
+    /// # Errors
+    ///
+    /// * If `end` is smaller than `start`.
+    /// * If the label is empty or contains double quotes or line breaks.
+    pub fn new(start: u32, end: u32, label: impl Into<String>) -> Result<Self, PacketDiagramError> {
+        if end < start {
+            return Err(PacketDiagramError::InvalidRange { start, end });
+        }
+        let label = label.into();
+        if label.trim().is_empty() {
+            return Err(PacketDiagramError::EmptyLabel);
+        }
+        if label.contains(['"', '\n', '\r']) {
+            return Err(PacketDiagramError::InvalidLabel(label));
+        }
+        Ok(Self { start, end, label })
+    }
+
+    /// Creates a field of the provided number of bits, starting at `start`.
+    ///
+    /// # Errors
+    ///
+    /// * If the width is zero.
+    /// * If the field would end beyond `u32::MAX`.
+    /// * If the label is empty or contains double quotes or line breaks.
+    pub fn with_width(
+        start: u32,
+        width: u32,
+        label: impl Into<String>,
+    ) -> Result<Self, PacketDiagramError> {
+        if width == 0 {
+            return Err(PacketDiagramError::ZeroWidth);
+        }
+        let end = start.checked_add(width - 1).ok_or(PacketDiagramError::RangeOverflow)?;
+        Self::new(start, end, label)
+    }
+
+    #[must_use]
+    /// Returns the first bit of the field.
+    pub fn start(&self) -> u32 {
+        self.start
+    }
+
+    #[must_use]
+    /// Returns the last bit of the field.
+    pub fn end(&self) -> u32 {
+        self.end
+    }
+
+    #[must_use]
+    /// Returns the number of bits of the field.
+    pub fn width(&self) -> u64 {
+        u64::from(self.end - self.start) + 1
+    }
+
+    #[must_use]
+    /// Returns the label of the field.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+impl Display for PacketField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.start == self.end {
+            write!(f, "{}: \"{}\"", self.start, self.label)
+        } else {
+            write!(f, "{}-{}: \"{}\"", self.start, self.end, self.label)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::borrow::ToOwned;
+
+    use super::*;
+
+    #[test]
+    fn test_packet_field_errors() {
+        assert_eq!(
+            PacketField::new(8, 7, "Flags"),
+            Err(PacketDiagramError::InvalidRange { start: 8, end: 7 })
+        );
+        assert_eq!(PacketField::new(0, 7, " "), Err(PacketDiagramError::EmptyLabel));
+        assert_eq!(
+            PacketField::new(0, 7, "\"Flags\""),
+            Err(PacketDiagramError::InvalidLabel("\"Flags\"".to_owned()))
+        );
+        assert_eq!(PacketField::with_width(0, 0, "Flags"), Err(PacketDiagramError::ZeroWidth));
+        assert_eq!(
+            PacketField::with_width(u32::MAX, 2, "Flags"),
+            Err(PacketDiagramError::RangeOverflow)
+        );
+    }
+}
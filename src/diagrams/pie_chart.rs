@@ -0,0 +1,133 @@
+//! Submodule providing structs for creating pie charts in Mermaid syntax.
+
+mod builder;
+mod configuration;
+mod slice;
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+pub use builder::PieChartBuilder;
+pub use configuration::{PieChartConfiguration, PieChartConfigurationBuilder};
+pub use slice::PieSlice;
+
+use crate::traits::TabbedDisplay;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a pie chart in Mermaid syntax.
+///
+/// Slices are rendered in the order in which they were added to the builder,
+/// which is also the order of the `pieN` theme variables of the automatic
+/// palette.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = PieChartBuilder::default()
+///         .configuration(PieChartConfigurationBuilder::default().show_data(true))?;
+///     builder.slice("Lipids", 42.0)?;
+///     builder.slice("Sugars", 7.25)?;
+///
+///     let chart = PieChart::from(builder);
+///     assert_eq!(chart.to_string(), "pie showData\n  \"Lipids\" : 42\n  \"Sugars\" : 7.25\n");
+///     Ok(())
+/// }
+/// ```
+pub struct PieChart {
+    /// Configuration of the chart.
+    configuration: PieChartConfiguration,
+    /// Slices of the chart.
+    slices: Vec<PieSlice>,
+}
+
+impl PieChart {
+    #[must_use]
+    /// Returns the configuration of the chart.
+    pub fn configuration(&self) -> &PieChartConfiguration {
+        &self.configuration
+    }
+
+    /// Returns an iterator over the slices of the chart.
+    pub fn slices(&self) -> impl Iterator<Item = &PieSlice> {
+        self.slices.iter()
+    }
+}
+
+impl Display for PieChart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for PieChart {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat((tab_count + 1) * 2);
+        self.configuration.fmt_frontmatter(f, self.slices.len())?;
+        write!(f, "{}pie", " ".repeat(tab_count * 2))?;
+        if self.configuration.show_data() {
+            write!(f, " showData")?;
+        }
+        writeln!(f)?;
+        for slice in &self.slices {
+            writeln!(f, "{indent}{slice}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format, string::ToString};
+
+    use super::*;
+    use crate::{errors::PieChartError, shared::Color, traits::ConfigurationBuilder};
+
+    #[test]
+    fn test_pie_chart_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = PieChartBuilder::default().configuration(
+            PieChartConfigurationBuilder::default().title("Metabolites")?.automatic_palette(80, 50),
+        )?;
+        builder.slice("Lipids", 3.0)?;
+        builder.slice("Sugars", 1.5)?;
+
+        let colors = Color::maximally_distinct(2, 80, 50);
+        let chart = PieChart::from(builder);
+        assert_eq!(
+            chart.to_string(),
+            format!(
+                concat!(
+                    "---\n",
+                    "title: Metabolites\n",
+                    "config:\n",
+                    "  theme: default\n",
+                    "  look: classic\n",
+                    "  themeVariables:\n",
+                    "    pie1: \"{}\"\n",
+                    "    pie2: \"{}\"\n",
+                    "---\n",
+                    "pie\n",
+                    "  \"Lipids\" : 3\n",
+                    "  \"Sugars\" : 1.5\n",
+                ),
+                colors[0].to_hex(),
+                colors[1].to_hex()
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_pie_chart_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = PieChartBuilder::default();
+        builder.slice("Lipids", 3.0)?;
+        assert_eq!(
+            builder.slice("Lipids", 1.0),
+            Err(PieChartError::DuplicateSlice("Lipids".to_string()))
+        );
+        assert_eq!(PieChart::from(builder).slices().count(), 1);
+        Ok(())
+    }
+}
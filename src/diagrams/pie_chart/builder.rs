@@ -0,0 +1,80 @@
+//! Submodule providing a builder for pie charts.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    diagrams::pie_chart::{
+        PieChart, PieSlice,
+        configuration::{PieChartConfiguration, PieChartConfigurationBuilder},
+    },
+    errors::{ConfigError, PieChartError},
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a pie chart.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = PieChartBuilder::default();
+///     builder.slice("Lipids", 42.0)?;
+///     builder.slice("Amino acids", 17.5)?;
+///     assert!(builder.slice("Lipids", 1.0).is_err());
+///
+///     let chart = PieChart::from(builder);
+///     assert_eq!(chart.slices().count(), 2);
+///     Ok(())
+/// }
+/// ```
+pub struct PieChartBuilder {
+    /// Configuration of the chart.
+    configuration: PieChartConfiguration,
+    /// Slices of the chart.
+    slices: Vec<PieSlice>,
+}
+
+impl PieChartBuilder {
+    /// Sets the configuration of the chart.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: PieChartConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Adds a slice to the chart.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The label of the slice.
+    /// * `value` - The value of the slice.
+    ///
+    /// # Errors
+    ///
+    /// * If the slice is invalid, as described in [`PieSlice::new`].
+    /// * If a slice with the same label already exists in the chart.
+    pub fn slice(&mut self, label: impl Into<String>, value: f64) -> Result<(), PieChartError> {
+        let slice = PieSlice::new(label, value)?;
+        if self.slices.iter().any(|other| other.label() == slice.label()) {
+            return Err(PieChartError::DuplicateSlice(slice.label().into()));
+        }
+        self.slices.push(slice);
+        Ok(())
+    }
+}
+
+impl From<PieChartBuilder> for PieChart {
+    fn from(builder: PieChartBuilder) -> Self {
+        PieChart { configuration: builder.configuration, slices: builder.slices }
+    }
+}
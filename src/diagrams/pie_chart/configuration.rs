@@ -0,0 +1,128 @@
+//! Submodule defining configuration specifically for pie charts in Mermaid.
+
+mod builder;
+
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::PieChartConfigurationBuilder;
+
+use crate::{
+    shared::{
+        Color, Direction, Renderer,
+        generic_configuration::{GenericConfiguration, Look, Theme},
+    },
+    traits::Configuration,
+};
+
+/// Maximum number of `pieN` theme variables supported by Mermaid.
+const MAXIMUM_PIE_VARIABLES: usize = 12;
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the configuration for a pie chart in Mermaid syntax.
+pub struct PieChartConfiguration {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfiguration,
+    /// Whether to display the values of the slices next to their labels.
+    show_data: bool,
+    /// Saturation and lightness of the automatic palette, if enabled.
+    automatic_palette: Option<(u8, u8)>,
+}
+
+impl PieChartConfiguration {
+    #[must_use]
+    /// Returns whether the values of the slices are displayed.
+    pub fn show_data(&self) -> bool {
+        self.show_data
+    }
+
+    #[must_use]
+    /// Returns the colors of the automatic palette for the provided number of
+    /// slices, or an empty vector if the automatic palette is disabled.
+    ///
+    /// Mermaid only supports the `pie1` to `pie12` theme variables, so at
+    /// most twelve colors are returned.
+    pub fn palette(&self, slice_count: usize) -> Vec<Color> {
+        let Some((saturation, lightness)) = self.automatic_palette else {
+            return Vec::new();
+        };
+        let count = u16::try_from(slice_count.min(MAXIMUM_PIE_VARIABLES)).unwrap_or_default();
+        Color::maximally_distinct(count, saturation, lightness)
+    }
+
+    /// Writes the frontmatter of the chart, including the `pieN` theme
+    /// variables of the automatic palette for the provided number of slices.
+    pub(crate) fn fmt_frontmatter(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        slice_count: usize,
+    ) -> fmt::Result {
+        let theme_variables: Vec<(String, String)> = self
+            .palette(slice_count)
+            .iter()
+            .enumerate()
+            .map(|(color_number, color)| {
+                (
+                    format!("themeVariables.pie{}", color_number + 1),
+                    format!("\"{}\"", color.to_hex()),
+                )
+            })
+            .collect();
+        self.generic.fmt_frontmatter(f, true, &theme_variables)
+    }
+}
+
+impl Display for PieChartConfiguration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_frontmatter(f, 0)
+    }
+}
+
+impl Configuration for PieChartConfiguration {
+    type Builder = PieChartConfigurationBuilder;
+
+    fn title(&self) -> Option<&str> {
+        self.generic.title()
+    }
+
+    fn direction(&self) -> Direction {
+        self.generic.direction()
+    }
+
+    fn renderer(&self) -> Renderer {
+        self.generic.renderer()
+    }
+
+    fn theme(&self) -> Theme {
+        self.generic.theme()
+    }
+
+    fn look(&self) -> Look {
+        self.generic.look()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format};
+
+    use super::*;
+    use crate::traits::ConfigurationBuilder;
+
+    #[test]
+    fn test_pie_chart_configuration_display_default() {
+        let config = PieChartConfiguration::default();
+        assert_eq!(format!("{config}"), "");
+        assert!(config.palette(3).is_empty());
+    }
+
+    #[test]
+    fn test_pie_chart_configuration_palette() -> Result<(), Box<dyn core::error::Error>> {
+        let config = PieChartConfigurationBuilder::default().automatic_palette(70, 60).build()?;
+
+        assert_eq!(config.palette(3), Color::maximally_distinct(3, 70, 60));
+        assert_eq!(config.palette(20).len(), 12);
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+//! Submodule providing a builder struct for the configuration of pie charts
+//! in Mermaid syntax.
+
+use alloc::string::ToString;
+
+use crate::{
+    diagrams::pie_chart::configuration::PieChartConfiguration,
+    errors::ConfigError,
+    shared::generic_configuration::{
+        Direction, GenericConfigurationBuilder, Look, Renderer, Theme,
+    },
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating pie chart configurations.
+pub struct PieChartConfigurationBuilder {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfigurationBuilder,
+    /// Whether to display the values of the slices next to their labels.
+    show_data: bool,
+    /// Saturation and lightness of the automatic palette, if enabled.
+    automatic_palette: Option<(u8, u8)>,
+}
+
+impl PieChartConfigurationBuilder {
+    #[must_use]
+    /// Sets whether to display the values of the slices next to their labels.
+    pub fn show_data(mut self, show_data: bool) -> Self {
+        self.show_data = show_data;
+        self
+    }
+
+    #[must_use]
+    /// Colors the slices with maximally distinct colors, by setting the
+    /// `pie1` to `pieN` theme variables of the chart.
+    ///
+    /// # Arguments
+    ///
+    /// * `saturation` - The saturation of the colors (0-100).
+    /// * `lightness` - The lightness of the colors (0-100).
+    pub fn automatic_palette(mut self, saturation: u8, lightness: u8) -> Self {
+        self.automatic_palette = Some((saturation, lightness));
+        self
+    }
+
+    /// Sets the theme to use for the diagram.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.generic = self.generic.theme(theme);
+        self
+    }
+
+    /// Sets the look to use for the diagram.
+    #[must_use]
+    pub fn look(mut self, look: Look) -> Self {
+        self.generic = self.generic.look(look);
+        self
+    }
+}
+
+impl TryFrom<PieChartConfigurationBuilder> for PieChartConfiguration {
+    type Error = ConfigError;
+
+    fn try_from(builder: PieChartConfigurationBuilder) -> Result<Self, Self::Error> {
+        Ok(PieChartConfiguration {
+            generic: builder.generic.try_into()?,
+            show_data: builder.show_data,
+            automatic_palette: builder.automatic_palette,
+        })
+    }
+}
+
+impl ConfigurationBuilder for PieChartConfigurationBuilder {
+    type Configuration = PieChartConfiguration;
+    type Error = ConfigError;
+
+    fn build(self) -> Result<Self::Configuration, Self::Error> {
+        self.try_into()
+    }
+
+    fn title<S: ToString>(mut self, title: S) -> Result<Self, Self::Error> {
+        self.generic = self.generic.title(title)?;
+        Ok(self)
+    }
+
+    fn direction(mut self, direction: Direction) -> Self {
+        self.generic = self.generic.direction(direction);
+        self
+    }
+
+    fn renderer(mut self, renderer: Renderer) -> Self {
+        self.generic = self.generic.renderer(renderer);
+        self
+    }
+}
@@ -0,0 +1,145 @@
+//! Submodule defining a slice of a pie chart in Mermaid syntax.
+
+use alloc::string::String;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+};
+
+use crate::errors::PieChartError;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a slice of a pie chart, i.e. a labelled non-negative value.
+///
+/// As the value is guaranteed to be finite, slices are totally ordered and
+/// may be hashed.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let slice = PieSlice::new("Lipids", 42.5)?;
+///     assert_eq!(slice.to_string(), "\"Lipids\" : 42.5");
+///
+///     assert!(PieSlice::new("Sugars", -1.0).is_err());
+///     assert!(PieSlice::new("Sugars", f64::NAN).is_err());
+///     Ok(())
+/// }
+/// ```
+pub struct PieSlice {
+    /// Label of the slice.
+    label: String,
+    /// Value of the slice.
+    value: f64,
+}
+
+impl PieSlice {
+    /// Creates a new slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The label of the slice.
+    /// * `value` - The value of the slice.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains double quotes or line breaks.
+    /// * If the value is negative, infinite or not a number.
+    pub fn new(label: impl Into<String>, value: f64) -> Result<Self, PieChartError> {
+        let label = label.into();
+        if label.trim().is_empty() {
+            return Err(PieChartError::EmptySliceLabel);
+        }
+        if label.contains(['"', '\n', '\r']) {
+            return Err(PieChartError::InvalidSliceLabel(label));
+        }
+        if !value.is_finite() {
+            return Err(PieChartError::NonFiniteSliceValue(label));
+        }
+        if value < 0.0 {
+            return Err(PieChartError::NegativeSliceValue(label));
+        }
+        // Adding zero normalizes a negative zero, so that it is neither
+        // displayed as `-0` nor distinguished from a positive zero.
+        Ok(Self { label, value: value + 0.0 })
+    }
+
+    #[must_use]
+    /// Returns the label of the slice.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the value of the slice.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl PartialEq for PieSlice {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for PieSlice {}
+
+impl PartialOrd for PieSlice {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PieSlice {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.label.cmp(&other.label).then(self.value.total_cmp(&other.value))
+    }
+}
+
+impl Hash for PieSlice {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.label.hash(state);
+        self.value.to_bits().hash(state);
+    }
+}
+
+impl Display for PieSlice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\" : {}", self.label, self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box, string::ToString};
+
+    use super::*;
+
+    #[test]
+    fn test_pie_slice_validation() -> Result<(), Box<dyn core::error::Error>> {
+        assert_eq!(PieSlice::new(" ", 1.0), Err(PieChartError::EmptySliceLabel));
+        assert_eq!(
+            PieSlice::new("a \"b\"", 1.0),
+            Err(PieChartError::InvalidSliceLabel("a \"b\"".to_owned()))
+        );
+        assert_eq!(
+            PieSlice::new("a", -0.5),
+            Err(PieChartError::NegativeSliceValue("a".to_owned()))
+        );
+        assert_eq!(
+            PieSlice::new("a", f64::INFINITY),
+            Err(PieChartError::NonFiniteSliceValue("a".to_owned()))
+        );
+
+        let zero = PieSlice::new("a", -0.0)?;
+        assert_eq!(zero.to_string(), "\"a\" : 0");
+        assert_eq!(zero, PieSlice::new("a", 0.0)?);
+        assert!(zero < PieSlice::new("a", 1.0)?);
+        Ok(())
+    }
+}
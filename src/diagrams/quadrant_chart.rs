@@ -0,0 +1,203 @@
+//! Submodule providing structs for creating quadrant charts in Mermaid syntax.
+
+mod builder;
+mod quadrant;
+mod quadrant_axis;
+mod quadrant_point;
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::QuadrantChartBuilder;
+pub use quadrant::Quadrant;
+pub use quadrant_axis::QuadrantAxis;
+pub use quadrant_point::{QuadrantPoint, QuadrantPointBuilder};
+
+use crate::{
+    errors::QuadrantChartError,
+    shared::generic_configuration::{GenericConfiguration, GenericConfigurationBuilder},
+    traits::TabbedDisplay,
+};
+
+/// Represents the configuration for a quadrant chart.
+pub type QuadrantChartConfiguration = GenericConfiguration;
+/// Represents the configuration builder for a quadrant chart.
+pub type QuadrantChartConfigurationBuilder = GenericConfigurationBuilder;
+
+/// Validates a text of a quadrant chart, which is always written between
+/// double quotes.
+pub(crate) fn validate_text(text: impl Into<String>) -> Result<String, QuadrantChartError> {
+    let text = text.into();
+    if text.trim().is_empty() {
+        return Err(QuadrantChartError::EmptyText);
+    }
+    if text.contains(['"', '\n', '\r']) {
+        return Err(QuadrantChartError::InvalidText(text));
+    }
+    Ok(text)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a quadrant chart in Mermaid syntax.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = QuadrantChartBuilder::default();
+///     builder.x_axis(QuadrantAxis::new("Low reach")?.high("High reach")?);
+///     builder.quadrant(Quadrant::TopRight, "Expand")?;
+///     builder.point(QuadrantPointBuilder::default().label("Campaign A")?.x(0.3)?.y(0.6)?)?;
+///
+///     let chart = QuadrantChart::from(builder);
+///     assert_eq!(
+///         chart.to_string(),
+///         concat!(
+///             "quadrantChart\n",
+///             "  x-axis \"Low reach\" --> \"High reach\"\n",
+///             "  quadrant-1 \"Expand\"\n",
+///             "  \"Campaign A\": [0.3, 0.6]\n",
+///         )
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct QuadrantChart {
+    /// Configuration of the chart.
+    configuration: QuadrantChartConfiguration,
+    /// Labels of the horizontal axis.
+    x_axis: Option<QuadrantAxis>,
+    /// Labels of the vertical axis.
+    y_axis: Option<QuadrantAxis>,
+    /// Titles of the quadrants.
+    quadrants: BTreeMap<Quadrant, String>,
+    /// Points of the chart.
+    points: Vec<QuadrantPoint>,
+}
+
+impl QuadrantChart {
+    #[must_use]
+    /// Returns the configuration of the chart.
+    pub fn configuration(&self) -> &QuadrantChartConfiguration {
+        &self.configuration
+    }
+
+    #[must_use]
+    /// Returns the labels of the horizontal axis, if any.
+    pub fn x_axis(&self) -> Option<&QuadrantAxis> {
+        self.x_axis.as_ref()
+    }
+
+    #[must_use]
+    /// Returns the labels of the vertical axis, if any.
+    pub fn y_axis(&self) -> Option<&QuadrantAxis> {
+        self.y_axis.as_ref()
+    }
+
+    #[must_use]
+    /// Returns the title of the provided quadrant, if any.
+    pub fn quadrant(&self, quadrant: Quadrant) -> Option<&str> {
+        self.quadrants.get(&quadrant).map(String::as_str)
+    }
+
+    /// Returns an iterator over the points of the chart.
+    pub fn points(&self) -> impl Iterator<Item = &QuadrantPoint> {
+        self.points.iter()
+    }
+}
+
+impl Display for QuadrantChart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for QuadrantChart {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat((tab_count + 1) * 2);
+        self.configuration.fmt_frontmatter(f, true, &[])?;
+        writeln!(f, "{}quadrantChart", " ".repeat(tab_count * 2))?;
+        if let Some(x_axis) = &self.x_axis {
+            writeln!(f, "{indent}x-axis {x_axis}")?;
+        }
+        if let Some(y_axis) = &self.y_axis {
+            writeln!(f, "{indent}y-axis {y_axis}")?;
+        }
+        for (quadrant, title) in &self.quadrants {
+            writeln!(f, "{indent}{quadrant} \"{title}\"")?;
+        }
+        for point in &self.points {
+            writeln!(f, "{indent}{point}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::{
+        shared::{Color, Unit},
+        traits::ConfigurationBuilder,
+    };
+
+    #[test]
+    fn test_quadrant_chart_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = QuadrantChartBuilder::default().configuration(
+            QuadrantChartConfigurationBuilder::default().title("Reach and engagement")?,
+        )?;
+        builder.x_axis(QuadrantAxis::new("Low Reach")?.high("High Reach")?);
+        builder.y_axis(QuadrantAxis::new("Low Engagement")?);
+        builder.quadrant(Quadrant::BottomLeft, "Re-evaluate")?;
+        builder.quadrant(Quadrant::TopRight, "We should expand")?;
+        builder.point(QuadrantPointBuilder::default().label("Campaign A")?.x(0.3)?.y(0.6)?)?;
+        builder.point(
+            QuadrantPointBuilder::default()
+                .label("Campaign B")?
+                .x(0.45)?
+                .y(0.23)?
+                .radius(Unit::Pixel(10))?
+                .color(Color::from((255, 51, 0)))
+                .stroke_color(Color::from((16, 240, 240)))
+                .stroke_width(Unit::Pixel(5))?,
+        )?;
+
+        let chart = QuadrantChart::from(builder);
+        assert_eq!(chart.quadrant(Quadrant::TopRight), Some("We should expand"));
+        assert_eq!(chart.quadrant(Quadrant::TopLeft), None);
+        assert_eq!(
+            chart.to_string(),
+            concat!(
+                "---\n",
+                "title: Reach and engagement\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "---\n",
+                "quadrantChart\n",
+                "  x-axis \"Low Reach\" --> \"High Reach\"\n",
+                "  y-axis \"Low Engagement\"\n",
+                "  quadrant-1 \"We should expand\"\n",
+                "  quadrant-3 \"Re-evaluate\"\n",
+                "  \"Campaign A\": [0.3, 0.6]\n",
+                "  \"Campaign B\": [0.45, 0.23] radius: 10, color: #ff3300, stroke-color: #10f0f0, ",
+                "stroke-width: 5px\n",
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_quadrant_chart_text_validation() {
+        assert_eq!(validate_text(" "), Err(QuadrantChartError::EmptyText));
+        assert_eq!(
+            QuadrantAxis::new("Low \"reach\""),
+            Err(QuadrantChartError::InvalidText("Low \"reach\"".to_string()))
+        );
+        assert!(QuadrantChartBuilder::default().quadrant(Quadrant::TopLeft, "a\nb").is_err());
+    }
+}
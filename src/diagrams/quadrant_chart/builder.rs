@@ -0,0 +1,112 @@
+//! Submodule providing a builder for quadrant charts.
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use crate::{
+    diagrams::quadrant_chart::{
+        Quadrant, QuadrantAxis, QuadrantChart, QuadrantChartConfiguration,
+        QuadrantChartConfigurationBuilder, QuadrantPoint, QuadrantPointBuilder, validate_text,
+    },
+    errors::{ConfigError, QuadrantChartError},
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a quadrant chart.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = QuadrantChartBuilder::default();
+///     builder.y_axis(QuadrantAxis::new("Low")?.high("High")?);
+///     builder.quadrant(Quadrant::TopLeft, "Need to promote")?;
+///     builder.point(QuadrantPointBuilder::default().label("A")?.x(0.1)?.y(0.9)?)?;
+///     assert!(builder.quadrant(Quadrant::BottomRight, "").is_err());
+///
+///     let chart = QuadrantChart::from(builder);
+///     assert_eq!(chart.points().count(), 1);
+///     Ok(())
+/// }
+/// ```
+pub struct QuadrantChartBuilder {
+    /// Configuration of the chart.
+    configuration: QuadrantChartConfiguration,
+    /// Labels of the horizontal axis.
+    x_axis: Option<QuadrantAxis>,
+    /// Labels of the vertical axis.
+    y_axis: Option<QuadrantAxis>,
+    /// Titles of the quadrants.
+    quadrants: BTreeMap<Quadrant, String>,
+    /// Points of the chart.
+    points: Vec<QuadrantPoint>,
+}
+
+impl QuadrantChartBuilder {
+    /// Sets the configuration of the chart.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: QuadrantChartConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Sets the labels of the horizontal axis, replacing any previous ones.
+    pub fn x_axis(&mut self, axis: QuadrantAxis) {
+        self.x_axis = Some(axis);
+    }
+
+    /// Sets the labels of the vertical axis, replacing any previous ones.
+    pub fn y_axis(&mut self, axis: QuadrantAxis) {
+        self.y_axis = Some(axis);
+    }
+
+    /// Sets the title of a quadrant, replacing any previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `quadrant` - The quadrant to entitle.
+    /// * `title` - The title of the quadrant.
+    ///
+    /// # Errors
+    ///
+    /// * If the title is empty or contains double quotes or line breaks.
+    pub fn quadrant(
+        &mut self,
+        quadrant: Quadrant,
+        title: impl Into<String>,
+    ) -> Result<(), QuadrantChartError> {
+        self.quadrants.insert(quadrant, validate_text(title)?);
+        Ok(())
+    }
+
+    /// Adds a point to the chart.
+    ///
+    /// # Errors
+    ///
+    /// * If the point builder fails to build.
+    pub fn point(&mut self, point: QuadrantPointBuilder) -> Result<(), QuadrantChartError> {
+        self.points.push(point.build()?);
+        Ok(())
+    }
+}
+
+impl From<QuadrantChartBuilder> for QuadrantChart {
+    fn from(builder: QuadrantChartBuilder) -> Self {
+        QuadrantChart {
+            configuration: builder.configuration,
+            x_axis: builder.x_axis,
+            y_axis: builder.y_axis,
+            quadrants: builder.quadrants,
+            points: builder.points,
+        }
+    }
+}
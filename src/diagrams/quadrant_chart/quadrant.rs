@@ -0,0 +1,37 @@
+//! Submodule defining the quadrants of quadrant charts.
+
+use core::fmt::{self, Display};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents one of the four quadrants of a quadrant chart, numbered
+/// counterclockwise from the top right as in Mermaid.
+pub enum Quadrant {
+    /// The top right quadrant, i.e. `quadrant-1`.
+    TopRight,
+    /// The top left quadrant, i.e. `quadrant-2`.
+    TopLeft,
+    /// The bottom left quadrant, i.e. `quadrant-3`.
+    BottomLeft,
+    /// The bottom right quadrant, i.e. `quadrant-4`.
+    BottomRight,
+}
+
+impl Quadrant {
+    #[must_use]
+    /// Returns the number of the quadrant, from 1 to 4.
+    pub fn number(self) -> u8 {
+        match self {
+            Quadrant::TopRight => 1,
+            Quadrant::TopLeft => 2,
+            Quadrant::BottomLeft => 3,
+            Quadrant::BottomRight => 4,
+        }
+    }
+}
+
+impl Display for Quadrant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "quadrant-{}", self.number())
+    }
+}
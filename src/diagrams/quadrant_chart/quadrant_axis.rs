@@ -0,0 +1,72 @@
+//! Submodule defining the labels of the axes of quadrant charts.
+
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+use crate::{diagrams::quadrant_chart::validate_text, errors::QuadrantChartError};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the labels of an axis of a quadrant chart, at its low end and
+/// optionally at its high end.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let axis = QuadrantAxis::new("Low effort")?.high("High effort")?;
+///     assert_eq!(axis.to_string(), "\"Low effort\" --> \"High effort\"");
+///     Ok(())
+/// }
+/// ```
+pub struct QuadrantAxis {
+    /// Label at the low end of the axis.
+    low: String,
+    /// Label at the high end of the axis, if any.
+    high: Option<String>,
+}
+
+impl QuadrantAxis {
+    /// Creates a new axis with a label at its low end.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains double quotes or line breaks.
+    pub fn new(low: impl Into<String>) -> Result<Self, QuadrantChartError> {
+        Ok(Self { low: validate_text(low)?, high: None })
+    }
+
+    /// Sets the label at the high end of the axis.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains double quotes or line breaks.
+    pub fn high(mut self, high: impl Into<String>) -> Result<Self, QuadrantChartError> {
+        self.high = Some(validate_text(high)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Returns the label at the low end of the axis.
+    pub fn low_label(&self) -> &str {
+        &self.low
+    }
+
+    #[must_use]
+    /// Returns the label at the high end of the axis, if any.
+    pub fn high_label(&self) -> Option<&str> {
+        self.high.as_deref()
+    }
+}
+
+impl Display for QuadrantAxis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self.low)?;
+        if let Some(high) = &self.high {
+            write!(f, " --> \"{high}\"")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+//! Submodule defining a point of a quadrant chart in Mermaid syntax.
+
+mod builder;
+use alloc::string::String;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+};
+
+pub use builder::QuadrantPointBuilder;
+
+use crate::shared::{Color, Unit};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a point of a quadrant chart, optionally with its own style.
+///
+/// As the coordinates are guaranteed to be finite, points are totally ordered
+/// and may be hashed.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let point = QuadrantPointBuilder::default()
+///         .label("Parser")?
+///         .x(0.25)?
+///         .y(0.9)?
+///         .radius(Unit::Pixel(8))?
+///         .color(Color::from((255, 51, 0)))
+///         .build()?;
+///
+///     assert_eq!(point.to_string(), "\"Parser\": [0.25, 0.9] radius: 8, color: #ff3300");
+///     Ok(())
+/// }
+/// ```
+pub struct QuadrantPoint {
+    /// Label of the point.
+    label: String,
+    /// Horizontal coordinate of the point, within `[0, 1]`.
+    x: f64,
+    /// Vertical coordinate of the point, within `[0, 1]`.
+    y: f64,
+    /// Radius of the point, in pixels.
+    radius: Option<u8>,
+    /// Fill color of the point.
+    color: Option<Color>,
+    /// Stroke color of the point.
+    stroke_color: Option<Color>,
+    /// Stroke width of the point, in pixels.
+    stroke_width: Option<u8>,
+}
+
+impl QuadrantPoint {
+    #[must_use]
+    /// Returns the label of the point.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the horizontal coordinate of the point.
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    #[must_use]
+    /// Returns the vertical coordinate of the point.
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    #[must_use]
+    /// Returns the radius of the point, if any.
+    pub fn radius(&self) -> Option<Unit> {
+        self.radius.map(Unit::Pixel)
+    }
+
+    #[must_use]
+    /// Returns the fill color of the point, if any.
+    pub fn color(&self) -> Option<Color> {
+        self.color
+    }
+
+    #[must_use]
+    /// Returns the stroke color of the point, if any.
+    pub fn stroke_color(&self) -> Option<Color> {
+        self.stroke_color
+    }
+
+    #[must_use]
+    /// Returns the stroke width of the point, if any.
+    pub fn stroke_width(&self) -> Option<Unit> {
+        self.stroke_width.map(Unit::Pixel)
+    }
+
+    /// Returns the style attributes of the point in a comparable form.
+    fn style(&self) -> (Option<u8>, Option<Color>, Option<Color>, Option<u8>) {
+        (self.radius, self.color, self.stroke_color, self.stroke_width)
+    }
+}
+
+impl PartialEq for QuadrantPoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QuadrantPoint {}
+
+impl PartialOrd for QuadrantPoint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QuadrantPoint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.label
+            .cmp(&other.label)
+            .then(self.x.total_cmp(&other.x))
+            .then(self.y.total_cmp(&other.y))
+            .then(self.style().cmp(&other.style()))
+    }
+}
+
+impl Hash for QuadrantPoint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.label.hash(state);
+        self.x.to_bits().hash(state);
+        self.y.to_bits().hash(state);
+        self.style().hash(state);
+    }
+}
+
+impl Display for QuadrantPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\": [{}, {}]", self.label, self.x, self.y)?;
+        let mut separator = " ";
+        if let Some(radius) = self.radius {
+            write!(f, "{separator}radius: {radius}")?;
+            separator = ", ";
+        }
+        if let Some(color) = self.color {
+            write!(f, "{separator}color: {}", color.to_hex())?;
+            separator = ", ";
+        }
+        if let Some(stroke_color) = self.stroke_color {
+            write!(f, "{separator}stroke-color: {}", stroke_color.to_hex())?;
+            separator = ", ";
+        }
+        if let Some(stroke_width) = self.stroke_width {
+            write!(f, "{separator}stroke-width: {stroke_width}px")?;
+        }
+        Ok(())
+    }
+}
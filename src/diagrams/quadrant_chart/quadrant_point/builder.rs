@@ -0,0 +1,178 @@
+//! Submodule defining the struct for building a point of a quadrant chart.
+
+use alloc::string::String;
+
+use crate::{
+    diagrams::quadrant_chart::{quadrant_point::QuadrantPoint, validate_text},
+    errors::QuadrantChartError,
+    shared::{Color, Unit},
+};
+
+/// Validates a coordinate of a point.
+fn coordinate(value: f64) -> Result<f64, QuadrantChartError> {
+    if (0.0..=1.0).contains(&value) {
+        // Adding zero normalizes a negative zero.
+        Ok(value + 0.0)
+    } else {
+        Err(QuadrantChartError::InvalidCoordinate)
+    }
+}
+
+/// Validates that a size is expressed in pixels, and returns their number.
+fn pixels(unit: Unit) -> Result<u8, QuadrantChartError> {
+    match unit {
+        Unit::Pixel(pixels) => Ok(pixels),
+        Unit::Point(_) => Err(QuadrantChartError::UnsupportedUnit(unit)),
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `QuadrantPoint`.
+pub struct QuadrantPointBuilder {
+    /// Label of the point.
+    label: Option<String>,
+    /// Horizontal coordinate of the point.
+    x: Option<f64>,
+    /// Vertical coordinate of the point.
+    y: Option<f64>,
+    /// Radius of the point, in pixels.
+    radius: Option<u8>,
+    /// Fill color of the point.
+    color: Option<Color>,
+    /// Stroke color of the point.
+    stroke_color: Option<Color>,
+    /// Stroke width of the point, in pixels.
+    stroke_width: Option<u8>,
+}
+
+impl QuadrantPointBuilder {
+    /// Sets the label of the point.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains double quotes or line breaks.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, QuadrantChartError> {
+        self.label = Some(validate_text(label)?);
+        Ok(self)
+    }
+
+    /// Sets the horizontal coordinate of the point.
+    ///
+    /// # Errors
+    ///
+    /// * If the coordinate is not a finite number within `[0, 1]`.
+    pub fn x(mut self, x: f64) -> Result<Self, QuadrantChartError> {
+        self.x = Some(coordinate(x)?);
+        Ok(self)
+    }
+
+    /// Sets the vertical coordinate of the point.
+    ///
+    /// # Errors
+    ///
+    /// * If the coordinate is not a finite number within `[0, 1]`.
+    pub fn y(mut self, y: f64) -> Result<Self, QuadrantChartError> {
+        self.y = Some(coordinate(y)?);
+        Ok(self)
+    }
+
+    /// Sets the radius of the point.
+    ///
+    /// # Errors
+    ///
+    /// * If the radius is not expressed in pixels.
+    pub fn radius(mut self, radius: Unit) -> Result<Self, QuadrantChartError> {
+        self.radius = Some(pixels(radius)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the fill color of the point.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    #[must_use]
+    /// Sets the stroke color of the point.
+    pub fn stroke_color(mut self, color: Color) -> Self {
+        self.stroke_color = Some(color);
+        self
+    }
+
+    /// Sets the stroke width of the point.
+    ///
+    /// # Errors
+    ///
+    /// * If the width is not expressed in pixels.
+    pub fn stroke_width(mut self, width: Unit) -> Result<Self, QuadrantChartError> {
+        self.stroke_width = Some(pixels(width)?);
+        Ok(self)
+    }
+
+    /// Builds the point.
+    ///
+    /// # Errors
+    ///
+    /// * If the label or a coordinate of the point is missing.
+    pub fn build(self) -> Result<QuadrantPoint, QuadrantChartError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<QuadrantPointBuilder> for QuadrantPoint {
+    type Error = QuadrantChartError;
+
+    fn try_from(builder: QuadrantPointBuilder) -> Result<Self, Self::Error> {
+        let label = builder.label.ok_or(QuadrantChartError::MissingPointLabel)?;
+        let (Some(x), Some(y)) = (builder.x, builder.y) else {
+            return Err(QuadrantChartError::MissingCoordinate(label));
+        };
+        Ok(QuadrantPoint {
+            label,
+            x,
+            y,
+            radius: builder.radius,
+            color: builder.color,
+            stroke_color: builder.stroke_color,
+            stroke_width: builder.stroke_width,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box};
+
+    use super::*;
+
+    #[test]
+    fn test_quadrant_point_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        assert_eq!(
+            QuadrantPointBuilder::default().x(1.5).err(),
+            Some(QuadrantChartError::InvalidCoordinate)
+        );
+        assert_eq!(
+            QuadrantPointBuilder::default().y(f64::NAN).err(),
+            Some(QuadrantChartError::InvalidCoordinate)
+        );
+        assert_eq!(
+            QuadrantPointBuilder::default().radius(Unit::Point(8)).err(),
+            Some(QuadrantChartError::UnsupportedUnit(Unit::Point(8)))
+        );
+        assert_eq!(
+            QuadrantPointBuilder::default().stroke_width(Unit::Point(2)).err(),
+            Some(QuadrantChartError::UnsupportedUnit(Unit::Point(2)))
+        );
+        assert_eq!(
+            QuadrantPointBuilder::default().x(0.5)?.build(),
+            Err(QuadrantChartError::MissingPointLabel)
+        );
+        assert_eq!(
+            QuadrantPointBuilder::default().label("A")?.x(0.5)?.build(),
+            Err(QuadrantChartError::MissingCoordinate("A".to_owned()))
+        );
+        Ok(())
+    }
+}
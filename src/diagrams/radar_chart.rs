@@ -0,0 +1,223 @@
+//! Submodule providing structs for creating radar charts in Mermaid syntax.
+
+mod builder;
+mod radar_curve;
+mod radar_options;
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::RadarChartBuilder;
+pub use radar_curve::RadarCurve;
+pub use radar_options::RadarGraticule;
+use radar_options::RadarOptions;
+
+use crate::{
+    errors::RadarChartError,
+    shared::generic_configuration::{GenericConfiguration, GenericConfigurationBuilder},
+    traits::TabbedDisplay,
+};
+
+/// Represents the configuration for a radar chart.
+pub type RadarChartConfiguration = GenericConfiguration;
+/// Represents the configuration builder for a radar chart.
+pub type RadarChartConfigurationBuilder = GenericConfigurationBuilder;
+
+/// Number of color scale variables provided by Mermaid themes, which bounds
+/// the number of curves that may be colored.
+pub(crate) const MAXIMUM_COLORED_CURVES: usize = 12;
+
+/// Validates a text of a radar chart, which is written within double quotes.
+pub(crate) fn validate_text(text: impl Into<String>) -> Result<String, RadarChartError> {
+    let text = text.into();
+    if text.trim().is_empty() {
+        return Err(RadarChartError::EmptyText);
+    }
+    if text.contains(['"', '\n', '\r']) {
+        return Err(RadarChartError::InvalidText(text));
+    }
+    Ok(text)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a radar chart in Mermaid syntax.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = RadarChartBuilder::default().max(100.0)?;
+///     builder.axis("Math")?;
+///     builder.axis("Science")?;
+///     builder.curve(RadarCurve::new([85.0, 90.0])?.label("Alice")?)?;
+///
+///     let chart = RadarChart::from(builder);
+///     assert_eq!(
+///         chart.to_string(),
+///         "radar-beta\n  axis v0[\"Math\"]\n  axis v1[\"Science\"]\n  curve v2[\"Alice\"]{85, 90}\n  max 100\n"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct RadarChart {
+    /// Configuration of the chart.
+    configuration: RadarChartConfiguration,
+    /// Labels of the axes of the chart.
+    axes: Vec<String>,
+    /// Curves of the chart.
+    curves: Vec<RadarCurve>,
+    /// Options controlling the scale and the grid of the chart.
+    options: RadarOptions,
+}
+
+impl RadarChart {
+    #[must_use]
+    /// Returns the configuration of the chart.
+    pub fn configuration(&self) -> &RadarChartConfiguration {
+        &self.configuration
+    }
+
+    /// Returns an iterator over the labels of the axes of the chart.
+    pub fn axes(&self) -> impl Iterator<Item = &str> {
+        self.axes.iter().map(String::as_str)
+    }
+
+    /// Returns an iterator over the curves of the chart.
+    pub fn curves(&self) -> impl Iterator<Item = &RadarCurve> {
+        self.curves.iter()
+    }
+
+    #[must_use]
+    /// Returns the value at the outer edge of the chart, if set.
+    pub fn max(&self) -> Option<f64> {
+        self.options.max
+    }
+
+    #[must_use]
+    /// Returns the value at the center of the chart, if set.
+    pub fn min(&self) -> Option<f64> {
+        self.options.min
+    }
+
+    #[must_use]
+    /// Returns the number of concentric rings of the grid, if set.
+    pub fn ticks(&self) -> Option<u8> {
+        self.options.ticks
+    }
+
+    #[must_use]
+    /// Returns the shape of the grid, if set.
+    pub fn graticule(&self) -> Option<RadarGraticule> {
+        self.options.graticule
+    }
+
+    /// Returns the `themeVariables.cScaleN` options holding the colors of the
+    /// colored curves, indexed by the position of the curve they apply to.
+    fn curve_colors(&self) -> Vec<(String, String)> {
+        self.curves
+            .iter()
+            .enumerate()
+            .filter_map(|(curve_number, curve)| {
+                Some((
+                    format!("themeVariables.cScale{curve_number}"),
+                    format!("\"{}\"", curve.get_color()?.to_hex()),
+                ))
+            })
+            .collect()
+    }
+}
+
+impl Display for RadarChart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for RadarChart {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat((tab_count + 1) * 2);
+        self.configuration.fmt_frontmatter(f, true, &self.curve_colors())?;
+        writeln!(f, "{}radar-beta", " ".repeat(tab_count * 2))?;
+        for (axis_number, label) in self.axes.iter().enumerate() {
+            writeln!(f, "{indent}axis v{axis_number}[\"{label}\"]")?;
+        }
+        for (curve_number, curve) in self.curves.iter().enumerate() {
+            write!(f, "{indent}curve v{}", self.axes.len() + curve_number)?;
+            if let Some(label) = curve.get_label() {
+                write!(f, "[\"{label}\"]")?;
+            }
+            write!(f, "{{")?;
+            for (value_number, value) in curve.values().iter().enumerate() {
+                if value_number > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{value}")?;
+            }
+            writeln!(f, "}}")?;
+        }
+        self.options.fmt_tabbed(f, tab_count + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::{
+        shared::{Color, generic_configuration::Theme},
+        traits::ConfigurationBuilder,
+    };
+
+    #[test]
+    fn test_radar_chart_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = RadarChartBuilder::default()
+            .configuration(
+                RadarChartConfigurationBuilder::default().title("Grades")?.theme(Theme::Dark),
+            )?
+            .min(0.0)?
+            .max(100.0)?
+            .ticks(4)?
+            .graticule(RadarGraticule::Polygon);
+        for axis in ["Math", "Science", "English"] {
+            builder.axis(axis)?;
+        }
+        builder.curve(RadarCurve::new([85.0, 90.0, 80.0])?.label("Alice")?)?;
+        builder.curve(
+            RadarCurve::new([70.5, 75.0, 85.0])?.label("Bob")?.color(Color::from((0, 0, 255))),
+        )?;
+        builder.curve(RadarCurve::new([-0.0, 50.0, 60.0])?)?;
+
+        let chart = RadarChart::from(builder);
+        assert_eq!(chart.axes().count(), 3);
+        assert_eq!(chart.curves().count(), 3);
+        assert_eq!(chart.graticule(), Some(RadarGraticule::Polygon));
+        assert_eq!(
+            chart.to_string(),
+            concat!(
+                "---\n",
+                "title: Grades\n",
+                "config:\n",
+                "  theme: dark\n",
+                "  look: classic\n",
+                "  themeVariables:\n",
+                "    cScale1: \"#0000ff\"\n",
+                "---\n",
+                "radar-beta\n",
+                "  axis v0[\"Math\"]\n",
+                "  axis v1[\"Science\"]\n",
+                "  axis v2[\"English\"]\n",
+                "  curve v3[\"Alice\"]{85, 90, 80}\n",
+                "  curve v4[\"Bob\"]{70.5, 75, 85}\n",
+                "  curve v5{0, 50, 60}\n",
+                "  max 100\n",
+                "  min 0\n",
+                "  ticks 4\n",
+                "  graticule polygon\n",
+            )
+        );
+        Ok(())
+    }
+}
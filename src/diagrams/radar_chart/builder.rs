@@ -0,0 +1,221 @@
+//! Submodule providing a builder for radar charts.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    diagrams::radar_chart::{
+        MAXIMUM_COLORED_CURVES, RadarChart, RadarChartConfiguration,
+        RadarChartConfigurationBuilder,
+        radar_curve::RadarCurve,
+        radar_options::{RadarGraticule, RadarOptions},
+        validate_text,
+    },
+    errors::{ConfigError, RadarChartError},
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a radar chart.
+///
+/// All axes must be added before the first curve, as each curve holds
+/// exactly one value per axis.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = RadarChartBuilder::default().graticule(RadarGraticule::Polygon);
+///     builder.axis("Speed")?;
+///     builder.axis("Power")?;
+///     builder.axis("Range")?;
+///     builder.curve(RadarCurve::new([3.0, 4.0, 2.0])?)?;
+///
+///     assert!(builder.curve(RadarCurve::new([1.0, 2.0])?).is_err());
+///     assert!(builder.axis("Weight").is_err());
+///
+///     let chart = RadarChart::from(builder);
+///     assert_eq!(chart.curves().count(), 1);
+///     Ok(())
+/// }
+/// ```
+pub struct RadarChartBuilder {
+    /// Configuration of the chart.
+    configuration: RadarChartConfiguration,
+    /// Labels of the axes of the chart.
+    axes: Vec<String>,
+    /// Curves of the chart.
+    curves: Vec<RadarCurve>,
+    /// Options controlling the scale and the grid of the chart.
+    options: RadarOptions,
+}
+
+impl RadarChartBuilder {
+    /// Sets the configuration of the chart.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: RadarChartConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Sets the value at the outer edge of the chart.
+    ///
+    /// # Errors
+    ///
+    /// * If the value is infinite or not a number.
+    /// * If the value is not greater than the minimum, when one is set.
+    pub fn max(mut self, max: f64) -> Result<Self, RadarChartError> {
+        if !max.is_finite() {
+            return Err(RadarChartError::NonFiniteValue);
+        }
+        if self.options.min.is_some_and(|min| min >= max) {
+            return Err(RadarChartError::InvalidRange);
+        }
+        // Adding zero normalizes a negative zero.
+        self.options.max = Some(max + 0.0);
+        Ok(self)
+    }
+
+    /// Sets the value at the center of the chart.
+    ///
+    /// # Errors
+    ///
+    /// * If the value is infinite or not a number.
+    /// * If the value is not less than the maximum, when one is set.
+    pub fn min(mut self, min: f64) -> Result<Self, RadarChartError> {
+        if !min.is_finite() {
+            return Err(RadarChartError::NonFiniteValue);
+        }
+        if self.options.max.is_some_and(|max| min >= max) {
+            return Err(RadarChartError::InvalidRange);
+        }
+        // Adding zero normalizes a negative zero.
+        self.options.min = Some(min + 0.0);
+        Ok(self)
+    }
+
+    /// Sets the number of concentric rings of the grid.
+    ///
+    /// # Errors
+    ///
+    /// * If the number of ticks is zero.
+    pub fn ticks(mut self, ticks: u8) -> Result<Self, RadarChartError> {
+        if ticks == 0 {
+            return Err(RadarChartError::ZeroTicks);
+        }
+        self.options.ticks = Some(ticks);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the shape of the grid.
+    pub fn graticule(mut self, graticule: RadarGraticule) -> Self {
+        self.options.graticule = Some(graticule);
+        self
+    }
+
+    /// Adds an axis to the chart.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains double quotes or line breaks.
+    /// * If curves were already added, as they would lack a value for the new
+    ///   axis.
+    pub fn axis(&mut self, label: impl Into<String>) -> Result<(), RadarChartError> {
+        let label = validate_text(label)?;
+        if let Some(curve) = self.curves.first() {
+            return Err(RadarChartError::CurveLengthMismatch {
+                expected: self.axes.len() + 1,
+                found: curve.values().len(),
+            });
+        }
+        self.axes.push(label);
+        Ok(())
+    }
+
+    /// Adds a curve to the chart.
+    ///
+    /// # Errors
+    ///
+    /// * If the number of values of the curve differs from the number of axes.
+    /// * If the curve is colored but Mermaid provides no color scale variable
+    ///   for its position.
+    pub fn curve(&mut self, curve: RadarCurve) -> Result<(), RadarChartError> {
+        if curve.values().len() != self.axes.len() {
+            return Err(RadarChartError::CurveLengthMismatch {
+                expected: self.axes.len(),
+                found: curve.values().len(),
+            });
+        }
+        if curve.get_color().is_some() && self.curves.len() >= MAXIMUM_COLORED_CURVES {
+            return Err(RadarChartError::TooManyColoredCurves(MAXIMUM_COLORED_CURVES));
+        }
+        self.curves.push(curve);
+        Ok(())
+    }
+}
+
+impl From<RadarChartBuilder> for RadarChart {
+    fn from(builder: RadarChartBuilder) -> Self {
+        RadarChart {
+            configuration: builder.configuration,
+            axes: builder.axes,
+            curves: builder.curves,
+            options: builder.options,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::Color;
+
+    #[test]
+    fn test_radar_chart_builder_errors() -> Result<(), RadarChartError> {
+        assert_eq!(
+            RadarChartBuilder::default().max(10.0)?.min(10.0),
+            Err(RadarChartError::InvalidRange)
+        );
+        assert_eq!(
+            RadarChartBuilder::default().min(5.0)?.max(-5.0),
+            Err(RadarChartError::InvalidRange)
+        );
+        assert_eq!(
+            RadarChartBuilder::default().max(f64::NAN),
+            Err(RadarChartError::NonFiniteValue)
+        );
+        assert_eq!(RadarChartBuilder::default().ticks(0), Err(RadarChartError::ZeroTicks));
+
+        let mut builder = RadarChartBuilder::default();
+        assert_eq!(builder.axis(" "), Err(RadarChartError::EmptyText));
+        assert_eq!(builder.axis("\"A\""), Err(RadarChartError::InvalidText("\"A\"".into())));
+        builder.axis("A")?;
+        builder.axis("B")?;
+        assert_eq!(
+            builder.curve(RadarCurve::new([1.0])?),
+            Err(RadarChartError::CurveLengthMismatch { expected: 2, found: 1 })
+        );
+        for _ in 0..MAXIMUM_COLORED_CURVES {
+            builder.curve(RadarCurve::new([1.0, 2.0])?.color(Color::from((0, 0, 0))))?;
+        }
+        assert_eq!(
+            builder.curve(RadarCurve::new([1.0, 2.0])?.color(Color::from((0, 0, 0)))),
+            Err(RadarChartError::TooManyColoredCurves(MAXIMUM_COLORED_CURVES))
+        );
+        builder.curve(RadarCurve::new([1.0, 2.0])?)?;
+        assert_eq!(
+            builder.axis("C"),
+            Err(RadarChartError::CurveLengthMismatch { expected: 3, found: 2 })
+        );
+        Ok(())
+    }
+}
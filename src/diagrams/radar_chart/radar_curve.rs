@@ -0,0 +1,138 @@
+//! Submodule defining the curves of radar charts.
+
+use alloc::{string::String, vec::Vec};
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use crate::{diagrams::radar_chart::validate_text, errors::RadarChartError, shared::Color};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a curve of a radar chart, holding one value per axis.
+///
+/// As the values are guaranteed to be finite, curves are totally ordered and
+/// may be hashed.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let curve =
+///         RadarCurve::new([85.0, 90.0, 80.0])?.label("Alice")?.color(Color::from((255, 0, 0)));
+///     assert_eq!(curve.values(), &[85.0, 90.0, 80.0]);
+///     assert_eq!(curve.get_label(), Some("Alice"));
+///
+///     assert!(RadarCurve::new([f64::INFINITY]).is_err());
+///     Ok(())
+/// }
+/// ```
+pub struct RadarCurve {
+    /// Label of the curve, shown in the legend.
+    label: Option<String>,
+    /// Values of the curve, one per axis.
+    values: Vec<f64>,
+    /// Color of the curve, if any.
+    color: Option<Color>,
+}
+
+impl RadarCurve {
+    /// Creates a new curve.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values of the curve, one per axis of the chart.
+    ///
+    /// # Errors
+    ///
+    /// * If a value is infinite or not a number.
+    pub fn new(values: impl IntoIterator<Item = f64>) -> Result<Self, RadarChartError> {
+        let values = values
+            .into_iter()
+            .map(|value| {
+                // Adding zero normalizes a negative zero.
+                if value.is_finite() {
+                    Ok(value + 0.0)
+                } else {
+                    Err(RadarChartError::NonFiniteValue)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { label: None, values, color: None })
+    }
+
+    /// Sets the label of the curve.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains double quotes or line breaks.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, RadarChartError> {
+        self.label = Some(validate_text(label)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the color of the curve.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    #[must_use]
+    /// Returns the label of the curve, if any.
+    pub fn get_label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    #[must_use]
+    /// Returns the values of the curve.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    #[must_use]
+    /// Returns the color of the curve, if any.
+    pub fn get_color(&self) -> Option<Color> {
+        self.color
+    }
+}
+
+impl PartialEq for RadarCurve {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for RadarCurve {}
+
+impl PartialOrd for RadarCurve {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RadarCurve {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.label.cmp(&other.label).then_with(|| self.color.cmp(&other.color)).then_with(|| {
+            self.values
+                .iter()
+                .zip(&other.values)
+                .map(|(value, other_value)| value.total_cmp(other_value))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or_else(|| self.values.len().cmp(&other.values.len()))
+        })
+    }
+}
+
+impl Hash for RadarCurve {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.label.hash(state);
+        self.color.hash(state);
+        for value in &self.values {
+            value.to_bits().hash(state);
+        }
+    }
+}
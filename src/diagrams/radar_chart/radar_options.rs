@@ -0,0 +1,99 @@
+//! Submodule defining the options of radar charts, which control the scale
+//! and the grid of the chart.
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+};
+
+use crate::traits::TabbedDisplay;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the shape of the grid drawn behind the curves of a radar chart.
+pub enum RadarGraticule {
+    /// The grid is made of concentric circles.
+    #[default]
+    Circle,
+    /// The grid is made of concentric polygons, with one vertex per axis.
+    Polygon,
+}
+
+impl Display for RadarGraticule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RadarGraticule::Circle => write!(f, "circle"),
+            RadarGraticule::Polygon => write!(f, "polygon"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Options of a radar chart, left to the Mermaid defaults when unset.
+pub(crate) struct RadarOptions {
+    /// Value at the outer edge of the chart.
+    pub(crate) max: Option<f64>,
+    /// Value at the center of the chart.
+    pub(crate) min: Option<f64>,
+    /// Number of concentric rings of the grid.
+    pub(crate) ticks: Option<u8>,
+    /// Shape of the grid.
+    pub(crate) graticule: Option<RadarGraticule>,
+}
+
+impl PartialEq for RadarOptions {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for RadarOptions {}
+
+impl PartialOrd for RadarOptions {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RadarOptions {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let total_cmp = |value: Option<f64>, other_value: Option<f64>| match (value, other_value) {
+            (Some(value), Some(other_value)) => value.total_cmp(&other_value),
+            (value, other_value) => value.is_some().cmp(&other_value.is_some()),
+        };
+        total_cmp(self.max, other.max)
+            .then_with(|| total_cmp(self.min, other.min))
+            .then_with(|| self.ticks.cmp(&other.ticks))
+            .then_with(|| self.graticule.cmp(&other.graticule))
+    }
+}
+
+impl Hash for RadarOptions {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.max.map(f64::to_bits).hash(state);
+        self.min.map(f64::to_bits).hash(state);
+        self.ticks.hash(state);
+        self.graticule.hash(state);
+    }
+}
+
+impl TabbedDisplay for RadarOptions {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        if let Some(max) = self.max {
+            writeln!(f, "{indent}max {max}")?;
+        }
+        if let Some(min) = self.min {
+            writeln!(f, "{indent}min {min}")?;
+        }
+        if let Some(ticks) = self.ticks {
+            writeln!(f, "{indent}ticks {ticks}")?;
+        }
+        if let Some(graticule) = self.graticule {
+            writeln!(f, "{indent}graticule {graticule}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,232 @@
+//! Submodule providing structs for creating requirement diagrams in Mermaid
+//! syntax.
+
+mod builder;
+mod requirement;
+mod requirement_element;
+mod requirement_relationship;
+use alloc::{rc::Rc, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::RequirementDiagramBuilder;
+pub use requirement::{
+    Requirement, RequirementBuilder, RequirementKind, RequirementRisk, RequirementVerifyMethod,
+};
+pub use requirement_element::{RequirementElement, RequirementElementBuilder};
+pub use requirement_relationship::{
+    RequirementEndpoint, RequirementRelationship, RequirementRelationshipKind,
+};
+
+use crate::{
+    errors::RequirementDiagramError,
+    shared::generic_configuration::{GenericConfiguration, GenericConfigurationBuilder},
+    traits::{Configuration, TabbedDisplay},
+};
+
+/// Represents the configuration for a requirement diagram.
+pub type RequirementDiagramConfiguration = GenericConfiguration;
+/// Represents the configuration builder for a requirement diagram.
+pub type RequirementDiagramConfigurationBuilder = GenericConfigurationBuilder;
+
+/// Validates a text of a requirement diagram, which is always written between
+/// double quotes.
+pub(crate) fn validate_text(text: impl Into<String>) -> Result<String, RequirementDiagramError> {
+    let text = text.into();
+    if text.trim().is_empty() {
+        return Err(RequirementDiagramError::EmptyText);
+    }
+    if text.contains(['"', '\n', '\r']) {
+        return Err(RequirementDiagramError::InvalidText(text));
+    }
+    Ok(text)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a requirement diagram in Mermaid syntax.
+///
+/// Requirements and elements are identified by their names, which are unique
+/// within the diagram.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = RequirementDiagramBuilder::default();
+///     let requirement = builder.requirement(RequirementBuilder::default().name("Logging")?)?;
+///     let element = builder.element(RequirementElementBuilder::default().name("Logger")?)?;
+///     builder.relationship(element, RequirementRelationshipKind::Satisfies, requirement)?;
+///
+///     let diagram = RequirementDiagram::from(builder);
+///     assert_eq!(
+///         diagram.to_string(),
+///         concat!(
+///             "requirementDiagram\n",
+///             "  direction LR\n",
+///             "  requirement \"Logging\" {\n",
+///             "  }\n",
+///             "  element \"Logger\" {\n",
+///             "  }\n",
+///             "  \"Logger\" - satisfies -> \"Logging\"\n",
+///         )
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct RequirementDiagram {
+    /// Configuration of the diagram.
+    configuration: RequirementDiagramConfiguration,
+    /// Requirements of the diagram.
+    requirements: Vec<Rc<Requirement>>,
+    /// Elements of the diagram.
+    elements: Vec<Rc<RequirementElement>>,
+    /// Relationships of the diagram.
+    relationships: Vec<RequirementRelationship>,
+}
+
+impl RequirementDiagram {
+    #[must_use]
+    /// Returns the configuration of the diagram.
+    pub fn configuration(&self) -> &RequirementDiagramConfiguration {
+        &self.configuration
+    }
+
+    /// Returns an iterator over the requirements of the diagram.
+    pub fn requirements(&self) -> impl Iterator<Item = &Rc<Requirement>> {
+        self.requirements.iter()
+    }
+
+    /// Returns an iterator over the elements of the diagram.
+    pub fn elements(&self) -> impl Iterator<Item = &Rc<RequirementElement>> {
+        self.elements.iter()
+    }
+
+    /// Returns an iterator over the relationships of the diagram.
+    pub fn relationships(&self) -> impl Iterator<Item = &RequirementRelationship> {
+        self.relationships.iter()
+    }
+}
+
+impl Display for RequirementDiagram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for RequirementDiagram {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat((tab_count + 1) * 2);
+        self.configuration.fmt_frontmatter(f, true, &[])?;
+        writeln!(f, "{}requirementDiagram", " ".repeat(tab_count * 2))?;
+        writeln!(f, "{indent}direction {}", self.configuration.direction())?;
+        for requirement in &self.requirements {
+            requirement.fmt_tabbed(f, tab_count + 1)?;
+        }
+        for element in &self.elements {
+            element.fmt_tabbed(f, tab_count + 1)?;
+        }
+        for relationship in &self.relationships {
+            writeln!(f, "{indent}{relationship}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::{shared::Direction, traits::ConfigurationBuilder};
+
+    #[test]
+    fn test_requirement_diagram_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = RequirementDiagramBuilder::default().configuration(
+            RequirementDiagramConfigurationBuilder::default()
+                .title("Audit trail")?
+                .direction(Direction::TopToBottom),
+        )?;
+        let parent = builder.requirement(
+            RequirementBuilder::default()
+                .name("Audit trail")?
+                .id("1")?
+                .text("Every change is recorded.")?
+                .risk(RequirementRisk::High)
+                .verify_method(RequirementVerifyMethod::Test),
+        )?;
+        let child = builder.requirement(
+            RequirementBuilder::default()
+                .name("Signed entries")?
+                .kind(RequirementKind::DesignConstraint)
+                .id("1.1")?,
+        )?;
+        let element = builder.element(
+            RequirementElementBuilder::default()
+                .name("audit_log")?
+                .element_type("module")?
+                .docref("src/audit.rs")?,
+        )?;
+        builder.relationship(parent.clone(), RequirementRelationshipKind::Contains, child)?;
+        builder.relationship(element, RequirementRelationshipKind::Satisfies, parent)?;
+
+        let diagram = RequirementDiagram::from(builder);
+        assert_eq!(diagram.requirements().count(), 2);
+        assert_eq!(diagram.elements().count(), 1);
+        assert_eq!(
+            diagram.to_string(),
+            concat!(
+                "---\n",
+                "title: Audit trail\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "---\n",
+                "requirementDiagram\n",
+                "  direction TB\n",
+                "  requirement \"Audit trail\" {\n",
+                "    id: \"1\"\n",
+                "    text: \"Every change is recorded.\"\n",
+                "    risk: high\n",
+                "    verifymethod: test\n",
+                "  }\n",
+                "  designConstraint \"Signed entries\" {\n",
+                "    id: \"1.1\"\n",
+                "  }\n",
+                "  element \"audit_log\" {\n",
+                "    type: \"module\"\n",
+                "    docref: \"src/audit.rs\"\n",
+                "  }\n",
+                "  \"Audit trail\" - contains -> \"Signed entries\"\n",
+                "  \"audit_log\" - satisfies -> \"Audit trail\"\n",
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_requirement_diagram_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = RequirementDiagramBuilder::default();
+        let requirement = builder.requirement(RequirementBuilder::default().name("Audit")?)?;
+        assert_eq!(
+            builder.element(RequirementElementBuilder::default().name("Audit")?),
+            Err(RequirementDiagramError::DuplicateName("Audit".to_owned()))
+        );
+        assert_eq!(
+            builder.requirement(RequirementBuilder::default()),
+            Err(RequirementDiagramError::MissingName)
+        );
+
+        let foreign = Rc::new(RequirementElementBuilder::default().name("Logger")?.build()?);
+        assert_eq!(
+            builder.relationship(foreign, RequirementRelationshipKind::Verifies, requirement),
+            Err(RequirementDiagramError::EndpointNotFound("Logger".to_owned()))
+        );
+        assert_eq!(
+            RequirementBuilder::default().text("a \"b\""),
+            Err(RequirementDiagramError::InvalidText("a \"b\"".to_string()))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+//! Submodule providing a builder for requirement diagrams.
+
+use alloc::{rc::Rc, vec::Vec};
+
+use crate::{
+    diagrams::requirement_diagram::{
+        RequirementDiagram, RequirementDiagramConfiguration,
+        RequirementDiagramConfigurationBuilder,
+        requirement::{Requirement, RequirementBuilder},
+        requirement_element::{RequirementElement, RequirementElementBuilder},
+        requirement_relationship::{
+            RequirementEndpoint, RequirementRelationship, RequirementRelationshipKind,
+        },
+    },
+    errors::{ConfigError, RequirementDiagramError},
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a requirement diagram.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = RequirementDiagramBuilder::default();
+///     let requirement = builder.requirement(
+///         RequirementBuilder::default()
+///             .name("Sample tracking")?
+///             .kind(RequirementKind::FunctionalRequirement)
+///             .risk(RequirementRisk::Medium),
+///     )?;
+///     let test = builder.element(
+///         RequirementElementBuilder::default().name("tracking_test")?.element_type("test")?,
+///     )?;
+///     builder.relationship(test, RequirementRelationshipKind::Verifies, requirement)?;
+///
+///     let diagram = RequirementDiagram::from(builder);
+///     assert_eq!(diagram.relationships().count(), 1);
+///     Ok(())
+/// }
+/// ```
+pub struct RequirementDiagramBuilder {
+    /// Configuration of the diagram.
+    configuration: RequirementDiagramConfiguration,
+    /// Requirements of the diagram.
+    requirements: Vec<Rc<Requirement>>,
+    /// Elements of the diagram.
+    elements: Vec<Rc<RequirementElement>>,
+    /// Relationships of the diagram.
+    relationships: Vec<RequirementRelationship>,
+}
+
+impl RequirementDiagramBuilder {
+    /// Sets the configuration of the diagram.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: RequirementDiagramConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Adds a requirement to the diagram.
+    ///
+    /// # Errors
+    ///
+    /// * If a requirement or element with the same name already exists in the
+    ///   diagram.
+    /// * If the requirement builder fails to build.
+    pub fn requirement(
+        &mut self,
+        requirement: RequirementBuilder,
+    ) -> Result<Rc<Requirement>, RequirementDiagramError> {
+        let requirement = Rc::new(requirement.build()?);
+        self.check_name_is_free(requirement.name())?;
+        self.requirements.push(requirement.clone());
+        Ok(requirement)
+    }
+
+    /// Adds an element to the diagram.
+    ///
+    /// # Errors
+    ///
+    /// * If a requirement or element with the same name already exists in the
+    ///   diagram.
+    /// * If the element builder fails to build.
+    pub fn element(
+        &mut self,
+        element: RequirementElementBuilder,
+    ) -> Result<Rc<RequirementElement>, RequirementDiagramError> {
+        let element = Rc::new(element.build()?);
+        self.check_name_is_free(element.name())?;
+        self.elements.push(element.clone());
+        Ok(element)
+    }
+
+    /// Adds a relationship between two requirements or elements of the
+    /// diagram.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The requirement or element from which the relationship
+    ///   starts.
+    /// * `kind` - The type of the relationship.
+    /// * `target` - The requirement or element at which the relationship ends.
+    ///
+    /// # Errors
+    ///
+    /// * If the source or the target is not part of the diagram.
+    pub fn relationship(
+        &mut self,
+        source: impl Into<RequirementEndpoint>,
+        kind: RequirementRelationshipKind,
+        target: impl Into<RequirementEndpoint>,
+    ) -> Result<(), RequirementDiagramError> {
+        let source = source.into();
+        let target = target.into();
+        for endpoint in [&source, &target] {
+            if !self.contains(endpoint) {
+                return Err(RequirementDiagramError::EndpointNotFound(endpoint.name().into()));
+            }
+        }
+        self.relationships.push(RequirementRelationship::new(source, kind, target));
+        Ok(())
+    }
+
+    /// Returns whether the provided requirement or element is part of the
+    /// diagram.
+    fn contains(&self, endpoint: &RequirementEndpoint) -> bool {
+        match endpoint {
+            RequirementEndpoint::Requirement(requirement) => {
+                self.requirements.iter().any(|other| other == requirement)
+            }
+            RequirementEndpoint::Element(element) => {
+                self.elements.iter().any(|other| other == element)
+            }
+        }
+    }
+
+    /// Checks that no requirement or element of the diagram has the provided
+    /// name.
+    fn check_name_is_free(&self, name: &str) -> Result<(), RequirementDiagramError> {
+        if self.requirements.iter().any(|requirement| requirement.name() == name)
+            || self.elements.iter().any(|element| element.name() == name)
+        {
+            return Err(RequirementDiagramError::DuplicateName(name.into()));
+        }
+        Ok(())
+    }
+}
+
+impl From<RequirementDiagramBuilder> for RequirementDiagram {
+    fn from(builder: RequirementDiagramBuilder) -> Self {
+        RequirementDiagram {
+            configuration: builder.configuration,
+            requirements: builder.requirements,
+            elements: builder.elements,
+            relationships: builder.relationships,
+        }
+    }
+}
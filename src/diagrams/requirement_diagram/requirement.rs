@@ -0,0 +1,193 @@
+//! Submodule defining a requirement of a requirement diagram in Mermaid
+//! syntax.
+
+mod builder;
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+pub use builder::RequirementBuilder;
+
+use crate::traits::TabbedDisplay;
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the kind of a requirement.
+pub enum RequirementKind {
+    /// A generic requirement.
+    #[default]
+    Requirement,
+    /// A requirement on the behaviour of the system.
+    FunctionalRequirement,
+    /// A requirement on the interfaces of the system.
+    InterfaceRequirement,
+    /// A requirement on the performance of the system.
+    PerformanceRequirement,
+    /// A requirement on the physical characteristics of the system.
+    PhysicalRequirement,
+    /// A constraint on the design of the system.
+    DesignConstraint,
+}
+
+impl Display for RequirementKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequirementKind::Requirement => write!(f, "requirement"),
+            RequirementKind::FunctionalRequirement => write!(f, "functionalRequirement"),
+            RequirementKind::InterfaceRequirement => write!(f, "interfaceRequirement"),
+            RequirementKind::PerformanceRequirement => write!(f, "performanceRequirement"),
+            RequirementKind::PhysicalRequirement => write!(f, "physicalRequirement"),
+            RequirementKind::DesignConstraint => write!(f, "designConstraint"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the risk associated with a requirement.
+pub enum RequirementRisk {
+    /// A low risk.
+    Low,
+    /// A medium risk.
+    Medium,
+    /// A high risk.
+    High,
+}
+
+impl Display for RequirementRisk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequirementRisk::Low => write!(f, "low"),
+            RequirementRisk::Medium => write!(f, "medium"),
+            RequirementRisk::High => write!(f, "high"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents how a requirement is verified.
+pub enum RequirementVerifyMethod {
+    /// The requirement is verified by analysis.
+    Analysis,
+    /// The requirement is verified by inspection.
+    Inspection,
+    /// The requirement is verified by testing.
+    Test,
+    /// The requirement is verified by demonstration.
+    Demonstration,
+}
+
+impl Display for RequirementVerifyMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequirementVerifyMethod::Analysis => write!(f, "analysis"),
+            RequirementVerifyMethod::Inspection => write!(f, "inspection"),
+            RequirementVerifyMethod::Test => write!(f, "test"),
+            RequirementVerifyMethod::Demonstration => write!(f, "demonstration"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a requirement of a requirement diagram.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let requirement = RequirementBuilder::default()
+///         .name("Traceability")?
+///         .kind(RequirementKind::FunctionalRequirement)
+///         .id("REQ-1")?
+///         .risk(RequirementRisk::High)
+///         .build()?;
+///
+///     assert_eq!(
+///         requirement.to_string(),
+///         "functionalRequirement \"Traceability\" {\n  id: \"REQ-1\"\n  risk: high\n}\n"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct Requirement {
+    /// Name of the requirement, unique within the diagram.
+    name: String,
+    /// Kind of the requirement.
+    kind: RequirementKind,
+    /// Identifier of the requirement in the documentation.
+    id: Option<String>,
+    /// Text of the requirement.
+    text: Option<String>,
+    /// Risk associated with the requirement.
+    risk: Option<RequirementRisk>,
+    /// How the requirement is verified.
+    verify_method: Option<RequirementVerifyMethod>,
+}
+
+impl Requirement {
+    #[must_use]
+    /// Returns the name of the requirement.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    /// Returns the kind of the requirement.
+    pub fn kind(&self) -> RequirementKind {
+        self.kind
+    }
+
+    #[must_use]
+    /// Returns the identifier of the requirement in the documentation, if
+    /// any.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    #[must_use]
+    /// Returns the text of the requirement, if any.
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    #[must_use]
+    /// Returns the risk associated with the requirement, if any.
+    pub fn risk(&self) -> Option<RequirementRisk> {
+        self.risk
+    }
+
+    #[must_use]
+    /// Returns how the requirement is verified, if specified.
+    pub fn verify_method(&self) -> Option<RequirementVerifyMethod> {
+        self.verify_method
+    }
+}
+
+impl Display for Requirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for Requirement {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        writeln!(f, "{indent}{} \"{}\" {{", self.kind, self.name)?;
+        if let Some(id) = &self.id {
+            writeln!(f, "{indent}  id: \"{id}\"")?;
+        }
+        if let Some(text) = &self.text {
+            writeln!(f, "{indent}  text: \"{text}\"")?;
+        }
+        if let Some(risk) = self.risk {
+            writeln!(f, "{indent}  risk: {risk}")?;
+        }
+        if let Some(verify_method) = self.verify_method {
+            writeln!(f, "{indent}  verifymethod: {verify_method}")?;
+        }
+        writeln!(f, "{indent}}}")
+    }
+}
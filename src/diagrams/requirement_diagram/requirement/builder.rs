@@ -0,0 +1,114 @@
+//! Submodule defining the struct for building a requirement of a requirement
+//! diagram.
+
+use alloc::string::String;
+
+use crate::{
+    diagrams::requirement_diagram::{
+        requirement::{Requirement, RequirementKind, RequirementRisk, RequirementVerifyMethod},
+        validate_text,
+    },
+    errors::RequirementDiagramError,
+};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `Requirement`.
+pub struct RequirementBuilder {
+    /// Name of the requirement.
+    name: Option<String>,
+    /// Kind of the requirement.
+    kind: RequirementKind,
+    /// Identifier of the requirement in the documentation.
+    id: Option<String>,
+    /// Text of the requirement.
+    text: Option<String>,
+    /// Risk associated with the requirement.
+    risk: Option<RequirementRisk>,
+    /// How the requirement is verified.
+    verify_method: Option<RequirementVerifyMethod>,
+}
+
+impl RequirementBuilder {
+    /// Sets the name of the requirement, which must be unique within the
+    /// diagram.
+    ///
+    /// # Errors
+    ///
+    /// * If the name is empty or contains double quotes or line breaks.
+    pub fn name(mut self, name: impl Into<String>) -> Result<Self, RequirementDiagramError> {
+        self.name = Some(validate_text(name)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the kind of the requirement.
+    pub fn kind(mut self, kind: RequirementKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the identifier of the requirement in the documentation.
+    ///
+    /// # Errors
+    ///
+    /// * If the identifier is empty or contains double quotes or line breaks.
+    pub fn id(mut self, id: impl Into<String>) -> Result<Self, RequirementDiagramError> {
+        self.id = Some(validate_text(id)?);
+        Ok(self)
+    }
+
+    /// Sets the text of the requirement.
+    ///
+    /// # Errors
+    ///
+    /// * If the text is empty or contains double quotes or line breaks.
+    pub fn text(mut self, text: impl Into<String>) -> Result<Self, RequirementDiagramError> {
+        self.text = Some(validate_text(text)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the risk associated with the requirement.
+    pub fn risk(mut self, risk: RequirementRisk) -> Self {
+        self.risk = Some(risk);
+        self
+    }
+
+    #[must_use]
+    /// Sets how the requirement is verified.
+    pub fn verify_method(mut self, verify_method: RequirementVerifyMethod) -> Self {
+        self.verify_method = Some(verify_method);
+        self
+    }
+
+    #[must_use]
+    /// Returns the name of the requirement, if set.
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Builds the requirement.
+    ///
+    /// # Errors
+    ///
+    /// * If the name of the requirement is missing.
+    pub fn build(self) -> Result<Requirement, RequirementDiagramError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<RequirementBuilder> for Requirement {
+    type Error = RequirementDiagramError;
+
+    fn try_from(builder: RequirementBuilder) -> Result<Self, Self::Error> {
+        Ok(Requirement {
+            name: builder.name.ok_or(RequirementDiagramError::MissingName)?,
+            kind: builder.kind,
+            id: builder.id,
+            text: builder.text,
+            risk: builder.risk,
+            verify_method: builder.verify_method,
+        })
+    }
+}
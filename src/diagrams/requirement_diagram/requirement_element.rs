@@ -0,0 +1,83 @@
+//! Submodule defining an element of a requirement diagram in Mermaid syntax,
+//! i.e. an artifact related to the requirements.
+
+mod builder;
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+pub use builder::RequirementElementBuilder;
+
+use crate::traits::TabbedDisplay;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents an element of a requirement diagram, such as a document or a
+/// piece of software satisfying or verifying requirements.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let element = RequirementElementBuilder::default()
+///         .name("Audit log")?
+///         .element_type("module")?
+///         .docref("docs/audit.md")?
+///         .build()?;
+///
+///     assert_eq!(
+///         element.to_string(),
+///         "element \"Audit log\" {\n  type: \"module\"\n  docref: \"docs/audit.md\"\n}\n"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct RequirementElement {
+    /// Name of the element, unique within the diagram.
+    name: String,
+    /// Type of the element.
+    element_type: Option<String>,
+    /// Reference to the documentation of the element.
+    docref: Option<String>,
+}
+
+impl RequirementElement {
+    #[must_use]
+    /// Returns the name of the element.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    /// Returns the type of the element, if any.
+    pub fn element_type(&self) -> Option<&str> {
+        self.element_type.as_deref()
+    }
+
+    #[must_use]
+    /// Returns the reference to the documentation of the element, if any.
+    pub fn docref(&self) -> Option<&str> {
+        self.docref.as_deref()
+    }
+}
+
+impl Display for RequirementElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for RequirementElement {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        writeln!(f, "{indent}element \"{}\" {{", self.name)?;
+        if let Some(element_type) = &self.element_type {
+            writeln!(f, "{indent}  type: \"{element_type}\"")?;
+        }
+        if let Some(docref) = &self.docref {
+            writeln!(f, "{indent}  docref: \"{docref}\"")?;
+        }
+        writeln!(f, "{indent}}}")
+    }
+}
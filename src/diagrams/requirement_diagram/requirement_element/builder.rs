@@ -0,0 +1,83 @@
+//! Submodule defining the struct for building an element of a requirement
+//! diagram.
+
+use alloc::string::String;
+
+use crate::{
+    diagrams::requirement_diagram::{requirement_element::RequirementElement, validate_text},
+    errors::RequirementDiagramError,
+};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `RequirementElement`.
+pub struct RequirementElementBuilder {
+    /// Name of the element.
+    name: Option<String>,
+    /// Type of the element.
+    element_type: Option<String>,
+    /// Reference to the documentation of the element.
+    docref: Option<String>,
+}
+
+impl RequirementElementBuilder {
+    /// Sets the name of the element, which must be unique within the diagram.
+    ///
+    /// # Errors
+    ///
+    /// * If the name is empty or contains double quotes or line breaks.
+    pub fn name(mut self, name: impl Into<String>) -> Result<Self, RequirementDiagramError> {
+        self.name = Some(validate_text(name)?);
+        Ok(self)
+    }
+
+    /// Sets the type of the element.
+    ///
+    /// # Errors
+    ///
+    /// * If the type is empty or contains double quotes or line breaks.
+    pub fn element_type(
+        mut self,
+        element_type: impl Into<String>,
+    ) -> Result<Self, RequirementDiagramError> {
+        self.element_type = Some(validate_text(element_type)?);
+        Ok(self)
+    }
+
+    /// Sets the reference to the documentation of the element.
+    ///
+    /// # Errors
+    ///
+    /// * If the reference is empty or contains double quotes or line breaks.
+    pub fn docref(mut self, docref: impl Into<String>) -> Result<Self, RequirementDiagramError> {
+        self.docref = Some(validate_text(docref)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Returns the name of the element, if set.
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Builds the element.
+    ///
+    /// # Errors
+    ///
+    /// * If the name of the element is missing.
+    pub fn build(self) -> Result<RequirementElement, RequirementDiagramError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<RequirementElementBuilder> for RequirementElement {
+    type Error = RequirementDiagramError;
+
+    fn try_from(builder: RequirementElementBuilder) -> Result<Self, Self::Error> {
+        Ok(RequirementElement {
+            name: builder.name.ok_or(RequirementDiagramError::MissingName)?,
+            element_type: builder.element_type,
+            docref: builder.docref,
+        })
+    }
+}
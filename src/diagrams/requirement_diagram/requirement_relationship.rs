@@ -0,0 +1,124 @@
+//! Submodule defining a typed relationship between the requirements and
+//! elements of a requirement diagram in Mermaid syntax.
+
+use alloc::rc::Rc;
+use core::fmt::{self, Display};
+
+use crate::diagrams::requirement_diagram::{
+    requirement::Requirement, requirement_element::RequirementElement,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the type of a relationship of a requirement diagram.
+pub enum RequirementRelationshipKind {
+    /// The source contains the target.
+    Contains,
+    /// The source is a copy of the target.
+    Copies,
+    /// The source is derived from the target.
+    Derives,
+    /// The source satisfies the target.
+    Satisfies,
+    /// The source verifies the target.
+    Verifies,
+    /// The source refines the target.
+    Refines,
+    /// The source traces to the target.
+    Traces,
+}
+
+impl Display for RequirementRelationshipKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequirementRelationshipKind::Contains => write!(f, "contains"),
+            RequirementRelationshipKind::Copies => write!(f, "copies"),
+            RequirementRelationshipKind::Derives => write!(f, "derives"),
+            RequirementRelationshipKind::Satisfies => write!(f, "satisfies"),
+            RequirementRelationshipKind::Verifies => write!(f, "verifies"),
+            RequirementRelationshipKind::Refines => write!(f, "refines"),
+            RequirementRelationshipKind::Traces => write!(f, "traces"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents either end of a relationship, i.e. a requirement or an element.
+pub enum RequirementEndpoint {
+    /// A requirement of the diagram.
+    Requirement(Rc<Requirement>),
+    /// An element of the diagram.
+    Element(Rc<RequirementElement>),
+}
+
+impl RequirementEndpoint {
+    #[must_use]
+    /// Returns the name of the requirement or element.
+    pub fn name(&self) -> &str {
+        match self {
+            RequirementEndpoint::Requirement(requirement) => requirement.name(),
+            RequirementEndpoint::Element(element) => element.name(),
+        }
+    }
+}
+
+impl From<Rc<Requirement>> for RequirementEndpoint {
+    fn from(requirement: Rc<Requirement>) -> Self {
+        RequirementEndpoint::Requirement(requirement)
+    }
+}
+
+impl From<Rc<RequirementElement>> for RequirementEndpoint {
+    fn from(element: Rc<RequirementElement>) -> Self {
+        RequirementEndpoint::Element(element)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a typed relationship from a source to a target, each of which
+/// is either a requirement or an element.
+pub struct RequirementRelationship {
+    /// Source of the relationship.
+    source: RequirementEndpoint,
+    /// Type of the relationship.
+    kind: RequirementRelationshipKind,
+    /// Target of the relationship.
+    target: RequirementEndpoint,
+}
+
+impl RequirementRelationship {
+    /// Creates a new relationship.
+    pub(crate) fn new(
+        source: RequirementEndpoint,
+        kind: RequirementRelationshipKind,
+        target: RequirementEndpoint,
+    ) -> Self {
+        Self { source, kind, target }
+    }
+
+    #[must_use]
+    /// Returns the source of the relationship.
+    pub fn source(&self) -> &RequirementEndpoint {
+        &self.source
+    }
+
+    #[must_use]
+    /// Returns the type of the relationship.
+    pub fn kind(&self) -> RequirementRelationshipKind {
+        self.kind
+    }
+
+    #[must_use]
+    /// Returns the target of the relationship.
+    pub fn target(&self) -> &RequirementEndpoint {
+        &self.target
+    }
+}
+
+impl Display for RequirementRelationship {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\" - {} -> \"{}\"", self.source.name(), self.kind, self.target.name())
+    }
+}
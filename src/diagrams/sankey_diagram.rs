@@ -0,0 +1,159 @@
+//! Submodule providing structs for creating sankey diagrams in Mermaid syntax.
+
+mod builder;
+mod configuration;
+mod sankey_flow;
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+pub use builder::SankeyDiagramBuilder;
+pub use configuration::{
+    SankeyDiagramConfiguration, SankeyDiagramConfigurationBuilder, SankeyLinkColor,
+    SankeyNodeAlignment,
+};
+pub use sankey_flow::SankeyFlow;
+
+use crate::traits::TabbedDisplay;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a sankey diagram in Mermaid syntax, rendered with the
+/// `sankey-beta` renderer.
+///
+/// The flows of the diagram are written as CSV rows. As leading whitespace
+/// would become part of the node names, rows are never indented.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = SankeyDiagramBuilder::default();
+///     builder.flow("Sample", "Extract", 10.0)?;
+///     builder.flow("Extract", "Waste", 2.5)?;
+///     assert!(builder.flow("Waste", "Sample", 1.0).is_err());
+///
+///     let diagram = SankeyDiagram::from(builder);
+///     assert_eq!(diagram.to_string(), "sankey-beta\n\nSample,Extract,10\nExtract,Waste,2.5\n");
+///     Ok(())
+/// }
+/// ```
+pub struct SankeyDiagram {
+    /// Configuration of the diagram.
+    configuration: SankeyDiagramConfiguration,
+    /// Flows of the diagram.
+    flows: Vec<SankeyFlow>,
+}
+
+impl SankeyDiagram {
+    #[must_use]
+    /// Returns the configuration of the diagram.
+    pub fn configuration(&self) -> &SankeyDiagramConfiguration {
+        &self.configuration
+    }
+
+    /// Returns an iterator over the flows of the diagram.
+    pub fn flows(&self) -> impl Iterator<Item = &SankeyFlow> {
+        self.flows.iter()
+    }
+
+    #[must_use]
+    /// Returns the names of the nodes of the diagram, in order of first
+    /// appearance.
+    pub fn nodes(&self) -> Vec<&str> {
+        let mut nodes: Vec<&str> = Vec::new();
+        for flow in &self.flows {
+            for name in [flow.source(), flow.target()] {
+                if !nodes.contains(&name) {
+                    nodes.push(name);
+                }
+            }
+        }
+        nodes
+    }
+}
+
+impl Display for SankeyDiagram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for SankeyDiagram {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        write!(f, "{}", self.configuration)?;
+        writeln!(f, "{}sankey-beta", " ".repeat(tab_count * 2))?;
+        writeln!(f)?;
+        for flow in &self.flows {
+            writeln!(f, "{flow}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box, string::ToString, vec};
+
+    use super::*;
+    use crate::{errors::SankeyDiagramError, traits::ConfigurationBuilder};
+
+    #[test]
+    fn test_sankey_diagram_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = SankeyDiagramBuilder::default().configuration(
+            SankeyDiagramConfigurationBuilder::default()
+                .title("Mass balance")?
+                .link_color(SankeyLinkColor::Source)
+                .node_alignment(SankeyNodeAlignment::Center),
+        )?;
+        builder.flow("Plasma", "Lipids, total", 3.25)?;
+        builder.flow("Plasma", "Metabolites", 1.5)?;
+        builder.flow("Lipids, total", "Phospholipids", 2.0)?;
+
+        let diagram = SankeyDiagram::from(builder);
+        assert_eq!(
+            diagram.nodes(),
+            vec!["Plasma", "Lipids, total", "Metabolites", "Phospholipids"]
+        );
+        assert_eq!(
+            diagram.to_string(),
+            concat!(
+                "---\n",
+                "title: Mass balance\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "  sankey:\n",
+                "    showValues: true\n",
+                "    linkColor: source\n",
+                "    nodeAlignment: center\n",
+                "---\n",
+                "sankey-beta\n",
+                "\n",
+                "Plasma,\"Lipids, total\",3.25\n",
+                "Plasma,Metabolites,1.5\n",
+                "\"Lipids, total\",Phospholipids,2\n",
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sankey_diagram_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = SankeyDiagramBuilder::default();
+        builder.flow("A", "B", 1.0)?;
+        builder.flow("B", "C", 1.0)?;
+        assert_eq!(
+            builder.flow("A", "B", 2.0),
+            Err(SankeyDiagramError::DuplicateFlow { from: "A".to_owned(), to: "B".to_owned() })
+        );
+        assert_eq!(
+            builder.flow("C", "A", 1.0),
+            Err(SankeyDiagramError::CircularFlow { from: "C".to_owned(), to: "A".to_owned() })
+        );
+        builder.flow("A", "C", 1.0)?;
+        assert_eq!(SankeyDiagram::from(builder).flows().count(), 3);
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+//! Submodule providing a builder for sankey diagrams.
+
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{
+    diagrams::sankey_diagram::{
+        SankeyDiagram, SankeyFlow,
+        configuration::{SankeyDiagramConfiguration, SankeyDiagramConfigurationBuilder},
+    },
+    errors::{ConfigError, SankeyDiagramError},
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a sankey diagram.
+///
+/// Nodes are created implicitly by the flows referring to them. As sankey
+/// diagrams are drawn from left to right, flows may not form cycles.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = SankeyDiagramBuilder::default();
+///     builder.flow("Sample", "Extract", 10.0)?;
+///     assert!(builder.flow("Sample", "Extract", 5.0).is_err());
+///     assert!(builder.flow("Sample", "Waste", -1.0).is_err());
+///
+///     let diagram = SankeyDiagram::from(builder);
+///     assert_eq!(diagram.nodes(), ["Sample", "Extract"]);
+///     Ok(())
+/// }
+/// ```
+pub struct SankeyDiagramBuilder {
+    /// Configuration of the diagram.
+    configuration: SankeyDiagramConfiguration,
+    /// Flows of the diagram.
+    flows: Vec<SankeyFlow>,
+}
+
+impl SankeyDiagramBuilder {
+    /// Sets the configuration of the diagram.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: SankeyDiagramConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Adds a flow to the diagram.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The name of the source node.
+    /// * `target` - The name of the target node.
+    /// * `value` - The value of the flow.
+    ///
+    /// # Errors
+    ///
+    /// * If the flow is invalid, as described in [`SankeyFlow::new`].
+    /// * If a flow between the same nodes already exists in the diagram.
+    /// * If the flow would close a cycle.
+    pub fn flow(
+        &mut self,
+        source: impl Into<String>,
+        target: impl Into<String>,
+        value: f64,
+    ) -> Result<(), SankeyDiagramError> {
+        let flow = SankeyFlow::new(source, target, value)?;
+        if self
+            .flows
+            .iter()
+            .any(|other| other.source() == flow.source() && other.target() == flow.target())
+        {
+            return Err(SankeyDiagramError::DuplicateFlow {
+                from: flow.source().into(),
+                to: flow.target().into(),
+            });
+        }
+        if self.reaches(flow.target(), flow.source()) {
+            return Err(SankeyDiagramError::CircularFlow {
+                from: flow.source().into(),
+                to: flow.target().into(),
+            });
+        }
+        self.flows.push(flow);
+        Ok(())
+    }
+
+    /// Returns whether the provided destination node can be reached from the
+    /// provided origin node by following the flows of the diagram.
+    fn reaches(&self, origin: &str, destination: &str) -> bool {
+        let mut stack = vec![origin];
+        let mut visited: Vec<&str> = Vec::new();
+        while let Some(node) = stack.pop() {
+            if node == destination {
+                return true;
+            }
+            if visited.contains(&node) {
+                continue;
+            }
+            visited.push(node);
+            stack.extend(
+                self.flows.iter().filter(|flow| flow.source() == node).map(SankeyFlow::target),
+            );
+        }
+        false
+    }
+}
+
+impl From<SankeyDiagramBuilder> for SankeyDiagram {
+    fn from(builder: SankeyDiagramBuilder) -> Self {
+        SankeyDiagram { configuration: builder.configuration, flows: builder.flows }
+    }
+}
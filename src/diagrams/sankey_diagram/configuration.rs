@@ -0,0 +1,148 @@
+//! Submodule defining configuration specifically for sankey diagrams in
+//! Mermaid.
+
+mod builder;
+mod link_color;
+mod node_alignment;
+
+use alloc::{string::ToString, vec, vec::Vec};
+use core::fmt::Display;
+
+pub use builder::SankeyDiagramConfigurationBuilder;
+pub use link_color::SankeyLinkColor;
+pub use node_alignment::SankeyNodeAlignment;
+
+use crate::{
+    shared::{
+        Direction, Renderer,
+        generic_configuration::{GenericConfiguration, Look, Theme},
+    },
+    traits::Configuration,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the configuration for a sankey diagram in Mermaid syntax.
+pub struct SankeyDiagramConfiguration {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfiguration,
+    /// Whether to display the values of the flows next to the nodes.
+    show_values: bool,
+    /// How the links are colored.
+    link_color: SankeyLinkColor,
+    /// How the nodes are aligned.
+    node_alignment: SankeyNodeAlignment,
+}
+
+impl Default for SankeyDiagramConfiguration {
+    fn default() -> Self {
+        Self {
+            generic: GenericConfiguration::default(),
+            show_values: true,
+            link_color: SankeyLinkColor::default(),
+            node_alignment: SankeyNodeAlignment::default(),
+        }
+    }
+}
+
+impl SankeyDiagramConfiguration {
+    #[must_use]
+    /// Returns whether the values of the flows are displayed.
+    pub fn show_values(&self) -> bool {
+        self.show_values
+    }
+
+    #[must_use]
+    /// Returns how the links are colored.
+    pub fn link_color(&self) -> SankeyLinkColor {
+        self.link_color
+    }
+
+    #[must_use]
+    /// Returns how the nodes are aligned.
+    pub fn node_alignment(&self) -> SankeyNodeAlignment {
+        self.node_alignment
+    }
+}
+
+impl Display for SankeyDiagramConfiguration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let default = SankeyDiagramConfiguration::default();
+        let has_sankey_options = self.show_values != default.show_values
+            || self.link_color != default.link_color
+            || self.node_alignment != default.node_alignment;
+        let options = if has_sankey_options {
+            vec![
+                ("sankey.showValues".to_string(), self.show_values.to_string()),
+                ("sankey.linkColor".to_string(), self.link_color.to_string()),
+                ("sankey.nodeAlignment".to_string(), self.node_alignment.to_string()),
+            ]
+        } else {
+            Vec::new()
+        };
+        self.generic.fmt_frontmatter(f, true, &options)
+    }
+}
+
+impl Configuration for SankeyDiagramConfiguration {
+    type Builder = SankeyDiagramConfigurationBuilder;
+
+    fn title(&self) -> Option<&str> {
+        self.generic.title()
+    }
+
+    fn direction(&self) -> Direction {
+        self.generic.direction()
+    }
+
+    fn renderer(&self) -> Renderer {
+        self.generic.renderer()
+    }
+
+    fn theme(&self) -> Theme {
+        self.generic.theme()
+    }
+
+    fn look(&self) -> Look {
+        self.generic.look()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format};
+
+    use super::*;
+    use crate::{shared::Color, traits::ConfigurationBuilder};
+
+    #[test]
+    fn test_sankey_diagram_configuration_display_default() {
+        let config = SankeyDiagramConfiguration::default();
+        assert_eq!(format!("{config}"), "");
+    }
+
+    #[test]
+    fn test_sankey_diagram_configuration_display_full() -> Result<(), Box<dyn core::error::Error>> {
+        let config = SankeyDiagramConfigurationBuilder::default()
+            .show_values(false)
+            .link_color(SankeyLinkColor::Color(Color::from((255, 0, 0))))
+            .node_alignment(SankeyNodeAlignment::Left)
+            .build()?;
+
+        assert_eq!(
+            format!("{config}"),
+            concat!(
+                "---\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "  sankey:\n",
+                "    showValues: false\n",
+                "    linkColor: \"#ff0000\"\n",
+                "    nodeAlignment: left\n",
+                "---\n",
+            )
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+//! Submodule providing a builder struct for the configuration of sankey
+//! diagrams in Mermaid syntax.
+
+use alloc::string::ToString;
+
+use crate::{
+    diagrams::sankey_diagram::configuration::{
+        SankeyDiagramConfiguration, SankeyLinkColor, SankeyNodeAlignment,
+    },
+    errors::ConfigError,
+    shared::generic_configuration::{
+        Direction, GenericConfigurationBuilder, Look, Renderer, Theme,
+    },
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating sankey diagram configurations.
+pub struct SankeyDiagramConfigurationBuilder {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfigurationBuilder,
+    /// Whether to display the values of the flows next to the nodes.
+    show_values: bool,
+    /// How the links are colored.
+    link_color: SankeyLinkColor,
+    /// How the nodes are aligned.
+    node_alignment: SankeyNodeAlignment,
+}
+
+impl Default for SankeyDiagramConfigurationBuilder {
+    fn default() -> Self {
+        Self {
+            generic: GenericConfigurationBuilder::default(),
+            show_values: true,
+            link_color: SankeyLinkColor::default(),
+            node_alignment: SankeyNodeAlignment::default(),
+        }
+    }
+}
+
+impl SankeyDiagramConfigurationBuilder {
+    #[must_use]
+    /// Sets whether to display the values of the flows next to the nodes.
+    pub fn show_values(mut self, show_values: bool) -> Self {
+        self.show_values = show_values;
+        self
+    }
+
+    #[must_use]
+    /// Sets how the links are colored.
+    pub fn link_color(mut self, link_color: SankeyLinkColor) -> Self {
+        self.link_color = link_color;
+        self
+    }
+
+    #[must_use]
+    /// Sets how the nodes are aligned.
+    pub fn node_alignment(mut self, node_alignment: SankeyNodeAlignment) -> Self {
+        self.node_alignment = node_alignment;
+        self
+    }
+
+    /// Sets the theme to use for the diagram.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.generic = self.generic.theme(theme);
+        self
+    }
+
+    /// Sets the look to use for the diagram.
+    #[must_use]
+    pub fn look(mut self, look: Look) -> Self {
+        self.generic = self.generic.look(look);
+        self
+    }
+}
+
+impl TryFrom<SankeyDiagramConfigurationBuilder> for SankeyDiagramConfiguration {
+    type Error = ConfigError;
+
+    fn try_from(builder: SankeyDiagramConfigurationBuilder) -> Result<Self, Self::Error> {
+        Ok(SankeyDiagramConfiguration {
+            generic: builder.generic.try_into()?,
+            show_values: builder.show_values,
+            link_color: builder.link_color,
+            node_alignment: builder.node_alignment,
+        })
+    }
+}
+
+impl ConfigurationBuilder for SankeyDiagramConfigurationBuilder {
+    type Configuration = SankeyDiagramConfiguration;
+    type Error = ConfigError;
+
+    fn build(self) -> Result<Self::Configuration, Self::Error> {
+        self.try_into()
+    }
+
+    fn title<S: ToString>(mut self, title: S) -> Result<Self, Self::Error> {
+        self.generic = self.generic.title(title)?;
+        Ok(self)
+    }
+
+    fn direction(mut self, direction: Direction) -> Self {
+        self.generic = self.generic.direction(direction);
+        self
+    }
+
+    fn renderer(mut self, renderer: Renderer) -> Self {
+        self.generic = self.generic.renderer(renderer);
+        self
+    }
+}
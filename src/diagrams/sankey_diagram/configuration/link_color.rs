@@ -0,0 +1,32 @@
+//! Submodule defining how the links of sankey diagrams are colored.
+
+use core::fmt::{self, Display};
+
+use crate::shared::Color;
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents how the links of a sankey diagram are colored.
+pub enum SankeyLinkColor {
+    /// Links take the color of their source node.
+    Source,
+    /// Links take the color of their target node.
+    Target,
+    /// Links fade from the color of their source node to the color of their
+    /// target node.
+    #[default]
+    Gradient,
+    /// All links have the provided color.
+    Color(Color),
+}
+
+impl Display for SankeyLinkColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SankeyLinkColor::Source => write!(f, "source"),
+            SankeyLinkColor::Target => write!(f, "target"),
+            SankeyLinkColor::Gradient => write!(f, "gradient"),
+            SankeyLinkColor::Color(color) => write!(f, "\"{}\"", color.to_hex()),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Submodule defining how the nodes of sankey diagrams are aligned.
+
+use core::fmt::{self, Display};
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents how the nodes of a sankey diagram are horizontally aligned.
+pub enum SankeyNodeAlignment {
+    /// Nodes without outgoing flows are moved to the right edge.
+    #[default]
+    Justify,
+    /// Nodes are aligned to the left.
+    Left,
+    /// Nodes are aligned to the right.
+    Right,
+    /// Nodes are centered between their sources and targets.
+    Center,
+}
+
+impl Display for SankeyNodeAlignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SankeyNodeAlignment::Justify => write!(f, "justify"),
+            SankeyNodeAlignment::Left => write!(f, "left"),
+            SankeyNodeAlignment::Right => write!(f, "right"),
+            SankeyNodeAlignment::Center => write!(f, "center"),
+        }
+    }
+}
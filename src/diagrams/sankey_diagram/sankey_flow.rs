@@ -0,0 +1,183 @@
+//! Submodule defining a flow between two nodes of a sankey diagram in
+//! Mermaid syntax.
+
+use alloc::string::String;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+};
+
+use crate::errors::SankeyDiagramError;
+
+/// Validates the name of a node.
+fn validate_node_name(name: impl Into<String>) -> Result<String, SankeyDiagramError> {
+    let name = name.into();
+    if name.trim().is_empty() {
+        return Err(SankeyDiagramError::EmptyNodeName);
+    }
+    if name.contains(['\n', '\r']) {
+        return Err(SankeyDiagramError::InvalidNodeName(name));
+    }
+    Ok(name)
+}
+
+/// Writes a node name as a CSV field, quoting it when it contains commas,
+/// double quotes or surrounding whitespace.
+fn fmt_csv_field(f: &mut fmt::Formatter<'_>, field: &str) -> fmt::Result {
+    if field.contains([',', '"']) || field.trim() != field {
+        write!(f, "\"{}\"", field.replace('"', "\"\""))
+    } else {
+        write!(f, "{field}")
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a flow of a sankey diagram, i.e. a strictly positive value
+/// going from a source node to a target node.
+///
+/// Flows are written as CSV rows, so node names containing commas or double
+/// quotes are quoted. As the value is guaranteed to be finite, flows are
+/// totally ordered and may be hashed.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let flow = SankeyFlow::new("Extract, dried", "Fraction \"A\"", 12.5)?;
+///     assert_eq!(flow.to_string(), "\"Extract, dried\",\"Fraction \"\"A\"\"\",12.5");
+///
+///     assert!(SankeyFlow::new("Extract", "Waste", 0.0).is_err());
+///     assert!(SankeyFlow::new("Extract", "Extract", 1.0).is_err());
+///     Ok(())
+/// }
+/// ```
+pub struct SankeyFlow {
+    /// Name of the source node.
+    source: String,
+    /// Name of the target node.
+    target: String,
+    /// Value of the flow.
+    value: f64,
+}
+
+impl SankeyFlow {
+    /// Creates a new flow.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The name of the source node.
+    /// * `target` - The name of the target node.
+    /// * `value` - The value of the flow.
+    ///
+    /// # Errors
+    ///
+    /// * If a node name is empty or contains line breaks.
+    /// * If the source and target nodes are the same.
+    /// * If the value is not strictly positive, infinite or not a number.
+    pub fn new(
+        source: impl Into<String>,
+        target: impl Into<String>,
+        value: f64,
+    ) -> Result<Self, SankeyDiagramError> {
+        let source = validate_node_name(source)?;
+        let target = validate_node_name(target)?;
+        if source == target {
+            return Err(SankeyDiagramError::SelfFlow(source));
+        }
+        if !value.is_finite() {
+            return Err(SankeyDiagramError::NonFiniteFlowValue(source));
+        }
+        if value <= 0.0 {
+            return Err(SankeyDiagramError::NonPositiveFlowValue(source));
+        }
+        Ok(Self { source, target, value })
+    }
+
+    #[must_use]
+    /// Returns the name of the source node.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    #[must_use]
+    /// Returns the name of the target node.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    #[must_use]
+    /// Returns the value of the flow.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl PartialEq for SankeyFlow {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SankeyFlow {}
+
+impl PartialOrd for SankeyFlow {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SankeyFlow {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.source
+            .cmp(&other.source)
+            .then_with(|| self.target.cmp(&other.target))
+            .then_with(|| self.value.total_cmp(&other.value))
+    }
+}
+
+impl Hash for SankeyFlow {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.source.hash(state);
+        self.target.hash(state);
+        self.value.to_bits().hash(state);
+    }
+}
+
+impl Display for SankeyFlow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_csv_field(f, &self.source)?;
+        write!(f, ",")?;
+        fmt_csv_field(f, &self.target)?;
+        write!(f, ",{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box, string::ToString};
+
+    use super::*;
+
+    #[test]
+    fn test_sankey_flow_quoting() -> Result<(), Box<dyn core::error::Error>> {
+        assert_eq!(SankeyFlow::new("Solar", "Grid", 2.0)?.to_string(), "Solar,Grid,2");
+        assert_eq!(SankeyFlow::new(" Solar", "Grid", 0.5)?.to_string(), "\" Solar\",Grid,0.5");
+        assert_eq!(
+            SankeyFlow::new("Solar", "Grid", -1.0),
+            Err(SankeyDiagramError::NonPositiveFlowValue("Solar".to_owned()))
+        );
+        assert_eq!(
+            SankeyFlow::new("Solar", "Grid", f64::INFINITY),
+            Err(SankeyDiagramError::NonFiniteFlowValue("Solar".to_owned()))
+        );
+        assert_eq!(
+            SankeyFlow::new("Solar", "A\nB", 1.0),
+            Err(SankeyDiagramError::InvalidNodeName("A\nB".to_owned()))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,265 @@
+//! Submodule providing structs for creating sequence diagrams in Mermaid
+//! syntax.
+
+mod builder;
+mod configuration;
+mod sequence_block;
+mod sequence_box;
+mod sequence_message;
+mod sequence_note;
+mod sequence_participant;
+use alloc::{rc::Rc, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::SequenceDiagramBuilder;
+pub use configuration::{SequenceDiagramConfiguration, SequenceDiagramConfigurationBuilder};
+pub use sequence_block::{SequenceBlock, SequenceBlockKind, SequenceBranch, SequenceItem};
+pub use sequence_box::{SequenceBox, SequenceBoxBuilder};
+pub use sequence_message::{SequenceMessage, SequenceMessageBuilder};
+pub use sequence_note::{NotePosition, SequenceNote};
+pub use sequence_participant::{ParticipantKind, SequenceParticipant, SequenceParticipantBuilder};
+
+use crate::{
+    shared::{StyleClass, generic_diagram::GenericDiagram},
+    traits::{Diagram, TabbedDisplay},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a sequence diagram in Mermaid syntax.
+///
+/// Participants are the nodes of the diagram and messages are its edges.
+/// Messages, activations, notes and blocks are rendered in the order in which
+/// they were added to the builder.
+///
+/// Mermaid does not support style classes in sequence diagrams, so any style
+/// class registered in the builder is not rendered.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = SequenceDiagramBuilder::default();
+///     let alice = builder.node(SequenceParticipantBuilder::default().label("Alice")?)?;
+///     let bob = builder.node(SequenceParticipantBuilder::default().label("Bob")?)?;
+///
+///     builder.open_block(SequenceBlockKind::Loop("Every minute".to_owned()));
+///     builder.edge(
+///         SequenceMessageBuilder::default()
+///             .source(alice.clone())?
+///             .destination(bob.clone())?
+///             .label("Ping")?
+///             .right_arrow_shape(ArrowShape::Normal)?,
+///     )?;
+///     builder.close_block()?;
+///
+///     let diagram = SequenceDiagram::from(builder);
+///     assert_eq!(
+///         diagram.to_string(),
+///         "sequenceDiagram\n  participant v0 as Alice\n  participant v1 as Bob\n  loop Every minute\n    v0->>v1: Ping\n  end\n"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct SequenceDiagram {
+    /// Underlying generic diagram holding participants and messages.
+    generic: GenericDiagram<SequenceParticipant, SequenceMessage, SequenceDiagramConfiguration>,
+    /// Boxes grouping participants.
+    boxes: Vec<Rc<SequenceBox>>,
+    /// Ordered statements of the diagram.
+    items: Vec<SequenceItem>,
+}
+
+impl SequenceDiagram {
+    /// Returns an iterator over the boxes grouping participants.
+    pub fn boxes(&self) -> impl Iterator<Item = &SequenceBox> {
+        self.boxes.iter().map(AsRef::as_ref)
+    }
+
+    #[must_use]
+    /// Returns the ordered top-level statements of the diagram.
+    pub fn items(&self) -> &[SequenceItem] {
+        &self.items
+    }
+}
+
+impl Diagram for SequenceDiagram {
+    type Builder = SequenceDiagramBuilder;
+    type Configuration = SequenceDiagramConfiguration;
+    type Edge = SequenceMessage;
+    type Node = SequenceParticipant;
+
+    fn configuration(&self) -> &Self::Configuration {
+        self.generic.configuration()
+    }
+
+    fn edges(&self) -> impl Iterator<Item = &Self::Edge> {
+        self.generic.edges()
+    }
+
+    fn get_node_by_id(&self, id: u64) -> Option<Rc<Self::Node>> {
+        self.generic.get_node_by_id(id)
+    }
+
+    fn get_style_class_by_name(&self, name: &str) -> Option<Rc<StyleClass>> {
+        self.generic.get_style_class_by_name(name)
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = &Self::Node> {
+        self.generic.nodes()
+    }
+
+    fn style_classes(&self) -> impl Iterator<Item = &StyleClass> {
+        self.generic.style_classes()
+    }
+}
+
+impl Display for SequenceDiagram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for SequenceDiagram {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        write!(f, "{}", self.configuration())?;
+        writeln!(f, "{indent}sequenceDiagram")?;
+        if self.configuration().autonumber() {
+            writeln!(f, "{indent}  autonumber")?;
+        }
+
+        // Participants are declared in insertion order, and a box is declared
+        // as a whole when its first participant is reached.
+        let mut declared_boxes: Vec<&Rc<SequenceBox>> = Vec::new();
+        for participant in self.nodes() {
+            match self.boxes.iter().find(|b| b.participants().any(|p| p.as_ref() == participant)) {
+                Some(participant_box) => {
+                    if !declared_boxes.contains(&participant_box) {
+                        participant_box.fmt_tabbed(f, tab_count + 1)?;
+                        declared_boxes.push(participant_box);
+                    }
+                }
+                None => participant.fmt_tabbed(f, tab_count + 1)?,
+            }
+        }
+
+        for item in &self.items {
+            item.fmt_tabbed(f, tab_count + 1)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        borrow::ToOwned,
+        boxed::Box,
+        string::{String, ToString},
+    };
+
+    use super::*;
+    use crate::{
+        shared::{ArrowShape, Color, LineStyle},
+        traits::{ConfigurationBuilder, DiagramBuilder, EdgeBuilder, NodeBuilder},
+    };
+
+    #[test]
+    fn test_sequence_diagram_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = SequenceDiagramBuilder::default().configuration(
+            SequenceDiagramConfigurationBuilder::default().title("Checkout")?.autonumber(true),
+        )?;
+        let user = builder.node(
+            SequenceParticipantBuilder::default().label("User")?.kind(ParticipantKind::Actor),
+        )?;
+        let api = builder.node(SequenceParticipantBuilder::default().label("API")?)?;
+        let db = builder.node(SequenceParticipantBuilder::default().label("Database")?)?;
+        builder.participant_box(
+            SequenceBoxBuilder::default()
+                .label("Backend")?
+                .color(Color::from((200, 220, 255)))
+                .participant(api.clone())?
+                .participant(db.clone())?,
+        )?;
+
+        builder.edge(
+            SequenceMessageBuilder::default()
+                .source(user.clone())?
+                .destination(api.clone())?
+                .label("Place order")?
+                .right_arrow_shape(ArrowShape::Normal)?,
+        )?;
+        builder.activate(&api)?;
+        builder.open_block(SequenceBlockKind::Alt("in stock".to_owned()));
+        builder.edge(
+            SequenceMessageBuilder::default()
+                .source(api.clone())?
+                .destination(db.clone())?
+                .label("Reserve")?
+                .right_arrow_shape(ArrowShape::Sharp)?,
+        )?;
+        builder.block_branch("out of stock")?;
+        builder
+            .note(SequenceNote::new(NotePosition::Spanning(api.clone(), db.clone()), "Retry"))?;
+        builder.close_block()?;
+        builder.edge(
+            SequenceMessageBuilder::default()
+                .source(api.clone())?
+                .destination(user.clone())?
+                .label("Done")?
+                .line_style(LineStyle::Dashed)
+                .right_arrow_shape(ArrowShape::Normal)?,
+        )?;
+        builder.deactivate(&api)?;
+
+        let diagram = SequenceDiagram::from(builder);
+        assert_eq!(diagram.boxes().count(), 1);
+        assert_eq!(diagram.items().len(), 5);
+        assert_eq!(
+            diagram.to_string(),
+            r"---
+title: Checkout
+config:
+  theme: default
+  look: classic
+---
+sequenceDiagram
+  autonumber
+  actor v0 as User
+  box rgb(200, 220, 255) Backend
+    participant v1 as API
+    participant v2 as Database
+  end
+  v0->>v1: Place order
+  activate v1
+  alt in stock
+    v1-)v2: Reserve
+  else out of stock
+    Note over v1,v2: Retry
+  end
+  v1-->>v0: Done
+  deactivate v1
+"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sequence_diagram_unclosed_blocks() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = SequenceDiagramBuilder::default();
+        let alice = builder.node(SequenceParticipantBuilder::default().label("Alice")?)?;
+        builder.open_block(SequenceBlockKind::Par("first".to_owned()));
+        builder.open_block(SequenceBlockKind::Critical(String::new()));
+        builder.note(SequenceNote::new(NotePosition::RightOf(alice), "Inner"))?;
+
+        let diagram = SequenceDiagram::from(builder);
+        assert_eq!(
+            diagram.to_string(),
+            "sequenceDiagram\n  participant v0 as Alice\n  par first\n    critical\n      Note right of v0: Inner\n    end\n  end\n"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,344 @@
+//! Submodule providing a builder for sequence diagrams in Mermaid syntax.
+
+use alloc::{borrow::ToOwned, rc::Rc, string::String, vec::Vec};
+
+use crate::{
+    diagrams::sequence_diagram::{
+        SequenceBlock, SequenceBlockKind, SequenceBox, SequenceBoxBuilder, SequenceDiagram,
+        SequenceDiagramConfiguration, SequenceDiagramConfigurationBuilder, SequenceItem,
+        SequenceMessage, SequenceMessageBuilder, SequenceNote, SequenceParticipant,
+        SequenceParticipantBuilder,
+    },
+    errors::SequenceDiagramError,
+    shared::{StyleClass, StyleClassBuilder, generic_diagram::GenericDiagramBuilder},
+    traits::{DiagramBuilder, Node},
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a builder for a sequence diagram in Mermaid syntax.
+///
+/// Statements are appended to the innermost open block, if any, or to the
+/// top level of the diagram otherwise. Blocks which are still open when the
+/// builder is converted into a [`SequenceDiagram`] are closed automatically.
+pub struct SequenceDiagramBuilder {
+    /// Underlying generic diagram builder holding participants and messages.
+    generic:
+        GenericDiagramBuilder<SequenceParticipant, SequenceMessage, SequenceDiagramConfiguration>,
+    /// Boxes grouping participants.
+    boxes: Vec<Rc<SequenceBox>>,
+    /// Ordered top-level statements of the diagram.
+    items: Vec<SequenceItem>,
+    /// Stack of the currently open blocks, from outermost to innermost.
+    open_blocks: Vec<SequenceBlock>,
+    /// Currently active participants, repeated once per nested activation.
+    active_participants: Vec<Rc<SequenceParticipant>>,
+}
+
+impl From<SequenceDiagramBuilder> for SequenceDiagram {
+    fn from(mut builder: SequenceDiagramBuilder) -> Self {
+        while builder.close_block().is_ok() {}
+        SequenceDiagram {
+            generic: builder.generic.into(),
+            boxes: builder.boxes,
+            items: builder.items,
+        }
+    }
+}
+
+impl SequenceDiagramBuilder {
+    /// Appends the provided statement to the innermost open block, or to the
+    /// top level of the diagram when no block is open.
+    fn push_item(&mut self, item: SequenceItem) {
+        match self.open_blocks.last_mut() {
+            Some(block) => block.push(item),
+            None => self.items.push(item),
+        }
+    }
+
+    /// Returns an error if the provided participant is not part of the diagram.
+    fn ensure_participant(
+        &self,
+        participant: &Rc<SequenceParticipant>,
+    ) -> Result<(), SequenceDiagramError> {
+        if self.generic.nodes().any(|p| p == participant) {
+            Ok(())
+        } else {
+            Err(SequenceDiagramError::ParticipantNotFound(participant.label().to_owned()))
+        }
+    }
+
+    /// Builds and adds a box grouping participants of the diagram.
+    ///
+    /// # Arguments
+    ///
+    /// * `participant_box` - The builder of the box to add.
+    ///
+    /// # Errors
+    ///
+    /// * If the box cannot be built.
+    /// * If any of the participants is not part of the diagram.
+    /// * If any of the participants already belongs to another box.
+    pub fn participant_box(
+        &mut self,
+        participant_box: SequenceBoxBuilder,
+    ) -> Result<Rc<SequenceBox>, crate::errors::Error> {
+        let participant_box = participant_box.build()?;
+        for participant in participant_box.participants() {
+            self.ensure_participant(participant)?;
+            if self.boxes.iter().any(|b| b.participants().any(|p| p == participant)) {
+                return Err(SequenceDiagramError::ParticipantAlreadyBoxed(
+                    participant.label().to_owned(),
+                )
+                .into());
+            }
+        }
+        let rc = Rc::new(participant_box);
+        self.boxes.push(rc.clone());
+        Ok(rc)
+    }
+
+    /// Activates the provided participant.
+    ///
+    /// # Errors
+    ///
+    /// * If the participant is not part of the diagram.
+    pub fn activate(
+        &mut self,
+        participant: &Rc<SequenceParticipant>,
+    ) -> Result<(), crate::errors::Error> {
+        self.ensure_participant(participant)?;
+        self.active_participants.push(participant.clone());
+        self.push_item(SequenceItem::Activate(participant.clone()));
+        Ok(())
+    }
+
+    /// Deactivates the provided participant.
+    ///
+    /// # Errors
+    ///
+    /// * If the participant is not part of the diagram.
+    /// * If the participant is not currently active.
+    pub fn deactivate(
+        &mut self,
+        participant: &Rc<SequenceParticipant>,
+    ) -> Result<(), crate::errors::Error> {
+        self.ensure_participant(participant)?;
+        let Some(position) = self.active_participants.iter().rposition(|p| p == participant) else {
+            return Err(
+                SequenceDiagramError::InactiveParticipant(participant.label().to_owned()).into()
+            );
+        };
+        self.active_participants.remove(position);
+        self.push_item(SequenceItem::Deactivate(participant.clone()));
+        Ok(())
+    }
+
+    /// Adds a note to the diagram.
+    ///
+    /// # Errors
+    ///
+    /// * If any of the participants the note refers to is not part of the
+    ///   diagram.
+    pub fn note(&mut self, note: SequenceNote) -> Result<(), crate::errors::Error> {
+        for participant in note.position().participants() {
+            self.ensure_participant(participant)?;
+        }
+        self.push_item(SequenceItem::Note(note));
+        Ok(())
+    }
+
+    /// Opens a new block, nested in the currently open block if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of block to open.
+    pub fn open_block(&mut self, kind: SequenceBlockKind) {
+        self.open_blocks.push(SequenceBlock::new(kind));
+    }
+
+    /// Starts a new branch in the innermost open block, i.e. an `else`
+    /// section of an `alt` block, an `and` section of a `par` block or an
+    /// `option` section of a `critical` block.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The label of the new branch.
+    ///
+    /// # Errors
+    ///
+    /// * If no block is currently open.
+    /// * If the innermost open block does not support multiple branches.
+    pub fn block_branch(&mut self, label: impl Into<String>) -> Result<(), crate::errors::Error> {
+        let block = self.open_blocks.last_mut().ok_or(SequenceDiagramError::NoOpenBlock)?;
+        block.branch(label)?;
+        Ok(())
+    }
+
+    /// Closes the innermost open block.
+    ///
+    /// # Errors
+    ///
+    /// * If no block is currently open.
+    pub fn close_block(&mut self) -> Result<(), crate::errors::Error> {
+        let block = self.open_blocks.pop().ok_or(SequenceDiagramError::NoOpenBlock)?;
+        self.push_item(SequenceItem::Block(block));
+        Ok(())
+    }
+}
+
+impl DiagramBuilder for SequenceDiagramBuilder {
+    type Configuration = SequenceDiagramConfiguration;
+    type ConfigurationBuilder = SequenceDiagramConfigurationBuilder;
+    type Diagram = SequenceDiagram;
+    type Edge = SequenceMessage;
+    type EdgeBuilder = SequenceMessageBuilder;
+    type Node = SequenceParticipant;
+    type NodeBuilder = SequenceParticipantBuilder;
+    type Error = crate::errors::Error;
+
+    fn configuration(
+        mut self,
+        configuration: Self::ConfigurationBuilder,
+    ) -> Result<Self, Self::Error> {
+        self.generic = self.generic.configuration(configuration)?;
+        Ok(self)
+    }
+
+    fn edge(&mut self, edge: Self::EdgeBuilder) -> Result<Rc<Self::Edge>, Self::Error> {
+        let message = self.generic.edge(edge)?;
+        self.push_item(SequenceItem::Message(message.clone()));
+        Ok(message)
+    }
+
+    fn get_node_by_id(&self, id: u64) -> Option<Rc<Self::Node>> {
+        self.generic.get_node_by_id(id)
+    }
+
+    fn node(&mut self, node: Self::NodeBuilder) -> Result<Rc<Self::Node>, Self::Error> {
+        self.generic.node(node)
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = &Rc<Self::Node>> + '_ {
+        self.generic.nodes()
+    }
+
+    fn number_of_edges(&self) -> usize {
+        self.generic.number_of_edges()
+    }
+
+    fn number_of_nodes(&self) -> usize {
+        self.generic.number_of_nodes()
+    }
+
+    fn style_class(
+        &mut self,
+        style_class: StyleClassBuilder,
+    ) -> Result<Rc<StyleClass>, Self::Error> {
+        self.generic.style_class(style_class)
+    }
+
+    fn get_style_class_by_name(&self, name: &str) -> Option<Rc<StyleClass>> {
+        self.generic.get_style_class_by_name(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+
+    use super::*;
+    use crate::{
+        diagrams::sequence_diagram::NotePosition,
+        errors::Error,
+        shared::ArrowShape,
+        traits::{EdgeBuilder, NodeBuilder},
+    };
+
+    #[test]
+    fn test_sequence_diagram_builder() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = SequenceDiagramBuilder::default();
+        let alice = builder.node(SequenceParticipantBuilder::default().label("Alice")?)?;
+        let bob = builder.node(SequenceParticipantBuilder::default().label("Bob")?)?;
+        assert_eq!(builder.number_of_nodes(), 2);
+        assert!(builder.get_node_by_id(bob.id()).is_some());
+
+        builder.edge(
+            SequenceMessageBuilder::default()
+                .source(alice.clone())?
+                .destination(bob.clone())?
+                .right_arrow_shape(ArrowShape::Normal)?,
+        )?;
+        assert_eq!(builder.number_of_edges(), 1);
+
+        builder.activate(&bob)?;
+        builder.activate(&bob)?;
+        builder.deactivate(&bob)?;
+        builder.deactivate(&bob)?;
+        assert_eq!(
+            builder.deactivate(&bob),
+            Err(Error::from(SequenceDiagramError::InactiveParticipant("Bob".to_owned())))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sequence_diagram_builder_blocks() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = SequenceDiagramBuilder::default();
+        assert_eq!(builder.close_block(), Err(Error::from(SequenceDiagramError::NoOpenBlock)));
+        assert_eq!(
+            builder.block_branch("else"),
+            Err(Error::from(SequenceDiagramError::NoOpenBlock))
+        );
+
+        builder.open_block(SequenceBlockKind::Loop("forever".to_owned()));
+        assert_eq!(
+            builder.block_branch("and"),
+            Err(Error::from(SequenceDiagramError::UnsupportedBranch("loop".to_owned())))
+        );
+        builder.open_block(SequenceBlockKind::Par("first".to_owned()));
+        builder.block_branch("second")?;
+        builder.close_block()?;
+        builder.close_block()?;
+
+        let diagram = SequenceDiagram::from(builder);
+        let [SequenceItem::Block(outer)] = diagram.items() else {
+            panic!("Expected a single top-level block");
+        };
+        let [SequenceItem::Block(inner)] = outer.items() else {
+            panic!("Expected a single nested block");
+        };
+        assert_eq!(inner.branches().len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sequence_diagram_builder_unknown_participants()
+    -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = SequenceDiagramBuilder::default();
+        let alice = builder.node(SequenceParticipantBuilder::default().label("Alice")?)?;
+        let stranger =
+            Rc::new(SequenceParticipantBuilder::default().label("Stranger")?.id(42).build()?);
+        let unknown = Error::from(SequenceDiagramError::ParticipantNotFound("Stranger".to_owned()));
+
+        assert_eq!(builder.activate(&stranger), Err(unknown.clone()));
+        assert_eq!(
+            builder.note(SequenceNote::new(
+                NotePosition::Spanning(alice.clone(), stranger.clone()),
+                "Hi"
+            )),
+            Err(unknown.clone())
+        );
+        assert_eq!(
+            builder.participant_box(SequenceBoxBuilder::default().participant(stranger)?),
+            Err(unknown)
+        );
+
+        builder.participant_box(SequenceBoxBuilder::default().participant(alice.clone())?)?;
+        assert_eq!(
+            builder.participant_box(SequenceBoxBuilder::default().participant(alice)?),
+            Err(Error::from(SequenceDiagramError::ParticipantAlreadyBoxed("Alice".to_owned())))
+        );
+        Ok(())
+    }
+}
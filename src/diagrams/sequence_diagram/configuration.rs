@@ -0,0 +1,94 @@
+//! Submodule defining configuration specifically for sequence diagrams in
+//! Mermaid.
+
+mod builder;
+
+use core::fmt::Display;
+
+pub use builder::SequenceDiagramConfigurationBuilder;
+
+use crate::{
+    shared::{
+        Direction, Renderer,
+        generic_configuration::{GenericConfiguration, Look, Theme},
+    },
+    traits::Configuration,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the configuration for a sequence diagram in Mermaid syntax.
+pub struct SequenceDiagramConfiguration {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfiguration,
+    /// Whether to automatically number the messages.
+    autonumber: bool,
+}
+
+impl SequenceDiagramConfiguration {
+    #[must_use]
+    /// Returns whether the messages are automatically numbered.
+    pub fn autonumber(&self) -> bool {
+        self.autonumber
+    }
+}
+
+impl Display for SequenceDiagramConfiguration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.generic.fmt_frontmatter(f, true, &[])
+    }
+}
+
+impl Configuration for SequenceDiagramConfiguration {
+    type Builder = SequenceDiagramConfigurationBuilder;
+
+    fn title(&self) -> Option<&str> {
+        self.generic.title()
+    }
+
+    fn direction(&self) -> Direction {
+        self.generic.direction()
+    }
+
+    fn renderer(&self) -> Renderer {
+        self.generic.renderer()
+    }
+
+    fn theme(&self) -> Theme {
+        self.generic.theme()
+    }
+
+    fn look(&self) -> Look {
+        self.generic.look()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format};
+
+    use super::*;
+    use crate::traits::ConfigurationBuilder;
+
+    #[test]
+    fn test_sequence_diagram_configuration_display_default() {
+        let config = SequenceDiagramConfiguration::default();
+        assert_eq!(format!("{config}"), "");
+    }
+
+    #[test]
+    fn test_sequence_diagram_configuration_display_full() -> Result<(), Box<dyn core::error::Error>>
+    {
+        let config = SequenceDiagramConfigurationBuilder::default()
+            .title("Handshake")?
+            .theme(Theme::Dark)
+            .look(Look::HandDrawn)
+            .build()?;
+
+        assert_eq!(
+            format!("{config}"),
+            "---\ntitle: Handshake\nconfig:\n  theme: dark\n  look: handDrawn\n---\n"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+//! Submodule providing a builder struct for the configuration of sequence
+//! diagrams in Mermaid syntax.
+
+use alloc::string::ToString;
+
+use crate::{
+    diagrams::sequence_diagram::configuration::SequenceDiagramConfiguration,
+    errors::ConfigError,
+    shared::generic_configuration::{
+        Direction, GenericConfigurationBuilder, Look, Renderer, Theme,
+    },
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating sequence diagram configurations.
+pub struct SequenceDiagramConfigurationBuilder {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfigurationBuilder,
+    /// Whether to automatically number the messages.
+    autonumber: bool,
+}
+
+impl SequenceDiagramConfigurationBuilder {
+    #[must_use]
+    /// Sets whether to automatically number the messages.
+    pub fn autonumber(mut self, autonumber: bool) -> Self {
+        self.autonumber = autonumber;
+        self
+    }
+
+    /// Sets the theme to use for the diagram.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.generic = self.generic.theme(theme);
+        self
+    }
+
+    /// Sets the look to use for the diagram.
+    #[must_use]
+    pub fn look(mut self, look: Look) -> Self {
+        self.generic = self.generic.look(look);
+        self
+    }
+}
+
+impl TryFrom<SequenceDiagramConfigurationBuilder> for SequenceDiagramConfiguration {
+    type Error = ConfigError;
+
+    fn try_from(builder: SequenceDiagramConfigurationBuilder) -> Result<Self, Self::Error> {
+        Ok(SequenceDiagramConfiguration {
+            generic: builder.generic.try_into()?,
+            autonumber: builder.autonumber,
+        })
+    }
+}
+
+impl ConfigurationBuilder for SequenceDiagramConfigurationBuilder {
+    type Configuration = SequenceDiagramConfiguration;
+    type Error = ConfigError;
+
+    fn build(self) -> Result<Self::Configuration, Self::Error> {
+        self.try_into()
+    }
+
+    fn title<S: ToString>(mut self, title: S) -> Result<Self, Self::Error> {
+        self.generic = self.generic.title(title)?;
+        Ok(self)
+    }
+
+    fn direction(mut self, direction: Direction) -> Self {
+        self.generic = self.generic.direction(direction);
+        self
+    }
+
+    fn renderer(mut self, renderer: Renderer) -> Self {
+        self.generic = self.generic.renderer(renderer);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+
+    use super::*;
+    use crate::traits::Configuration;
+
+    #[test]
+    fn test_sequence_diagram_configuration_builder() -> Result<(), Box<dyn core::error::Error>> {
+        let config = SequenceDiagramConfigurationBuilder::default()
+            .title("Handshake")?
+            .autonumber(true)
+            .theme(Theme::Forest)
+            .look(Look::Neo)
+            .build()?;
+
+        assert!(config.autonumber());
+        assert_eq!(config.title(), Some("Handshake"));
+        assert_eq!(config.theme(), Theme::Forest);
+        assert_eq!(config.look(), Look::Neo);
+        assert!(matches!(
+            SequenceDiagramConfigurationBuilder::default().title(""),
+            Err(ConfigError::EmptyTitle)
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,294 @@
+//! Submodule defining the ordered statements of a sequence diagram, including
+//! the nested blocks such as `loop`, `alt` or `par`.
+
+use alloc::{
+    rc::Rc,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Display};
+
+use crate::{
+    diagrams::sequence_diagram::{
+        sequence_message::SequenceMessage, sequence_note::SequenceNote,
+        sequence_participant::SequenceParticipant,
+    },
+    errors::SequenceDiagramError,
+    shared::{Color, NODE_LETTER},
+    traits::{Node, TabbedDisplay},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the kind of a block in a sequence diagram, together with the
+/// label of its first branch.
+pub enum SequenceBlockKind {
+    /// A `loop` block, repeating its content.
+    Loop(String),
+    /// An `alt` block, whose branches are separated by `else`.
+    Alt(String),
+    /// An `opt` block, whose content is optional.
+    Opt(String),
+    /// A `par` block, whose branches are separated by `and`.
+    Par(String),
+    /// A `critical` block, whose branches are separated by `option`.
+    Critical(String),
+    /// A `break` block, interrupting the sequence.
+    Break(String),
+    /// A `rect` block, highlighting its content with a background color.
+    Rect(Color),
+}
+
+impl SequenceBlockKind {
+    #[must_use]
+    /// Returns the keyword opening the block.
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            SequenceBlockKind::Loop(_) => "loop",
+            SequenceBlockKind::Alt(_) => "alt",
+            SequenceBlockKind::Opt(_) => "opt",
+            SequenceBlockKind::Par(_) => "par",
+            SequenceBlockKind::Critical(_) => "critical",
+            SequenceBlockKind::Break(_) => "break",
+            SequenceBlockKind::Rect(_) => "rect",
+        }
+    }
+
+    #[must_use]
+    /// Returns the keyword separating the branches of the block, if the block
+    /// supports multiple branches.
+    pub fn branch_keyword(&self) -> Option<&'static str> {
+        match self {
+            SequenceBlockKind::Alt(_) => Some("else"),
+            SequenceBlockKind::Par(_) => Some("and"),
+            SequenceBlockKind::Critical(_) => Some("option"),
+            _ => None,
+        }
+    }
+}
+
+impl Display for SequenceBlockKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.keyword())?;
+        match self {
+            SequenceBlockKind::Rect(color) => write!(f, " {}", color.to_css_rgb()),
+            SequenceBlockKind::Loop(label)
+            | SequenceBlockKind::Alt(label)
+            | SequenceBlockKind::Opt(label)
+            | SequenceBlockKind::Par(label)
+            | SequenceBlockKind::Critical(label)
+            | SequenceBlockKind::Break(label) => {
+                if label.is_empty() {
+                    Ok(())
+                } else {
+                    write!(f, " {label}")
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents an additional branch of a block, such as an `else` section of
+/// an `alt` block.
+pub struct SequenceBranch {
+    /// The label of the branch.
+    label: String,
+    /// The statements in the branch.
+    items: Vec<SequenceItem>,
+}
+
+impl SequenceBranch {
+    #[must_use]
+    /// Returns the label of the branch.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the statements in the branch.
+    pub fn items(&self) -> &[SequenceItem] {
+        &self.items
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a block in a sequence diagram, grouping statements.
+pub struct SequenceBlock {
+    /// The kind of the block.
+    kind: SequenceBlockKind,
+    /// The statements in the first branch of the block.
+    items: Vec<SequenceItem>,
+    /// The additional branches of the block.
+    branches: Vec<SequenceBranch>,
+}
+
+impl SequenceBlock {
+    /// Creates a new empty block of the provided kind.
+    pub(crate) fn new(kind: SequenceBlockKind) -> Self {
+        Self { kind, items: Vec::new(), branches: Vec::new() }
+    }
+
+    /// Appends a statement to the last branch of the block.
+    pub(crate) fn push(&mut self, item: SequenceItem) {
+        match self.branches.last_mut() {
+            Some(branch) => branch.items.push(item),
+            None => self.items.push(item),
+        }
+    }
+
+    /// Starts a new branch in the block.
+    ///
+    /// # Errors
+    ///
+    /// * If the block does not support multiple branches.
+    pub(crate) fn branch(&mut self, label: impl Into<String>) -> Result<(), SequenceDiagramError> {
+        if self.kind.branch_keyword().is_none() {
+            return Err(SequenceDiagramError::UnsupportedBranch(self.kind.keyword().to_string()));
+        }
+        self.branches.push(SequenceBranch { label: label.into(), items: Vec::new() });
+        Ok(())
+    }
+
+    #[must_use]
+    /// Returns the kind of the block.
+    pub fn kind(&self) -> &SequenceBlockKind {
+        &self.kind
+    }
+
+    #[must_use]
+    /// Returns the statements in the first branch of the block.
+    pub fn items(&self) -> &[SequenceItem] {
+        &self.items
+    }
+
+    #[must_use]
+    /// Returns the additional branches of the block.
+    pub fn branches(&self) -> &[SequenceBranch] {
+        &self.branches
+    }
+}
+
+impl Display for SequenceBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for SequenceBlock {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        writeln!(f, "{indent}{}", self.kind)?;
+        for item in &self.items {
+            item.fmt_tabbed(f, tab_count + 1)?;
+        }
+        if let Some(keyword) = self.kind.branch_keyword() {
+            for branch in &self.branches {
+                if branch.label.is_empty() {
+                    writeln!(f, "{indent}{keyword}")?;
+                } else {
+                    writeln!(f, "{indent}{keyword} {}", branch.label)?;
+                }
+                for item in &branch.items {
+                    item.fmt_tabbed(f, tab_count + 1)?;
+                }
+            }
+        }
+        writeln!(f, "{indent}end")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a statement of a sequence diagram, in the order in which it
+/// appears.
+pub enum SequenceItem {
+    /// A message between two participants.
+    Message(Rc<SequenceMessage>),
+    /// The activation of a participant.
+    Activate(Rc<SequenceParticipant>),
+    /// The deactivation of a participant.
+    Deactivate(Rc<SequenceParticipant>),
+    /// A note placed relative to participants.
+    Note(SequenceNote),
+    /// A block grouping further statements.
+    Block(SequenceBlock),
+}
+
+impl Display for SequenceItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for SequenceItem {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        match self {
+            SequenceItem::Message(message) => message.fmt_tabbed(f, tab_count),
+            SequenceItem::Activate(participant) => {
+                writeln!(f, "{indent}activate {NODE_LETTER}{}", participant.id())
+            }
+            SequenceItem::Deactivate(participant) => {
+                writeln!(f, "{indent}deactivate {NODE_LETTER}{}", participant.id())
+            }
+            SequenceItem::Note(note) => note.fmt_tabbed(f, tab_count),
+            SequenceItem::Block(block) => block.fmt_tabbed(f, tab_count),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box, format};
+
+    use super::*;
+    use crate::{
+        diagrams::sequence_diagram::{NotePosition, SequenceParticipantBuilder},
+        traits::NodeBuilder,
+    };
+
+    #[test]
+    fn test_sequence_block_display() -> Result<(), Box<dyn core::error::Error>> {
+        let alice = Rc::new(SequenceParticipantBuilder::default().label("Alice")?.id(0).build()?);
+
+        let mut block = SequenceBlock::new(SequenceBlockKind::Alt("is sick".to_owned()));
+        block
+            .push(SequenceItem::Note(SequenceNote::new(NotePosition::Over(alice.clone()), "Sick")));
+        block.branch("is well")?;
+        block.push(SequenceItem::Activate(alice.clone()));
+        block.push(SequenceItem::Deactivate(alice));
+
+        assert_eq!(
+            format!("{block}"),
+            "alt is sick\n  Note over v0: Sick\nelse is well\n  activate v0\n  deactivate v0\nend\n"
+        );
+        assert_eq!(block.items().len(), 1);
+        assert_eq!(block.branches().len(), 1);
+        assert_eq!(block.branches()[0].label(), "is well");
+        assert_eq!(block.branches()[0].items().len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sequence_block_kinds() {
+        let red = Color::from((255, 0, 0));
+        assert_eq!(
+            format!("{}", SequenceBlockKind::Loop("Every minute".to_owned())),
+            "loop Every minute"
+        );
+        assert_eq!(format!("{}", SequenceBlockKind::Opt(String::new())), "opt");
+        assert_eq!(format!("{}", SequenceBlockKind::Rect(red)), "rect rgb(255, 0, 0)");
+        assert_eq!(SequenceBlockKind::Par(String::new()).branch_keyword(), Some("and"));
+        assert_eq!(SequenceBlockKind::Critical(String::new()).branch_keyword(), Some("option"));
+        assert_eq!(SequenceBlockKind::Break(String::new()).branch_keyword(), None);
+
+        let mut block = SequenceBlock::new(SequenceBlockKind::Rect(red));
+        assert_eq!(
+            block.branch("nope"),
+            Err(SequenceDiagramError::UnsupportedBranch("rect".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,226 @@
+//! Submodule defining boxes grouping participants of a sequence diagram in
+//! Mermaid syntax.
+
+use alloc::{borrow::ToOwned, rc::Rc, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+use crate::{
+    diagrams::sequence_diagram::sequence_participant::SequenceParticipant,
+    errors::SequenceDiagramError,
+    shared::Color,
+    traits::{Node, TabbedDisplay},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a box grouping participants of a sequence diagram, optionally
+/// with a label and a background color.
+pub struct SequenceBox {
+    /// The label of the box, if any.
+    label: Option<String>,
+    /// The background color of the box, if any.
+    color: Option<Color>,
+    /// The participants grouped in the box.
+    participants: Vec<Rc<SequenceParticipant>>,
+}
+
+impl SequenceBox {
+    #[must_use]
+    /// Returns the label of the box, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    #[must_use]
+    /// Returns the background color of the box, if any.
+    pub fn color(&self) -> Option<Color> {
+        self.color
+    }
+
+    /// Returns an iterator over the participants grouped in the box.
+    pub fn participants(&self) -> impl Iterator<Item = &Rc<SequenceParticipant>> {
+        self.participants.iter()
+    }
+}
+
+impl Display for SequenceBox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for SequenceBox {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        // Mermaid interprets the first word of the box as a color whenever it
+        // is a valid CSS color, so we always provide one explicitly to avoid
+        // labels such as `Red team` being swallowed.
+        let color = self.color.map_or_else(|| "transparent".to_owned(), Color::to_css_rgb);
+        write!(f, "{indent}box {color}")?;
+        if let Some(label) = &self.label {
+            write!(f, " {label}")?;
+        }
+        writeln!(f)?;
+        for participant in &self.participants {
+            participant.fmt_tabbed(f, tab_count + 1)?;
+        }
+        writeln!(f, "{indent}end")
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `SequenceBox`.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = SequenceDiagramBuilder::default();
+///     let alice = builder.node(SequenceParticipantBuilder::default().label("Alice")?)?;
+///     let bob = builder.node(SequenceParticipantBuilder::default().label("Bob")?)?;
+///
+///     builder.participant_box(
+///         SequenceBoxBuilder::default()
+///             .label("Team")?
+///             .color(Color::pastel_cyan())
+///             .participant(alice)?
+///             .participant(bob)?,
+///     )?;
+///     Ok(())
+/// }
+/// ```
+pub struct SequenceBoxBuilder {
+    /// The label of the box, if any.
+    label: Option<String>,
+    /// The background color of the box, if any.
+    color: Option<Color>,
+    /// The participants grouped in the box.
+    participants: Vec<Rc<SequenceParticipant>>,
+}
+
+impl SequenceBoxBuilder {
+    /// Sets the label of the box.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, SequenceDiagramError> {
+        let label = label.into();
+        if label.is_empty() {
+            return Err(SequenceDiagramError::EmptyBoxLabel);
+        }
+        self.label = Some(label);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the background color of the box.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Adds a participant to the box.
+    ///
+    /// # Errors
+    ///
+    /// * If the participant has already been added to the box.
+    pub fn participant(
+        mut self,
+        participant: Rc<SequenceParticipant>,
+    ) -> Result<Self, SequenceDiagramError> {
+        if self.participants.contains(&participant) {
+            return Err(SequenceDiagramError::ParticipantAlreadyBoxed(
+                participant.label().to_owned(),
+            ));
+        }
+        self.participants.push(participant);
+        Ok(self)
+    }
+
+    /// Builds the box.
+    ///
+    /// # Errors
+    ///
+    /// * If the box does not contain any participant.
+    pub fn build(self) -> Result<SequenceBox, SequenceDiagramError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<SequenceBoxBuilder> for SequenceBox {
+    type Error = SequenceDiagramError;
+
+    fn try_from(builder: SequenceBoxBuilder) -> Result<Self, Self::Error> {
+        if builder.participants.is_empty() {
+            return Err(SequenceDiagramError::EmptyBox);
+        }
+        Ok(SequenceBox {
+            label: builder.label,
+            color: builder.color,
+            participants: builder.participants,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format};
+
+    use super::*;
+    use crate::{
+        diagrams::sequence_diagram::{ParticipantKind, SequenceParticipantBuilder},
+        traits::NodeBuilder,
+    };
+
+    #[test]
+    fn test_sequence_box_display() -> Result<(), Box<dyn core::error::Error>> {
+        let alice = Rc::new(SequenceParticipantBuilder::default().label("Alice")?.id(0).build()?);
+        let bob = Rc::new(
+            SequenceParticipantBuilder::default()
+                .label("Bob")?
+                .id(1)
+                .kind(ParticipantKind::Actor)
+                .build()?,
+        );
+
+        let sequence_box = SequenceBoxBuilder::default()
+            .label("Red team")?
+            .participant(alice.clone())?
+            .participant(bob.clone())?
+            .build()?;
+        assert_eq!(
+            format!("{sequence_box}"),
+            "box transparent Red team\n  participant v0 as Alice\n  actor v1 as Bob\nend\n"
+        );
+
+        let sequence_box = SequenceBoxBuilder::default()
+            .color(Color::from((255, 0, 0)))
+            .participant(alice)?
+            .build()?;
+        assert_eq!(
+            format!("{sequence_box}"),
+            "box rgb(255, 0, 0)\n  participant v0 as Alice\nend\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sequence_box_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let alice = Rc::new(SequenceParticipantBuilder::default().label("Alice")?.id(0).build()?);
+
+        assert_eq!(
+            SequenceBoxBuilder::default().label(""),
+            Err(SequenceDiagramError::EmptyBoxLabel)
+        );
+        assert_eq!(SequenceBoxBuilder::default().build(), Err(SequenceDiagramError::EmptyBox));
+        assert_eq!(
+            SequenceBoxBuilder::default().participant(alice.clone())?.participant(alice),
+            Err(SequenceDiagramError::ParticipantAlreadyBoxed("Alice".to_owned()))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+//! Submodule defining a message which may be exchanged between participants
+//! of a sequence diagram in Mermaid syntax.
+
+mod builder;
+use alloc::rc::Rc;
+use core::fmt::{self, Display};
+
+pub use builder::SequenceMessageBuilder;
+
+use crate::{
+    diagrams::sequence_diagram::sequence_participant::SequenceParticipant,
+    shared::{ArrowShape, GenericEdge, LineStyle, NODE_LETTER, StyleClass},
+    traits::{Edge, Node},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a message sent from one participant to another in a sequence
+/// diagram.
+///
+/// The arrow is derived from the line style and the arrow shapes of the
+/// message:
+///
+/// | Line style | Left arrow | Right arrow | Mermaid |
+/// |------------|------------|-------------|---------|
+/// | `Solid`    | -          | -           | `->`    |
+/// | `Solid`    | -          | `Normal`    | `->>`   |
+/// | `Solid`    | -          | `X`         | `-x`    |
+/// | `Solid`    | -          | `Sharp`     | `-)`    |
+/// | `Solid`    | `Normal`   | `Normal`    | `<<->>` |
+///
+/// The `Dashed` line style doubles the dash, e.g. `-->>`.
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let alice = Rc::new(SequenceParticipantBuilder::default().label("Alice")?.id(0).build()?);
+///     let bob = Rc::new(SequenceParticipantBuilder::default().label("Bob")?.id(1).build()?);
+///
+///     let message = SequenceMessageBuilder::default()
+///         .source(alice)?
+///         .destination(bob)?
+///         .label("Hello")?
+///         .line_style(LineStyle::Dashed)
+///         .right_arrow_shape(ArrowShape::Normal)?
+///         .build()?;
+///
+///     assert_eq!(message.to_string(), "v0-->>v1: Hello\n");
+///     Ok(())
+/// }
+/// ```
+pub struct SequenceMessage {
+    /// Underlying generic edge.
+    edge: GenericEdge<SequenceParticipant>,
+}
+
+impl Edge for SequenceMessage {
+    type Builder = SequenceMessageBuilder;
+    type Node = SequenceParticipant;
+
+    fn label(&self) -> Option<&str> {
+        self.edge.label()
+    }
+
+    fn source(&self) -> &Rc<Self::Node> {
+        self.edge.source()
+    }
+
+    fn destination(&self) -> &Rc<Self::Node> {
+        self.edge.destination()
+    }
+
+    fn line_style(&self) -> LineStyle {
+        self.edge.line_style()
+    }
+
+    fn classes(&self) -> impl Iterator<Item = &StyleClass> {
+        self.edge.classes()
+    }
+
+    fn left_arrow_shape(&self) -> Option<ArrowShape> {
+        self.edge.left_arrow_shape()
+    }
+
+    fn right_arrow_shape(&self) -> Option<ArrowShape> {
+        self.edge.right_arrow_shape()
+    }
+}
+
+impl Display for SequenceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use crate::traits::TabbedDisplay;
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl crate::traits::TabbedDisplay for SequenceMessage {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        writeln!(
+            f,
+            "{indent}{NODE_LETTER}{}{left_arrow}{segment}{right_arrow}{NODE_LETTER}{}: {}",
+            self.source().id(),
+            self.destination().id(),
+            self.label().unwrap_or_default(),
+            left_arrow = if self.left_arrow_shape().is_some() { "<<" } else { "" },
+            segment = match self.line_style() {
+                LineStyle::Dashed => "--",
+                LineStyle::Solid | LineStyle::Thick => "-",
+            },
+            right_arrow = match self.right_arrow_shape() {
+                None => ">",
+                Some(ArrowShape::X) => "x",
+                Some(ArrowShape::Sharp) => ")",
+                // Participants only accept the normal, cross and sharp shapes,
+                // so messages never have the other ones.
+                Some(
+                    ArrowShape::Normal
+                    | ArrowShape::Circle
+                    | ArrowShape::Triangle
+                    | ArrowShape::Star
+                    | ArrowShape::ZeroOrOne
+                    | ArrowShape::ExactlyOne
+                    | ArrowShape::ZeroOrMore
+                    | ArrowShape::OneOrMore,
+                ) => ">>",
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format};
+
+    use super::*;
+    use crate::{
+        diagrams::sequence_diagram::SequenceParticipantBuilder,
+        traits::{EdgeBuilder, NodeBuilder},
+    };
+
+    #[test]
+    fn test_sequence_message_arrows() -> Result<(), Box<dyn core::error::Error>> {
+        let alice = Rc::new(SequenceParticipantBuilder::default().label("Alice")?.id(0).build()?);
+        let bob = Rc::new(SequenceParticipantBuilder::default().label("Bob")?.id(1).build()?);
+        let base = SequenceMessageBuilder::default()
+            .source(alice.clone())?
+            .destination(bob.clone())?
+            .label("Hi")?;
+
+        assert_eq!(format!("{}", base.clone().build()?), "v0->v1: Hi\n");
+        assert_eq!(
+            format!("{}", base.clone().line_style(LineStyle::Dashed).build()?),
+            "v0-->v1: Hi\n"
+        );
+        assert_eq!(
+            format!("{}", base.clone().right_arrow_shape(ArrowShape::Normal)?.build()?),
+            "v0->>v1: Hi\n"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                base.clone()
+                    .line_style(LineStyle::Dashed)
+                    .right_arrow_shape(ArrowShape::Normal)?
+                    .build()?
+            ),
+            "v0-->>v1: Hi\n"
+        );
+        assert_eq!(
+            format!("{}", base.clone().right_arrow_shape(ArrowShape::X)?.build()?),
+            "v0-xv1: Hi\n"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                base.clone()
+                    .line_style(LineStyle::Dashed)
+                    .right_arrow_shape(ArrowShape::Sharp)?
+                    .build()?
+            ),
+            "v0--)v1: Hi\n"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                base.left_arrow_shape(ArrowShape::Normal)?
+                    .right_arrow_shape(ArrowShape::Normal)?
+                    .build()?
+            ),
+            "v0<<->>v1: Hi\n"
+        );
+        Ok(())
+    }
+}
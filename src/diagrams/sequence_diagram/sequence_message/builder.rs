@@ -0,0 +1,170 @@
+//! Submodule defining the struct for building a sequence diagram message.
+
+use alloc::{rc::Rc, string::ToString};
+
+use crate::{
+    diagrams::sequence_diagram::{
+        sequence_message::SequenceMessage, sequence_participant::SequenceParticipant,
+    },
+    errors::EdgeError,
+    shared::{ArrowShape, LineStyle, generic_edge::GenericEdgeBuilder},
+    traits::{Edge, EdgeBuilder},
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `SequenceMessage`.
+///
+/// # Example
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let alice = Rc::new(SequenceParticipantBuilder::default().label("Alice")?.id(0).build()?);
+///     let bob = Rc::new(SequenceParticipantBuilder::default().label("Bob")?.id(1).build()?);
+///
+///     let message = SequenceMessageBuilder::default()
+///         .source(alice)?
+///         .destination(bob)?
+///         .right_arrow_shape(ArrowShape::Sharp)?
+///         .build()?;
+///     Ok(())
+/// }
+/// ```
+pub struct SequenceMessageBuilder {
+    /// Underlying generic edge builder.
+    edge_builder: GenericEdgeBuilder<SequenceParticipant>,
+}
+
+impl TryFrom<SequenceMessageBuilder> for SequenceMessage {
+    type Error = EdgeError;
+
+    fn try_from(builder: SequenceMessageBuilder) -> Result<Self, Self::Error> {
+        let edge = builder.edge_builder.build()?;
+
+        if edge.line_style() == LineStyle::Thick {
+            return Err(EdgeError::UnsupportedLineStyle(LineStyle::Thick));
+        }
+
+        // Sequence diagrams only support bidirectional messages with normal
+        // arrowheads on both ends, i.e. `<<->>` and `<<-->>`.
+        if let Some(left) = edge.left_arrow_shape() {
+            if left != ArrowShape::Normal {
+                return Err(EdgeError::IncompatibleLeftArrowShape(left));
+            }
+            match edge.right_arrow_shape() {
+                Some(ArrowShape::Normal) => {}
+                Some(right) => return Err(EdgeError::IncompatibleRightArrowShape(right)),
+                None => return Err(EdgeError::IncompatibleLeftArrowShape(left)),
+            }
+        }
+
+        Ok(SequenceMessage { edge })
+    }
+}
+
+impl EdgeBuilder for SequenceMessageBuilder {
+    type Edge = SequenceMessage;
+    type Node = SequenceParticipant;
+    type Error = EdgeError;
+
+    fn build(self) -> Result<Self::Edge, Self::Error> {
+        self.try_into()
+    }
+
+    fn source(mut self, node: Rc<Self::Node>) -> Result<Self, Self::Error> {
+        self.edge_builder = self.edge_builder.source(node)?;
+        Ok(self)
+    }
+
+    fn destination(mut self, node: Rc<Self::Node>) -> Result<Self, Self::Error> {
+        self.edge_builder = self.edge_builder.destination(node)?;
+        Ok(self)
+    }
+
+    fn label<S: ToString>(mut self, label: S) -> Result<Self, Self::Error> {
+        self.edge_builder = self.edge_builder.label(label)?;
+        Ok(self)
+    }
+
+    fn line_style(mut self, style: LineStyle) -> Self {
+        self.edge_builder = self.edge_builder.line_style(style);
+        self
+    }
+
+    fn left_arrow_shape(mut self, shape: ArrowShape) -> Result<Self, Self::Error> {
+        self.edge_builder = self.edge_builder.left_arrow_shape(shape)?;
+        Ok(self)
+    }
+
+    fn right_arrow_shape(mut self, shape: ArrowShape) -> Result<Self, Self::Error> {
+        self.edge_builder = self.edge_builder.right_arrow_shape(shape)?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+
+    use super::*;
+    use crate::{
+        diagrams::sequence_diagram::SequenceParticipantBuilder,
+        traits::{Node, NodeBuilder},
+    };
+
+    #[test]
+    fn test_sequence_message_builder() -> Result<(), Box<dyn core::error::Error>> {
+        let alice = Rc::new(SequenceParticipantBuilder::default().label("Alice")?.id(0).build()?);
+        let bob = Rc::new(SequenceParticipantBuilder::default().label("Bob")?.id(1).build()?);
+
+        let message = SequenceMessageBuilder::default()
+            .source(alice)?
+            .destination(bob)?
+            .label("Hello")?
+            .line_style(LineStyle::Dashed)
+            .right_arrow_shape(ArrowShape::X)?
+            .build()?;
+
+        assert_eq!(message.source().id(), 0);
+        assert_eq!(message.destination().id(), 1);
+        assert_eq!(message.label(), Some("Hello"));
+        assert_eq!(message.line_style(), LineStyle::Dashed);
+        assert_eq!(message.left_arrow_shape(), None);
+        assert_eq!(message.right_arrow_shape(), Some(ArrowShape::X));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sequence_message_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let alice = Rc::new(SequenceParticipantBuilder::default().label("Alice")?.id(0).build()?);
+        let bob = Rc::new(SequenceParticipantBuilder::default().label("Bob")?.id(1).build()?);
+        let base = SequenceMessageBuilder::default().source(alice)?.destination(bob)?;
+
+        assert_eq!(
+            base.clone().right_arrow_shape(ArrowShape::Triangle),
+            Err(EdgeError::IncompatibleRightArrowShape(ArrowShape::Triangle))
+        );
+        assert_eq!(
+            base.clone().line_style(LineStyle::Thick).build(),
+            Err(EdgeError::UnsupportedLineStyle(LineStyle::Thick))
+        );
+        assert_eq!(
+            base.clone().left_arrow_shape(ArrowShape::X)?.build(),
+            Err(EdgeError::IncompatibleLeftArrowShape(ArrowShape::X))
+        );
+        assert_eq!(
+            base.clone().left_arrow_shape(ArrowShape::Normal)?.build(),
+            Err(EdgeError::IncompatibleLeftArrowShape(ArrowShape::Normal))
+        );
+        assert_eq!(
+            base.left_arrow_shape(ArrowShape::Normal)?.right_arrow_shape(ArrowShape::X)?.build(),
+            Err(EdgeError::IncompatibleRightArrowShape(ArrowShape::X))
+        );
+        assert_eq!(SequenceMessageBuilder::default().build(), Err(EdgeError::MissingSource));
+        Ok(())
+    }
+}
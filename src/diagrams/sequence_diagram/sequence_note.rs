@@ -0,0 +1,123 @@
+//! Submodule defining notes which may be attached to participants of a
+//! sequence diagram in Mermaid syntax.
+
+use alloc::{rc::Rc, string::String};
+use core::fmt::{self, Display};
+
+use crate::{
+    diagrams::sequence_diagram::sequence_participant::SequenceParticipant, shared::NODE_LETTER,
+    traits::Node,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents where a note is placed relative to the participants.
+pub enum NotePosition {
+    /// The note is placed to the left of the participant.
+    LeftOf(Rc<SequenceParticipant>),
+    /// The note is placed to the right of the participant.
+    RightOf(Rc<SequenceParticipant>),
+    /// The note is placed over the participant.
+    Over(Rc<SequenceParticipant>),
+    /// The note spans over the two participants.
+    Spanning(Rc<SequenceParticipant>, Rc<SequenceParticipant>),
+}
+
+impl NotePosition {
+    /// Returns an iterator over the participants the note refers to.
+    pub fn participants(&self) -> impl Iterator<Item = &Rc<SequenceParticipant>> {
+        let (first, second) = match self {
+            NotePosition::LeftOf(participant)
+            | NotePosition::RightOf(participant)
+            | NotePosition::Over(participant) => (participant, None),
+            NotePosition::Spanning(first, second) => (first, Some(second)),
+        };
+        core::iter::once(first).chain(second)
+    }
+}
+
+impl Display for NotePosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotePosition::LeftOf(participant) => {
+                write!(f, "left of {NODE_LETTER}{}", participant.id())
+            }
+            NotePosition::RightOf(participant) => {
+                write!(f, "right of {NODE_LETTER}{}", participant.id())
+            }
+            NotePosition::Over(participant) => write!(f, "over {NODE_LETTER}{}", participant.id()),
+            NotePosition::Spanning(first, second) => {
+                write!(f, "over {NODE_LETTER}{},{NODE_LETTER}{}", first.id(), second.id())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a note in a sequence diagram.
+pub struct SequenceNote {
+    /// Where the note is placed.
+    position: NotePosition,
+    /// The text of the note.
+    text: String,
+}
+
+impl SequenceNote {
+    /// Creates a new note at the provided position.
+    pub fn new(position: NotePosition, text: impl Into<String>) -> Self {
+        Self { position, text: text.into() }
+    }
+
+    #[must_use]
+    /// Returns where the note is placed.
+    pub fn position(&self) -> &NotePosition {
+        &self.position
+    }
+
+    #[must_use]
+    /// Returns the text of the note.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl Display for SequenceNote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use crate::traits::TabbedDisplay;
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl crate::traits::TabbedDisplay for SequenceNote {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        writeln!(f, "{indent}Note {}: {}", self.position, self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format, vec::Vec};
+
+    use super::*;
+    use crate::{diagrams::sequence_diagram::SequenceParticipantBuilder, traits::NodeBuilder};
+
+    #[test]
+    fn test_sequence_note_display() -> Result<(), Box<dyn core::error::Error>> {
+        let alice = Rc::new(SequenceParticipantBuilder::default().label("Alice")?.id(0).build()?);
+        let bob = Rc::new(SequenceParticipantBuilder::default().label("Bob")?.id(1).build()?);
+
+        let note = SequenceNote::new(NotePosition::LeftOf(alice.clone()), "Thinking");
+        assert_eq!(format!("{note}"), "Note left of v0: Thinking\n");
+        let note = SequenceNote::new(NotePosition::RightOf(bob.clone()), "Waiting");
+        assert_eq!(format!("{note}"), "Note right of v1: Waiting\n");
+        let note = SequenceNote::new(NotePosition::Over(alice.clone()), "Alone");
+        assert_eq!(format!("{note}"), "Note over v0: Alone\n");
+        let note = SequenceNote::new(NotePosition::Spanning(alice, bob), "Together");
+        assert_eq!(format!("{note}"), "Note over v0,v1: Together\n");
+        assert_eq!(note.text(), "Together");
+        assert_eq!(note.position().participants().map(|p| p.id()).collect::<Vec<_>>(), [0, 1]);
+        Ok(())
+    }
+}
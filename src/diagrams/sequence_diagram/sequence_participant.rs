@@ -0,0 +1,140 @@
+//! Submodule defining a participant which may be used in a sequence diagram
+//! in Mermaid syntax.
+
+mod builder;
+use core::fmt::{self, Display};
+
+pub use builder::SequenceParticipantBuilder;
+
+use crate::{
+    shared::{ArrowShape, GenericNode, NODE_LETTER, StyleClass, StyleProperty},
+    traits::Node,
+};
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents how a participant is drawn in a sequence diagram.
+pub enum ParticipantKind {
+    /// A participant drawn as a box, declared with `participant`.
+    #[default]
+    Participant,
+    /// A participant drawn as a stick figure, declared with `actor`.
+    Actor,
+}
+
+impl Display for ParticipantKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParticipantKind::Participant => write!(f, "participant"),
+            ParticipantKind::Actor => write!(f, "actor"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a participant in a sequence diagram, which is rendered using
+/// its label as alias.
+///
+/// # Examples
+///
+/// ```
+/// use mermaid_builder::{
+///     diagrams::sequence_diagram::{ParticipantKind, SequenceParticipantBuilder},
+///     traits::{Node, NodeBuilder},
+/// };
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let alice = SequenceParticipantBuilder::default()
+///         .label("Alice")?
+///         .kind(ParticipantKind::Actor)
+///         .id(0)
+///         .build()?;
+///
+///     assert_eq!(alice.label(), "Alice");
+///     assert_eq!(alice.to_string(), "actor v0 as Alice\n");
+///     Ok(())
+/// }
+/// ```
+pub struct SequenceParticipant {
+    /// Underlying node structure.
+    node: GenericNode,
+    /// How the participant is drawn.
+    kind: ParticipantKind,
+}
+
+impl SequenceParticipant {
+    #[must_use]
+    /// Returns how the participant is drawn.
+    pub fn kind(&self) -> ParticipantKind {
+        self.kind
+    }
+}
+
+impl Node for SequenceParticipant {
+    type Builder = SequenceParticipantBuilder;
+
+    fn label(&self) -> &str {
+        self.node.label()
+    }
+
+    fn id(&self) -> u64 {
+        self.node.id()
+    }
+
+    fn styles(&self) -> impl Iterator<Item = &StyleProperty> {
+        self.node.styles()
+    }
+
+    fn classes(&self) -> impl Iterator<Item = &StyleClass> {
+        self.node.classes()
+    }
+
+    fn is_compatible_arrow_shape(shape: ArrowShape) -> bool {
+        matches!(shape, ArrowShape::Normal | ArrowShape::X | ArrowShape::Sharp)
+    }
+}
+
+impl Display for SequenceParticipant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use crate::traits::TabbedDisplay;
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl crate::traits::TabbedDisplay for SequenceParticipant {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        writeln!(f, "{indent}{} {NODE_LETTER}{} as {}", self.kind, self.id(), self.label())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format};
+
+    use super::*;
+    use crate::traits::NodeBuilder;
+
+    #[test]
+    fn test_sequence_participant_display() -> Result<(), Box<dyn core::error::Error>> {
+        let participant = SequenceParticipantBuilder::default().label("Alice")?.id(3).build()?;
+        assert_eq!(format!("{participant}"), "participant v3 as Alice\n");
+
+        let actor = SequenceParticipantBuilder::default()
+            .label("Bob")?
+            .id(4)
+            .kind(ParticipantKind::Actor)
+            .build()?;
+        assert_eq!(format!("{actor}"), "actor v4 as Bob\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_sequence_participant_arrow_shapes() {
+        assert!(SequenceParticipant::is_compatible_arrow_shape(ArrowShape::Normal));
+        assert!(SequenceParticipant::is_compatible_arrow_shape(ArrowShape::X));
+        assert!(SequenceParticipant::is_compatible_arrow_shape(ArrowShape::Sharp));
+        assert!(!SequenceParticipant::is_compatible_arrow_shape(ArrowShape::Triangle));
+    }
+}
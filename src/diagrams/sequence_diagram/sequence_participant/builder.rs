@@ -0,0 +1,131 @@
+//! Submodule defining a builder struct to construct participants in sequence
+//! diagrams.
+
+use alloc::{
+    rc::Rc,
+    string::{String, ToString},
+};
+
+use crate::{
+    diagrams::sequence_diagram::sequence_participant::{ParticipantKind, SequenceParticipant},
+    errors::NodeError,
+    shared::{StyleClass, StyleClassError, StyleProperty, generic_node::GenericNodeBuilder},
+    traits::NodeBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `SequenceParticipant`.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let participant = SequenceParticipantBuilder::default()
+///         .label("Alice")?
+///         .kind(ParticipantKind::Actor)
+///         .id(0)
+///         .build()?;
+///     Ok(())
+/// }
+/// ```
+pub struct SequenceParticipantBuilder {
+    /// Shared attributes builder for the participant.
+    builder: GenericNodeBuilder,
+    /// How the participant is drawn.
+    kind: ParticipantKind,
+}
+
+impl SequenceParticipantBuilder {
+    #[must_use]
+    /// Sets how the participant is drawn.
+    pub fn kind(mut self, kind: ParticipantKind) -> Self {
+        self.kind = kind;
+        self
+    }
+}
+
+impl TryFrom<SequenceParticipantBuilder> for SequenceParticipant {
+    type Error = NodeError;
+
+    fn try_from(builder: SequenceParticipantBuilder) -> Result<Self, Self::Error> {
+        Ok(SequenceParticipant { node: builder.builder.try_into()?, kind: builder.kind })
+    }
+}
+
+impl NodeBuilder for SequenceParticipantBuilder {
+    type Node = SequenceParticipant;
+    type Error = NodeError;
+
+    fn build(self) -> Result<Self::Node, Self::Error> {
+        self.try_into()
+    }
+
+    fn id(mut self, id: u64) -> Self {
+        self.builder = self.builder.id(id);
+        self
+    }
+
+    fn get_id(&self) -> Option<u64> {
+        self.builder.get_id()
+    }
+
+    fn label<S: ToString>(mut self, label: S) -> Result<Self, Self::Error> {
+        self.builder = self.builder.label(label)?;
+        Ok(self)
+    }
+
+    fn get_label(&self) -> Option<&String> {
+        self.builder.get_label()
+    }
+
+    fn style_class(mut self, style_class: Rc<StyleClass>) -> Result<Self, StyleClassError> {
+        self.builder = self.builder.style_class(style_class)?;
+        Ok(self)
+    }
+
+    fn style_property(mut self, property: StyleProperty) -> Result<Self, StyleClassError> {
+        self.builder = self.builder.style_property(property)?;
+        Ok(self)
+    }
+
+    fn style_properties(&self) -> impl Iterator<Item = &StyleProperty> {
+        self.builder.style_properties()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+
+    use super::*;
+    use crate::traits::Node;
+
+    #[test]
+    fn test_sequence_participant_builder() -> Result<(), Box<dyn core::error::Error>> {
+        let participant = SequenceParticipantBuilder::default()
+            .id(1)
+            .label("Alice")?
+            .kind(ParticipantKind::Actor)
+            .build()?;
+
+        assert_eq!(participant.id(), 1);
+        assert_eq!(participant.label(), "Alice");
+        assert_eq!(participant.kind(), ParticipantKind::Actor);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sequence_participant_builder_errors() {
+        assert!(matches!(
+            SequenceParticipantBuilder::default().label(""),
+            Err(NodeError::EmptyLabel)
+        ));
+        assert!(matches!(
+            SequenceParticipantBuilder::default().id(0).build(),
+            Err(NodeError::MissingLabel)
+        ));
+    }
+}
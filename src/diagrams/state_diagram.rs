@@ -0,0 +1,250 @@
+//! Submodule providing structs for creating state diagrams in Mermaid syntax.
+
+mod builder;
+mod state_node;
+mod state_transition;
+use alloc::{rc::Rc, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::StateDiagramBuilder;
+pub use state_node::{StateKind, StateNode, StateNodeBuilder, StateNote, StateNotePosition};
+pub use state_transition::{StateTransition, StateTransitionBuilder};
+
+use crate::{
+    shared::{
+        NODE_LETTER, StyleClass,
+        generic_configuration::{GenericConfiguration, GenericConfigurationBuilder},
+        generic_diagram::GenericDiagram,
+    },
+    traits::{Configuration, Diagram, Node, TabbedDisplay},
+};
+
+/// Represents the configuration for a state diagram.
+pub type StateDiagramConfiguration = GenericConfiguration;
+/// Represents the configuration builder for a state diagram.
+pub type StateDiagramConfigurationBuilder = GenericConfigurationBuilder;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a state diagram in Mermaid syntax, rendered with the
+/// `stateDiagram-v2` renderer.
+///
+/// Transitions are written within the innermost composite state containing
+/// both of their endpoints, so that `[*]` pseudo-states refer to the start
+/// and end of the correct scope.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = StateDiagramBuilder::default();
+///     let start = builder.node(StateNodeBuilder::default().kind(StateKind::Start))?;
+///     let idle = builder.node(StateNodeBuilder::default().label("Idle")?)?;
+///     builder.edge(StateTransitionBuilder::default().source(start)?.destination(idle)?)?;
+///
+///     let diagram = StateDiagram::from(builder);
+///     assert_eq!(
+///         diagram.to_string(),
+///         "stateDiagram-v2\n  direction LR\n  state \"Idle\" as v1\n  [*] --> v1\n"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct StateDiagram {
+    /// Underlying generic diagram.
+    generic: GenericDiagram<StateNode, StateTransition, StateDiagramConfiguration>,
+}
+
+impl Diagram for StateDiagram {
+    type Builder = StateDiagramBuilder;
+    type Configuration = StateDiagramConfiguration;
+    type Edge = StateTransition;
+    type Node = StateNode;
+
+    fn configuration(&self) -> &Self::Configuration {
+        self.generic.configuration()
+    }
+
+    fn edges(&self) -> impl Iterator<Item = &Self::Edge> {
+        self.generic.edges()
+    }
+
+    fn get_node_by_id(&self, id: u64) -> Option<Rc<Self::Node>> {
+        self.generic.get_node_by_id(id)
+    }
+
+    fn get_style_class_by_name(&self, name: &str) -> Option<Rc<StyleClass>> {
+        self.generic.get_style_class_by_name(name)
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = &Self::Node> {
+        self.generic.nodes()
+    }
+
+    fn style_classes(&self) -> impl Iterator<Item = &StyleClass> {
+        self.generic.style_classes()
+    }
+}
+
+impl Display for StateDiagram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for StateDiagram {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat((tab_count + 1) * 2);
+        self.configuration().fmt_frontmatter(f, true, &[])?;
+        writeln!(f, "{}stateDiagram-v2", " ".repeat(tab_count * 2))?;
+        writeln!(f, "{indent}direction {}", self.configuration().direction())?;
+        for style_class in self.style_classes() {
+            if self.nodes().any(|n| n.classes().any(|sc| sc == style_class)) {
+                style_class.fmt_tabbed(f, tab_count + 1)?;
+            }
+        }
+
+        let top_level_states =
+            self.nodes().filter(|state| !self.nodes().any(|other| other.nests(state)));
+        let transitions: Vec<&StateTransition> = self.edges().collect();
+        state_node::fmt_region(f, tab_count + 1, top_level_states, &transitions)?;
+
+        // Class assignments and styles are only applied by Mermaid at the top
+        // level, so they are written after all the composite states.
+        for state in self.nodes() {
+            for class in state.classes() {
+                writeln!(f, "{indent}class {NODE_LETTER}{} {}", state.id(), class.name())?;
+            }
+            if state.has_styles() {
+                write!(f, "{indent}style {NODE_LETTER}{} ", state.id())?;
+                for (style_number, style) in state.styles().enumerate() {
+                    if style_number > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{style}")?;
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::{
+        shared::{Color, Direction, StyleClassBuilder, StyleProperty},
+        traits::{ConfigurationBuilder, DiagramBuilder, EdgeBuilder, NodeBuilder},
+    };
+
+    #[test]
+    fn test_state_diagram_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = StateDiagramBuilder::default().configuration(
+            StateDiagramConfigurationBuilder::default()
+                .title("Jobs")?
+                .direction(Direction::TopToBottom),
+        )?;
+        let failed = builder.style_class(
+            StyleClassBuilder::default()
+                .name("failed")?
+                .property(StyleProperty::Fill(Color::from((255, 0, 0))))?,
+        )?;
+
+        let start = builder.node(StateNodeBuilder::default().kind(StateKind::Start))?;
+        let inner_start = builder.node(StateNodeBuilder::default().kind(StateKind::Start))?;
+        let fetch = builder.node(StateNodeBuilder::default().label("Fetch")?)?;
+        let log = builder.node(StateNodeBuilder::default().label("Log")?)?;
+        let running = builder.node(
+            StateNodeBuilder::default()
+                .label("Running")?
+                .direction(Direction::LeftToRight)
+                .substate(inner_start.clone())?
+                .substate(fetch.clone())?
+                .concurrent_region()?
+                .substate(log.clone())?,
+        )?;
+        let check =
+            builder.node(StateNodeBuilder::default().label("Check")?.kind(StateKind::Choice))?;
+        let error = builder.node(
+            StateNodeBuilder::default()
+                .label("Error")?
+                .style_class(failed)?
+                .note(StateNotePosition::RightOf, "Retried later")?,
+        )?;
+        let end = builder.node(StateNodeBuilder::default().kind(StateKind::End))?;
+
+        builder
+            .edge(StateTransitionBuilder::default().source(start)?.destination(running.clone())?)?;
+        builder.edge(StateTransitionBuilder::default().source(inner_start)?.destination(fetch)?)?;
+        builder.edge(
+            StateTransitionBuilder::default()
+                .source(running)?
+                .destination(check.clone())?
+                .label("done")?,
+        )?;
+        builder.edge(
+            StateTransitionBuilder::default()
+                .source(check.clone())?
+                .destination(error)?
+                .label("ko")?,
+        )?;
+        builder.edge(StateTransitionBuilder::default().source(check)?.destination(end)?)?;
+
+        let diagram = StateDiagram::from(builder);
+        assert_eq!(diagram.nodes().count(), 8);
+        assert_eq!(diagram.edges().count(), 5);
+        assert_eq!(
+            diagram.to_string(),
+            concat!(
+                "---\n",
+                "title: Jobs\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "---\n",
+                "stateDiagram-v2\n",
+                "  direction TB\n",
+                "  classDef failed fill: #ff0000\n",
+                "  state \"Running\" as v4 {\n",
+                "    direction LR\n",
+                "    state \"Fetch\" as v2\n",
+                "    [*] --> v2\n",
+                "    --\n",
+                "    state \"Log\" as v3\n",
+                "  }\n",
+                "  state v5 <<choice>>\n",
+                "  state \"Error\" as v6\n",
+                "  note right of v6 : Retried later\n",
+                "  [*] --> v4\n",
+                "  v4 --> v5 : done\n",
+                "  v5 --> v6 : ko\n",
+                "  v5 --> [*]\n",
+                "  class v6 failed\n",
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_state_diagram_styles() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = StateDiagramBuilder::default();
+        builder.node(
+            StateNodeBuilder::default()
+                .label("Hot")?
+                .style_property(StyleProperty::Fill(Color::from((255, 0, 0))))?
+                .style_property(StyleProperty::Color(Color::from((255, 255, 255))))?,
+        )?;
+
+        let diagram = StateDiagram::from(builder);
+        assert!(
+            diagram.to_string().ends_with("  style v0 fill: #ff0000,color: #ffffff\n"),
+            "{diagram}"
+        );
+        Ok(())
+    }
+}
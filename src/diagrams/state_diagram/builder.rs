@@ -0,0 +1,224 @@
+//! Submodule providing a builder for state diagrams in Mermaid syntax.
+
+use alloc::{borrow::ToOwned, rc::Rc};
+
+use crate::{
+    diagrams::state_diagram::{
+        StateDiagram, StateDiagramConfiguration, StateDiagramConfigurationBuilder, StateNode,
+        StateNodeBuilder, StateTransition, StateTransitionBuilder,
+    },
+    errors::{NodeError, StateDiagramError},
+    shared::{StyleClass, StyleClassBuilder, generic_diagram::GenericDiagramBuilder},
+    traits::{DiagramBuilder, Edge, EdgeBuilder, Node},
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a builder for a state diagram in Mermaid syntax.
+///
+/// Substates must be added to the builder before the composite state which
+/// contains them, and each state may be nested in at most one composite
+/// state. Transitions may not connect states lying in different concurrent
+/// regions of a composite state.
+pub struct StateDiagramBuilder {
+    /// Underlying generic diagram builder.
+    generic: GenericDiagramBuilder<StateNode, StateTransition, StateDiagramConfiguration>,
+}
+
+impl From<StateDiagramBuilder> for StateDiagram {
+    fn from(builder: StateDiagramBuilder) -> Self {
+        StateDiagram { generic: builder.generic.into() }
+    }
+}
+
+impl DiagramBuilder for StateDiagramBuilder {
+    type Configuration = StateDiagramConfiguration;
+    type ConfigurationBuilder = StateDiagramConfigurationBuilder;
+    type Diagram = StateDiagram;
+    type Edge = StateTransition;
+    type EdgeBuilder = StateTransitionBuilder;
+    type Node = StateNode;
+    type NodeBuilder = StateNodeBuilder;
+    type Error = crate::errors::Error;
+
+    fn configuration(
+        mut self,
+        configuration: Self::ConfigurationBuilder,
+    ) -> Result<Self, Self::Error> {
+        self.generic = self.generic.configuration(configuration)?;
+        Ok(self)
+    }
+
+    fn edge(&mut self, edge: Self::EdgeBuilder) -> Result<Rc<Self::Edge>, Self::Error> {
+        let transition = edge.clone().build()?;
+        if let Some(composite) = self
+            .generic
+            .nodes()
+            .find(|state| state.separates(transition.source(), transition.destination()))
+        {
+            return Err(StateDiagramError::CrossRegionTransition(
+                transition.source().label().to_owned(),
+                transition.destination().label().to_owned(),
+                composite.label().to_owned(),
+            )
+            .into());
+        }
+        self.generic.edge(edge)
+    }
+
+    fn get_node_by_id(&self, id: u64) -> Option<Rc<Self::Node>> {
+        self.generic.get_node_by_id(id)
+    }
+
+    fn node(&mut self, node: Self::NodeBuilder) -> Result<Rc<Self::Node>, Self::Error> {
+        if let Some(substate) =
+            node.substates().find(|substate| !self.generic.nodes().any(|state| state == *substate))
+        {
+            return Err(NodeError::NodeNotFound(substate.label().to_owned()).into());
+        }
+        if let Some(substate) = node.substates().find(|substate| {
+            self.generic.nodes().any(|state| state.substates().any(|nested| nested == *substate))
+        }) {
+            return Err(NodeError::AlreadyNested(substate.label().to_owned()).into());
+        }
+        if let Some(transition) = self
+            .generic
+            .edges()
+            .find(|transition| node.separates(transition.source(), transition.destination()))
+        {
+            return Err(StateDiagramError::CrossRegionTransition(
+                transition.source().label().to_owned(),
+                transition.destination().label().to_owned(),
+                node.error_label(),
+            )
+            .into());
+        }
+        self.generic.node(node)
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = &Rc<Self::Node>> + '_ {
+        self.generic.nodes()
+    }
+
+    fn number_of_edges(&self) -> usize {
+        self.generic.number_of_edges()
+    }
+
+    fn number_of_nodes(&self) -> usize {
+        self.generic.number_of_nodes()
+    }
+
+    fn style_class(
+        &mut self,
+        style_class: StyleClassBuilder,
+    ) -> Result<Rc<StyleClass>, Self::Error> {
+        self.generic.style_class(style_class)
+    }
+
+    fn get_style_class_by_name(&self, name: &str) -> Option<Rc<StyleClass>> {
+        self.generic.get_style_class_by_name(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+
+    use super::*;
+    use crate::{
+        errors::{EdgeError, Error},
+        traits::{EdgeBuilder, NodeBuilder},
+    };
+
+    #[test]
+    fn test_state_diagram_builder() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = StateDiagramBuilder::default();
+        let idle = builder.node(StateNodeBuilder::default().label("Idle")?)?;
+        let busy = builder.node(StateNodeBuilder::default().label("Busy")?)?;
+        let active = builder.node(
+            StateNodeBuilder::default()
+                .label("Active")?
+                .substate(idle.clone())?
+                .substate(busy.clone())?,
+        )?;
+
+        assert_eq!(builder.number_of_nodes(), 3);
+        assert_eq!(builder.get_node_by_id(2), Some(active.clone()));
+
+        builder.edge(StateTransitionBuilder::default().source(idle.clone())?.destination(busy)?)?;
+        assert_eq!(builder.number_of_edges(), 1);
+
+        assert_eq!(
+            builder.node(StateNodeBuilder::default().label("Other")?.substate(idle)?),
+            Err(Error::Node(NodeError::AlreadyNested("Idle".to_owned())))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_state_diagram_builder_cross_region_transitions()
+    -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = StateDiagramBuilder::default();
+        let left = builder.node(StateNodeBuilder::default().label("Left")?)?;
+        let inner = builder.node(StateNodeBuilder::default().label("Inner")?)?;
+        let right =
+            builder.node(StateNodeBuilder::default().label("Right")?.substate(inner.clone())?)?;
+        builder.node(
+            StateNodeBuilder::default()
+                .label("Parallel")?
+                .substate(left.clone())?
+                .concurrent_region()?
+                .substate(right.clone())?,
+        )?;
+
+        assert_eq!(
+            builder
+                .edge(StateTransitionBuilder::default().source(left.clone())?.destination(inner)?),
+            Err(Error::StateDiagram(StateDiagramError::CrossRegionTransition(
+                "Left".to_owned(),
+                "Inner".to_owned(),
+                "Parallel".to_owned()
+            )))
+        );
+        assert_eq!(builder.number_of_edges(), 0);
+
+        let mut builder = StateDiagramBuilder::default();
+        let first = builder.node(StateNodeBuilder::default().label("First")?)?;
+        let second = builder.node(StateNodeBuilder::default().label("Second")?)?;
+        builder.edge(
+            StateTransitionBuilder::default().source(first.clone())?.destination(second.clone())?,
+        )?;
+        assert_eq!(
+            builder.node(
+                StateNodeBuilder::default()
+                    .label("Parallel")?
+                    .substate(first)?
+                    .concurrent_region()?
+                    .substate(second)?
+            ),
+            Err(Error::StateDiagram(StateDiagramError::CrossRegionTransition(
+                "First".to_owned(),
+                "Second".to_owned(),
+                "Parallel".to_owned()
+            )))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_state_diagram_builder_unknown_states() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = StateDiagramBuilder::default();
+        let known = builder.node(StateNodeBuilder::default().label("Known")?)?;
+        let unknown = Rc::new(StateNodeBuilder::default().label("Unknown")?.id(7).build()?);
+
+        assert_eq!(
+            builder.node(StateNodeBuilder::default().label("Parent")?.substate(unknown.clone())?),
+            Err(Error::Node(NodeError::NodeNotFound("Unknown".to_owned())))
+        );
+        assert_eq!(
+            builder.edge(StateTransitionBuilder::default().source(known)?.destination(unknown)?),
+            Err(Error::Edge(EdgeError::DestinationNodeNotFound("Unknown".to_owned())))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,308 @@
+//! Submodule defining a state which may be used in a state diagram in Mermaid
+//! syntax.
+
+mod builder;
+mod kind;
+mod note;
+use alloc::{rc::Rc, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::StateNodeBuilder;
+pub use kind::StateKind;
+pub use note::{StateNote, StateNotePosition};
+
+use crate::{
+    diagrams::state_diagram::state_transition::StateTransition,
+    shared::{
+        ArrowShape, GenericNode, NODE_LETTER, StyleClass, StyleProperty,
+        generic_configuration::Direction,
+    },
+    traits::{Edge, Node, TabbedDisplay},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a state in a state diagram, which may be a pseudo-state such as
+/// `[*]` or a composite state containing one or more concurrent regions.
+///
+/// # Examples
+///
+/// ```
+/// use mermaid_builder::{
+///     diagrams::state_diagram::{StateKind, StateNodeBuilder},
+///     traits::{Node, NodeBuilder},
+/// };
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let state = StateNodeBuilder::default().label("Idle")?.id(0).build()?;
+///     assert_eq!(state.to_string(), "state \"Idle\" as v0\n");
+///
+///     let choice =
+///         StateNodeBuilder::default().label("Check")?.kind(StateKind::Choice).id(1).build()?;
+///     assert_eq!(choice.to_string(), "state v1 <<choice>>\n");
+///     Ok(())
+/// }
+/// ```
+pub struct StateNode {
+    /// Underlying node structure.
+    node: GenericNode,
+    /// The kind of the state.
+    kind: StateKind,
+    /// The concurrent regions of the state, when it is a composite state.
+    regions: Vec<Vec<Rc<StateNode>>>,
+    /// The direction of the composite state, if applicable.
+    direction: Option<Direction>,
+    /// The note attached to the state, if any.
+    note: Option<StateNote>,
+}
+
+impl StateNode {
+    #[must_use]
+    /// Returns the kind of the state.
+    pub fn kind(&self) -> StateKind {
+        self.kind
+    }
+
+    #[must_use]
+    /// Returns whether the state is a composite state.
+    pub fn is_composite(&self) -> bool {
+        !self.regions.is_empty()
+    }
+
+    /// Returns an iterator over the concurrent regions of the composite state.
+    pub fn regions(&self) -> impl Iterator<Item = &[Rc<StateNode>]> {
+        self.regions.iter().map(Vec::as_slice)
+    }
+
+    /// Returns an iterator over the direct substates of the composite state,
+    /// across all of its regions.
+    pub fn substates(&self) -> impl Iterator<Item = &Rc<StateNode>> {
+        self.regions.iter().flatten()
+    }
+
+    #[must_use]
+    /// Returns the direction of the composite state, if set.
+    pub fn direction(&self) -> Option<Direction> {
+        self.direction
+    }
+
+    #[must_use]
+    /// Returns the note attached to the state, if any.
+    pub fn note(&self) -> Option<&StateNote> {
+        self.note.as_ref()
+    }
+
+    /// Returns whether the provided state is nested, at any depth, within the
+    /// current state.
+    pub(crate) fn nests(&self, state: &StateNode) -> bool {
+        self.substates().any(|substate| substate.as_ref() == state || substate.nests(state))
+    }
+
+    /// Returns whether the provided states lie in different concurrent
+    /// regions of the composite state.
+    pub(crate) fn separates(&self, source: &StateNode, destination: &StateNode) -> bool {
+        separated_by(&self.regions, source, destination)
+    }
+
+    /// Returns the identifier used to refer to the state in transitions.
+    pub(crate) fn reference(&self) -> Reference<'_> {
+        Reference(self)
+    }
+
+    /// Writes the state followed by the transitions which belong to its
+    /// scope, so that `[*]` pseudo-states resolve to the correct composite
+    /// state.
+    pub(crate) fn fmt_scoped(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        tab_count: usize,
+        transitions: &[&StateTransition],
+    ) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        match self.kind {
+            StateKind::Start | StateKind::End => {}
+            StateKind::Choice | StateKind::Fork | StateKind::Join => {
+                let stereotype = self.kind.stereotype().unwrap_or_default();
+                writeln!(f, "{indent}state {NODE_LETTER}{} <<{stereotype}>>", self.id())?;
+            }
+            StateKind::State if self.regions.is_empty() => {
+                writeln!(f, "{indent}state \"{}\" as {NODE_LETTER}{}", self.label(), self.id())?;
+            }
+            StateKind::State => {
+                writeln!(f, "{indent}state \"{}\" as {NODE_LETTER}{} {{", self.label(), self.id())?;
+                if let Some(direction) = self.direction {
+                    writeln!(f, "{indent}  direction {direction}")?;
+                }
+                for (region_number, region) in self.regions.iter().enumerate() {
+                    if region_number > 0 {
+                        writeln!(f, "{indent}  --")?;
+                    }
+                    let region_transitions: Vec<&StateTransition> = transitions
+                        .iter()
+                        .copied()
+                        .filter(|transition| {
+                            contains(region, transition.source())
+                                && contains(region, transition.destination())
+                        })
+                        .collect();
+                    fmt_region(
+                        f,
+                        tab_count + 1,
+                        region.iter().map(AsRef::as_ref),
+                        &region_transitions,
+                    )?;
+                }
+                writeln!(f, "{indent}}}")?;
+            }
+        }
+        if let Some(note) = &self.note {
+            note.fmt_attached(f, tab_count, self)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns whether the provided state belongs, at any depth, to the region.
+fn contains(region: &[Rc<StateNode>], state: &StateNode) -> bool {
+    region.iter().any(|substate| substate.as_ref() == state || substate.nests(state))
+}
+
+/// Returns whether the provided states lie, at any depth, in different
+/// concurrent regions among the provided ones.
+pub(crate) fn separated_by(
+    regions: &[Vec<Rc<StateNode>>],
+    source: &StateNode,
+    destination: &StateNode,
+) -> bool {
+    let source_region = regions.iter().position(|region| contains(region, source));
+    let destination_region = regions.iter().position(|region| contains(region, destination));
+    matches!((source_region, destination_region), (Some(a), Some(b)) if a != b)
+}
+
+/// Writes the provided states, each followed by the transitions nested within
+/// them, and then the remaining transitions of the region.
+pub(crate) fn fmt_region<'a>(
+    f: &mut fmt::Formatter<'_>,
+    tab_count: usize,
+    states: impl IntoIterator<Item = &'a StateNode>,
+    transitions: &[&StateTransition],
+) -> fmt::Result {
+    let mut remaining: Vec<&StateTransition> = transitions.to_vec();
+    for state in states {
+        let (nested, rest): (Vec<&StateTransition>, Vec<&StateTransition>) =
+            remaining.into_iter().partition(|transition| {
+                state.nests(transition.source()) && state.nests(transition.destination())
+            });
+        state.fmt_scoped(f, tab_count, &nested)?;
+        remaining = rest;
+    }
+    for transition in remaining {
+        transition.fmt_tabbed(f, tab_count)?;
+    }
+    Ok(())
+}
+
+/// Helper displaying how a state is referred to in transitions and notes.
+pub(crate) struct Reference<'a>(&'a StateNode);
+
+impl Display for Reference<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.kind.is_terminal() {
+            write!(f, "[*]")
+        } else {
+            write!(f, "{NODE_LETTER}{}", self.0.id())
+        }
+    }
+}
+
+impl Node for StateNode {
+    type Builder = StateNodeBuilder;
+
+    fn label(&self) -> &str {
+        self.node.label()
+    }
+
+    fn id(&self) -> u64 {
+        self.node.id()
+    }
+
+    fn styles(&self) -> impl Iterator<Item = &StyleProperty> {
+        self.node.styles()
+    }
+
+    fn classes(&self) -> impl Iterator<Item = &StyleClass> {
+        self.node.classes()
+    }
+
+    fn is_compatible_arrow_shape(shape: ArrowShape) -> bool {
+        matches!(shape, ArrowShape::Normal)
+    }
+}
+
+impl Display for StateNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for StateNode {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        self.fmt_scoped(f, tab_count, &[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format};
+
+    use super::*;
+    use crate::traits::NodeBuilder;
+
+    #[test]
+    fn test_state_node_display_kinds() -> Result<(), Box<dyn core::error::Error>> {
+        let start = StateNodeBuilder::default().kind(StateKind::Start).id(0).build()?;
+        assert_eq!(format!("{start}"), "");
+        assert_eq!(start.label(), "[*]");
+        assert_eq!(format!("{}", start.reference()), "[*]");
+
+        let fork =
+            StateNodeBuilder::default().label("Split")?.kind(StateKind::Fork).id(1).build()?;
+        assert_eq!(format!("{fork}"), "state v1 <<fork>>\n");
+        assert_eq!(format!("{}", fork.reference()), "v1");
+        Ok(())
+    }
+
+    #[test]
+    fn test_state_node_display_composite() -> Result<(), Box<dyn core::error::Error>> {
+        let first = Rc::new(StateNodeBuilder::default().label("First")?.id(0).build()?);
+        let second = Rc::new(StateNodeBuilder::default().label("Second")?.id(1).build()?);
+        let composite = StateNodeBuilder::default()
+            .label("Active")?
+            .id(2)
+            .substate(first.clone())?
+            .concurrent_region()?
+            .substate(second.clone())?
+            .direction(Direction::LeftToRight)
+            .note(StateNotePosition::RightOf, "Runs both")?
+            .build()?;
+
+        assert!(composite.is_composite());
+        assert!(composite.nests(&first));
+        assert!(!first.nests(&composite));
+        assert_eq!(composite.regions().count(), 2);
+        assert_eq!(composite.substates().count(), 2);
+        assert_eq!(
+            format!("{composite}"),
+            concat!(
+                "state \"Active\" as v2 {\n",
+                "  direction LR\n",
+                "  state \"First\" as v0\n",
+                "  --\n",
+                "  state \"Second\" as v1\n",
+                "}\n",
+                "note right of v2 : Runs both\n",
+            )
+        );
+        Ok(())
+    }
+}
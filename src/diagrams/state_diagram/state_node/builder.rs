@@ -0,0 +1,307 @@
+//! Submodule defining a builder struct to construct states in the state
+//! diagram Mermaid diagrams.
+
+use alloc::{
+    borrow::ToOwned,
+    rc::Rc,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    diagrams::state_diagram::state_node::{StateKind, StateNode, StateNote, StateNotePosition},
+    errors::NodeError,
+    shared::{
+        StyleClass, StyleClassError, StyleProperty, generic_configuration::Direction,
+        generic_node::GenericNodeBuilder,
+    },
+    traits::{Node, NodeBuilder},
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `StateNode`.
+///
+/// The `[*]` pseudo-states do not need a label, as they are rendered as `[*]`
+/// regardless of it.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let state = StateNodeBuilder::default()
+///         .label("Idle")?
+///         .note(StateNotePosition::RightOf, "Waiting for jobs")?
+///         .id(0)
+///         .build()?;
+///     let start = StateNodeBuilder::default().kind(StateKind::Start).id(1).build()?;
+///     Ok(())
+/// }
+/// ```
+pub struct StateNodeBuilder {
+    /// Shared attributes builder for the state.
+    builder: GenericNodeBuilder,
+    /// The kind of the state.
+    kind: StateKind,
+    /// The concurrent regions of the state, when it is a composite state.
+    regions: Vec<Vec<Rc<StateNode>>>,
+    /// The direction of the composite state, if applicable.
+    direction: Option<Direction>,
+    /// The note attached to the state, if any.
+    note: Option<StateNote>,
+}
+
+impl StateNodeBuilder {
+    /// Returns the label used in errors, falling back to `[*]` for the
+    /// unlabelled pseudo-states.
+    pub(crate) fn error_label(&self) -> String {
+        self.builder.get_label().cloned().unwrap_or_else(|| "[*]".to_owned())
+    }
+
+    #[must_use]
+    /// Sets the kind of the state.
+    pub fn kind(mut self, kind: StateKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Adds a substate to the current concurrent region of the state, making
+    /// it a composite state.
+    ///
+    /// # Arguments
+    ///
+    /// * `substate`: The substate to be added, wrapped in a `Rc` for shared
+    ///   ownership.
+    ///
+    /// # Errors
+    ///
+    /// * If the substate is already present in the state, an error is returned.
+    pub fn substate(mut self, substate: Rc<StateNode>) -> Result<Self, NodeError> {
+        if self.regions.iter().flatten().any(|state| state == &substate) {
+            return Err(NodeError::DuplicateNode(substate.label().to_owned()));
+        }
+
+        match self.regions.last_mut() {
+            Some(region) => region.push(substate),
+            None => self.regions.push(alloc::vec![substate]),
+        }
+        Ok(self)
+    }
+
+    /// Starts a new concurrent region, which is separated from the previous
+    /// one by `--` in the rendered diagram.
+    ///
+    /// # Errors
+    ///
+    /// * If the current region does not contain any substate.
+    pub fn concurrent_region(mut self) -> Result<Self, NodeError> {
+        if self.regions.last().is_none_or(Vec::is_empty) {
+            return Err(NodeError::EmptySubnodeGroup(self.error_label()));
+        }
+        self.regions.push(Vec::new());
+        Ok(self)
+    }
+
+    /// Returns an iterator over the substates added so far, across all of the
+    /// concurrent regions.
+    pub fn substates(&self) -> impl Iterator<Item = &Rc<StateNode>> {
+        self.regions.iter().flatten()
+    }
+
+    /// Returns whether the provided states lie in different concurrent
+    /// regions of the state being built.
+    pub(crate) fn separates(&self, source: &StateNode, destination: &StateNode) -> bool {
+        super::separated_by(&self.regions, source, destination)
+    }
+
+    #[must_use]
+    /// Returns whether the current state builder is a composite state.
+    pub fn is_composite(&self) -> bool {
+        !self.regions.is_empty()
+    }
+
+    #[must_use]
+    /// Sets the direction of the composite state.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction`: The direction of the composite state.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Attaches a note to the state.
+    ///
+    /// # Arguments
+    ///
+    /// * `position`: On which side of the state the note is placed.
+    /// * `text`: The text of the note, which may span multiple lines.
+    ///
+    /// # Errors
+    ///
+    /// * If the state is one of the `[*]` pseudo-states, which cannot be
+    ///   referred to by notes.
+    pub fn note(
+        mut self,
+        position: StateNotePosition,
+        text: impl Into<String>,
+    ) -> Result<Self, NodeError> {
+        if self.kind.is_terminal() {
+            return Err(NodeError::UnsupportedNote(self.error_label()));
+        }
+        self.note = Some(StateNote::new(position, text));
+        Ok(self)
+    }
+}
+
+impl TryFrom<StateNodeBuilder> for StateNode {
+    type Error = NodeError;
+
+    fn try_from(mut builder: StateNodeBuilder) -> Result<Self, Self::Error> {
+        if builder.kind != StateKind::State && !builder.regions.is_empty() {
+            return Err(NodeError::UnsupportedSubnodes(builder.error_label()));
+        }
+
+        if builder.regions.last().is_some_and(Vec::is_empty) {
+            return Err(NodeError::EmptySubnodeGroup(builder.error_label()));
+        }
+
+        if builder.direction.is_some() && builder.regions.is_empty() {
+            return Err(NodeError::MissingSubnodes);
+        }
+
+        if builder.kind.is_terminal() && builder.note.is_some() {
+            return Err(NodeError::UnsupportedNote(builder.error_label()));
+        }
+
+        if builder.kind.is_terminal() && builder.builder.get_label().is_none() {
+            builder.builder = builder.builder.label("[*]")?;
+        }
+
+        Ok(StateNode {
+            node: builder.builder.try_into()?,
+            kind: builder.kind,
+            regions: builder.regions,
+            direction: builder.direction,
+            note: builder.note,
+        })
+    }
+}
+
+impl NodeBuilder for StateNodeBuilder {
+    type Node = StateNode;
+    type Error = NodeError;
+
+    fn build(self) -> Result<Self::Node, Self::Error> {
+        self.try_into()
+    }
+
+    fn id(mut self, id: u64) -> Self {
+        self.builder = self.builder.id(id);
+        self
+    }
+
+    fn get_id(&self) -> Option<u64> {
+        self.builder.get_id()
+    }
+
+    fn label<S: ToString>(mut self, label: S) -> Result<Self, Self::Error> {
+        self.builder = self.builder.label(label)?;
+        Ok(self)
+    }
+
+    fn get_label(&self) -> Option<&String> {
+        self.builder.get_label()
+    }
+
+    fn style_class(mut self, style_class: Rc<StyleClass>) -> Result<Self, StyleClassError> {
+        self.builder = self.builder.style_class(style_class)?;
+        Ok(self)
+    }
+
+    fn style_property(mut self, property: StyleProperty) -> Result<Self, StyleClassError> {
+        self.builder = self.builder.style_property(property)?;
+        Ok(self)
+    }
+
+    fn style_properties(&self) -> impl Iterator<Item = &StyleProperty> {
+        self.builder.style_properties()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+
+    use super::*;
+
+    #[test]
+    fn test_state_node_builder() -> Result<(), Box<dyn core::error::Error>> {
+        let substate = Rc::new(StateNodeBuilder::default().label("Sub")?.id(1).build()?);
+
+        let builder = StateNodeBuilder::default().label("Parent")?.id(2);
+        assert!(!builder.is_composite());
+        let builder = builder.substate(substate.clone())?;
+        assert!(builder.is_composite());
+
+        let state = builder.direction(Direction::TopToBottom).build()?;
+        assert_eq!(state.id(), 2);
+        assert_eq!(state.label(), "Parent");
+        assert_eq!(state.kind(), StateKind::State);
+        assert_eq!(state.direction(), Some(Direction::TopToBottom));
+        assert_eq!(state.substates().collect::<Vec<_>>(), [&substate]);
+        assert!(state.note().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_state_node_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let substate = Rc::new(StateNodeBuilder::default().label("Sub")?.id(1).build()?);
+
+        assert_eq!(
+            StateNodeBuilder::default()
+                .label("Parent")?
+                .substate(substate.clone())?
+                .substate(substate.clone()),
+            Err(NodeError::DuplicateNode("Sub".to_owned()))
+        );
+        assert_eq!(
+            StateNodeBuilder::default().label("Parent")?.concurrent_region(),
+            Err(NodeError::EmptySubnodeGroup("Parent".to_owned()))
+        );
+        assert_eq!(
+            StateNodeBuilder::default()
+                .label("Parent")?
+                .id(0)
+                .substate(substate.clone())?
+                .concurrent_region()?
+                .build(),
+            Err(NodeError::EmptySubnodeGroup("Parent".to_owned()))
+        );
+        assert_eq!(
+            StateNodeBuilder::default()
+                .label("Choice")?
+                .kind(StateKind::Choice)
+                .substate(substate)?
+                .build(),
+            Err(NodeError::UnsupportedSubnodes("Choice".to_owned()))
+        );
+        assert_eq!(
+            StateNodeBuilder::default().kind(StateKind::End).note(StateNotePosition::LeftOf, "x"),
+            Err(NodeError::UnsupportedNote("[*]".to_owned()))
+        );
+        assert_eq!(
+            StateNodeBuilder::default()
+                .label("Plain")?
+                .id(0)
+                .direction(Direction::LeftToRight)
+                .build(),
+            Err(NodeError::MissingSubnodes)
+        );
+        assert_eq!(StateNodeBuilder::default().id(0).build(), Err(NodeError::MissingLabel));
+        Ok(())
+    }
+}
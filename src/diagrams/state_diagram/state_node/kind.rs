@@ -0,0 +1,39 @@
+//! Submodule defining the kinds of states available in state diagrams.
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the kind of a state in a state diagram.
+pub enum StateKind {
+    /// A regular state, which may also be a composite state.
+    #[default]
+    State,
+    /// The `[*]` pseudo-state from which the enclosing scope starts.
+    Start,
+    /// The `[*]` pseudo-state at which the enclosing scope ends.
+    End,
+    /// A `<<choice>>` pseudo-state, branching on a condition.
+    Choice,
+    /// A `<<fork>>` pseudo-state, splitting into concurrent paths.
+    Fork,
+    /// A `<<join>>` pseudo-state, merging concurrent paths.
+    Join,
+}
+
+impl StateKind {
+    #[must_use]
+    /// Returns the stereotype used to declare the pseudo-state, if any.
+    pub fn stereotype(self) -> Option<&'static str> {
+        match self {
+            StateKind::Choice => Some("choice"),
+            StateKind::Fork => Some("fork"),
+            StateKind::Join => Some("join"),
+            StateKind::State | StateKind::Start | StateKind::End => None,
+        }
+    }
+
+    #[must_use]
+    /// Returns whether the state is one of the `[*]` pseudo-states.
+    pub fn is_terminal(self) -> bool {
+        matches!(self, StateKind::Start | StateKind::End)
+    }
+}
@@ -0,0 +1,99 @@
+//! Submodule defining notes which may be attached to states of a state
+//! diagram in Mermaid syntax.
+
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+use crate::diagrams::state_diagram::state_node::StateNode;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents on which side of the state a note is placed.
+pub enum StateNotePosition {
+    /// The note is placed to the left of the state.
+    LeftOf,
+    /// The note is placed to the right of the state.
+    RightOf,
+}
+
+impl Display for StateNotePosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StateNotePosition::LeftOf => write!(f, "left of"),
+            StateNotePosition::RightOf => write!(f, "right of"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a note attached to a state.
+pub struct StateNote {
+    /// Where the note is placed.
+    position: StateNotePosition,
+    /// The text of the note.
+    text: String,
+}
+
+impl StateNote {
+    /// Creates a new note at the provided position.
+    pub fn new(position: StateNotePosition, text: impl Into<String>) -> Self {
+        Self { position, text: text.into() }
+    }
+
+    #[must_use]
+    /// Returns where the note is placed.
+    pub fn position(&self) -> StateNotePosition {
+        self.position
+    }
+
+    #[must_use]
+    /// Returns the text of the note.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Writes the note attached to the provided state, using the multi-line
+    /// syntax when the text spans several lines.
+    pub(crate) fn fmt_attached(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        tab_count: usize,
+        state: &StateNode,
+    ) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        if self.text.contains('\n') {
+            writeln!(f, "{indent}note {} {}", self.position, state.reference())?;
+            for line in self.text.lines() {
+                writeln!(f, "{indent}  {line}")?;
+            }
+            writeln!(f, "{indent}end note")
+        } else {
+            writeln!(f, "{indent}note {} {} : {}", self.position, state.reference(), self.text)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format};
+
+    use super::*;
+    use crate::{diagrams::state_diagram::StateNodeBuilder, traits::NodeBuilder};
+
+    #[test]
+    fn test_state_note_multiline() -> Result<(), Box<dyn core::error::Error>> {
+        let state = StateNodeBuilder::default()
+            .label("Idle")?
+            .id(3)
+            .note(StateNotePosition::LeftOf, "Waiting for\ninput")?
+            .build()?;
+
+        assert_eq!(state.note().map(StateNote::position), Some(StateNotePosition::LeftOf));
+        assert_eq!(
+            format!("{state}"),
+            "state \"Idle\" as v3\nnote left of v3\n  Waiting for\n  input\nend note\n"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+//! Submodule defining a transition between states of a state diagram in
+//! Mermaid syntax.
+
+mod builder;
+use alloc::rc::Rc;
+use core::fmt::{self, Display};
+
+pub use builder::StateTransitionBuilder;
+
+use crate::{
+    diagrams::state_diagram::state_node::StateNode,
+    shared::{ArrowShape, GenericEdge, LineStyle, StyleClass},
+    traits::{Edge, TabbedDisplay},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a transition from one state to another in a state diagram,
+/// optionally labelled with the event triggering it.
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let start = Rc::new(StateNodeBuilder::default().kind(StateKind::Start).id(0).build()?);
+///     let idle = Rc::new(StateNodeBuilder::default().label("Idle")?.id(1).build()?);
+///
+///     let transition = StateTransitionBuilder::default()
+///         .source(start)?
+///         .destination(idle)?
+///         .label("boot")?
+///         .build()?;
+///
+///     assert_eq!(transition.to_string(), "[*] --> v1 : boot\n");
+///     Ok(())
+/// }
+/// ```
+pub struct StateTransition {
+    /// Underlying generic edge.
+    edge: GenericEdge<StateNode>,
+}
+
+impl Edge for StateTransition {
+    type Builder = StateTransitionBuilder;
+    type Node = StateNode;
+
+    fn label(&self) -> Option<&str> {
+        self.edge.label()
+    }
+
+    fn source(&self) -> &Rc<Self::Node> {
+        self.edge.source()
+    }
+
+    fn destination(&self) -> &Rc<Self::Node> {
+        self.edge.destination()
+    }
+
+    fn line_style(&self) -> LineStyle {
+        self.edge.line_style()
+    }
+
+    fn classes(&self) -> impl Iterator<Item = &StyleClass> {
+        self.edge.classes()
+    }
+
+    fn left_arrow_shape(&self) -> Option<ArrowShape> {
+        self.edge.left_arrow_shape()
+    }
+
+    fn right_arrow_shape(&self) -> Option<ArrowShape> {
+        self.edge.right_arrow_shape()
+    }
+}
+
+impl Display for StateTransition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for StateTransition {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        write!(f, "{indent}{} --> {}", self.source().reference(), self.destination().reference())?;
+        if let Some(label) = self.label() {
+            write!(f, " : {label}")?;
+        }
+        writeln!(f)
+    }
+}
@@ -0,0 +1,169 @@
+//! Submodule defining the struct for building a state diagram transition.
+
+use alloc::{borrow::ToOwned, rc::Rc, string::ToString};
+
+use crate::{
+    diagrams::state_diagram::{
+        state_node::{StateKind, StateNode},
+        state_transition::StateTransition,
+    },
+    errors::EdgeError,
+    shared::{ArrowShape, LineStyle, generic_edge::GenericEdgeBuilder},
+    traits::{Edge, EdgeBuilder, Node},
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `StateTransition`.
+///
+/// Transitions are always rendered as `-->`, so only the solid line style and
+/// the normal right arrow shape are accepted.
+///
+/// # Example
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let idle = Rc::new(StateNodeBuilder::default().label("Idle")?.id(0).build()?);
+///     let busy = Rc::new(StateNodeBuilder::default().label("Busy")?.id(1).build()?);
+///
+///     let transition = StateTransitionBuilder::default()
+///         .source(idle)?
+///         .destination(busy)?
+///         .label("job")?
+///         .build()?;
+///     Ok(())
+/// }
+/// ```
+pub struct StateTransitionBuilder {
+    /// Underlying generic edge builder.
+    edge_builder: GenericEdgeBuilder<StateNode>,
+}
+
+impl TryFrom<StateTransitionBuilder> for StateTransition {
+    type Error = EdgeError;
+
+    fn try_from(builder: StateTransitionBuilder) -> Result<Self, Self::Error> {
+        let edge = builder.edge_builder.build()?;
+
+        if edge.line_style() != LineStyle::Solid {
+            return Err(EdgeError::UnsupportedLineStyle(edge.line_style()));
+        }
+
+        if let Some(left) = edge.left_arrow_shape() {
+            return Err(EdgeError::IncompatibleLeftArrowShape(left));
+        }
+
+        if edge.source().kind() == StateKind::End {
+            return Err(EdgeError::InvalidSource(edge.source().label().to_owned()));
+        }
+
+        if edge.destination().kind() == StateKind::Start {
+            return Err(EdgeError::InvalidDestination(edge.destination().label().to_owned()));
+        }
+
+        Ok(StateTransition { edge })
+    }
+}
+
+impl EdgeBuilder for StateTransitionBuilder {
+    type Edge = StateTransition;
+    type Node = StateNode;
+    type Error = EdgeError;
+
+    fn build(self) -> Result<Self::Edge, Self::Error> {
+        self.try_into()
+    }
+
+    fn source(mut self, node: Rc<Self::Node>) -> Result<Self, Self::Error> {
+        self.edge_builder = self.edge_builder.source(node)?;
+        Ok(self)
+    }
+
+    fn destination(mut self, node: Rc<Self::Node>) -> Result<Self, Self::Error> {
+        self.edge_builder = self.edge_builder.destination(node)?;
+        Ok(self)
+    }
+
+    fn label<S: ToString>(mut self, label: S) -> Result<Self, Self::Error> {
+        self.edge_builder = self.edge_builder.label(label)?;
+        Ok(self)
+    }
+
+    fn line_style(mut self, style: LineStyle) -> Self {
+        self.edge_builder = self.edge_builder.line_style(style);
+        self
+    }
+
+    fn left_arrow_shape(mut self, shape: ArrowShape) -> Result<Self, Self::Error> {
+        self.edge_builder = self.edge_builder.left_arrow_shape(shape)?;
+        Ok(self)
+    }
+
+    fn right_arrow_shape(mut self, shape: ArrowShape) -> Result<Self, Self::Error> {
+        self.edge_builder = self.edge_builder.right_arrow_shape(shape)?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+
+    use super::*;
+    use crate::{diagrams::state_diagram::StateNodeBuilder, traits::NodeBuilder};
+
+    #[test]
+    fn test_state_transition_builder() -> Result<(), Box<dyn core::error::Error>> {
+        let idle = Rc::new(StateNodeBuilder::default().label("Idle")?.id(0).build()?);
+        let busy = Rc::new(StateNodeBuilder::default().label("Busy")?.id(1).build()?);
+
+        let transition = StateTransitionBuilder::default()
+            .source(idle)?
+            .destination(busy)?
+            .label("job")?
+            .right_arrow_shape(ArrowShape::Normal)?
+            .build()?;
+
+        assert_eq!(transition.source().id(), 0);
+        assert_eq!(transition.destination().id(), 1);
+        assert_eq!(transition.label(), Some("job"));
+        assert_eq!(transition.line_style(), LineStyle::Solid);
+        Ok(())
+    }
+
+    #[test]
+    fn test_state_transition_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let start = Rc::new(StateNodeBuilder::default().kind(StateKind::Start).id(0).build()?);
+        let idle = Rc::new(StateNodeBuilder::default().label("Idle")?.id(1).build()?);
+        let end =
+            Rc::new(StateNodeBuilder::default().label("Done")?.kind(StateKind::End).id(2).build()?);
+        let base =
+            StateTransitionBuilder::default().source(idle.clone())?.destination(end.clone())?;
+
+        assert_eq!(
+            base.clone().line_style(LineStyle::Dashed).build(),
+            Err(EdgeError::UnsupportedLineStyle(LineStyle::Dashed))
+        );
+        assert_eq!(
+            base.clone().left_arrow_shape(ArrowShape::Normal)?.build(),
+            Err(EdgeError::IncompatibleLeftArrowShape(ArrowShape::Normal))
+        );
+        assert_eq!(
+            base.right_arrow_shape(ArrowShape::X),
+            Err(EdgeError::IncompatibleRightArrowShape(ArrowShape::X))
+        );
+        assert_eq!(
+            StateTransitionBuilder::default().source(end)?.destination(idle.clone())?.build(),
+            Err(EdgeError::InvalidSource("Done".to_owned()))
+        );
+        assert_eq!(
+            StateTransitionBuilder::default().source(idle)?.destination(start)?.build(),
+            Err(EdgeError::InvalidDestination("[*]".to_owned()))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+//! Submodule providing structs for creating timelines in Mermaid syntax.
+
+mod builder;
+mod configuration;
+mod timeline_period;
+mod timeline_section;
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+pub use builder::TimelineBuilder;
+pub use configuration::{TimelineConfiguration, TimelineConfigurationBuilder};
+pub use timeline_period::{TimelinePeriod, TimelinePeriodBuilder};
+pub use timeline_section::TimelineSection;
+
+use crate::traits::{Configuration, TabbedDisplay};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a timeline in Mermaid syntax.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = TimelineBuilder::default()
+///         .configuration(TimelineConfigurationBuilder::default().title("Releases")?)?;
+///     builder.period(TimelinePeriodBuilder::default().label("2023")?.event("v0.1")?)?;
+///     builder.period(
+///         TimelinePeriodBuilder::default().label("2024")?.event("v0.2")?.event("v1.0")?,
+///     )?;
+///
+///     let timeline = Timeline::from(builder);
+///     assert_eq!(
+///         timeline.to_string(),
+///         "timeline\n  title Releases\n  2023 : v0.1\n  2024 : v0.2 : v1.0\n"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct Timeline {
+    /// Configuration of the timeline.
+    configuration: TimelineConfiguration,
+    /// Sections of the timeline.
+    sections: Vec<TimelineSection>,
+}
+
+impl Timeline {
+    #[must_use]
+    /// Returns the configuration of the timeline.
+    pub fn configuration(&self) -> &TimelineConfiguration {
+        &self.configuration
+    }
+
+    /// Returns an iterator over the sections of the timeline.
+    pub fn sections(&self) -> impl Iterator<Item = &TimelineSection> {
+        self.sections.iter()
+    }
+
+    /// Returns an iterator over the periods of the timeline.
+    pub fn periods(&self) -> impl Iterator<Item = &TimelinePeriod> {
+        self.sections.iter().flat_map(TimelineSection::periods)
+    }
+}
+
+impl Display for Timeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for Timeline {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat((tab_count + 1) * 2);
+        write!(f, "{}", self.configuration)?;
+        writeln!(f, "{}timeline", " ".repeat(tab_count * 2))?;
+        if let Some(title) = self.configuration.title() {
+            writeln!(f, "{indent}title {title}")?;
+        }
+        for section in &self.sections {
+            let period_tab_count = if let Some(label) = section.label() {
+                writeln!(f, "{indent}section {label}")?;
+                tab_count + 2
+            } else {
+                tab_count + 1
+            };
+            for period in section.periods() {
+                period.fmt_tabbed(f, period_tab_count)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::{shared::generic_configuration::Theme, traits::ConfigurationBuilder};
+
+    #[test]
+    fn test_timeline_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = TimelineBuilder::default().configuration(
+            TimelineConfigurationBuilder::default()
+                .title("Lab history")?
+                .theme(Theme::Neutral)
+                .disable_multicolor(true),
+        )?;
+        builder.section("Founding")?;
+        builder.period(TimelinePeriodBuilder::default().label("2015")?.event("First grant")?)?;
+        builder.section("Growth")?;
+        builder.period(
+            TimelinePeriodBuilder::default()
+                .label("2018")?
+                .event("New building")?
+                .event("Mass spectrometer")?,
+        )?;
+        builder.period(TimelinePeriodBuilder::default().label("2021")?.event("Spin-off")?)?;
+
+        let timeline = Timeline::from(builder);
+        assert_eq!(timeline.sections().count(), 2);
+        assert_eq!(timeline.periods().count(), 3);
+        assert_eq!(
+            timeline.to_string(),
+            concat!(
+                "---\n",
+                "config:\n",
+                "  theme: neutral\n",
+                "  look: classic\n",
+                "  timeline:\n",
+                "    disableMulticolor: true\n",
+                "---\n",
+                "timeline\n",
+                "  title Lab history\n",
+                "  section Founding\n",
+                "    2015 : First grant\n",
+                "  section Growth\n",
+                "    2018 : New building : Mass spectrometer\n",
+                "    2021 : Spin-off\n",
+            )
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+//! Submodule providing a builder for timelines.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    diagrams::timeline::{
+        Timeline,
+        configuration::{TimelineConfiguration, TimelineConfigurationBuilder},
+        timeline_period::TimelinePeriodBuilder,
+        timeline_section::TimelineSection,
+    },
+    errors::{ConfigError, TimelineError},
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a timeline.
+///
+/// Periods are added to the most recently opened section.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = TimelineBuilder::default();
+///     builder.section("Early days")?;
+///     builder.period(TimelinePeriodBuilder::default().label("2019")?.event("First commit")?)?;
+///
+///     let timeline = Timeline::from(builder);
+///     assert_eq!(timeline.periods().count(), 1);
+///     Ok(())
+/// }
+/// ```
+pub struct TimelineBuilder {
+    /// Configuration of the timeline.
+    configuration: TimelineConfiguration,
+    /// Sections of the timeline.
+    sections: Vec<TimelineSection>,
+}
+
+impl TimelineBuilder {
+    /// Sets the configuration of the timeline.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: TimelineConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Opens a new section, to which the subsequently added periods belong.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty.
+    /// * If the label contains a colon, a hash or a line break.
+    pub fn section(&mut self, label: impl Into<String>) -> Result<(), TimelineError> {
+        let label = label.into();
+        if label.trim().is_empty() {
+            return Err(TimelineError::EmptySectionLabel);
+        }
+        if label.contains([':', '#', '\n', '\r']) {
+            return Err(TimelineError::InvalidSectionLabel(label));
+        }
+        self.sections.push(TimelineSection::new(Some(label)));
+        Ok(())
+    }
+
+    /// Adds a period to the current section of the timeline.
+    ///
+    /// # Errors
+    ///
+    /// * If the period builder fails to build.
+    pub fn period(&mut self, period: TimelinePeriodBuilder) -> Result<(), TimelineError> {
+        let period = period.build()?;
+        if self.sections.is_empty() {
+            self.sections.push(TimelineSection::new(None));
+        }
+        if let Some(section) = self.sections.last_mut() {
+            section.push(period);
+        }
+        Ok(())
+    }
+}
+
+impl From<TimelineBuilder> for Timeline {
+    fn from(builder: TimelineBuilder) -> Self {
+        Timeline { configuration: builder.configuration, sections: builder.sections }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box};
+
+    use super::*;
+
+    #[test]
+    fn test_timeline_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = TimelineBuilder::default();
+        assert_eq!(builder.section(""), Err(TimelineError::EmptySectionLabel));
+        assert_eq!(
+            builder.section("Q1: planning"),
+            Err(TimelineError::InvalidSectionLabel("Q1: planning".to_owned()))
+        );
+        assert_eq!(
+            builder.period(TimelinePeriodBuilder::default().label("2024")?),
+            Err(TimelineError::PeriodWithoutEvents("2024".to_owned()))
+        );
+        assert_eq!(Timeline::from(builder).sections().count(), 0);
+        Ok(())
+    }
+}
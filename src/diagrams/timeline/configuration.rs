@@ -0,0 +1,110 @@
+//! Submodule defining configuration specifically for timelines in Mermaid.
+
+mod builder;
+
+use alloc::{string::ToString, vec::Vec};
+use core::fmt::Display;
+
+pub use builder::TimelineConfigurationBuilder;
+
+use crate::{
+    shared::{
+        Direction, Renderer,
+        generic_configuration::{GenericConfiguration, Look, Theme},
+    },
+    traits::Configuration,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the configuration for a timeline in Mermaid syntax.
+///
+/// The title of a timeline is written within the diagram rather than in its
+/// frontmatter.
+pub struct TimelineConfiguration {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfiguration,
+    /// Whether to draw all the sections and periods with the same color.
+    disable_multicolor: bool,
+}
+
+impl TimelineConfiguration {
+    #[must_use]
+    /// Returns whether all the sections and periods are drawn with the same
+    /// color.
+    pub fn disable_multicolor(&self) -> bool {
+        self.disable_multicolor
+    }
+}
+
+impl Display for TimelineConfiguration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut options = Vec::new();
+        if self.disable_multicolor {
+            options.push(("timeline.disableMulticolor".to_string(), "true".to_string()));
+        }
+        self.generic.fmt_frontmatter(f, false, &options)
+    }
+}
+
+impl Configuration for TimelineConfiguration {
+    type Builder = TimelineConfigurationBuilder;
+
+    fn title(&self) -> Option<&str> {
+        self.generic.title()
+    }
+
+    fn direction(&self) -> Direction {
+        self.generic.direction()
+    }
+
+    fn renderer(&self) -> Renderer {
+        self.generic.renderer()
+    }
+
+    fn theme(&self) -> Theme {
+        self.generic.theme()
+    }
+
+    fn look(&self) -> Look {
+        self.generic.look()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format};
+
+    use super::*;
+    use crate::traits::ConfigurationBuilder;
+
+    #[test]
+    fn test_timeline_configuration_display_default() -> Result<(), Box<dyn core::error::Error>> {
+        let config = TimelineConfigurationBuilder::default().title("History")?.build()?;
+        assert_eq!(format!("{config}"), "");
+        Ok(())
+    }
+
+    #[test]
+    fn test_timeline_configuration_display_full() -> Result<(), Box<dyn core::error::Error>> {
+        let config = TimelineConfigurationBuilder::default()
+            .theme(Theme::Forest)
+            .disable_multicolor(true)
+            .build()?;
+
+        assert!(config.disable_multicolor());
+        assert_eq!(
+            format!("{config}"),
+            concat!(
+                "---\n",
+                "config:\n",
+                "  theme: forest\n",
+                "  look: classic\n",
+                "  timeline:\n",
+                "    disableMulticolor: true\n",
+                "---\n",
+            )
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+//! Submodule providing a builder struct for the configuration of timelines in
+//! Mermaid syntax.
+
+use alloc::string::ToString;
+
+use crate::{
+    diagrams::timeline::configuration::TimelineConfiguration,
+    errors::ConfigError,
+    shared::generic_configuration::{
+        Direction, GenericConfigurationBuilder, Look, Renderer, Theme,
+    },
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating timeline configurations.
+pub struct TimelineConfigurationBuilder {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfigurationBuilder,
+    /// Whether to draw all the sections and periods with the same color.
+    disable_multicolor: bool,
+}
+
+impl TimelineConfigurationBuilder {
+    #[must_use]
+    /// Sets whether to draw all the sections and periods with the same color.
+    pub fn disable_multicolor(mut self, disable_multicolor: bool) -> Self {
+        self.disable_multicolor = disable_multicolor;
+        self
+    }
+
+    /// Sets the theme to use for the diagram.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.generic = self.generic.theme(theme);
+        self
+    }
+
+    /// Sets the look to use for the diagram.
+    #[must_use]
+    pub fn look(mut self, look: Look) -> Self {
+        self.generic = self.generic.look(look);
+        self
+    }
+}
+
+impl TryFrom<TimelineConfigurationBuilder> for TimelineConfiguration {
+    type Error = ConfigError;
+
+    fn try_from(builder: TimelineConfigurationBuilder) -> Result<Self, Self::Error> {
+        Ok(TimelineConfiguration {
+            generic: builder.generic.try_into()?,
+            disable_multicolor: builder.disable_multicolor,
+        })
+    }
+}
+
+impl ConfigurationBuilder for TimelineConfigurationBuilder {
+    type Configuration = TimelineConfiguration;
+    type Error = ConfigError;
+
+    fn build(self) -> Result<Self::Configuration, Self::Error> {
+        self.try_into()
+    }
+
+    fn title<S: ToString>(mut self, title: S) -> Result<Self, Self::Error> {
+        self.generic = self.generic.title(title)?;
+        Ok(self)
+    }
+
+    fn direction(mut self, direction: Direction) -> Self {
+        self.generic = self.generic.direction(direction);
+        self
+    }
+
+    fn renderer(mut self, renderer: Renderer) -> Self {
+        self.generic = self.generic.renderer(renderer);
+        self
+    }
+}
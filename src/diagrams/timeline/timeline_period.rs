@@ -0,0 +1,65 @@
+//! Submodule defining a time period of a timeline in Mermaid syntax.
+
+mod builder;
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::TimelinePeriodBuilder;
+
+use crate::traits::TabbedDisplay;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a time period of a timeline, carrying one or more events.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let period = TimelinePeriodBuilder::default()
+///         .label("2004")?
+///         .event("Facebook")?
+///         .event("Google")?
+///         .build()?;
+///
+///     assert_eq!(period.to_string(), "2004 : Facebook : Google\n");
+///     Ok(())
+/// }
+/// ```
+pub struct TimelinePeriod {
+    /// Label of the period, e.g. a year.
+    label: String,
+    /// Events which happened during the period.
+    events: Vec<String>,
+}
+
+impl TimelinePeriod {
+    #[must_use]
+    /// Returns the label of the period.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns an iterator over the events of the period.
+    pub fn events(&self) -> impl Iterator<Item = &str> {
+        self.events.iter().map(String::as_str)
+    }
+}
+
+impl Display for TimelinePeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for TimelinePeriod {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        write!(f, "{}{}", " ".repeat(tab_count * 2), self.label)?;
+        for event in &self.events {
+            write!(f, " : {event}")?;
+        }
+        writeln!(f)
+    }
+}
@@ -0,0 +1,112 @@
+//! Submodule defining the struct for building a time period of a timeline.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{diagrams::timeline::timeline_period::TimelinePeriod, errors::TimelineError};
+
+/// Characters which may not appear in period labels and events, as they
+/// delimit events or start comments.
+const RESERVED_CHARACTERS: [char; 4] = [':', '#', '\n', '\r'];
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `TimelinePeriod`.
+pub struct TimelinePeriodBuilder {
+    /// Label of the period.
+    label: Option<String>,
+    /// Events which happened during the period.
+    events: Vec<String>,
+}
+
+impl TimelinePeriodBuilder {
+    /// Sets the label of the period.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty.
+    /// * If the label contains a colon, a hash or a line break.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, TimelineError> {
+        let label = label.into();
+        if label.trim().is_empty() {
+            return Err(TimelineError::EmptyPeriodLabel);
+        }
+        if label.contains(RESERVED_CHARACTERS) {
+            return Err(TimelineError::InvalidPeriodLabel(label));
+        }
+        self.label = Some(label);
+        Ok(self)
+    }
+
+    /// Adds an event to the period.
+    ///
+    /// # Errors
+    ///
+    /// * If the event is empty.
+    /// * If the event contains a colon, a hash or a line break.
+    pub fn event(mut self, event: impl Into<String>) -> Result<Self, TimelineError> {
+        let event = event.into();
+        if event.trim().is_empty() {
+            return Err(TimelineError::EmptyEvent);
+        }
+        if event.contains(RESERVED_CHARACTERS) {
+            return Err(TimelineError::InvalidEvent(event));
+        }
+        self.events.push(event);
+        Ok(self)
+    }
+
+    /// Builds the period.
+    ///
+    /// # Errors
+    ///
+    /// * If the label of the period is missing.
+    /// * If the period has no events.
+    pub fn build(self) -> Result<TimelinePeriod, TimelineError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<TimelinePeriodBuilder> for TimelinePeriod {
+    type Error = TimelineError;
+
+    fn try_from(builder: TimelinePeriodBuilder) -> Result<Self, Self::Error> {
+        let label = builder.label.ok_or(TimelineError::MissingPeriodLabel)?;
+        if builder.events.is_empty() {
+            return Err(TimelineError::PeriodWithoutEvents(label));
+        }
+        Ok(TimelinePeriod { label, events: builder.events })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, boxed::Box};
+
+    use super::*;
+
+    #[test]
+    fn test_timeline_period_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        assert_eq!(
+            TimelinePeriodBuilder::default().label(" "),
+            Err(TimelineError::EmptyPeriodLabel)
+        );
+        assert_eq!(
+            TimelinePeriodBuilder::default().label("12:00"),
+            Err(TimelineError::InvalidPeriodLabel("12:00".to_owned()))
+        );
+        assert_eq!(TimelinePeriodBuilder::default().event(""), Err(TimelineError::EmptyEvent));
+        assert_eq!(
+            TimelinePeriodBuilder::default().event("#1"),
+            Err(TimelineError::InvalidEvent("#1".to_owned()))
+        );
+        assert_eq!(
+            TimelinePeriodBuilder::default().event("Launch")?.build(),
+            Err(TimelineError::MissingPeriodLabel)
+        );
+        assert_eq!(
+            TimelinePeriodBuilder::default().label("2024")?.build(),
+            Err(TimelineError::PeriodWithoutEvents("2024".to_owned()))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+//! Submodule defining a section grouping time periods of a timeline.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::diagrams::timeline::timeline_period::TimelinePeriod;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a section of a timeline, i.e. a group of consecutive periods.
+///
+/// Periods added before any section is opened belong to an unlabelled
+/// section.
+pub struct TimelineSection {
+    /// Label of the section, if any.
+    label: Option<String>,
+    /// Periods of the section, in the order in which they were added.
+    periods: Vec<TimelinePeriod>,
+}
+
+impl TimelineSection {
+    /// Creates a new empty section.
+    pub(crate) fn new(label: Option<String>) -> Self {
+        Self { label, periods: Vec::new() }
+    }
+
+    /// Adds a period to the section.
+    pub(crate) fn push(&mut self, period: TimelinePeriod) {
+        self.periods.push(period);
+    }
+
+    #[must_use]
+    /// Returns the label of the section, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Returns an iterator over the periods of the section.
+    pub fn periods(&self) -> impl Iterator<Item = &TimelinePeriod> {
+        self.periods.iter()
+    }
+}
@@ -0,0 +1,219 @@
+//! Submodule providing structs for creating treemaps in Mermaid syntax.
+
+mod builder;
+mod configuration;
+mod treemap_leaf;
+mod treemap_section;
+use alloc::{rc::Rc, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::TreemapBuilder;
+pub use configuration::{TreemapConfiguration, TreemapConfigurationBuilder};
+pub use treemap_leaf::{TreemapLeaf, TreemapLeafBuilder};
+pub use treemap_section::{TreemapSection, TreemapSectionBuilder};
+
+use crate::{errors::TreemapError, shared::StyleClass, traits::TabbedDisplay};
+
+/// Validates a label of a treemap, which is written within double quotes.
+pub(crate) fn validate_text(text: impl Into<String>) -> Result<String, TreemapError> {
+    let text = text.into();
+    if text.trim().is_empty() {
+        return Err(TreemapError::EmptyText);
+    }
+    if text.contains(['"', '\n', '\r']) {
+        return Err(TreemapError::InvalidText(text));
+    }
+    Ok(text)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A member of a treemap, nested within a section or at the top level.
+pub(crate) enum TreemapMember {
+    /// A section, which may contain further members.
+    Section(Rc<TreemapSection>),
+    /// A leaf carrying a value.
+    Leaf(Rc<TreemapLeaf>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a treemap in Mermaid syntax.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = TreemapBuilder::default();
+///     let raw = builder.section(None, TreemapSectionBuilder::default().label("Raw")?)?;
+///     builder.leaf(Some(&raw), TreemapLeafBuilder::default().label("Images")?.value(120.0)?)?;
+///     builder.leaf(Some(&raw), TreemapLeafBuilder::default().label("Logs")?.value(4.5)?)?;
+///
+///     let treemap = Treemap::from(builder);
+///     assert_eq!(treemap.value(&raw), 124.5);
+///     assert_eq!(
+///         treemap.to_string(),
+///         "treemap-beta\n  \"Raw\"\n    \"Images\": 120\n    \"Logs\": 4.5\n"
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct Treemap {
+    /// Configuration of the treemap.
+    configuration: TreemapConfiguration,
+    /// Style classes defined in the treemap.
+    style_classes: Vec<Rc<StyleClass>>,
+    /// Members of the treemap, along with the identifier of their section,
+    /// if any.
+    members: Vec<(Option<u64>, TreemapMember)>,
+}
+
+impl Treemap {
+    #[must_use]
+    /// Returns the configuration of the treemap.
+    pub fn configuration(&self) -> &TreemapConfiguration {
+        &self.configuration
+    }
+
+    /// Returns an iterator over the style classes defined in the treemap.
+    pub fn style_classes(&self) -> impl Iterator<Item = &StyleClass> {
+        self.style_classes.iter().map(AsRef::as_ref)
+    }
+
+    /// Returns an iterator over the sections of the treemap, at any depth.
+    pub fn sections(&self) -> impl Iterator<Item = &TreemapSection> {
+        self.members.iter().filter_map(|(_, member)| match member {
+            TreemapMember::Section(section) => Some(section.as_ref()),
+            TreemapMember::Leaf(_) => None,
+        })
+    }
+
+    /// Returns an iterator over the leaves of the treemap, at any depth.
+    pub fn leaves(&self) -> impl Iterator<Item = &TreemapLeaf> {
+        self.members.iter().filter_map(|(_, member)| match member {
+            TreemapMember::Section(_) => None,
+            TreemapMember::Leaf(leaf) => Some(leaf.as_ref()),
+        })
+    }
+
+    #[must_use]
+    /// Returns the total value of the leaves nested, at any depth, within the
+    /// provided section.
+    pub fn value(&self, section: &TreemapSection) -> f64 {
+        self.members
+            .iter()
+            .filter(|(parent, _)| *parent == Some(section.id()))
+            .map(|(_, member)| match member {
+                TreemapMember::Section(section) => self.value(section),
+                TreemapMember::Leaf(leaf) => leaf.value(),
+            })
+            .sum()
+    }
+
+    /// Writes the members nested directly within the provided section, or the
+    /// top-level members, followed by their own nested members.
+    fn fmt_members(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        parent: Option<u64>,
+        tab_count: usize,
+    ) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        for (_, member) in self.members.iter().filter(|(member_parent, _)| *member_parent == parent)
+        {
+            match member {
+                TreemapMember::Section(section) => {
+                    writeln!(f, "{indent}{section}")?;
+                    self.fmt_members(f, Some(section.id()), tab_count + 1)?;
+                }
+                TreemapMember::Leaf(leaf) => writeln!(f, "{indent}{leaf}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for Treemap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for Treemap {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        write!(f, "{}", self.configuration)?;
+        writeln!(f, "{}treemap-beta", " ".repeat(tab_count * 2))?;
+        self.fmt_members(f, None, tab_count + 1)?;
+        for style_class in &self.style_classes {
+            style_class.fmt_tabbed(f, tab_count + 1)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::{
+        shared::{Color, StyleClassBuilder, StyleProperty},
+        traits::ConfigurationBuilder,
+    };
+
+    #[test]
+    fn test_treemap_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = TreemapBuilder::default().configuration(
+            TreemapConfigurationBuilder::default().title("Storage")?.value_format(",")?,
+        )?;
+        let hot = builder.style_class(
+            StyleClassBuilder::default()
+                .name("hot")?
+                .property(StyleProperty::Fill(Color::from((255, 0, 0))))?,
+        )?;
+        let survey = builder.section(None, TreemapSectionBuilder::default().label("Survey")?)?;
+        builder
+            .leaf(Some(&survey), TreemapLeafBuilder::default().label("Catalog")?.value(1500.0)?)?;
+        let spectra = builder.section(
+            Some(&survey),
+            TreemapSectionBuilder::default().label("Spectra")?.style_class(hot.clone()),
+        )?;
+        builder.leaf(
+            Some(&spectra),
+            TreemapLeafBuilder::default().label("Raw")?.value(12000.0)?.style_class(hot),
+        )?;
+        builder
+            .leaf(Some(&spectra), TreemapLeafBuilder::default().label("Reduced")?.value(800.0)?)?;
+        builder.leaf(None, TreemapLeafBuilder::default().label("Scratch")?.value(-0.0)?)?;
+
+        let treemap = Treemap::from(builder);
+        assert_eq!(treemap.sections().count(), 2);
+        assert_eq!(treemap.leaves().count(), 4);
+        assert_eq!(treemap.value(&survey).to_bits(), 14300.0_f64.to_bits());
+        assert_eq!(treemap.value(&spectra).to_bits(), 12800.0_f64.to_bits());
+        assert_eq!(
+            treemap.to_string(),
+            concat!(
+                "---\n",
+                "title: Storage\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "  treemap:\n",
+                "    valueFormat: ','\n",
+                "---\n",
+                "treemap-beta\n",
+                "  \"Survey\"\n",
+                "    \"Catalog\": 1500\n",
+                "    \"Spectra\":::hot\n",
+                "      \"Raw\": 12000:::hot\n",
+                "      \"Reduced\": 800\n",
+                "  \"Scratch\": 0\n",
+                "  classDef hot fill: #ff0000\n",
+            )
+        );
+        Ok(())
+    }
+}
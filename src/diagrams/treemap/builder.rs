@@ -0,0 +1,237 @@
+//! Submodule providing a builder for treemaps.
+
+use alloc::{rc::Rc, string::ToString, vec::Vec};
+
+use crate::{
+    diagrams::treemap::{
+        Treemap, TreemapMember,
+        configuration::{TreemapConfiguration, TreemapConfigurationBuilder},
+        treemap_leaf::{TreemapLeaf, TreemapLeafBuilder},
+        treemap_section::{TreemapSection, TreemapSectionBuilder},
+    },
+    errors::{ConfigError, TreemapError},
+    shared::{StyleClass, StyleClassBuilder, StyleClassError},
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a treemap.
+///
+/// Sections and leaves are nested within a section which is already part of
+/// the treemap, or placed at its top level. Sections carry no value of their
+/// own, as their size is the sum of the values of their leaves.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = TreemapBuilder::default();
+///     let archive = builder.section(None, TreemapSectionBuilder::default().label("Archive")?)?;
+///     builder.leaf(Some(&archive), TreemapLeafBuilder::default().label("2024")?.value(42.0)?)?;
+///
+///     let mut other = TreemapBuilder::default();
+///     assert!(
+///         other
+///             .leaf(Some(&archive), TreemapLeafBuilder::default().label("2025")?.value(1.0)?)
+///             .is_err()
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct TreemapBuilder {
+    /// Configuration of the treemap.
+    configuration: TreemapConfiguration,
+    /// Style classes defined in the treemap.
+    style_classes: Vec<Rc<StyleClass>>,
+    /// Sections of the treemap, indexed by their identifier.
+    sections: Vec<Rc<TreemapSection>>,
+    /// Members of the treemap, along with the identifier of their section,
+    /// if any.
+    members: Vec<(Option<u64>, TreemapMember)>,
+}
+
+impl TreemapBuilder {
+    /// Sets the configuration of the treemap.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: TreemapConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Defines a style class in the treemap, which may then be applied to its
+    /// sections and leaves.
+    ///
+    /// # Errors
+    ///
+    /// * If the style class builder fails to build.
+    /// * If a style class with the same name is already defined.
+    pub fn style_class(
+        &mut self,
+        style_class: StyleClassBuilder,
+    ) -> Result<Rc<StyleClass>, StyleClassError> {
+        let style_class = style_class.build()?;
+        if self.get_style_class_by_name(style_class.name()).is_some() {
+            return Err(StyleClassError::DuplicateClass(style_class.name().to_string()));
+        }
+        let style_class = Rc::new(style_class);
+        self.style_classes.push(style_class.clone());
+        Ok(style_class)
+    }
+
+    /// Adds a section to the treemap.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The section within which the section is nested, if any.
+    /// * `section` - The builder of the section to add.
+    ///
+    /// # Errors
+    ///
+    /// * If the parent section is not part of the treemap.
+    /// * If the style class of the section is not defined in the treemap.
+    /// * If the section builder fails to build.
+    pub fn section(
+        &mut self,
+        parent: Option<&Rc<TreemapSection>>,
+        section: TreemapSectionBuilder,
+    ) -> Result<Rc<TreemapSection>, TreemapError> {
+        let parent = self.check_parent(parent)?;
+        self.check_style_class(section.get_style_class())?;
+        let section = Rc::new(section.build(self.sections.len() as u64)?);
+        self.sections.push(section.clone());
+        self.members.push((parent, TreemapMember::Section(section.clone())));
+        Ok(section)
+    }
+
+    /// Adds a leaf to the treemap.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The section within which the leaf is nested, if any.
+    /// * `leaf` - The builder of the leaf to add.
+    ///
+    /// # Errors
+    ///
+    /// * If the parent section is not part of the treemap.
+    /// * If the style class of the leaf is not defined in the treemap.
+    /// * If the leaf builder fails to build.
+    pub fn leaf(
+        &mut self,
+        parent: Option<&Rc<TreemapSection>>,
+        leaf: TreemapLeafBuilder,
+    ) -> Result<Rc<TreemapLeaf>, TreemapError> {
+        let parent = self.check_parent(parent)?;
+        self.check_style_class(leaf.get_style_class())?;
+        let leaf = Rc::new(leaf.build()?);
+        self.members.push((parent, TreemapMember::Leaf(leaf.clone())));
+        Ok(leaf)
+    }
+
+    #[must_use]
+    /// Returns the section with the provided ID, if any.
+    pub fn get_section_by_id(&self, id: u64) -> Option<Rc<TreemapSection>> {
+        self.sections.iter().find(|section| section.id() == id).cloned()
+    }
+
+    #[must_use]
+    /// Returns the style class with the provided name, if any.
+    pub fn get_style_class_by_name(&self, name: &str) -> Option<Rc<StyleClass>> {
+        self.style_classes.iter().find(|style_class| style_class.name() == name).cloned()
+    }
+
+    /// Returns the identifier of the provided parent section, after checking
+    /// that it is part of the treemap.
+    fn check_parent(
+        &self,
+        parent: Option<&Rc<TreemapSection>>,
+    ) -> Result<Option<u64>, TreemapError> {
+        let Some(parent) = parent else {
+            return Ok(None);
+        };
+        if self.get_section_by_id(parent.id()).as_ref() != Some(parent) {
+            return Err(TreemapError::SectionNotFound(parent.label().to_string()));
+        }
+        Ok(Some(parent.id()))
+    }
+
+    /// Checks that the provided style class, if any, is defined in the
+    /// treemap.
+    fn check_style_class(&self, style_class: Option<&Rc<StyleClass>>) -> Result<(), TreemapError> {
+        if let Some(style_class) = style_class
+            && self.get_style_class_by_name(style_class.name()).as_ref() != Some(style_class)
+        {
+            return Err(TreemapError::StyleClassNotFound(style_class.name().to_string()));
+        }
+        Ok(())
+    }
+}
+
+impl From<TreemapBuilder> for Treemap {
+    fn from(builder: TreemapBuilder) -> Self {
+        Treemap {
+            configuration: builder.configuration,
+            style_classes: builder.style_classes,
+            members: builder.members,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+
+    use super::*;
+    use crate::shared::{Color, StyleProperty};
+
+    #[test]
+    fn test_treemap_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let fill = StyleClassBuilder::default()
+            .name("cold")?
+            .property(StyleProperty::Fill(Color::from((0, 0, 255))))?;
+        let mut builder = TreemapBuilder::default();
+        let mut other = TreemapBuilder::default();
+        let cold = other.style_class(fill.clone())?;
+        let foreign = other.section(None, TreemapSectionBuilder::default().label("Elsewhere")?)?;
+
+        assert_eq!(
+            builder.leaf(Some(&foreign), TreemapLeafBuilder::default().label("A")?.value(1.0)?),
+            Err(TreemapError::SectionNotFound("Elsewhere".into()))
+        );
+        assert_eq!(
+            builder.section(
+                None,
+                TreemapSectionBuilder::default().label("B")?.style_class(cold.clone())
+            ),
+            Err(TreemapError::StyleClassNotFound("cold".into()))
+        );
+        builder.style_class(fill.clone())?;
+        assert_eq!(builder.style_class(fill), Err(StyleClassError::DuplicateClass("cold".into())));
+        assert_eq!(
+            builder.section(None, TreemapSectionBuilder::default()),
+            Err(TreemapError::MissingLabel)
+        );
+        assert_eq!(
+            builder.leaf(None, TreemapLeafBuilder::default().label("C")?),
+            Err(TreemapError::MissingValue)
+        );
+        assert_eq!(TreemapLeafBuilder::default().value(-0.5), Err(TreemapError::NegativeValue));
+        assert_eq!(
+            TreemapLeafBuilder::default().value(f64::INFINITY),
+            Err(TreemapError::NonFiniteValue)
+        );
+        assert_eq!(
+            TreemapLeafBuilder::default().label("\"D\""),
+            Err(TreemapError::InvalidText("\"D\"".into()))
+        );
+        Ok(())
+    }
+}
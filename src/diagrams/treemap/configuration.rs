@@ -0,0 +1,130 @@
+//! Submodule defining configuration specifically for treemaps in Mermaid.
+
+mod builder;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Display;
+
+pub use builder::TreemapConfigurationBuilder;
+
+use crate::{
+    shared::{
+        Direction, Renderer,
+        generic_configuration::{GenericConfiguration, Look, Theme},
+    },
+    traits::Configuration,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the configuration for a treemap in Mermaid syntax.
+pub struct TreemapConfiguration {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfiguration,
+    /// Format specifier of the values of the leaves, following the syntax of
+    /// `d3-format`.
+    value_format: Option<String>,
+    /// Whether to display the values of the leaves.
+    show_values: bool,
+}
+
+impl Default for TreemapConfiguration {
+    fn default() -> Self {
+        Self { generic: GenericConfiguration::default(), value_format: None, show_values: true }
+    }
+}
+
+impl TreemapConfiguration {
+    #[must_use]
+    /// Returns the format specifier of the values of the leaves, if any.
+    pub fn value_format(&self) -> Option<&str> {
+        self.value_format.as_deref()
+    }
+
+    #[must_use]
+    /// Returns whether the values of the leaves are displayed.
+    pub fn show_values(&self) -> bool {
+        self.show_values
+    }
+}
+
+impl Display for TreemapConfiguration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut options = Vec::new();
+        if let Some(value_format) = &self.value_format {
+            options.push(("treemap.valueFormat".to_string(), format!("'{value_format}'")));
+        }
+        if !self.show_values {
+            options.push(("treemap.showValues".to_string(), "false".to_string()));
+        }
+        self.generic.fmt_frontmatter(f, true, &options)
+    }
+}
+
+impl Configuration for TreemapConfiguration {
+    type Builder = TreemapConfigurationBuilder;
+
+    fn title(&self) -> Option<&str> {
+        self.generic.title()
+    }
+
+    fn direction(&self) -> Direction {
+        self.generic.direction()
+    }
+
+    fn renderer(&self) -> Renderer {
+        self.generic.renderer()
+    }
+
+    fn theme(&self) -> Theme {
+        self.generic.theme()
+    }
+
+    fn look(&self) -> Look {
+        self.generic.look()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format};
+
+    use super::*;
+    use crate::{errors::ConfigError, traits::ConfigurationBuilder};
+
+    #[test]
+    fn test_treemap_configuration_display() -> Result<(), Box<dyn core::error::Error>> {
+        assert_eq!(format!("{}", TreemapConfiguration::default()), "");
+
+        let config = TreemapConfigurationBuilder::default()
+            .value_format("$,.2f")?
+            .show_values(false)
+            .build()?;
+        assert_eq!(
+            format!("{config}"),
+            concat!(
+                "---\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "  treemap:\n",
+                "    valueFormat: '$,.2f'\n",
+                "    showValues: false\n",
+                "---\n",
+            )
+        );
+        assert_eq!(
+            TreemapConfigurationBuilder::default().value_format(" "),
+            Err(ConfigError::InvalidValueFormat)
+        );
+        assert_eq!(
+            TreemapConfigurationBuilder::default().value_format("'.1%'"),
+            Err(ConfigError::InvalidValueFormat)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+//! Submodule providing a builder struct for the configuration of treemaps in
+//! Mermaid syntax.
+
+use alloc::string::{String, ToString};
+
+use crate::{
+    diagrams::treemap::configuration::TreemapConfiguration,
+    errors::ConfigError,
+    shared::generic_configuration::{
+        Direction, GenericConfigurationBuilder, Look, Renderer, Theme,
+    },
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating treemap configurations.
+pub struct TreemapConfigurationBuilder {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfigurationBuilder,
+    /// Format specifier of the values of the leaves.
+    value_format: Option<String>,
+    /// Whether to display the values of the leaves.
+    show_values: bool,
+}
+
+impl Default for TreemapConfigurationBuilder {
+    fn default() -> Self {
+        Self {
+            generic: GenericConfigurationBuilder::default(),
+            value_format: None,
+            show_values: true,
+        }
+    }
+}
+
+impl TreemapConfigurationBuilder {
+    /// Sets the format specifier of the values of the leaves, following the
+    /// syntax of `d3-format` (e.g. `,` for thousands separators, `$,.2f` for
+    /// currencies or `.1%` for percentages).
+    ///
+    /// # Errors
+    ///
+    /// * If the format is empty or contains single quotes or line breaks.
+    pub fn value_format(mut self, value_format: impl Into<String>) -> Result<Self, ConfigError> {
+        let value_format = value_format.into();
+        if value_format.trim().is_empty() || value_format.contains(['\'', '\n', '\r']) {
+            return Err(ConfigError::InvalidValueFormat);
+        }
+        self.value_format = Some(value_format);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets whether to display the values of the leaves.
+    pub fn show_values(mut self, show_values: bool) -> Self {
+        self.show_values = show_values;
+        self
+    }
+
+    /// Sets the theme to use for the diagram.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.generic = self.generic.theme(theme);
+        self
+    }
+
+    /// Sets the look to use for the diagram.
+    #[must_use]
+    pub fn look(mut self, look: Look) -> Self {
+        self.generic = self.generic.look(look);
+        self
+    }
+}
+
+impl TryFrom<TreemapConfigurationBuilder> for TreemapConfiguration {
+    type Error = ConfigError;
+
+    fn try_from(builder: TreemapConfigurationBuilder) -> Result<Self, Self::Error> {
+        Ok(TreemapConfiguration {
+            generic: builder.generic.try_into()?,
+            value_format: builder.value_format,
+            show_values: builder.show_values,
+        })
+    }
+}
+
+impl ConfigurationBuilder for TreemapConfigurationBuilder {
+    type Configuration = TreemapConfiguration;
+    type Error = ConfigError;
+
+    fn build(self) -> Result<Self::Configuration, Self::Error> {
+        self.try_into()
+    }
+
+    fn title<S: ToString>(mut self, title: S) -> Result<Self, Self::Error> {
+        self.generic = self.generic.title(title)?;
+        Ok(self)
+    }
+
+    fn direction(mut self, direction: Direction) -> Self {
+        self.generic = self.generic.direction(direction);
+        self
+    }
+
+    fn renderer(mut self, renderer: Renderer) -> Self {
+        self.generic = self.generic.renderer(renderer);
+        self
+    }
+}
@@ -0,0 +1,104 @@
+//! Submodule defining the leaves of treemaps in Mermaid syntax.
+
+mod builder;
+use alloc::{rc::Rc, string::String};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+};
+
+pub use builder::TreemapLeafBuilder;
+
+use crate::shared::StyleClass;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a leaf of a treemap, whose area is proportional to its value.
+///
+/// As the value is guaranteed to be finite and non-negative, leaves are
+/// totally ordered and may be hashed.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let leaf = TreemapLeafBuilder::default().label("Spectra")?.value(1250.5)?.build()?;
+///     assert_eq!(leaf.to_string(), "\"Spectra\": 1250.5");
+///
+///     assert!(TreemapLeafBuilder::default().value(-1.0).is_err());
+///     assert!(TreemapLeafBuilder::default().label("Empty")?.build().is_err());
+///     Ok(())
+/// }
+/// ```
+pub struct TreemapLeaf {
+    /// Label of the leaf.
+    label: String,
+    /// Value of the leaf.
+    value: f64,
+    /// Style class applied to the leaf, if any.
+    style_class: Option<Rc<StyleClass>>,
+}
+
+impl TreemapLeaf {
+    #[must_use]
+    /// Returns the label of the leaf.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the value of the leaf.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    #[must_use]
+    /// Returns the style class applied to the leaf, if any.
+    pub fn style_class(&self) -> Option<&StyleClass> {
+        self.style_class.as_deref()
+    }
+}
+
+impl PartialEq for TreemapLeaf {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TreemapLeaf {}
+
+impl PartialOrd for TreemapLeaf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TreemapLeaf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.label
+            .cmp(&other.label)
+            .then_with(|| self.value.total_cmp(&other.value))
+            .then_with(|| self.style_class.cmp(&other.style_class))
+    }
+}
+
+impl Hash for TreemapLeaf {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.label.hash(state);
+        self.value.to_bits().hash(state);
+        self.style_class.hash(state);
+    }
+}
+
+impl Display for TreemapLeaf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\": {}", self.label, self.value)?;
+        if let Some(style_class) = &self.style_class {
+            write!(f, ":::{}", style_class.name())?;
+        }
+        Ok(())
+    }
+}
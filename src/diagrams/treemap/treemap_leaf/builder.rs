@@ -0,0 +1,124 @@
+//! Submodule defining the struct for building a leaf of a treemap.
+
+use alloc::{rc::Rc, string::String};
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    diagrams::treemap::{treemap_leaf::TreemapLeaf, validate_text},
+    errors::TreemapError,
+    shared::StyleClass,
+};
+
+#[derive(Default, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `TreemapLeaf`.
+pub struct TreemapLeafBuilder {
+    /// Label of the leaf.
+    label: Option<String>,
+    /// Value of the leaf.
+    value: Option<f64>,
+    /// Style class applied to the leaf.
+    style_class: Option<Rc<StyleClass>>,
+}
+
+impl TreemapLeafBuilder {
+    /// Sets the label of the leaf.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains double quotes or line breaks.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, TreemapError> {
+        self.label = Some(validate_text(label)?);
+        Ok(self)
+    }
+
+    /// Sets the value of the leaf.
+    ///
+    /// # Errors
+    ///
+    /// * If the value is infinite or not a number.
+    /// * If the value is negative.
+    pub fn value(mut self, value: f64) -> Result<Self, TreemapError> {
+        if !value.is_finite() {
+            return Err(TreemapError::NonFiniteValue);
+        }
+        if value < 0.0 {
+            return Err(TreemapError::NegativeValue);
+        }
+        // Adding zero normalizes a negative zero.
+        self.value = Some(value + 0.0);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the style class applied to the leaf, which must be defined in the
+    /// treemap.
+    pub fn style_class(mut self, style_class: Rc<StyleClass>) -> Self {
+        self.style_class = Some(style_class);
+        self
+    }
+
+    #[must_use]
+    /// Returns the style class applied to the leaf, if set.
+    pub fn get_style_class(&self) -> Option<&Rc<StyleClass>> {
+        self.style_class.as_ref()
+    }
+
+    /// Builds the leaf.
+    ///
+    /// # Errors
+    ///
+    /// * If the label or the value is missing.
+    pub fn build(self) -> Result<TreemapLeaf, TreemapError> {
+        self.try_into()
+    }
+}
+
+impl PartialEq for TreemapLeafBuilder {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TreemapLeafBuilder {}
+
+impl PartialOrd for TreemapLeafBuilder {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TreemapLeafBuilder {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.label
+            .cmp(&other.label)
+            .then_with(|| match (self.value, other.value) {
+                (Some(value), Some(other_value)) => value.total_cmp(&other_value),
+                (value, other_value) => value.is_some().cmp(&other_value.is_some()),
+            })
+            .then_with(|| self.style_class.cmp(&other.style_class))
+    }
+}
+
+impl Hash for TreemapLeafBuilder {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.label.hash(state);
+        self.value.map(f64::to_bits).hash(state);
+        self.style_class.hash(state);
+    }
+}
+
+impl TryFrom<TreemapLeafBuilder> for TreemapLeaf {
+    type Error = TreemapError;
+
+    fn try_from(builder: TreemapLeafBuilder) -> Result<Self, Self::Error> {
+        Ok(TreemapLeaf {
+            label: builder.label.ok_or(TreemapError::MissingLabel)?,
+            value: builder.value.ok_or(TreemapError::MissingValue)?,
+            style_class: builder.style_class,
+        })
+    }
+}
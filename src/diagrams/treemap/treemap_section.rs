@@ -0,0 +1,65 @@
+//! Submodule defining the sections of treemaps in Mermaid syntax.
+
+mod builder;
+use alloc::{rc::Rc, string::String};
+use core::fmt::{self, Display};
+
+pub use builder::TreemapSectionBuilder;
+
+use crate::shared::StyleClass;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a section of a treemap, which groups nested sections and leaves
+/// and whose size is the sum of their values.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = TreemapBuilder::default();
+///     let section = builder.section(None, TreemapSectionBuilder::default().label("Archive")?)?;
+///     assert_eq!(section.to_string(), "\"Archive\"");
+///     Ok(())
+/// }
+/// ```
+pub struct TreemapSection {
+    /// Unique identifier of the section, used to nest members under it.
+    id: u64,
+    /// Label of the section.
+    label: String,
+    /// Style class applied to the section, if any.
+    style_class: Option<Rc<StyleClass>>,
+}
+
+impl TreemapSection {
+    #[must_use]
+    /// Returns the unique identifier of the section.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[must_use]
+    /// Returns the label of the section.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the style class applied to the section, if any.
+    pub fn style_class(&self) -> Option<&StyleClass> {
+        self.style_class.as_deref()
+    }
+}
+
+impl Display for TreemapSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self.label)?;
+        if let Some(style_class) = &self.style_class {
+            write!(f, ":::{}", style_class.name())?;
+        }
+        Ok(())
+    }
+}
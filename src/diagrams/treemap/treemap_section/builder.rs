@@ -0,0 +1,57 @@
+//! Submodule defining the struct for building a section of a treemap.
+
+use alloc::{rc::Rc, string::String};
+
+use crate::{
+    diagrams::treemap::{treemap_section::TreemapSection, validate_text},
+    errors::TreemapError,
+    shared::StyleClass,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `TreemapSection`.
+///
+/// Sections are identified by the treemap to which they are added, which
+/// assigns their identifier.
+pub struct TreemapSectionBuilder {
+    /// Label of the section.
+    label: Option<String>,
+    /// Style class applied to the section.
+    style_class: Option<Rc<StyleClass>>,
+}
+
+impl TreemapSectionBuilder {
+    /// Sets the label of the section.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains double quotes or line breaks.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, TreemapError> {
+        self.label = Some(validate_text(label)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the style class applied to the section, which must be defined in
+    /// the treemap.
+    pub fn style_class(mut self, style_class: Rc<StyleClass>) -> Self {
+        self.style_class = Some(style_class);
+        self
+    }
+
+    #[must_use]
+    /// Returns the style class applied to the section, if set.
+    pub fn get_style_class(&self) -> Option<&Rc<StyleClass>> {
+        self.style_class.as_ref()
+    }
+
+    /// Builds the section with the provided identifier.
+    pub(crate) fn build(self, id: u64) -> Result<TreemapSection, TreemapError> {
+        Ok(TreemapSection {
+            id,
+            label: self.label.ok_or(TreemapError::MissingLabel)?,
+            style_class: self.style_class,
+        })
+    }
+}
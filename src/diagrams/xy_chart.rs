@@ -0,0 +1,199 @@
+//! Submodule providing structs for creating XY charts in Mermaid syntax.
+
+mod builder;
+mod configuration;
+mod xy_chart_axis;
+mod xy_chart_range;
+mod xy_chart_series;
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::XYChartBuilder;
+pub use configuration::{XYChartConfiguration, XYChartConfigurationBuilder};
+pub use xy_chart_axis::{XYChartXAxis, XYChartXValues, XYChartYAxis};
+pub use xy_chart_range::XYChartRange;
+pub use xy_chart_series::{XYChartSeries, XYChartSeriesKind};
+
+use crate::{
+    errors::XYChartError,
+    traits::{Configuration, TabbedDisplay},
+};
+
+/// Validates a text of an XY chart, which is always written between double
+/// quotes.
+pub(crate) fn validate_text(text: impl Into<String>) -> Result<String, XYChartError> {
+    let text = text.into();
+    if text.trim().is_empty() {
+        return Err(XYChartError::EmptyText);
+    }
+    if text.contains(['"', '\n', '\r']) {
+        return Err(XYChartError::InvalidText(text));
+    }
+    Ok(text)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents an XY chart in Mermaid syntax, rendered with the
+/// `xychart-beta` renderer.
+///
+/// All the series of a chart have the same number of values, which is also
+/// the number of categories of a categorical x-axis.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = XYChartBuilder::default();
+///     builder.x_axis(XYChartXAxis::categories(["v1", "v2"])?)?;
+///     builder.series(XYChartSeries::bar([12.0, 9.5])?)?;
+///     assert!(builder.series(XYChartSeries::line([1.0])?).is_err());
+///
+///     let chart = XYChart::from(builder);
+///     assert_eq!(chart.to_string(), "xychart-beta\n  x-axis [\"v1\", \"v2\"]\n  bar [12, 9.5]\n");
+///     Ok(())
+/// }
+/// ```
+pub struct XYChart {
+    /// Configuration of the chart.
+    configuration: XYChartConfiguration,
+    /// The x-axis of the chart.
+    x_axis: Option<XYChartXAxis>,
+    /// The y-axis of the chart.
+    y_axis: XYChartYAxis,
+    /// Series of the chart.
+    series: Vec<XYChartSeries>,
+}
+
+impl XYChart {
+    #[must_use]
+    /// Returns the configuration of the chart.
+    pub fn configuration(&self) -> &XYChartConfiguration {
+        &self.configuration
+    }
+
+    #[must_use]
+    /// Returns the x-axis of the chart, if any.
+    pub fn x_axis(&self) -> Option<&XYChartXAxis> {
+        self.x_axis.as_ref()
+    }
+
+    #[must_use]
+    /// Returns the y-axis of the chart.
+    pub fn y_axis(&self) -> &XYChartYAxis {
+        &self.y_axis
+    }
+
+    /// Returns an iterator over the series of the chart.
+    pub fn series(&self) -> impl Iterator<Item = &XYChartSeries> {
+        self.series.iter()
+    }
+}
+
+impl Display for XYChart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for XYChart {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat((tab_count + 1) * 2);
+        write!(f, "{}", self.configuration)?;
+        write!(f, "{}xychart-beta", " ".repeat(tab_count * 2))?;
+        if self.configuration.horizontal() {
+            write!(f, " horizontal")?;
+        }
+        writeln!(f)?;
+        if let Some(title) = self.configuration.title() {
+            writeln!(f, "{indent}title \"{title}\"")?;
+        }
+        if let Some(x_axis) = &self.x_axis {
+            writeln!(f, "{indent}x-axis {x_axis}")?;
+        }
+        if !self.y_axis.is_empty() {
+            writeln!(f, "{indent}y-axis {}", self.y_axis)?;
+        }
+        for series in &self.series {
+            writeln!(f, "{indent}{series}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::traits::ConfigurationBuilder;
+
+    #[test]
+    fn test_xy_chart_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = XYChartBuilder::default().configuration(
+            XYChartConfigurationBuilder::default()
+                .title("Sales Revenue")?
+                .horizontal(true)
+                .height(600)?,
+        )?;
+        builder.series(XYChartSeries::bar([5000.0, 6000.0, 7500.0])?)?;
+        builder.series(XYChartSeries::line([5000.0, 6000.0, 7500.0])?.title("Trend")?)?;
+        builder.x_axis(XYChartXAxis::categories(["jan", "feb", "mar"])?)?;
+        builder.y_axis(
+            XYChartYAxis::default()
+                .title("Revenue (in $)")?
+                .range(XYChartRange::new(4000.0, 11000.0)?),
+        );
+
+        let chart = XYChart::from(builder);
+        assert_eq!(chart.series().count(), 2);
+        assert_eq!(
+            chart.to_string(),
+            concat!(
+                "---\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "  xyChart:\n",
+                "    height: 600\n",
+                "---\n",
+                "xychart-beta horizontal\n",
+                "  title \"Sales Revenue\"\n",
+                "  x-axis [\"jan\", \"feb\", \"mar\"]\n",
+                "  y-axis \"Revenue (in $)\" 4000 --> 11000\n",
+                "  bar [5000, 6000, 7500]\n",
+                "  line \"Trend\" [5000, 6000, 7500]\n",
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_xy_chart_builder_errors() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = XYChartBuilder::default();
+        builder.series(XYChartSeries::line([1.0, 2.0])?)?;
+        assert_eq!(
+            builder.series(XYChartSeries::bar([1.0, 2.0, 3.0])?),
+            Err(XYChartError::SeriesLengthMismatch { expected: 2, found: 3 })
+        );
+        assert_eq!(
+            builder.x_axis(XYChartXAxis::categories(["a", "b", "c"])?),
+            Err(XYChartError::SeriesLengthMismatch { expected: 3, found: 2 })
+        );
+        builder.x_axis(XYChartXAxis::range(XYChartRange::new(-1.0, 1.0)?).title("Time")?)?;
+        assert_eq!(
+            XYChartXAxis::categories::<[&str; 0], &str>([]),
+            Err(XYChartError::EmptyCategories)
+        );
+        assert_eq!(
+            XYChartXAxis::categories(["a\"b"]),
+            Err(XYChartError::InvalidText("a\"b".to_string()))
+        );
+
+        let chart = XYChart::from(builder);
+        assert_eq!(chart.to_string(), "xychart-beta\n  x-axis \"Time\" -1 --> 1\n  line [1, 2]\n");
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+//! Submodule providing a builder for XY charts.
+
+use alloc::vec::Vec;
+
+use crate::{
+    diagrams::xy_chart::{
+        XYChart, XYChartSeries, XYChartXAxis, XYChartYAxis,
+        configuration::{XYChartConfiguration, XYChartConfigurationBuilder},
+    },
+    errors::{ConfigError, XYChartError},
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating an XY chart.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = XYChartBuilder::default()
+///         .configuration(XYChartConfigurationBuilder::default().title("Benchmarks")?)?;
+///     builder.x_axis(XYChartXAxis::categories(["small", "large"])?.title("Input")?)?;
+///     builder.y_axis(XYChartYAxis::default().title("Time (ms)")?);
+///     builder.series(XYChartSeries::bar([1.5, 12.0])?)?;
+///     builder.series(XYChartSeries::line([1.2, 9.8])?)?;
+///
+///     let chart = XYChart::from(builder);
+///     assert_eq!(chart.series().count(), 2);
+///     Ok(())
+/// }
+/// ```
+pub struct XYChartBuilder {
+    /// Configuration of the chart.
+    configuration: XYChartConfiguration,
+    /// The x-axis of the chart.
+    x_axis: Option<XYChartXAxis>,
+    /// The y-axis of the chart.
+    y_axis: XYChartYAxis,
+    /// Series of the chart.
+    series: Vec<XYChartSeries>,
+}
+
+impl XYChartBuilder {
+    /// Sets the configuration of the chart.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: XYChartConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Sets the x-axis of the chart, replacing any previous one.
+    ///
+    /// # Errors
+    ///
+    /// * If the axis is categorical and the number of its categories differs
+    ///   from the number of values of the series already in the chart.
+    pub fn x_axis(&mut self, axis: XYChartXAxis) -> Result<(), XYChartError> {
+        if let (Some(expected), Some(series)) = (axis.category_count(), self.series.first()) {
+            let found = series.values().len();
+            if expected != found {
+                return Err(XYChartError::SeriesLengthMismatch { expected, found });
+            }
+        }
+        self.x_axis = Some(axis);
+        Ok(())
+    }
+
+    /// Sets the y-axis of the chart, replacing any previous one.
+    pub fn y_axis(&mut self, axis: XYChartYAxis) {
+        self.y_axis = axis;
+    }
+
+    /// Adds a series to the chart.
+    ///
+    /// # Errors
+    ///
+    /// * If the number of values of the series differs from the number of
+    ///   categories of the x-axis, or from the number of values of the series
+    ///   already in the chart.
+    pub fn series(&mut self, series: XYChartSeries) -> Result<(), XYChartError> {
+        let expected = self
+            .x_axis
+            .as_ref()
+            .and_then(XYChartXAxis::category_count)
+            .or_else(|| self.series.first().map(|first| first.values().len()));
+        let found = series.values().len();
+        if let Some(expected) = expected
+            && expected != found
+        {
+            return Err(XYChartError::SeriesLengthMismatch { expected, found });
+        }
+        self.series.push(series);
+        Ok(())
+    }
+}
+
+impl From<XYChartBuilder> for XYChart {
+    fn from(builder: XYChartBuilder) -> Self {
+        XYChart {
+            configuration: builder.configuration,
+            x_axis: builder.x_axis,
+            y_axis: builder.y_axis,
+            series: builder.series,
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! Submodule defining configuration specifically for XY charts in Mermaid.
+
+mod builder;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Display;
+
+pub use builder::XYChartConfigurationBuilder;
+
+use crate::{
+    shared::{
+        Color, Direction, Renderer,
+        generic_configuration::{GenericConfiguration, Look, Theme},
+    },
+    traits::Configuration,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the configuration for an XY chart in Mermaid syntax.
+///
+/// The title of an XY chart is written within the diagram rather than in its
+/// frontmatter.
+pub struct XYChartConfiguration {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfiguration,
+    /// Whether the x-axis is drawn vertically, with horizontal bars.
+    horizontal: bool,
+    /// Width of the chart, in pixels.
+    width: Option<u32>,
+    /// Height of the chart, in pixels.
+    height: Option<u32>,
+    /// Colors of the series, in the order in which they are declared.
+    plot_colors: Vec<Color>,
+}
+
+impl XYChartConfiguration {
+    #[must_use]
+    /// Returns whether the x-axis is drawn vertically, with horizontal bars.
+    pub fn horizontal(&self) -> bool {
+        self.horizontal
+    }
+
+    #[must_use]
+    /// Returns the width of the chart in pixels, if any.
+    pub fn width(&self) -> Option<u32> {
+        self.width
+    }
+
+    #[must_use]
+    /// Returns the height of the chart in pixels, if any.
+    pub fn height(&self) -> Option<u32> {
+        self.height
+    }
+
+    /// Returns an iterator over the colors of the series.
+    pub fn plot_colors(&self) -> impl Iterator<Item = Color> + '_ {
+        self.plot_colors.iter().copied()
+    }
+}
+
+impl Display for XYChartConfiguration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut options = Vec::new();
+        if let Some(width) = self.width {
+            options.push(("xyChart.width".to_string(), width.to_string()));
+        }
+        if let Some(height) = self.height {
+            options.push(("xyChart.height".to_string(), height.to_string()));
+        }
+        if !self.plot_colors.is_empty() {
+            let colors: Vec<String> = self.plot_colors.iter().map(|color| color.to_hex()).collect();
+            options.push((
+                "themeVariables.xyChart.plotColorPalette".to_string(),
+                format!("\"{}\"", colors.join(", ")),
+            ));
+        }
+        self.generic.fmt_frontmatter(f, false, &options)
+    }
+}
+
+impl Configuration for XYChartConfiguration {
+    type Builder = XYChartConfigurationBuilder;
+
+    fn title(&self) -> Option<&str> {
+        self.generic.title()
+    }
+
+    fn direction(&self) -> Direction {
+        self.generic.direction()
+    }
+
+    fn renderer(&self) -> Renderer {
+        self.generic.renderer()
+    }
+
+    fn theme(&self) -> Theme {
+        self.generic.theme()
+    }
+
+    fn look(&self) -> Look {
+        self.generic.look()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, format};
+
+    use super::*;
+    use crate::traits::ConfigurationBuilder;
+
+    #[test]
+    fn test_xy_chart_configuration_display() -> Result<(), Box<dyn core::error::Error>> {
+        assert_eq!(format!("{}", XYChartConfiguration::default()), "");
+
+        let config = XYChartConfigurationBuilder::default()
+            .width(900)?
+            .plot_color(Color::from((0, 0, 0)))
+            .plot_color(Color::from((0, 0, 255)))
+            .build()?;
+        assert_eq!(
+            format!("{config}"),
+            concat!(
+                "---\n",
+                "config:\n",
+                "  theme: default\n",
+                "  look: classic\n",
+                "  xyChart:\n",
+                "    width: 900\n",
+                "  themeVariables:\n",
+                "    xyChart:\n",
+                "      plotColorPalette: \"#000000, #0000ff\"\n",
+                "---\n",
+            )
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+//! Submodule providing a builder struct for the configuration of XY charts in
+//! Mermaid syntax.
+
+use alloc::{string::ToString, vec::Vec};
+
+use crate::{
+    diagrams::xy_chart::configuration::XYChartConfiguration,
+    errors::ConfigError,
+    shared::{
+        Color,
+        generic_configuration::{Direction, GenericConfigurationBuilder, Look, Renderer, Theme},
+    },
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating XY chart configurations.
+pub struct XYChartConfigurationBuilder {
+    /// Generic configuration options which apply to all Mermaid diagrams.
+    generic: GenericConfigurationBuilder,
+    /// Whether the x-axis is drawn vertically, with horizontal bars.
+    horizontal: bool,
+    /// Width of the chart, in pixels.
+    width: Option<u32>,
+    /// Height of the chart, in pixels.
+    height: Option<u32>,
+    /// Colors of the series, in the order in which they are declared.
+    plot_colors: Vec<Color>,
+}
+
+impl XYChartConfigurationBuilder {
+    #[must_use]
+    /// Sets whether the x-axis is drawn vertically, with horizontal bars.
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.horizontal = horizontal;
+        self
+    }
+
+    /// Sets the width of the chart, in pixels.
+    ///
+    /// # Errors
+    ///
+    /// * If the width is zero.
+    pub fn width(mut self, width: u32) -> Result<Self, ConfigError> {
+        if width == 0 {
+            return Err(ConfigError::ZeroChartDimension);
+        }
+        self.width = Some(width);
+        Ok(self)
+    }
+
+    /// Sets the height of the chart, in pixels.
+    ///
+    /// # Errors
+    ///
+    /// * If the height is zero.
+    pub fn height(mut self, height: u32) -> Result<Self, ConfigError> {
+        if height == 0 {
+            return Err(ConfigError::ZeroChartDimension);
+        }
+        self.height = Some(height);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Appends a color to the palette of the series, which Mermaid assigns to
+    /// the series in the order in which they are declared.
+    pub fn plot_color(mut self, color: Color) -> Self {
+        self.plot_colors.push(color);
+        self
+    }
+
+    /// Sets the theme to use for the diagram.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.generic = self.generic.theme(theme);
+        self
+    }
+
+    /// Sets the look to use for the diagram.
+    #[must_use]
+    pub fn look(mut self, look: Look) -> Self {
+        self.generic = self.generic.look(look);
+        self
+    }
+}
+
+impl TryFrom<XYChartConfigurationBuilder> for XYChartConfiguration {
+    type Error = ConfigError;
+
+    fn try_from(builder: XYChartConfigurationBuilder) -> Result<Self, Self::Error> {
+        Ok(XYChartConfiguration {
+            generic: builder.generic.try_into()?,
+            horizontal: builder.horizontal,
+            width: builder.width,
+            height: builder.height,
+            plot_colors: builder.plot_colors,
+        })
+    }
+}
+
+impl ConfigurationBuilder for XYChartConfigurationBuilder {
+    type Configuration = XYChartConfiguration;
+    type Error = ConfigError;
+
+    fn build(self) -> Result<Self::Configuration, Self::Error> {
+        self.try_into()
+    }
+
+    fn title<S: ToString>(mut self, title: S) -> Result<Self, Self::Error> {
+        self.generic = self.generic.title(title)?;
+        Ok(self)
+    }
+
+    fn direction(mut self, direction: Direction) -> Self {
+        self.generic = self.generic.direction(direction);
+        self
+    }
+
+    fn renderer(mut self, renderer: Renderer) -> Self {
+        self.generic = self.generic.renderer(renderer);
+        self
+    }
+}
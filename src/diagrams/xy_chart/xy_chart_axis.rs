@@ -0,0 +1,195 @@
+//! Submodule defining the axes of XY charts.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+use crate::{
+    diagrams::xy_chart::{XYChartRange, validate_text},
+    errors::XYChartError,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the values along the x-axis of an XY chart.
+pub enum XYChartXValues {
+    /// One category per value of the series.
+    Categories(Vec<String>),
+    /// A numeric range over which the values of the series are spread.
+    Range(XYChartRange),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the x-axis of an XY chart, either categorical or numeric.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let axis = XYChartXAxis::categories(["jan", "feb"])?.title("Month")?;
+///     assert_eq!(axis.to_string(), "\"Month\" [\"jan\", \"feb\"]");
+///
+///     let axis = XYChartXAxis::range(XYChartRange::new(0.0, 100.0)?);
+///     assert_eq!(axis.to_string(), "0 --> 100");
+///     Ok(())
+/// }
+/// ```
+pub struct XYChartXAxis {
+    /// Title of the axis.
+    title: Option<String>,
+    /// Values along the axis.
+    values: XYChartXValues,
+}
+
+impl XYChartXAxis {
+    /// Creates a new categorical axis.
+    ///
+    /// # Errors
+    ///
+    /// * If no category is provided.
+    /// * If a category is empty or contains double quotes or line breaks.
+    pub fn categories<I, S>(categories: I) -> Result<Self, XYChartError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let categories =
+            categories.into_iter().map(validate_text).collect::<Result<Vec<_>, _>>()?;
+        if categories.is_empty() {
+            return Err(XYChartError::EmptyCategories);
+        }
+        Ok(Self { title: None, values: XYChartXValues::Categories(categories) })
+    }
+
+    #[must_use]
+    /// Creates a new numeric axis.
+    pub fn range(range: XYChartRange) -> Self {
+        Self { title: None, values: XYChartXValues::Range(range) }
+    }
+
+    /// Sets the title of the axis.
+    ///
+    /// # Errors
+    ///
+    /// * If the title is empty or contains double quotes or line breaks.
+    pub fn title(mut self, title: impl Into<String>) -> Result<Self, XYChartError> {
+        self.title = Some(validate_text(title)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Returns the title of the axis, if any.
+    pub fn get_title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    #[must_use]
+    /// Returns the values along the axis.
+    pub fn values(&self) -> &XYChartXValues {
+        &self.values
+    }
+
+    #[must_use]
+    /// Returns the number of values expected in each series, if the axis is
+    /// categorical.
+    pub fn category_count(&self) -> Option<usize> {
+        match &self.values {
+            XYChartXValues::Categories(categories) => Some(categories.len()),
+            XYChartXValues::Range(_) => None,
+        }
+    }
+}
+
+impl Display for XYChartXAxis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(title) = &self.title {
+            write!(f, "\"{title}\" ")?;
+        }
+        match &self.values {
+            XYChartXValues::Categories(categories) => {
+                write!(f, "[")?;
+                for (category_number, category) in categories.iter().enumerate() {
+                    if category_number > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "\"{category}\"")?;
+                }
+                write!(f, "]")
+            }
+            XYChartXValues::Range(range) => write!(f, "{range}"),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the y-axis of an XY chart, whose range is otherwise derived by
+/// Mermaid from the values of the series.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let axis = XYChartYAxis::default().title("Revenue")?.range(XYChartRange::new(0.0, 10.0)?);
+///     assert_eq!(axis.to_string(), "\"Revenue\" 0 --> 10");
+///     Ok(())
+/// }
+/// ```
+pub struct XYChartYAxis {
+    /// Title of the axis.
+    title: Option<String>,
+    /// Range of the axis.
+    range: Option<XYChartRange>,
+}
+
+impl XYChartYAxis {
+    /// Sets the title of the axis.
+    ///
+    /// # Errors
+    ///
+    /// * If the title is empty or contains double quotes or line breaks.
+    pub fn title(mut self, title: impl Into<String>) -> Result<Self, XYChartError> {
+        self.title = Some(validate_text(title)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the range of the axis.
+    pub fn range(mut self, range: XYChartRange) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    #[must_use]
+    /// Returns the title of the axis, if any.
+    pub fn get_title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    #[must_use]
+    /// Returns the range of the axis, if any.
+    pub fn get_range(&self) -> Option<XYChartRange> {
+        self.range
+    }
+
+    #[must_use]
+    /// Returns whether the axis has neither a title nor a range.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.range.is_none()
+    }
+}
+
+impl Display for XYChartYAxis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.title, &self.range) {
+            (Some(title), Some(range)) => write!(f, "\"{title}\" {range}"),
+            (Some(title), None) => write!(f, "\"{title}\""),
+            (None, Some(range)) => write!(f, "{range}"),
+            (None, None) => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Submodule defining the numeric ranges of the axes of XY charts.
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+};
+
+use crate::errors::XYChartError;
+
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the numeric range of an axis of an XY chart.
+///
+/// As the bounds are guaranteed to be finite, ranges are totally ordered and
+/// may be hashed.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let range = XYChartRange::new(0.0, 12.5)?;
+///     assert_eq!(range.to_string(), "0 --> 12.5");
+///
+///     assert!(XYChartRange::new(1.0, 1.0).is_err());
+///     assert!(XYChartRange::new(0.0, f64::INFINITY).is_err());
+///     Ok(())
+/// }
+/// ```
+pub struct XYChartRange {
+    /// Lower bound of the range.
+    min: f64,
+    /// Upper bound of the range.
+    max: f64,
+}
+
+impl XYChartRange {
+    /// Creates a new range.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The lower bound of the range.
+    /// * `max` - The upper bound of the range.
+    ///
+    /// # Errors
+    ///
+    /// * If a bound is infinite or not a number.
+    /// * If the lower bound is not strictly less than the upper bound.
+    pub fn new(min: f64, max: f64) -> Result<Self, XYChartError> {
+        if !min.is_finite() || !max.is_finite() || min >= max {
+            return Err(XYChartError::InvalidRange);
+        }
+        // Adding zero normalizes a negative zero.
+        Ok(Self { min: min + 0.0, max: max + 0.0 })
+    }
+
+    #[must_use]
+    /// Returns the lower bound of the range.
+    pub fn min(self) -> f64 {
+        self.min
+    }
+
+    #[must_use]
+    /// Returns the upper bound of the range.
+    pub fn max(self) -> f64 {
+        self.max
+    }
+}
+
+impl PartialEq for XYChartRange {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for XYChartRange {}
+
+impl PartialOrd for XYChartRange {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for XYChartRange {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.min.total_cmp(&other.min).then(self.max.total_cmp(&other.max))
+    }
+}
+
+impl Hash for XYChartRange {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.min.to_bits().hash(state);
+        self.max.to_bits().hash(state);
+    }
+}
+
+impl Display for XYChartRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} --> {}", self.min, self.max)
+    }
+}
@@ -0,0 +1,189 @@
+//! Submodule defining the series of XY charts.
+
+use alloc::{string::String, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+};
+
+use crate::{diagrams::xy_chart::validate_text, errors::XYChartError};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents how a series of an XY chart is plotted.
+pub enum XYChartSeriesKind {
+    /// The series is plotted as bars.
+    Bar,
+    /// The series is plotted as a line.
+    Line,
+}
+
+impl Display for XYChartSeriesKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            XYChartSeriesKind::Bar => write!(f, "bar"),
+            XYChartSeriesKind::Line => write!(f, "line"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a series of values of an XY chart, plotted as bars or as a line.
+///
+/// As the values are guaranteed to be finite, series are totally ordered and
+/// may be hashed.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let series = XYChartSeries::bar([5000.0, 6000.0, 7500.5])?.title("Revenue")?;
+///     assert_eq!(series.to_string(), "bar \"Revenue\" [5000, 6000, 7500.5]");
+///
+///     assert!(XYChartSeries::line([]).is_err());
+///     assert!(XYChartSeries::line([f64::NAN]).is_err());
+///     Ok(())
+/// }
+/// ```
+pub struct XYChartSeries {
+    /// How the series is plotted.
+    kind: XYChartSeriesKind,
+    /// Title of the series.
+    title: Option<String>,
+    /// Values of the series.
+    values: Vec<f64>,
+}
+
+impl XYChartSeries {
+    /// Creates a new series.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - How the series is plotted.
+    /// * `values` - The values of the series.
+    ///
+    /// # Errors
+    ///
+    /// * If no value is provided.
+    /// * If a value is infinite or not a number.
+    pub fn new(
+        kind: XYChartSeriesKind,
+        values: impl IntoIterator<Item = f64>,
+    ) -> Result<Self, XYChartError> {
+        let values = values
+            .into_iter()
+            .map(|value| {
+                // Adding zero normalizes a negative zero.
+                if value.is_finite() { Ok(value + 0.0) } else { Err(XYChartError::NonFiniteValue) }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if values.is_empty() {
+            return Err(XYChartError::EmptySeries);
+        }
+        Ok(Self { kind, title: None, values })
+    }
+
+    /// Creates a new series plotted as bars.
+    ///
+    /// # Errors
+    ///
+    /// * If the values are invalid, as described in [`XYChartSeries::new`].
+    pub fn bar(values: impl IntoIterator<Item = f64>) -> Result<Self, XYChartError> {
+        Self::new(XYChartSeriesKind::Bar, values)
+    }
+
+    /// Creates a new series plotted as a line.
+    ///
+    /// # Errors
+    ///
+    /// * If the values are invalid, as described in [`XYChartSeries::new`].
+    pub fn line(values: impl IntoIterator<Item = f64>) -> Result<Self, XYChartError> {
+        Self::new(XYChartSeriesKind::Line, values)
+    }
+
+    /// Sets the title of the series.
+    ///
+    /// # Errors
+    ///
+    /// * If the title is empty or contains double quotes or line breaks.
+    pub fn title(mut self, title: impl Into<String>) -> Result<Self, XYChartError> {
+        self.title = Some(validate_text(title)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Returns how the series is plotted.
+    pub fn kind(&self) -> XYChartSeriesKind {
+        self.kind
+    }
+
+    #[must_use]
+    /// Returns the title of the series, if any.
+    pub fn get_title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    #[must_use]
+    /// Returns the values of the series.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+}
+
+impl PartialEq for XYChartSeries {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for XYChartSeries {}
+
+impl PartialOrd for XYChartSeries {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for XYChartSeries {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.kind.cmp(&other.kind).then_with(|| self.title.cmp(&other.title)).then_with(|| {
+            self.values
+                .iter()
+                .zip(&other.values)
+                .map(|(value, other_value)| value.total_cmp(other_value))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or_else(|| self.values.len().cmp(&other.values.len()))
+        })
+    }
+}
+
+impl Hash for XYChartSeries {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.kind.hash(state);
+        self.title.hash(state);
+        for value in &self.values {
+            value.to_bits().hash(state);
+        }
+    }
+}
+
+impl Display for XYChartSeries {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if let Some(title) = &self.title {
+            write!(f, " \"{title}\"")?;
+        }
+        write!(f, " [")?;
+        for (value_number, value) in self.values.iter().enumerate() {
+            if value_number > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{value}")?;
+        }
+        write!(f, "]")
+    }
+}
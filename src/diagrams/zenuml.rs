@@ -0,0 +1,243 @@
+//! Submodule providing structs for creating ZenUML diagrams in Mermaid
+//! syntax.
+
+mod builder;
+mod zenuml_call;
+mod zenuml_participant;
+mod zenuml_statement;
+use alloc::{rc::Rc, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::ZenUMLBuilder;
+pub use zenuml_call::{ZenUMLCall, ZenUMLCallBuilder};
+pub use zenuml_participant::{ZenUMLParticipant, ZenUMLParticipantBuilder, ZenUMLParticipantKind};
+pub use zenuml_statement::{
+    ZenUMLBlock, ZenUMLBlockKind, ZenUMLBranch, ZenUMLBranchKind, ZenUMLStatement,
+};
+
+use crate::{
+    errors::ZenUMLError,
+    shared::generic_configuration::{GenericConfiguration, GenericConfigurationBuilder},
+    traits::{Configuration, TabbedDisplay},
+};
+
+/// Represents the configuration for a ZenUML diagram.
+pub type ZenUMLConfiguration = GenericConfiguration;
+/// Represents the configuration builder for a ZenUML diagram.
+pub type ZenUMLConfigurationBuilder = GenericConfigurationBuilder;
+
+/// Validates a label of a ZenUML diagram, which is written within double
+/// quotes.
+pub(crate) fn validate_label(label: impl Into<String>) -> Result<String, ZenUMLError> {
+    let label = label.into();
+    if label.trim().is_empty() {
+        return Err(ZenUMLError::EmptyText);
+    }
+    if label.contains(['"', '\n', '\r']) {
+        return Err(ZenUMLError::InvalidText(label));
+    }
+    Ok(label)
+}
+
+/// Validates a text of a ZenUML diagram, such as a condition or a message,
+/// which is written without quotes and may not open or close a block.
+pub(crate) fn validate_text(text: impl Into<String>) -> Result<String, ZenUMLError> {
+    let text = text.into();
+    if text.trim().is_empty() {
+        return Err(ZenUMLError::EmptyText);
+    }
+    if text.contains(['{', '}', '\n', '\r']) {
+        return Err(ZenUMLError::InvalidText(text));
+    }
+    Ok(text)
+}
+
+/// Validates a name of a ZenUML diagram, such as a method or a variable,
+/// which must be an identifier.
+pub(crate) fn validate_identifier(name: impl Into<String>) -> Result<String, ZenUMLError> {
+    let name = name.into();
+    let mut chars = name.chars();
+    if !chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        || !chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return Err(ZenUMLError::InvalidIdentifier(name));
+    }
+    Ok(name)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a ZenUML diagram in Mermaid syntax, which describes sequences
+/// of interactions with a code-like syntax.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = ZenUMLBuilder::default();
+///     let client = builder.participant(
+///         ZenUMLParticipantBuilder::default().label("Client")?.kind(ZenUMLParticipantKind::Actor),
+///     )?;
+///     let server = builder.participant(ZenUMLParticipantBuilder::default().label("Server")?)?;
+///     builder.open_call(
+///         ZenUMLCallBuilder::default().source(client).destination(server).method("fetch")?,
+///     )?;
+///     builder.return_value("page")?;
+///
+///     let diagram = ZenUML::from(builder);
+///     assert_eq!(
+///         diagram.to_string(),
+///         concat!(
+///             "zenuml\n",
+///             "  @Actor v0 as \"Client\"\n",
+///             "  v1 as \"Server\"\n",
+///             "  v0->v1.fetch() {\n",
+///             "    return page\n",
+///             "  }\n",
+///         )
+///     );
+///     Ok(())
+/// }
+/// ```
+pub struct ZenUML {
+    /// Configuration of the diagram.
+    configuration: ZenUMLConfiguration,
+    /// Participants of the diagram.
+    participants: Vec<Rc<ZenUMLParticipant>>,
+    /// Ordered top-level statements of the diagram.
+    statements: Vec<ZenUMLStatement>,
+}
+
+impl ZenUML {
+    #[must_use]
+    /// Returns the configuration of the diagram.
+    pub fn configuration(&self) -> &ZenUMLConfiguration {
+        &self.configuration
+    }
+
+    /// Returns an iterator over the participants of the diagram.
+    pub fn participants(&self) -> impl Iterator<Item = &ZenUMLParticipant> {
+        self.participants.iter().map(AsRef::as_ref)
+    }
+
+    #[must_use]
+    /// Returns the top-level statements of the diagram.
+    pub fn statements(&self) -> &[ZenUMLStatement] {
+        &self.statements
+    }
+}
+
+impl Display for ZenUML {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for ZenUML {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat((tab_count + 1) * 2);
+        self.configuration.fmt_frontmatter(f, false, &[])?;
+        writeln!(f, "{}zenuml", " ".repeat(tab_count * 2))?;
+        if let Some(title) = self.configuration.title() {
+            writeln!(f, "{indent}title {title}")?;
+        }
+        for participant in &self.participants {
+            writeln!(f, "{indent}{participant}")?;
+        }
+        for statement in &self.statements {
+            statement.fmt_tabbed(f, tab_count + 1)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::traits::ConfigurationBuilder;
+
+    #[test]
+    fn test_zenuml_display() -> Result<(), Box<dyn core::error::Error>> {
+        let mut builder = ZenUMLBuilder::default()
+            .configuration(ZenUMLConfigurationBuilder::default().title("Order flow")?)?;
+        let customer = builder.participant(
+            ZenUMLParticipantBuilder::default()
+                .label("Customer")?
+                .kind(ZenUMLParticipantKind::Actor),
+        )?;
+        let orders = builder.participant(ZenUMLParticipantBuilder::default().label("Orders")?)?;
+        let database = builder.participant(
+            ZenUMLParticipantBuilder::default()
+                .label("Order store")?
+                .kind(ZenUMLParticipantKind::Database),
+        )?;
+
+        builder.async_message(&customer, &orders, "Browse catalog")?;
+        builder.open_call(
+            ZenUMLCallBuilder::default()
+                .source(customer.clone())
+                .destination(orders.clone())
+                .method("place")?
+                .argument("item")?
+                .argument("quantity")?
+                .assignee("order")?,
+        )?;
+        builder.open_try();
+        builder
+            .call(ZenUMLCallBuilder::default().destination(database.clone()).method("insert")?)?;
+        builder.catch("StorageException e")?;
+        builder.open_if("retries > 0")?;
+        builder.call(ZenUMLCallBuilder::default().destination(orders.clone()).method("retry")?)?;
+        builder.else_branch()?;
+        builder.return_value("null")?;
+        builder.close_block()?;
+        builder.finally()?;
+        builder.close_block()?;
+        builder.open_while("pending")?;
+        builder.call(ZenUMLCallBuilder::default().destination(database).method("poll")?)?;
+        builder.close_block()?;
+        builder.return_value("order")?;
+        builder.close_block()?;
+        builder.open_for("line in order.lines")?;
+        builder.async_message(&orders, &customer, "Line confirmed")?;
+
+        let diagram = ZenUML::from(builder);
+        assert_eq!(diagram.participants().count(), 3);
+        assert_eq!(diagram.statements().len(), 3);
+        assert_eq!(
+            diagram.to_string(),
+            concat!(
+                "zenuml\n",
+                "  title Order flow\n",
+                "  @Actor v0 as \"Customer\"\n",
+                "  v1 as \"Orders\"\n",
+                "  @Database v2 as \"Order store\"\n",
+                "  v0->v1: Browse catalog\n",
+                "  order = v0->v1.place(item, quantity) {\n",
+                "    try {\n",
+                "      v2.insert()\n",
+                "    } catch(StorageException e) {\n",
+                "      if(retries > 0) {\n",
+                "        v1.retry()\n",
+                "      } else {\n",
+                "        return null\n",
+                "      }\n",
+                "    } finally {\n",
+                "    }\n",
+                "    while(pending) {\n",
+                "      v2.poll()\n",
+                "    }\n",
+                "    return order\n",
+                "  }\n",
+                "  for(line in order.lines) {\n",
+                "    v1->v0: Line confirmed\n",
+                "  }\n",
+            )
+        );
+        Ok(())
+    }
+}
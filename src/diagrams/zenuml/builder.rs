@@ -0,0 +1,362 @@
+//! Submodule providing a builder for ZenUML diagrams.
+
+use alloc::{format, rc::Rc, string::String, vec::Vec};
+
+use crate::{
+    diagrams::zenuml::{
+        ZenUML, ZenUMLConfiguration, ZenUMLConfigurationBuilder, validate_text,
+        zenuml_call::{ZenUMLCall, ZenUMLCallBuilder},
+        zenuml_participant::{ZenUMLParticipant, ZenUMLParticipantBuilder},
+        zenuml_statement::{ZenUMLBlock, ZenUMLBlockKind, ZenUMLBranchKind, ZenUMLStatement},
+    },
+    errors::{ConfigError, ZenUMLError},
+    shared::NODE_LETTER,
+    traits::ConfigurationBuilder,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a ZenUML diagram.
+///
+/// Statements are appended to the innermost open block, if any, or to the
+/// top level of the diagram otherwise. Blocks which are still open when the
+/// builder is converted into a [`ZenUML`] diagram are closed automatically.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut builder = ZenUMLBuilder::default();
+///     let cache = builder.participant(ZenUMLParticipantBuilder::default().label("Cache")?)?;
+///     builder.open_if("cold")?;
+///     builder.call(ZenUMLCallBuilder::default().destination(cache).method("warm")?)?;
+///
+///     assert!(builder.catch("e").is_err());
+///     assert!(builder.return_value("nothing").is_err());
+///     Ok(())
+/// }
+/// ```
+pub struct ZenUMLBuilder {
+    /// Configuration of the diagram.
+    configuration: ZenUMLConfiguration,
+    /// Participants of the diagram.
+    participants: Vec<Rc<ZenUMLParticipant>>,
+    /// Ordered top-level statements of the diagram.
+    statements: Vec<ZenUMLStatement>,
+    /// Stack of the currently open blocks, from outermost to innermost.
+    open_blocks: Vec<ZenUMLBlock>,
+}
+
+impl ZenUMLBuilder {
+    /// Sets the configuration of the diagram.
+    ///
+    /// # Errors
+    ///
+    /// * If the configuration builder fails to build.
+    pub fn configuration(
+        mut self,
+        configuration: ZenUMLConfigurationBuilder,
+    ) -> Result<Self, ConfigError> {
+        self.configuration = configuration.build()?;
+        Ok(self)
+    }
+
+    /// Adds a participant to the diagram.
+    ///
+    /// Participants without an ID receive the number of participants in the
+    /// diagram, or the next ID which no participant already uses.
+    ///
+    /// # Errors
+    ///
+    /// * If a participant with the same ID already exists in the diagram.
+    /// * If the participant builder fails to build.
+    pub fn participant(
+        &mut self,
+        participant: ZenUMLParticipantBuilder,
+    ) -> Result<Rc<ZenUMLParticipant>, ZenUMLError> {
+        let id = participant.get_id().unwrap_or_else(|| {
+            let mut id = self.participants.len() as u64;
+            while self.get_participant_by_id(id).is_some() {
+                id += 1;
+            }
+            id
+        });
+        if self.get_participant_by_id(id).is_some() {
+            return Err(ZenUMLError::DuplicateId(format!("{NODE_LETTER}{id}")));
+        }
+        let participant = Rc::new(participant.id(id).build()?);
+        self.participants.push(participant.clone());
+        Ok(participant)
+    }
+
+    #[must_use]
+    /// Returns the participant with the provided ID, if any.
+    pub fn get_participant_by_id(&self, id: u64) -> Option<Rc<ZenUMLParticipant>> {
+        self.participants.iter().find(|participant| participant.id() == id).cloned()
+    }
+
+    /// Adds a synchronous call without a body.
+    ///
+    /// # Errors
+    ///
+    /// * If the call builder fails to build.
+    /// * If a participant of the call is not part of the diagram.
+    pub fn call(&mut self, call: ZenUMLCallBuilder) -> Result<Rc<ZenUMLCall>, ZenUMLError> {
+        let call = self.build_call(call)?;
+        self.push_statement(ZenUMLStatement::Call(call.clone()));
+        Ok(call)
+    }
+
+    /// Adds a synchronous call and opens its body, in which the subsequent
+    /// statements are nested until the body is closed.
+    ///
+    /// # Errors
+    ///
+    /// * If the call builder fails to build.
+    /// * If a participant of the call is not part of the diagram.
+    pub fn open_call(&mut self, call: ZenUMLCallBuilder) -> Result<Rc<ZenUMLCall>, ZenUMLError> {
+        let call = self.build_call(call)?;
+        self.open_blocks.push(ZenUMLBlock::new(ZenUMLBlockKind::Call(call.clone())));
+        Ok(call)
+    }
+
+    /// Adds an asynchronous message between two participants.
+    ///
+    /// # Errors
+    ///
+    /// * If a participant is not part of the diagram.
+    /// * If the text is empty or contains braces or line breaks.
+    pub fn async_message(
+        &mut self,
+        source: &Rc<ZenUMLParticipant>,
+        destination: &Rc<ZenUMLParticipant>,
+        text: impl Into<String>,
+    ) -> Result<(), ZenUMLError> {
+        self.ensure_participant(source)?;
+        self.ensure_participant(destination)?;
+        self.push_statement(ZenUMLStatement::AsyncMessage {
+            source: source.clone(),
+            destination: destination.clone(),
+            text: validate_text(text)?,
+        });
+        Ok(())
+    }
+
+    /// Returns a value from the body of the innermost open call.
+    ///
+    /// # Errors
+    ///
+    /// * If no call body is currently open.
+    /// * If the value is empty or contains braces or line breaks.
+    pub fn return_value(&mut self, value: impl Into<String>) -> Result<(), ZenUMLError> {
+        if !self.open_blocks.iter().any(|block| matches!(block.kind(), ZenUMLBlockKind::Call(_))) {
+            return Err(ZenUMLError::ReturnOutsideCall);
+        }
+        self.push_statement(ZenUMLStatement::Return(validate_text(value)?));
+        Ok(())
+    }
+
+    /// Opens an `if` block.
+    ///
+    /// # Errors
+    ///
+    /// * If the condition is empty or contains braces or line breaks.
+    pub fn open_if(&mut self, condition: impl Into<String>) -> Result<(), ZenUMLError> {
+        self.open_blocks.push(ZenUMLBlock::new(ZenUMLBlockKind::If(validate_text(condition)?)));
+        Ok(())
+    }
+
+    /// Opens a `while` loop.
+    ///
+    /// # Errors
+    ///
+    /// * If the condition is empty or contains braces or line breaks.
+    pub fn open_while(&mut self, condition: impl Into<String>) -> Result<(), ZenUMLError> {
+        self.open_blocks.push(ZenUMLBlock::new(ZenUMLBlockKind::While(validate_text(condition)?)));
+        Ok(())
+    }
+
+    /// Opens a `for` loop.
+    ///
+    /// # Errors
+    ///
+    /// * If the condition is empty or contains braces or line breaks.
+    pub fn open_for(&mut self, condition: impl Into<String>) -> Result<(), ZenUMLError> {
+        self.open_blocks.push(ZenUMLBlock::new(ZenUMLBlockKind::For(validate_text(condition)?)));
+        Ok(())
+    }
+
+    /// Opens a `try` block.
+    pub fn open_try(&mut self) {
+        self.open_blocks.push(ZenUMLBlock::new(ZenUMLBlockKind::Try));
+    }
+
+    /// Starts an `else if` branch in the innermost open `if` block.
+    ///
+    /// # Errors
+    ///
+    /// * If the condition is empty or contains braces or line breaks.
+    /// * If no `if` block is the innermost open block.
+    /// * If the block already has an `else` branch.
+    pub fn else_if(&mut self, condition: impl Into<String>) -> Result<(), ZenUMLError> {
+        self.branch(ZenUMLBranchKind::ElseIf(validate_text(condition)?))
+    }
+
+    /// Starts the final `else` branch in the innermost open `if` block.
+    ///
+    /// # Errors
+    ///
+    /// * If no `if` block is the innermost open block.
+    /// * If the block already has an `else` branch.
+    pub fn else_branch(&mut self) -> Result<(), ZenUMLError> {
+        self.branch(ZenUMLBranchKind::Else)
+    }
+
+    /// Starts a `catch` branch in the innermost open `try` block.
+    ///
+    /// # Arguments
+    ///
+    /// * `exception` - The caught exception, such as `IOException e`.
+    ///
+    /// # Errors
+    ///
+    /// * If the exception is empty or contains braces or line breaks.
+    /// * If no `try` block is the innermost open block.
+    /// * If the block already has a `finally` branch.
+    pub fn catch(&mut self, exception: impl Into<String>) -> Result<(), ZenUMLError> {
+        self.branch(ZenUMLBranchKind::Catch(validate_text(exception)?))
+    }
+
+    /// Starts the final `finally` branch in the innermost open `try` block.
+    ///
+    /// # Errors
+    ///
+    /// * If no `try` block is the innermost open block.
+    /// * If the block already has a `finally` branch.
+    pub fn finally(&mut self) -> Result<(), ZenUMLError> {
+        self.branch(ZenUMLBranchKind::Finally)
+    }
+
+    /// Closes the innermost open block.
+    ///
+    /// # Errors
+    ///
+    /// * If no block is currently open.
+    pub fn close_block(&mut self) -> Result<(), ZenUMLError> {
+        let block = self.open_blocks.pop().ok_or(ZenUMLError::NoOpenBlock)?;
+        self.push_statement(ZenUMLStatement::Block(block));
+        Ok(())
+    }
+
+    /// Starts a new branch in the innermost open block.
+    fn branch(&mut self, kind: ZenUMLBranchKind) -> Result<(), ZenUMLError> {
+        self.open_blocks.last_mut().ok_or(ZenUMLError::NoOpenBlock)?.branch(kind)
+    }
+
+    /// Builds the provided call, after checking that its participants are
+    /// part of the diagram.
+    fn build_call(&self, call: ZenUMLCallBuilder) -> Result<Rc<ZenUMLCall>, ZenUMLError> {
+        let call = call.build()?;
+        if let Some(source) = call.source() {
+            self.ensure_participant(source)?;
+        }
+        self.ensure_participant(call.destination())?;
+        Ok(Rc::new(call))
+    }
+
+    /// Returns an error if the provided participant is not part of the
+    /// diagram.
+    fn ensure_participant(&self, participant: &Rc<ZenUMLParticipant>) -> Result<(), ZenUMLError> {
+        if self.get_participant_by_id(participant.id()).as_ref() == Some(participant) {
+            Ok(())
+        } else {
+            Err(ZenUMLError::ParticipantNotFound(format!("{NODE_LETTER}{}", participant.id())))
+        }
+    }
+
+    /// Appends the provided statement to the innermost open block, or to the
+    /// top level of the diagram when no block is open.
+    fn push_statement(&mut self, statement: ZenUMLStatement) {
+        match self.open_blocks.last_mut() {
+            Some(block) => block.push(statement),
+            None => self.statements.push(statement),
+        }
+    }
+}
+
+impl From<ZenUMLBuilder> for ZenUML {
+    fn from(mut builder: ZenUMLBuilder) -> Self {
+        while builder.close_block().is_ok() {}
+        ZenUML {
+            configuration: builder.configuration,
+            participants: builder.participants,
+            statements: builder.statements,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::borrow::ToOwned;
+
+    use super::*;
+
+    #[test]
+    fn test_zenuml_builder_skips_taken_ids() -> Result<(), ZenUMLError> {
+        let mut builder = ZenUMLBuilder::default();
+        let api = builder.participant(ZenUMLParticipantBuilder::default().label("API")?.id(1))?;
+        let database = builder.participant(ZenUMLParticipantBuilder::default().label("DB")?)?;
+        assert_eq!((api.id(), database.id()), (1, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_zenuml_builder_errors() -> Result<(), ZenUMLError> {
+        let mut builder = ZenUMLBuilder::default();
+        let mut other = ZenUMLBuilder::default();
+        let api = builder.participant(ZenUMLParticipantBuilder::default().label("API")?)?;
+        let foreign =
+            other.participant(ZenUMLParticipantBuilder::default().label("Elsewhere")?.id(7))?;
+
+        assert_eq!(
+            builder.participant(ZenUMLParticipantBuilder::default().label("Dup")?.id(0)),
+            Err(ZenUMLError::DuplicateId("v0".to_owned()))
+        );
+        assert_eq!(
+            builder.call(ZenUMLCallBuilder::default().destination(foreign.clone()).method("go")?),
+            Err(ZenUMLError::ParticipantNotFound("v7".to_owned()))
+        );
+        assert_eq!(
+            builder.async_message(&api, &foreign, "Hi"),
+            Err(ZenUMLError::ParticipantNotFound("v7".to_owned()))
+        );
+        assert_eq!(
+            builder.call(ZenUMLCallBuilder::default().method("go")?),
+            Err(ZenUMLError::MissingDestination)
+        );
+        assert_eq!(
+            builder.call(ZenUMLCallBuilder::default().destination(api.clone())),
+            Err(ZenUMLError::MissingMethod)
+        );
+        assert_eq!(
+            ZenUMLCallBuilder::default().argument("a, b"),
+            Err(ZenUMLError::InvalidText("a, b".to_owned()))
+        );
+        assert_eq!(
+            ZenUMLCallBuilder::default().assignee("1st"),
+            Err(ZenUMLError::InvalidIdentifier("1st".to_owned()))
+        );
+        assert_eq!(builder.open_if("x {"), Err(ZenUMLError::InvalidText("x {".to_owned())));
+        assert_eq!(builder.else_branch(), Err(ZenUMLError::NoOpenBlock));
+        assert_eq!(builder.close_block(), Err(ZenUMLError::NoOpenBlock));
+        assert_eq!(builder.return_value("x"), Err(ZenUMLError::ReturnOutsideCall));
+
+        builder.open_call(ZenUMLCallBuilder::default().destination(api).method("get")?)?;
+        builder.open_while("more")?;
+        assert_eq!(builder.finally(), Err(ZenUMLError::UnsupportedBranch("finally".to_owned())));
+        builder.return_value("partial")?;
+        Ok(())
+    }
+}
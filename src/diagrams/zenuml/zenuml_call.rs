@@ -0,0 +1,102 @@
+//! Submodule defining the synchronous calls of ZenUML diagrams in Mermaid
+//! syntax.
+
+mod builder;
+use alloc::{rc::Rc, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+pub use builder::ZenUMLCallBuilder;
+
+use crate::{diagrams::zenuml::zenuml_participant::ZenUMLParticipant, shared::NODE_LETTER};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a synchronous call of a method of a participant, optionally
+/// assigning its return value.
+///
+/// When no source is set, the call originates from the participant whose
+/// method body encloses it.
+///
+/// # Example
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let service = Rc::new(ZenUMLParticipantBuilder::default().label("Service")?.id(1).build()?);
+///     let call = ZenUMLCallBuilder::default()
+///         .destination(service)
+///         .method("find")?
+///         .argument("id")?
+///         .assignee("user")?
+///         .build()?;
+///     assert_eq!(call.to_string(), "user = v1.find(id)");
+///
+///     assert!(ZenUMLCallBuilder::default().method("find all").is_err());
+///     Ok(())
+/// }
+/// ```
+pub struct ZenUMLCall {
+    /// Participant from which the call originates, if explicit.
+    source: Option<Rc<ZenUMLParticipant>>,
+    /// Participant whose method is called.
+    destination: Rc<ZenUMLParticipant>,
+    /// Name of the called method.
+    method: String,
+    /// Arguments of the call.
+    arguments: Vec<String>,
+    /// Variable to which the return value is assigned, if any.
+    assignee: Option<String>,
+}
+
+impl ZenUMLCall {
+    #[must_use]
+    /// Returns the participant from which the call originates, if explicit.
+    pub fn source(&self) -> Option<&Rc<ZenUMLParticipant>> {
+        self.source.as_ref()
+    }
+
+    #[must_use]
+    /// Returns the participant whose method is called.
+    pub fn destination(&self) -> &Rc<ZenUMLParticipant> {
+        &self.destination
+    }
+
+    #[must_use]
+    /// Returns the name of the called method.
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    /// Returns an iterator over the arguments of the call.
+    pub fn arguments(&self) -> impl Iterator<Item = &str> {
+        self.arguments.iter().map(String::as_str)
+    }
+
+    #[must_use]
+    /// Returns the variable to which the return value is assigned, if any.
+    pub fn assignee(&self) -> Option<&str> {
+        self.assignee.as_deref()
+    }
+}
+
+impl Display for ZenUMLCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(assignee) = &self.assignee {
+            write!(f, "{assignee} = ")?;
+        }
+        if let Some(source) = &self.source {
+            write!(f, "{NODE_LETTER}{}->", source.id())?;
+        }
+        write!(f, "{NODE_LETTER}{}.{}(", self.destination.id(), self.method)?;
+        for (argument_number, argument) in self.arguments.iter().enumerate() {
+            if argument_number > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{argument}")?;
+        }
+        write!(f, ")")
+    }
+}
@@ -0,0 +1,102 @@
+//! Submodule defining the struct for building a synchronous call of a ZenUML
+//! diagram.
+
+use alloc::{rc::Rc, string::String, vec::Vec};
+
+use crate::{
+    diagrams::zenuml::{
+        validate_identifier, validate_text, zenuml_call::ZenUMLCall,
+        zenuml_participant::ZenUMLParticipant,
+    },
+    errors::ZenUMLError,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `ZenUMLCall`.
+pub struct ZenUMLCallBuilder {
+    /// Participant from which the call originates.
+    source: Option<Rc<ZenUMLParticipant>>,
+    /// Participant whose method is called.
+    destination: Option<Rc<ZenUMLParticipant>>,
+    /// Name of the called method.
+    method: Option<String>,
+    /// Arguments of the call.
+    arguments: Vec<String>,
+    /// Variable to which the return value is assigned.
+    assignee: Option<String>,
+}
+
+impl ZenUMLCallBuilder {
+    #[must_use]
+    /// Sets the participant from which the call originates.
+    pub fn source(mut self, source: Rc<ZenUMLParticipant>) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    #[must_use]
+    /// Sets the participant whose method is called.
+    pub fn destination(mut self, destination: Rc<ZenUMLParticipant>) -> Self {
+        self.destination = Some(destination);
+        self
+    }
+
+    /// Sets the name of the called method.
+    ///
+    /// # Errors
+    ///
+    /// * If the name is not a valid identifier.
+    pub fn method(mut self, method: impl Into<String>) -> Result<Self, ZenUMLError> {
+        self.method = Some(validate_identifier(method)?);
+        Ok(self)
+    }
+
+    /// Appends an argument to the call.
+    ///
+    /// # Errors
+    ///
+    /// * If the argument is empty.
+    /// * If the argument contains commas, parentheses, braces or line breaks.
+    pub fn argument(mut self, argument: impl Into<String>) -> Result<Self, ZenUMLError> {
+        let argument = validate_text(argument)?;
+        if argument.contains([',', '(', ')']) {
+            return Err(ZenUMLError::InvalidText(argument));
+        }
+        self.arguments.push(argument);
+        Ok(self)
+    }
+
+    /// Sets the variable to which the return value is assigned.
+    ///
+    /// # Errors
+    ///
+    /// * If the name is not a valid identifier.
+    pub fn assignee(mut self, assignee: impl Into<String>) -> Result<Self, ZenUMLError> {
+        self.assignee = Some(validate_identifier(assignee)?);
+        Ok(self)
+    }
+
+    /// Builds the call.
+    ///
+    /// # Errors
+    ///
+    /// * If the destination or the method is missing.
+    pub fn build(self) -> Result<ZenUMLCall, ZenUMLError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<ZenUMLCallBuilder> for ZenUMLCall {
+    type Error = ZenUMLError;
+
+    fn try_from(builder: ZenUMLCallBuilder) -> Result<Self, Self::Error> {
+        Ok(ZenUMLCall {
+            source: builder.source,
+            destination: builder.destination.ok_or(ZenUMLError::MissingDestination)?,
+            method: builder.method.ok_or(ZenUMLError::MissingMethod)?,
+            arguments: builder.arguments,
+            assignee: builder.assignee,
+        })
+    }
+}
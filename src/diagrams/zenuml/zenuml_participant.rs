@@ -0,0 +1,103 @@
+//! Submodule defining the participants of ZenUML diagrams in Mermaid syntax.
+
+mod builder;
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+pub use builder::ZenUMLParticipantBuilder;
+
+use crate::shared::NODE_LETTER;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the annotator of a participant, which determines its icon.
+pub enum ZenUMLParticipantKind {
+    /// A plain participant, drawn as a box.
+    #[default]
+    Participant,
+    /// A human actor.
+    Actor,
+    /// A boundary of the system.
+    Boundary,
+    /// A controller.
+    Control,
+    /// A database.
+    Database,
+    /// An entity.
+    Entity,
+    /// A message queue.
+    Queue,
+}
+
+impl ZenUMLParticipantKind {
+    #[must_use]
+    /// Returns the annotator declaring the participant, if any.
+    pub fn annotator(self) -> Option<&'static str> {
+        match self {
+            ZenUMLParticipantKind::Participant => None,
+            ZenUMLParticipantKind::Actor => Some("@Actor"),
+            ZenUMLParticipantKind::Boundary => Some("@Boundary"),
+            ZenUMLParticipantKind::Control => Some("@Control"),
+            ZenUMLParticipantKind::Database => Some("@Database"),
+            ZenUMLParticipantKind::Entity => Some("@Entity"),
+            ZenUMLParticipantKind::Queue => Some("@Queue"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a participant of a ZenUML diagram.
+///
+/// # Example
+///
+/// ```
+/// use mermaid_builder::prelude::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let participant = ZenUMLParticipantBuilder::default()
+///         .label("Orders")?
+///         .kind(ZenUMLParticipantKind::Database)
+///         .id(2)
+///         .build()?;
+///     assert_eq!(participant.to_string(), "@Database v2 as \"Orders\"");
+///     Ok(())
+/// }
+/// ```
+pub struct ZenUMLParticipant {
+    /// Unique identifier of the participant.
+    id: u64,
+    /// Label of the participant.
+    label: String,
+    /// Annotator of the participant.
+    kind: ZenUMLParticipantKind,
+}
+
+impl ZenUMLParticipant {
+    #[must_use]
+    /// Returns the unique identifier of the participant.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[must_use]
+    /// Returns the label of the participant.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    #[must_use]
+    /// Returns the annotator of the participant.
+    pub fn kind(&self) -> ZenUMLParticipantKind {
+        self.kind
+    }
+}
+
+impl Display for ZenUMLParticipant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(annotator) = self.kind.annotator() {
+            write!(f, "{annotator} ")?;
+        }
+        write!(f, "{NODE_LETTER}{} as \"{}\"", self.id, self.label)
+    }
+}
@@ -0,0 +1,77 @@
+//! Submodule defining the struct for building a participant of a ZenUML
+//! diagram.
+
+use alloc::string::String;
+
+use crate::{
+    diagrams::zenuml::{
+        validate_label,
+        zenuml_participant::{ZenUMLParticipant, ZenUMLParticipantKind},
+    },
+    errors::ZenUMLError,
+};
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Builder for creating a `ZenUMLParticipant`.
+pub struct ZenUMLParticipantBuilder {
+    /// Unique identifier of the participant.
+    id: Option<u64>,
+    /// Label of the participant.
+    label: Option<String>,
+    /// Annotator of the participant.
+    kind: ZenUMLParticipantKind,
+}
+
+impl ZenUMLParticipantBuilder {
+    /// Sets the label of the participant.
+    ///
+    /// # Errors
+    ///
+    /// * If the label is empty or contains double quotes or line breaks.
+    pub fn label(mut self, label: impl Into<String>) -> Result<Self, ZenUMLError> {
+        self.label = Some(validate_label(label)?);
+        Ok(self)
+    }
+
+    #[must_use]
+    /// Sets the annotator of the participant.
+    pub fn kind(mut self, kind: ZenUMLParticipantKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    #[must_use]
+    /// Sets the unique identifier of the participant.
+    pub fn id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[must_use]
+    /// Returns the identifier of the participant, if set.
+    pub fn get_id(&self) -> Option<u64> {
+        self.id
+    }
+
+    /// Builds the participant.
+    ///
+    /// # Errors
+    ///
+    /// * If the ID or the label is missing.
+    pub fn build(self) -> Result<ZenUMLParticipant, ZenUMLError> {
+        self.try_into()
+    }
+}
+
+impl TryFrom<ZenUMLParticipantBuilder> for ZenUMLParticipant {
+    type Error = ZenUMLError;
+
+    fn try_from(builder: ZenUMLParticipantBuilder) -> Result<Self, Self::Error> {
+        Ok(ZenUMLParticipant {
+            id: builder.id.ok_or(ZenUMLError::MissingId)?,
+            label: builder.label.ok_or(ZenUMLError::MissingLabel)?,
+            kind: builder.kind,
+        })
+    }
+}
@@ -0,0 +1,282 @@
+//! Submodule defining the ordered statements of a ZenUML diagram, including
+//! the nested blocks such as method bodies, conditions and loops.
+
+use alloc::{rc::Rc, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+use crate::{
+    diagrams::zenuml::{zenuml_call::ZenUMLCall, zenuml_participant::ZenUMLParticipant},
+    errors::ZenUMLError,
+    shared::NODE_LETTER,
+    traits::TabbedDisplay,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the kind of a block in a ZenUML diagram, together with the
+/// condition of its first branch.
+pub enum ZenUMLBlockKind {
+    /// The body of a synchronous call, holding the nested calls.
+    Call(Rc<ZenUMLCall>),
+    /// An `if` block, whose branches are `else if` and `else`.
+    If(String),
+    /// A `while` loop.
+    While(String),
+    /// A `for` loop.
+    For(String),
+    /// A `try` block, whose branches are `catch` and `finally`.
+    Try,
+}
+
+impl Display for ZenUMLBlockKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ZenUMLBlockKind::Call(call) => write!(f, "{call}"),
+            ZenUMLBlockKind::If(condition) => write!(f, "if({condition})"),
+            ZenUMLBlockKind::While(condition) => write!(f, "while({condition})"),
+            ZenUMLBlockKind::For(condition) => write!(f, "for({condition})"),
+            ZenUMLBlockKind::Try => write!(f, "try"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents the kind of an additional branch of a block.
+pub enum ZenUMLBranchKind {
+    /// An `else if` branch of an `if` block, with its condition.
+    ElseIf(String),
+    /// The final `else` branch of an `if` block.
+    Else,
+    /// A `catch` branch of a `try` block, with the caught exception.
+    Catch(String),
+    /// The final `finally` branch of a `try` block.
+    Finally,
+}
+
+impl ZenUMLBranchKind {
+    #[must_use]
+    /// Returns the keyword opening the branch.
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            ZenUMLBranchKind::ElseIf(_) => "else if",
+            ZenUMLBranchKind::Else => "else",
+            ZenUMLBranchKind::Catch(_) => "catch",
+            ZenUMLBranchKind::Finally => "finally",
+        }
+    }
+
+    /// Returns whether no branch may follow the branch.
+    fn is_final(&self) -> bool {
+        matches!(self, ZenUMLBranchKind::Else | ZenUMLBranchKind::Finally)
+    }
+}
+
+impl Display for ZenUMLBranchKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.keyword())?;
+        match self {
+            ZenUMLBranchKind::ElseIf(condition) => write!(f, "({condition})"),
+            ZenUMLBranchKind::Catch(exception) => write!(f, "({exception})"),
+            ZenUMLBranchKind::Else | ZenUMLBranchKind::Finally => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents an additional branch of a block, such as an `else` branch of an
+/// `if` block.
+pub struct ZenUMLBranch {
+    /// The kind of the branch.
+    kind: ZenUMLBranchKind,
+    /// The statements in the branch.
+    statements: Vec<ZenUMLStatement>,
+}
+
+impl ZenUMLBranch {
+    #[must_use]
+    /// Returns the kind of the branch.
+    pub fn kind(&self) -> &ZenUMLBranchKind {
+        &self.kind
+    }
+
+    #[must_use]
+    /// Returns the statements in the branch.
+    pub fn statements(&self) -> &[ZenUMLStatement] {
+        &self.statements
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a block in a ZenUML diagram, grouping statements within braces.
+pub struct ZenUMLBlock {
+    /// The kind of the block.
+    kind: ZenUMLBlockKind,
+    /// The statements in the first branch of the block.
+    statements: Vec<ZenUMLStatement>,
+    /// The additional branches of the block.
+    branches: Vec<ZenUMLBranch>,
+}
+
+impl ZenUMLBlock {
+    /// Creates a new empty block of the provided kind.
+    pub(crate) fn new(kind: ZenUMLBlockKind) -> Self {
+        Self { kind, statements: Vec::new(), branches: Vec::new() }
+    }
+
+    /// Appends a statement to the last branch of the block.
+    pub(crate) fn push(&mut self, statement: ZenUMLStatement) {
+        match self.branches.last_mut() {
+            Some(branch) => branch.statements.push(statement),
+            None => self.statements.push(statement),
+        }
+    }
+
+    /// Starts a new branch in the block.
+    ///
+    /// # Errors
+    ///
+    /// * If the block does not support the branch, or already ended with an
+    ///   `else` or `finally` branch.
+    pub(crate) fn branch(&mut self, kind: ZenUMLBranchKind) -> Result<(), ZenUMLError> {
+        let supported = match &self.kind {
+            ZenUMLBlockKind::If(_) => {
+                matches!(kind, ZenUMLBranchKind::ElseIf(_) | ZenUMLBranchKind::Else)
+            }
+            ZenUMLBlockKind::Try => {
+                matches!(kind, ZenUMLBranchKind::Catch(_) | ZenUMLBranchKind::Finally)
+            }
+            ZenUMLBlockKind::Call(_) | ZenUMLBlockKind::While(_) | ZenUMLBlockKind::For(_) => false,
+        };
+        if !supported || self.branches.last().is_some_and(|branch| branch.kind.is_final()) {
+            return Err(ZenUMLError::UnsupportedBranch(kind.keyword().into()));
+        }
+        self.branches.push(ZenUMLBranch { kind, statements: Vec::new() });
+        Ok(())
+    }
+
+    #[must_use]
+    /// Returns the kind of the block.
+    pub fn kind(&self) -> &ZenUMLBlockKind {
+        &self.kind
+    }
+
+    #[must_use]
+    /// Returns the statements in the first branch of the block.
+    pub fn statements(&self) -> &[ZenUMLStatement] {
+        &self.statements
+    }
+
+    #[must_use]
+    /// Returns the additional branches of the block.
+    pub fn branches(&self) -> &[ZenUMLBranch] {
+        &self.branches
+    }
+}
+
+impl Display for ZenUMLBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for ZenUMLBlock {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        writeln!(f, "{indent}{} {{", self.kind)?;
+        for statement in &self.statements {
+            statement.fmt_tabbed(f, tab_count + 1)?;
+        }
+        for branch in &self.branches {
+            writeln!(f, "{indent}}} {} {{", branch.kind)?;
+            for statement in &branch.statements {
+                statement.fmt_tabbed(f, tab_count + 1)?;
+            }
+        }
+        writeln!(f, "{indent}}}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Represents a statement of a ZenUML diagram, in the order in which it
+/// appears.
+pub enum ZenUMLStatement {
+    /// A synchronous call without a body.
+    Call(Rc<ZenUMLCall>),
+    /// An asynchronous message between two participants.
+    AsyncMessage {
+        /// The participant sending the message.
+        source: Rc<ZenUMLParticipant>,
+        /// The participant receiving the message.
+        destination: Rc<ZenUMLParticipant>,
+        /// The content of the message.
+        text: String,
+    },
+    /// A value returned from the body of the enclosing call.
+    Return(String),
+    /// A block grouping further statements.
+    Block(ZenUMLBlock),
+}
+
+impl Display for ZenUMLStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tabbed(f, 0)
+    }
+}
+
+impl TabbedDisplay for ZenUMLStatement {
+    fn fmt_tabbed(&self, f: &mut fmt::Formatter<'_>, tab_count: usize) -> fmt::Result {
+        let indent = " ".repeat(tab_count * 2);
+        match self {
+            ZenUMLStatement::Call(call) => writeln!(f, "{indent}{call}"),
+            ZenUMLStatement::AsyncMessage { source, destination, text } => {
+                writeln!(
+                    f,
+                    "{indent}{NODE_LETTER}{}->{NODE_LETTER}{}: {text}",
+                    source.id(),
+                    destination.id()
+                )
+            }
+            ZenUMLStatement::Return(value) => writeln!(f, "{indent}return {value}"),
+            ZenUMLStatement::Block(block) => block.fmt_tabbed(f, tab_count),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, format};
+
+    use super::*;
+
+    #[test]
+    fn test_zenuml_block_branches() -> Result<(), ZenUMLError> {
+        let mut block = ZenUMLBlock::new(ZenUMLBlockKind::If("ok".to_owned()));
+        block.push(ZenUMLStatement::Return("a".to_owned()));
+        block.branch(ZenUMLBranchKind::ElseIf("retry".to_owned()))?;
+        block.branch(ZenUMLBranchKind::Else)?;
+        block.push(ZenUMLStatement::Return("b".to_owned()));
+        assert_eq!(
+            block.branch(ZenUMLBranchKind::Else),
+            Err(ZenUMLError::UnsupportedBranch("else".to_owned()))
+        );
+        assert_eq!(
+            block.branch(ZenUMLBranchKind::Finally),
+            Err(ZenUMLError::UnsupportedBranch("finally".to_owned()))
+        );
+        assert_eq!(
+            format!("{block}"),
+            "if(ok) {\n  return a\n} else if(retry) {\n} else {\n  return b\n}\n"
+        );
+
+        let mut block = ZenUMLBlock::new(ZenUMLBlockKind::While("busy".to_owned()));
+        assert_eq!(
+            block.branch(ZenUMLBranchKind::Catch("e".to_owned())),
+            Err(ZenUMLError::UnsupportedBranch("catch".to_owned()))
+        );
+        Ok(())
+    }
+}
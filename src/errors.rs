@@ -3,12 +3,54 @@
 
 use thiserror::Error;
 
+mod architecture_diagram_error;
+pub use architecture_diagram_error::ArchitectureDiagramError;
+mod block_diagram_error;
+pub use block_diagram_error::BlockDiagramError;
+mod c4_diagram_error;
+pub use c4_diagram_error::C4DiagramError;
 mod config_error;
 pub use config_error::ConfigError;
 mod edge_error;
 pub use edge_error::EdgeError;
+mod gantt_error;
+pub use gantt_error::GanttError;
+mod git_graph_error;
+pub use git_graph_error::GitGraphError;
+mod journey_error;
+pub use journey_error::JourneyError;
+mod kanban_error;
+pub use kanban_error::KanbanError;
+mod mindmap_error;
+pub use mindmap_error::MindmapError;
 mod node_error;
 pub use node_error::NodeError;
+mod packet_diagram_error;
+pub use packet_diagram_error::PacketDiagramError;
+mod parse_error;
+pub use parse_error::{ParseError, ParseErrorKind};
+mod pie_chart_error;
+pub use pie_chart_error::PieChartError;
+mod quadrant_chart_error;
+pub use quadrant_chart_error::QuadrantChartError;
+mod radar_chart_error;
+pub use radar_chart_error::RadarChartError;
+mod requirement_diagram_error;
+pub use requirement_diagram_error::RequirementDiagramError;
+mod sankey_diagram_error;
+pub use sankey_diagram_error::SankeyDiagramError;
+mod sequence_diagram_error;
+pub use sequence_diagram_error::SequenceDiagramError;
+mod state_diagram_error;
+pub use state_diagram_error::StateDiagramError;
+mod timeline_error;
+pub use timeline_error::TimelineError;
+mod treemap_error;
+pub use treemap_error::TreemapError;
+mod xy_chart_error;
+pub use xy_chart_error::XYChartError;
+mod zenuml_error;
+pub use zenuml_error::ZenUMLError;
 
 pub use crate::shared::style_class::StyleClassError;
 
@@ -29,4 +71,64 @@ pub enum Error {
     /// An error regarding style classes.
     #[error("Style class error: {0}")]
     StyleClass(#[from] StyleClassError),
+    /// An error regarding sequence diagrams.
+    #[error("Sequence diagram error: {0}")]
+    SequenceDiagram(#[from] SequenceDiagramError),
+    /// An error regarding state diagrams.
+    #[error("State diagram error: {0}")]
+    StateDiagram(#[from] StateDiagramError),
+    /// An error regarding Gantt charts.
+    #[error("Gantt error: {0}")]
+    Gantt(#[from] GanttError),
+    /// An error regarding pie charts.
+    #[error("Pie chart error: {0}")]
+    PieChart(#[from] PieChartError),
+    /// An error regarding mindmaps.
+    #[error("Mindmap error: {0}")]
+    Mindmap(#[from] MindmapError),
+    /// An error regarding timelines.
+    #[error("Timeline error: {0}")]
+    Timeline(#[from] TimelineError),
+    /// An error regarding git graphs.
+    #[error("Git graph error: {0}")]
+    GitGraph(#[from] GitGraphError),
+    /// An error regarding quadrant charts.
+    #[error("Quadrant chart error: {0}")]
+    QuadrantChart(#[from] QuadrantChartError),
+    /// An error regarding XY charts.
+    #[error("XY chart error: {0}")]
+    XYChart(#[from] XYChartError),
+    /// An error regarding sankey diagrams.
+    #[error("Sankey diagram error: {0}")]
+    SankeyDiagram(#[from] SankeyDiagramError),
+    /// An error regarding requirement diagrams.
+    #[error("Requirement diagram error: {0}")]
+    RequirementDiagram(#[from] RequirementDiagramError),
+    /// An error regarding C4 diagrams.
+    #[error("C4 diagram error: {0}")]
+    C4Diagram(#[from] C4DiagramError),
+    /// An error regarding block diagrams.
+    #[error("Block diagram error: {0}")]
+    BlockDiagram(#[from] BlockDiagramError),
+    /// An error regarding architecture diagrams.
+    #[error("Architecture diagram error: {0}")]
+    ArchitectureDiagram(#[from] ArchitectureDiagramError),
+    /// An error regarding packet diagrams.
+    #[error("Packet diagram error: {0}")]
+    PacketDiagram(#[from] PacketDiagramError),
+    /// An error regarding kanban boards.
+    #[error("Kanban error: {0}")]
+    Kanban(#[from] KanbanError),
+    /// An error regarding user journeys.
+    #[error("Journey error: {0}")]
+    Journey(#[from] JourneyError),
+    /// An error regarding radar charts.
+    #[error("Radar chart error: {0}")]
+    RadarChart(#[from] RadarChartError),
+    /// An error regarding treemaps.
+    #[error("Treemap error: {0}")]
+    Treemap(#[from] TreemapError),
+    /// An error regarding ZenUML diagrams.
+    #[error("ZenUML error: {0}")]
+    ZenUML(#[from] ZenUMLError),
 }
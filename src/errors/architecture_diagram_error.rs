@@ -0,0 +1,43 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building architecture diagrams in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to architecture diagrams in Mermaid.
+pub enum ArchitectureDiagramError {
+    /// The provided text is empty.
+    #[error("Text cannot be empty.")]
+    EmptyText,
+    /// The provided text contains characters reserved by the syntax.
+    #[error("Text `{0}` contains reserved characters.")]
+    InvalidText(String),
+    /// The provided icon name is not a valid icon name.
+    #[error("Invalid icon name: `{0}`")]
+    InvalidIcon(String),
+    /// The label of the group or service is missing.
+    #[error("Label is missing.")]
+    MissingLabel,
+    /// The ID of the group or service is missing.
+    #[error("ID is missing.")]
+    MissingId,
+    /// A group, service or junction with the same ID already exists in the
+    /// diagram.
+    #[error("Group, service or junction `{0}` already exists.")]
+    DuplicateId(String),
+    /// The provided group does not exist in the diagram.
+    #[error("Group not found: `{0}`")]
+    GroupNotFound(String),
+    /// The provided service or junction does not exist in the diagram.
+    #[error("Service or junction not found: `{0}`")]
+    EndpointNotFound(String),
+    /// The source of the edge is missing.
+    #[error("Edge source is missing.")]
+    MissingSource,
+    /// The destination of the edge is missing.
+    #[error("Edge destination is missing.")]
+    MissingDestination,
+}
@@ -0,0 +1,50 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building block diagrams in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+use crate::{diagrams::flowchart::FlowchartNodeShape, shared::ArrowShape};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to block diagrams in Mermaid.
+pub enum BlockDiagramError {
+    /// The provided text is empty.
+    #[error("Text cannot be empty.")]
+    EmptyText,
+    /// The provided text contains characters reserved by the syntax.
+    #[error("Text `{0}` contains reserved characters.")]
+    InvalidText(String),
+    /// The label of the block or block arrow is missing.
+    #[error("Label is missing.")]
+    MissingLabel,
+    /// The ID of the block is missing.
+    #[error("ID is missing.")]
+    MissingId,
+    /// The shape cannot be drawn in block diagrams.
+    #[error("`{0}` shapes are not supported in block diagrams.")]
+    UnsupportedShape(FlowchartNodeShape),
+    /// The block would span zero columns.
+    #[error("Blocks must span at least one column.")]
+    ZeroSpan,
+    /// The diagram or composite block would have zero columns.
+    #[error("The number of columns must be at least one.")]
+    ZeroColumns,
+    /// A block with the same ID already exists in the diagram.
+    #[error("Block `{0}` already exists.")]
+    DuplicateId(String),
+    /// The provided block does not exist in the diagram.
+    #[error("Block not found: `{0}`")]
+    BlockNotFound(String),
+    /// The source of the edge is missing.
+    #[error("Edge source is missing.")]
+    MissingSource,
+    /// The destination of the edge is missing.
+    #[error("Edge destination is missing.")]
+    MissingDestination,
+    /// The arrow shape cannot be drawn at the ends of block diagram edges.
+    #[error("Incompatible arrow shape: `{}`", .0.right())]
+    IncompatibleArrowShape(ArrowShape),
+}
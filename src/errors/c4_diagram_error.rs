@@ -0,0 +1,53 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building C4 diagrams in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+use crate::diagrams::c4_diagram::{C4BoundaryKind, C4ElementKind};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to C4 diagrams in Mermaid.
+pub enum C4DiagramError {
+    /// The provided text is empty.
+    #[error("Text cannot be empty.")]
+    EmptyText,
+    /// The provided text contains characters reserved by the syntax.
+    #[error("Text `{0}` contains reserved characters.")]
+    InvalidText(String),
+    /// The label of the element, boundary or relationship is missing.
+    #[error("Label is missing.")]
+    MissingLabel,
+    /// The ID of the element or boundary is missing.
+    #[error("ID is missing.")]
+    MissingId,
+    /// The kind of element cannot be stored in a database or a queue.
+    #[error("`{0}` elements cannot be databases or queues.")]
+    UnsupportedStorage(C4ElementKind),
+    /// The kind of element does not have a technology.
+    #[error("`{0}` elements do not have a technology.")]
+    UnsupportedTechnology(C4ElementKind),
+    /// The kind of boundary does not have a type.
+    #[error("`{0}` boundaries do not have a type.")]
+    UnsupportedBoundaryType(C4BoundaryKind),
+    /// The kind of boundary does not have a description.
+    #[error("`{0}` boundaries do not have a description.")]
+    UnsupportedBoundaryDescription(C4BoundaryKind),
+    /// An element or boundary with the same ID already exists in the diagram.
+    #[error("Element or boundary `{0}` already exists.")]
+    DuplicateId(String),
+    /// The provided boundary does not exist in the diagram.
+    #[error("Boundary not found: `{0}`")]
+    BoundaryNotFound(String),
+    /// The provided element does not exist in the diagram.
+    #[error("Element not found: `{0}`")]
+    ElementNotFound(String),
+    /// The source of the relationship is missing.
+    #[error("Relationship source is missing.")]
+    MissingSource,
+    /// The destination of the relationship is missing.
+    #[error("Relationship destination is missing.")]
+    MissingDestination,
+}
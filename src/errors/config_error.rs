@@ -3,6 +3,8 @@
 
 use thiserror::Error;
 
+use crate::{diagrams::gantt::GanttDate, shared::Direction};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 /// Enum representing errors related to configuration in Mermaid diagrams.
@@ -10,4 +12,25 @@ pub enum ConfigError {
     /// The provided diagram title is empty.
     #[error("Configuration title cannot be empty.")]
     EmptyTitle,
+    /// The provided axis format is empty.
+    #[error("Axis format cannot be empty.")]
+    EmptyAxisFormat,
+    /// The provided direction is not supported by the diagram.
+    #[error("Direction `{0}` is not supported by this diagram.")]
+    UnsupportedDirection(Direction),
+    /// The provided main branch name is not a valid branch name.
+    #[error("Main branch name is not a valid branch name.")]
+    InvalidMainBranchName,
+    /// The provided chart dimension is zero.
+    #[error("Chart dimensions must be strictly positive.")]
+    ZeroChartDimension,
+    /// The provided ticket base URL is empty or contains reserved characters.
+    #[error("Ticket base URL is empty or contains reserved characters.")]
+    InvalidTicketBaseUrl,
+    /// The provided value format is empty or contains reserved characters.
+    #[error("Value format is empty or contains reserved characters.")]
+    InvalidValueFormat,
+    /// The provided date cannot be written in the date format of the chart.
+    #[error("Date `{0}` cannot be written in the date format of the chart.")]
+    UnrepresentableDate(GanttDate),
 }
@@ -5,7 +5,7 @@ use alloc::string::String;
 
 use thiserror::Error;
 
-use crate::shared::ArrowShape;
+use crate::shared::{ArrowShape, LineStyle};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -14,18 +14,30 @@ pub enum EdgeError {
     /// The provided edge label is empty.
     #[error("Edge label cannot be empty.")]
     EmptyLabel,
+    /// The provided flowchart edge label spans multiple lines.
+    #[error("Edge label `{0}` cannot contain line breaks.")]
+    InvalidLabel(String),
     /// The provided left arrow shape is not compatible with the diagram.
     #[error("Incompatible left arrow shape: `{}`", .0.left())]
     IncompatibleLeftArrowShape(ArrowShape),
     /// The provided right arrow shape is not compatible with the diagram.
     #[error("Incompatible right arrow shape: `{}`", .0.right())]
     IncompatibleRightArrowShape(ArrowShape),
+    /// The provided line style is not supported by the diagram.
+    #[error("Unsupported line style: `{0:?}`")]
+    UnsupportedLineStyle(LineStyle),
     /// The provided source node does not exist in the diagram.
     #[error("Source node not found: `{0}`")]
     SourceNodeNotFound(String),
     /// The provided destination node does not exist in the diagram.
     #[error("Destination node not found: `{0}`")]
     DestinationNodeNotFound(String),
+    /// The provided node cannot be the source of an edge.
+    #[error("Node `{0}` cannot be the source of an edge.")]
+    InvalidSource(String),
+    /// The provided node cannot be the destination of an edge.
+    #[error("Node `{0}` cannot be the destination of an edge.")]
+    InvalidDestination(String),
     /// The source node is missing.
     #[error("Source node is missing.")]
     MissingSource,
@@ -0,0 +1,75 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building Gantt charts in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+use crate::diagrams::gantt::GanttDate;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to Gantt charts in Mermaid.
+pub enum GanttError {
+    /// The provided calendar date does not exist.
+    #[error("Invalid date: `{year:04}-{month:02}-{day:02}`")]
+    InvalidDate {
+        /// The year of the date.
+        year: u16,
+        /// The month of the date.
+        month: u8,
+        /// The day of the date.
+        day: u8,
+    },
+    /// The provided time of the day does not exist.
+    #[error("Invalid time: `{hour:02}:{minute:02}:{second:02}`")]
+    InvalidTime {
+        /// The hour of the time.
+        hour: u8,
+        /// The minute of the time.
+        minute: u8,
+        /// The second of the time.
+        second: u8,
+    },
+    /// The provided section label is empty.
+    #[error("Section label cannot be empty.")]
+    EmptySectionLabel,
+    /// The provided section label contains characters reserved by the
+    /// syntax.
+    #[error("Section label `{0}` contains reserved characters.")]
+    InvalidSectionLabel(String),
+    /// The provided task label is empty.
+    #[error("Task label cannot be empty.")]
+    EmptyTaskLabel,
+    /// The provided task label contains characters reserved by the syntax.
+    #[error("Task label `{0}` contains reserved characters.")]
+    InvalidTaskLabel(String),
+    /// The task label is missing.
+    #[error("Task label is missing.")]
+    MissingTaskLabel,
+    /// The task ID is missing.
+    #[error("Task ID is missing.")]
+    MissingTaskId,
+    /// The task has neither a start date nor a dependency to start after.
+    #[error("Task `{0}` is missing a start.")]
+    MissingStart(String),
+    /// The task has neither an end date, a duration nor a task to run until.
+    #[error("Task `{0}` is missing an end.")]
+    MissingEnd(String),
+    /// The task ends before it starts.
+    #[error("Task `{0}` ends before it starts.")]
+    EndBeforeStart(String),
+    /// The task already depends on the provided task.
+    #[error("Task `{0}` is already a dependency.")]
+    DuplicateDependency(String),
+    /// The referenced task does not exist in the chart.
+    #[error("Task not found: `{0}`")]
+    TaskNotFound(String),
+    /// A task with the same ID already exists in the chart.
+    #[error("Task `{0}` already exists.")]
+    DuplicateTask(String),
+    /// The date of the task cannot be written in the date format of the
+    /// chart.
+    #[error("Date `{0}` cannot be written in the date format of the chart.")]
+    UnrepresentableDate(GanttDate),
+}
@@ -0,0 +1,49 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building git graphs in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to git graphs in Mermaid.
+pub enum GitGraphError {
+    /// The provided branch name is not a valid branch name.
+    #[error("Invalid branch name: `{0}`")]
+    InvalidBranchName(String),
+    /// The provided commit ID is empty or contains reserved characters.
+    #[error("Invalid commit ID: `{0}`")]
+    InvalidCommitId(String),
+    /// The provided tag is empty or contains reserved characters.
+    #[error("Invalid tag: `{0}`")]
+    InvalidTag(String),
+    /// A branch with the same name already exists in the graph.
+    #[error("Branch `{0}` already exists.")]
+    DuplicateBranch(String),
+    /// The referenced branch does not exist in the graph.
+    #[error("Branch not found: `{0}`")]
+    BranchNotFound(String),
+    /// A commit with the same ID already exists in the graph.
+    #[error("Commit `{0}` already exists.")]
+    DuplicateCommit(String),
+    /// The referenced commit does not exist in the graph.
+    #[error("Commit not found: `{0}`")]
+    CommitNotFound(String),
+    /// The branch is merged into itself.
+    #[error("Branch `{0}` cannot be merged into itself.")]
+    SelfMerge(String),
+    /// The branch has no commits, so it cannot take part in a merge.
+    #[error("Branch `{0}` has no commits.")]
+    EmptyBranch(String),
+    /// The merged branch has no commits which are not already on the current
+    /// branch.
+    #[error("Branch `{0}` has nothing to merge.")]
+    NothingToMerge(String),
+    /// The cherry-picked commit is on the current branch.
+    #[error("Commit `{0}` is already on the current branch.")]
+    CherryPickFromSameBranch(String),
+    /// The cherry-picked commit is a merge commit.
+    #[error("Merge commit `{0}` cannot be cherry-picked.")]
+    CherryPickMergeCommit(String),
+}
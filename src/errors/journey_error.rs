@@ -0,0 +1,27 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building user journeys in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to user journeys in Mermaid.
+pub enum JourneyError {
+    /// The provided text is empty.
+    #[error("Text cannot be empty.")]
+    EmptyText,
+    /// The provided text contains characters reserved by the syntax.
+    #[error("Text `{0}` contains reserved characters.")]
+    InvalidText(String),
+    /// The provided satisfaction score is outside of the `1..=5` range.
+    #[error("Score {0} is not between 1 and 5.")]
+    InvalidScore(u8),
+    /// The label of the task is missing.
+    #[error("Task label is missing.")]
+    MissingTaskLabel,
+    /// The satisfaction score of the task is missing.
+    #[error("Score of task `{0}` is missing.")]
+    MissingScore(String),
+}
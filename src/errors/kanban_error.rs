@@ -0,0 +1,30 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building kanban boards in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to kanban boards in Mermaid.
+pub enum KanbanError {
+    /// The provided text is empty.
+    #[error("Text cannot be empty.")]
+    EmptyText,
+    /// The provided text contains characters reserved by the syntax.
+    #[error("Text `{0}` contains reserved characters.")]
+    InvalidText(String),
+    /// The label of the column or item is missing.
+    #[error("Label is missing.")]
+    MissingLabel,
+    /// The ID of the column or item is missing.
+    #[error("ID is missing.")]
+    MissingId,
+    /// A column or item with the same ID already exists in the board.
+    #[error("Column or item `{0}` already exists.")]
+    DuplicateId(String),
+    /// The provided column does not exist in the board.
+    #[error("Column not found: `{0}`")]
+    ColumnNotFound(String),
+}
@@ -0,0 +1,46 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building mindmaps in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to mindmaps in Mermaid.
+pub enum MindmapError {
+    /// The provided node label is empty.
+    #[error("Node label cannot be empty.")]
+    EmptyLabel,
+    /// The provided node label contains characters reserved by the syntax of
+    /// the node shape.
+    #[error("Node label `{0}` contains reserved characters.")]
+    InvalidLabel(String),
+    /// The node label is missing.
+    #[error("Node label is missing.")]
+    MissingLabel,
+    /// The node ID is missing.
+    #[error("Node ID is missing.")]
+    MissingId,
+    /// The provided icon is empty or contains reserved characters.
+    #[error("Invalid icon: `{0}`")]
+    InvalidIcon(String),
+    /// The provided class name is empty or contains reserved characters.
+    #[error("Invalid class name: `{0}`")]
+    InvalidClassName(String),
+    /// The class was already assigned to the node.
+    #[error("Class `{0}` is already assigned to the node.")]
+    DuplicateClass(String),
+    /// A node with the same ID already exists in the mindmap.
+    #[error("Node `{0}` already exists.")]
+    DuplicateNode(String),
+    /// The provided parent node does not exist in the mindmap.
+    #[error("Parent node not found: `{0}`")]
+    ParentNotFound(String),
+    /// The mindmap has no root.
+    #[error("Mindmap must have a root.")]
+    MissingRoot,
+    /// A root was provided while the mindmap already has one.
+    #[error("Mindmap already has a root: `{0}`")]
+    MultipleRoots(String),
+}
@@ -12,6 +12,12 @@ pub enum NodeError {
     /// The provided node label is empty.
     #[error("Node label cannot be empty.")]
     EmptyLabel,
+    /// The provided flowchart node label spans multiple lines.
+    #[error("Node label `{0}` cannot contain line breaks.")]
+    InvalidLabel(String),
+    /// The provided attribute comment contains reserved characters.
+    #[error("Attribute comment `{0}` contains reserved characters.")]
+    InvalidAttributeComment(String),
     /// The provided node ID is empty.
     #[error("Node ID cannot be empty.")]
     EmptyId,
@@ -30,4 +36,19 @@ pub enum NodeError {
     /// The subnodes are missing (required for subgraph with direction).
     #[error("Subnodes are missing.")]
     MissingSubnodes,
+    /// The node does not support subnodes.
+    #[error("Node `{0}` cannot contain subnodes.")]
+    UnsupportedSubnodes(String),
+    /// The node does not support notes.
+    #[error("Node `{0}` cannot have a note.")]
+    UnsupportedNote(String),
+    /// A group of subnodes, such as a concurrent region, is empty.
+    #[error("Node `{0}` contains an empty group of subnodes.")]
+    EmptySubnodeGroup(String),
+    /// The provided node does not exist in the diagram.
+    #[error("Node `{0}` not found.")]
+    NodeNotFound(String),
+    /// The provided node is already nested in another node.
+    #[error("Node `{0}` is already nested in another node.")]
+    AlreadyNested(String),
 }
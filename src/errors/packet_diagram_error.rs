@@ -0,0 +1,48 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building packet diagrams in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to packet diagrams in Mermaid.
+pub enum PacketDiagramError {
+    /// The provided field label is empty.
+    #[error("Field label cannot be empty.")]
+    EmptyLabel,
+    /// The provided field label contains characters reserved by the syntax.
+    #[error("Field label `{0}` contains reserved characters.")]
+    InvalidLabel(String),
+    /// The last bit of the field comes before its first bit.
+    #[error("Invalid bit range: `{start}-{end}`")]
+    InvalidRange {
+        /// The first bit of the field.
+        start: u32,
+        /// The last bit of the field.
+        end: u32,
+    },
+    /// The field would not contain any bit.
+    #[error("Field width must be at least one bit.")]
+    ZeroWidth,
+    /// The field would end beyond the largest representable bit offset.
+    #[error("Field ends beyond the largest representable bit offset.")]
+    RangeOverflow,
+    /// The field starts within a previous field.
+    #[error("Field starting at bit {start} overlaps the previous field, which ends at bit {}.", .next - 1)]
+    OverlappingRange {
+        /// The first bit of the field.
+        start: u32,
+        /// The first bit following the previous field.
+        next: u32,
+    },
+    /// The field leaves bits uncovered after the previous field.
+    #[error("Field starting at bit {start} leaves a gap after bit {next}.")]
+    NonContiguousRange {
+        /// The first bit of the field.
+        start: u32,
+        /// The first bit following the previous field.
+        next: u32,
+    },
+}
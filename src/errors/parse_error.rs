@@ -0,0 +1,80 @@
+//! Submodule defining the errors which may occur while parsing Mermaid syntax
+//! back into diagrams.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[error("Line {line}, column {column}: {kind}")]
+/// Struct representing an error which occurred while parsing Mermaid syntax,
+/// together with the position in the source at which it occurred.
+pub struct ParseError {
+    /// The line of the source at which the error occurred, starting at one.
+    line: usize,
+    /// The column of the line at which the error occurred, starting at one.
+    column: usize,
+    /// The reason why the parsing failed.
+    kind: ParseErrorKind,
+}
+
+impl ParseError {
+    /// Creates a new parse error at the provided position.
+    pub(crate) fn new(line: usize, column: usize, kind: ParseErrorKind) -> Self {
+        Self { line, column, kind }
+    }
+
+    #[must_use]
+    /// Returns the line of the source at which the error occurred, starting
+    /// at one.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    #[must_use]
+    /// Returns the column, counted in characters and starting at one, at
+    /// which the error occurred.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    #[must_use]
+    /// Returns the reason why the parsing failed.
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing the reasons why parsing Mermaid syntax may fail.
+pub enum ParseErrorKind {
+    /// The source does not start with the expected diagram header.
+    #[error("Expected the `{0}` diagram header.")]
+    MissingHeader(String),
+    /// A block, such as the frontmatter or a subgraph, is never closed.
+    #[error("The `{0}` block is never closed.")]
+    UnclosedBlock(String),
+    /// The statement is not recognized.
+    #[error("Unexpected statement: `{0}`")]
+    UnexpectedStatement(String),
+    /// The value could not be parsed.
+    #[error("Invalid value: `{0}`")]
+    InvalidValue(String),
+    /// The configuration key is not supported.
+    #[error("Unsupported configuration key: `{0}`")]
+    UnsupportedKey(String),
+    /// The identifier is defined more than once.
+    #[error("Duplicate identifier: `{0}`")]
+    DuplicateIdentifier(String),
+    /// The identifier is used before being defined.
+    #[error("Unknown identifier: `{0}`")]
+    UnknownIdentifier(String),
+    /// The syntax is valid Mermaid, but cannot be represented by the diagram.
+    #[error("Unsupported syntax: `{0}`")]
+    UnsupportedSyntax(String),
+    /// The parsed statements were rejected while building the diagram.
+    #[error("{0}")]
+    Diagram(#[from] crate::errors::Error),
+}
@@ -0,0 +1,27 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building pie charts in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to pie charts in Mermaid.
+pub enum PieChartError {
+    /// The provided slice label is empty.
+    #[error("Slice label cannot be empty.")]
+    EmptySliceLabel,
+    /// The provided slice label contains characters reserved by the syntax.
+    #[error("Slice label `{0}` contains reserved characters.")]
+    InvalidSliceLabel(String),
+    /// The value of the slice is negative.
+    #[error("Slice `{0}` has a negative value.")]
+    NegativeSliceValue(String),
+    /// The value of the slice is infinite or not a number.
+    #[error("Slice `{0}` has a non-finite value.")]
+    NonFiniteSliceValue(String),
+    /// A slice with the same label already exists in the chart.
+    #[error("Slice `{0}` already exists.")]
+    DuplicateSlice(String),
+}
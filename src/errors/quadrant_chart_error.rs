@@ -0,0 +1,32 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building quadrant charts in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+use crate::shared::Unit;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to quadrant charts in Mermaid.
+pub enum QuadrantChartError {
+    /// The provided text is empty.
+    #[error("Text cannot be empty.")]
+    EmptyText,
+    /// The provided text contains characters reserved by the syntax.
+    #[error("Text `{0}` contains reserved characters.")]
+    InvalidText(String),
+    /// The point label is missing.
+    #[error("Point label is missing.")]
+    MissingPointLabel,
+    /// A coordinate of the point is missing.
+    #[error("Point `{0}` is missing a coordinate.")]
+    MissingCoordinate(String),
+    /// The provided coordinate is not a finite number within `[0, 1]`.
+    #[error("Point coordinates must be finite and within `[0, 1]`.")]
+    InvalidCoordinate,
+    /// The provided unit is not supported by quadrant charts.
+    #[error("Unit `{0}` is not supported, only pixels are.")]
+    UnsupportedUnit(Unit),
+}
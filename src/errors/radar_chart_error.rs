@@ -0,0 +1,39 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building radar charts in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to radar charts in Mermaid.
+pub enum RadarChartError {
+    /// The provided text is empty.
+    #[error("Text cannot be empty.")]
+    EmptyText,
+    /// The provided text contains characters reserved by the syntax.
+    #[error("Text `{0}` contains reserved characters.")]
+    InvalidText(String),
+    /// The provided value is infinite or not a number.
+    #[error("Values must be finite.")]
+    NonFiniteValue,
+    /// The number of values of a curve does not match the number of axes.
+    #[error("Expected {expected} values, one per axis, but found {found}.")]
+    CurveLengthMismatch {
+        /// The number of axes of the chart.
+        expected: usize,
+        /// The number of values of the curve.
+        found: usize,
+    },
+    /// The minimum of the chart is not strictly less than its maximum.
+    #[error("The minimum must be strictly less than the maximum.")]
+    InvalidRange,
+    /// The number of ticks is zero.
+    #[error("The number of ticks must be at least one.")]
+    ZeroTicks,
+    /// The curve cannot be colored, as Mermaid only provides a limited number
+    /// of color scale variables.
+    #[error("Only the first {0} curves may be colored.")]
+    TooManyColoredCurves(usize),
+}
@@ -0,0 +1,28 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building requirement diagrams in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to requirement diagrams in Mermaid.
+pub enum RequirementDiagramError {
+    /// The provided text is empty.
+    #[error("Text cannot be empty.")]
+    EmptyText,
+    /// The provided text contains characters reserved by the syntax.
+    #[error("Text `{0}` contains reserved characters.")]
+    InvalidText(String),
+    /// The name of the requirement or element is missing.
+    #[error("Name is missing.")]
+    MissingName,
+    /// A requirement or element with the same name already exists in the
+    /// diagram.
+    #[error("Name `{0}` is already used in the diagram.")]
+    DuplicateName(String),
+    /// The provided requirement or element does not exist in the diagram.
+    #[error("Requirement or element not found: `{0}`")]
+    EndpointNotFound(String),
+}
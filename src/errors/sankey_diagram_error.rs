@@ -0,0 +1,43 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building sankey diagrams in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to sankey diagrams in Mermaid.
+pub enum SankeyDiagramError {
+    /// The provided node name is empty.
+    #[error("Node name cannot be empty.")]
+    EmptyNodeName,
+    /// The provided node name contains line breaks.
+    #[error("Node name `{0}` contains line breaks.")]
+    InvalidNodeName(String),
+    /// The value of the flow is zero or negative.
+    #[error("Flow from `{0}` has a value which is not strictly positive.")]
+    NonPositiveFlowValue(String),
+    /// The value of the flow is infinite or not a number.
+    #[error("Flow from `{0}` has a non-finite value.")]
+    NonFiniteFlowValue(String),
+    /// The flow goes from a node to itself.
+    #[error("Flow from `{0}` to itself is not allowed.")]
+    SelfFlow(String),
+    /// A flow between the same nodes already exists in the diagram.
+    #[error("Flow from `{from}` to `{to}` already exists.")]
+    DuplicateFlow {
+        /// Name of the source node.
+        from: String,
+        /// Name of the target node.
+        to: String,
+    },
+    /// The flow would close a cycle, which sankey diagrams cannot draw.
+    #[error("Flow from `{from}` to `{to}` would create a cycle.")]
+    CircularFlow {
+        /// Name of the source node.
+        from: String,
+        /// Name of the target node.
+        to: String,
+    },
+}
@@ -0,0 +1,33 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building sequence diagrams in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to sequence diagrams in Mermaid.
+pub enum SequenceDiagramError {
+    /// The referenced participant does not exist in the diagram.
+    #[error("Participant not found: `{0}`")]
+    ParticipantNotFound(String),
+    /// The participant has already been placed in a box.
+    #[error("Participant `{0}` already belongs to a box.")]
+    ParticipantAlreadyBoxed(String),
+    /// The participant is deactivated without being active.
+    #[error("Participant `{0}` is not active.")]
+    InactiveParticipant(String),
+    /// The provided box label is empty.
+    #[error("Box label cannot be empty.")]
+    EmptyBoxLabel,
+    /// A box was provided without any participants.
+    #[error("Box must contain at least one participant.")]
+    EmptyBox,
+    /// A block operation was requested while no block is open.
+    #[error("No block is currently open.")]
+    NoOpenBlock,
+    /// The currently open block does not support multiple branches.
+    #[error("Block `{0}` does not support additional branches.")]
+    UnsupportedBranch(String),
+}
@@ -0,0 +1,16 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building state diagrams in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to state diagrams in Mermaid.
+pub enum StateDiagramError {
+    /// The transition connects states in different concurrent regions of a
+    /// composite state, which Mermaid cannot represent.
+    #[error("Transition from `{0}` to `{1}` crosses the concurrent regions of `{2}`.")]
+    CrossRegionTransition(String, String, String),
+}
@@ -0,0 +1,36 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building timelines in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to timelines in Mermaid.
+pub enum TimelineError {
+    /// The provided section label is empty.
+    #[error("Section label cannot be empty.")]
+    EmptySectionLabel,
+    /// The provided section label contains characters reserved by the syntax.
+    #[error("Section label `{0}` contains reserved characters.")]
+    InvalidSectionLabel(String),
+    /// The provided period label is empty.
+    #[error("Period label cannot be empty.")]
+    EmptyPeriodLabel,
+    /// The provided period label contains characters reserved by the syntax.
+    #[error("Period label `{0}` contains reserved characters.")]
+    InvalidPeriodLabel(String),
+    /// The period label is missing.
+    #[error("Period label is missing.")]
+    MissingPeriodLabel,
+    /// The provided event is empty.
+    #[error("Event cannot be empty.")]
+    EmptyEvent,
+    /// The provided event contains characters reserved by the syntax.
+    #[error("Event `{0}` contains reserved characters.")]
+    InvalidEvent(String),
+    /// The period has no events.
+    #[error("Period `{0}` must have at least one event.")]
+    PeriodWithoutEvents(String),
+}
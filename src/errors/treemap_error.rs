@@ -0,0 +1,36 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building treemaps in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to treemaps in Mermaid.
+pub enum TreemapError {
+    /// The provided text is empty.
+    #[error("Text cannot be empty.")]
+    EmptyText,
+    /// The provided text contains characters reserved by the syntax.
+    #[error("Text `{0}` contains reserved characters.")]
+    InvalidText(String),
+    /// The provided value is infinite or not a number.
+    #[error("Values must be finite.")]
+    NonFiniteValue,
+    /// The provided value is negative.
+    #[error("Values cannot be negative.")]
+    NegativeValue,
+    /// The label of the section or leaf is missing.
+    #[error("Label is missing.")]
+    MissingLabel,
+    /// The value of the leaf is missing.
+    #[error("Value is missing.")]
+    MissingValue,
+    /// The provided section does not exist in the treemap.
+    #[error("Section not found: `{0}`")]
+    SectionNotFound(String),
+    /// The provided style class is not defined in the treemap.
+    #[error("Style class not found: `{0}`")]
+    StyleClassNotFound(String),
+}
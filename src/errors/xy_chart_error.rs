@@ -0,0 +1,38 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building XY charts in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to XY charts in Mermaid.
+pub enum XYChartError {
+    /// The provided text is empty.
+    #[error("Text cannot be empty.")]
+    EmptyText,
+    /// The provided text contains characters reserved by the syntax.
+    #[error("Text `{0}` contains reserved characters.")]
+    InvalidText(String),
+    /// The categorical axis has no categories.
+    #[error("A categorical axis must have at least one category.")]
+    EmptyCategories,
+    /// The provided range is not finite or not increasing.
+    #[error("Axis ranges must be finite and strictly increasing.")]
+    InvalidRange,
+    /// The series has no values.
+    #[error("A series must have at least one value.")]
+    EmptySeries,
+    /// A value of the series is infinite or not a number.
+    #[error("Series values must be finite.")]
+    NonFiniteValue,
+    /// The number of values of the series does not match the x-axis.
+    #[error("Series has {found} values, but the x-axis expects {expected}.")]
+    SeriesLengthMismatch {
+        /// The number of values expected by the x-axis.
+        expected: usize,
+        /// The number of values of the series.
+        found: usize,
+    },
+}
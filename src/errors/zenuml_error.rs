@@ -0,0 +1,48 @@
+//! Submodule providing an enumeration of possible errors that can occur while
+//! building ZenUML diagrams in Mermaid syntax.
+
+use alloc::string::String;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Enum representing errors specific to ZenUML diagrams in Mermaid.
+pub enum ZenUMLError {
+    /// The provided text is empty.
+    #[error("Text cannot be empty.")]
+    EmptyText,
+    /// The provided text contains characters reserved by the syntax.
+    #[error("Text `{0}` contains reserved characters.")]
+    InvalidText(String),
+    /// The provided name is not a valid identifier.
+    #[error("`{0}` is not a valid identifier.")]
+    InvalidIdentifier(String),
+    /// The label of the participant is missing.
+    #[error("Label is missing.")]
+    MissingLabel,
+    /// The ID of the participant is missing.
+    #[error("ID is missing.")]
+    MissingId,
+    /// A participant with the same ID already exists in the diagram.
+    #[error("Participant `{0}` already exists.")]
+    DuplicateId(String),
+    /// The provided participant does not exist in the diagram.
+    #[error("Participant not found: `{0}`")]
+    ParticipantNotFound(String),
+    /// The destination of the call is missing.
+    #[error("Destination is missing.")]
+    MissingDestination,
+    /// The method of the call is missing.
+    #[error("Method is missing.")]
+    MissingMethod,
+    /// No block is currently open.
+    #[error("No block is currently open.")]
+    NoOpenBlock,
+    /// The branch cannot follow the innermost open block or its last branch.
+    #[error("Branch `{0}` is not supported here.")]
+    UnsupportedBranch(String),
+    /// A value is returned outside of the body of a call.
+    #[error("Values may only be returned within the body of a call.")]
+    ReturnOutsideCall,
+}
@@ -7,12 +7,24 @@ pub mod diagrams;
 mod errors;
 mod shared;
 pub mod traits;
-pub use errors::{ConfigError, EdgeError, Error, NodeError, StyleClassError};
+pub use errors::{
+    ArchitectureDiagramError, BlockDiagramError, C4DiagramError, ConfigError, EdgeError, Error,
+    GanttError, GitGraphError, JourneyError, KanbanError, MindmapError, NodeError,
+    PacketDiagramError, ParseError, ParseErrorKind, PieChartError, QuadrantChartError,
+    RadarChartError, RequirementDiagramError, SankeyDiagramError, SequenceDiagramError,
+    StateDiagramError, StyleClassError, TimelineError, TreemapError, XYChartError, ZenUMLError,
+};
 
 /// Submodule providing common traits and types for Mermaid diagrams.
 pub mod prelude {
     pub use crate::{
-        diagrams::{class_diagram::*, entity_relationship::*, flowchart::*},
+        diagrams::{
+            architecture_diagram::*, block_diagram::*, c4_diagram::*, class_diagram::*,
+            entity_relationship::*, flowchart::*, gantt::*, git_graph::*, journey::*, kanban::*,
+            mindmap::*, packet_diagram::*, pie_chart::*, quadrant_chart::*, radar_chart::*,
+            requirement_diagram::*, sankey_diagram::*, sequence_diagram::*, state_diagram::*,
+            timeline::*, treemap::*, xy_chart::*, zenuml::*,
+        },
         shared::{
             ArrowShape, Color, Direction, FontWeight, LineStyle, Renderer, StyleClass,
             StyleClassBuilder, StyleProperty, Unit,
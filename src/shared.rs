@@ -20,4 +20,5 @@ pub(crate) use constants::{EDGE_LETTER, NODE_LETTER};
 pub(crate) mod generic_node;
 pub(crate) use generic_node::GenericNode;
 pub(crate) mod generic_edge;
+pub(crate) mod parsing;
 pub(crate) use generic_edge::GenericEdge;
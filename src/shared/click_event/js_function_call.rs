@@ -16,6 +16,13 @@ pub struct JsFunctionCall {
     args: Vec<String>,
 }
 
+impl JsFunctionCall {
+    /// Creates a new call to the provided JavaScript function.
+    pub(crate) fn new(function_name: impl Into<String>, args: Vec<String>) -> Self {
+        Self { function_name: function_name.into(), args }
+    }
+}
+
 impl Display for JsFunctionCall {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let args_str = if self.args.is_empty() {
@@ -2,7 +2,11 @@
 //! level of a Mermaid diagram.
 
 mod renderers;
-use alloc::string::{String, ToString};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::fmt::Display;
 
 pub use renderers::Renderer;
@@ -14,7 +18,8 @@ mod look;
 pub use look::Look;
 
 use crate::{
-    errors::ConfigError,
+    errors::{ConfigError, ParseError},
+    shared::parsing::parse_configuration,
     traits::{Configuration, ConfigurationBuilder},
 };
 
@@ -32,6 +37,107 @@ pub struct GenericConfiguration {
     theme: Theme,
     /// The look to use for the diagram.
     look: Look,
+    /// The options which are not modelled, such as `logLevel`, keyed by
+    /// their dotted path within the `config` section and written as in an
+    /// init directive.
+    extra_options: BTreeMap<String, String>,
+}
+
+impl GenericConfiguration {
+    #[must_use]
+    /// Returns the options which are not modelled, such as `logLevel` or
+    /// `flowchart.nodeSpacing`, keyed by their dotted path within the
+    /// `config` section, with their values written as in an init directive.
+    pub fn extra_options(&self) -> &BTreeMap<String, String> {
+        &self.extra_options
+    }
+
+    /// Writes the options which are not modelled as an init directive, such
+    /// as `%%{init: {'flowchart': {'nodeSpacing': 50}}}%%`, if there are any.
+    pub(crate) fn fmt_extra_options(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.extra_options.is_empty() {
+            return Ok(());
+        }
+        write!(f, "%%{{init: {{")?;
+        // The sections which are open, from the outermost one. The options
+        // are sorted, so the options of a section are contiguous.
+        let mut sections: Vec<&str> = Vec::new();
+        let mut first = true;
+        for (path, value) in &self.extra_options {
+            let mut keys: Vec<&str> = path.split('.').collect();
+            let key = keys.pop().unwrap_or_default();
+            let common =
+                sections.iter().zip(&keys).take_while(|(section, key)| section == key).count();
+            while sections.len() > common {
+                sections.pop();
+                write!(f, "}}")?;
+            }
+            for section in &keys[common..] {
+                write!(f, "{}'{section}': {{", if first { "" } else { ", " })?;
+                sections.push(section);
+                first = true;
+            }
+            write!(f, "{}'{key}': {value}", if first { "" } else { ", " })?;
+            first = false;
+        }
+        for _ in sections {
+            write!(f, "}}")?;
+        }
+        writeln!(f, "}}}}%%")
+    }
+
+    /// Writes the frontmatter of a diagram, omitting it when its title,
+    /// layout, theme and look all have their default values and it has no
+    /// other options.
+    ///
+    /// Diagrams which write their title within their body pass `with_title`
+    /// as `false`. The `options` are the options specific to the diagram,
+    /// such as `gitGraph.showBranches` or `themeVariables.pie1`, keyed by
+    /// their dotted path within the `config` section and written as in YAML.
+    /// The options of a section must be contiguous.
+    pub(crate) fn fmt_frontmatter(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        with_title: bool,
+        options: &[(String, String)],
+    ) -> core::fmt::Result {
+        let title = self.title.as_deref().filter(|_| with_title);
+        if title.is_none()
+            && self.renderer == Renderer::default()
+            && self.theme == Theme::default()
+            && self.look == Look::default()
+            && options.is_empty()
+        {
+            return self.fmt_extra_options(f);
+        }
+        writeln!(f, "---")?;
+        if let Some(title) = title {
+            writeln!(f, "title: {title}")?;
+        }
+        writeln!(f, "config:")?;
+        if self.renderer != Renderer::default() {
+            writeln!(f, "  layout: {}", self.renderer)?;
+        }
+        writeln!(f, "  theme: {}", self.theme)?;
+        writeln!(f, "  look: {}", self.look)?;
+        // The sections which are open, from the outermost one.
+        let mut sections: Vec<&str> = Vec::new();
+        for (path, value) in options {
+            let mut keys: Vec<&str> = path.split('.').collect();
+            let key = keys.pop().unwrap_or_default();
+            let common =
+                sections.iter().zip(&keys).take_while(|(section, key)| section == key).count();
+            sections.truncate(common);
+            for section in &keys[common..] {
+                writeln!(f, "{:indent$}{section}:", "", indent = 2 * (sections.len() + 1))?;
+                sections.push(section);
+            }
+            writeln!(f, "{:indent$}{key}: {value}", "", indent = 2 * (sections.len() + 1))?;
+        }
+        writeln!(f, "---")?;
+
+        self.fmt_extra_options(f)
+    }
 }
 
 impl Configuration for GenericConfiguration {
@@ -70,7 +176,7 @@ impl Display for GenericConfiguration {
         }
         writeln!(f, "---")?;
 
-        Ok(())
+        self.fmt_extra_options(f)
     }
 }
 
@@ -88,6 +194,8 @@ pub struct GenericConfigurationBuilder {
     theme: Theme,
     /// The look to use for the diagram.
     look: Look,
+    /// The options which are not modelled, keyed by their dotted path.
+    extra_options: BTreeMap<String, String>,
 }
 
 impl TryFrom<GenericConfigurationBuilder> for GenericConfiguration {
@@ -100,6 +208,7 @@ impl TryFrom<GenericConfigurationBuilder> for GenericConfiguration {
             direction: builder.direction,
             theme: builder.theme,
             look: builder.look,
+            extra_options: builder.extra_options,
         })
     }
 }
@@ -133,6 +242,25 @@ impl ConfigurationBuilder for GenericConfigurationBuilder {
 }
 
 impl GenericConfigurationBuilder {
+    /// Parses the configuration of a diagram written in the Mermaid syntax
+    /// from its `---` frontmatter and its `%%{init: {...}}%%` directives,
+    /// ignoring its statements.
+    ///
+    /// The `title`, `theme`, `look` and `layout` keys are supported. Other
+    /// keys of the `config` section, including those of the sections
+    /// specific to a diagram, are kept as
+    /// [`extra_options`](GenericConfiguration::extra_options), which are
+    /// written back as an init directive.
+    ///
+    /// # Errors
+    ///
+    /// * If the frontmatter or a directive is malformed, sets an invalid value
+    ///   or has a key outside of the `config` section other than `title`,
+    ///   with the line and column at which the error occurred.
+    pub fn parse(source: &str) -> Result<Self, ParseError> {
+        parse_configuration(source).map(|(configuration, _)| configuration)
+    }
+
     /// Sets the theme to use for the diagram.
     #[must_use]
     pub fn theme(mut self, theme: Theme) -> Self {
@@ -146,6 +274,14 @@ impl GenericConfigurationBuilder {
         self.look = look;
         self
     }
+
+    /// Keeps an option which is not modelled, with its dotted path within the
+    /// `config` section and its value written as in an init directive.
+    #[must_use]
+    pub(crate) fn extra_option(mut self, path: String, value: String) -> Self {
+        self.extra_options.insert(path, value);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -163,6 +299,50 @@ mod tests {
         );
     }
 
+    /// Helper displaying the frontmatter of a configuration.
+    struct Frontmatter<'a>(&'a GenericConfiguration, bool, &'a [(String, String)]);
+
+    impl Display for Frontmatter<'_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            self.0.fmt_frontmatter(f, self.1, self.2)
+        }
+    }
+
+    #[test]
+    fn test_generic_configuration_frontmatter() -> Result<(), ConfigError> {
+        let config = GenericConfiguration::default();
+        assert_eq!(format!("{}", Frontmatter(&config, true, &[])), "");
+
+        let config = GenericConfigurationBuilder::default().title("My Diagram")?.build()?;
+        assert_eq!(
+            format!("{}", Frontmatter(&config, true, &[])),
+            "---\ntitle: My Diagram\nconfig:\n  theme: default\n  look: classic\n---\n"
+        );
+        assert_eq!(format!("{}", Frontmatter(&config, false, &[])), "");
+
+        let options = [
+            ("gitGraph.showBranches".to_string(), "false".to_string()),
+            ("themeVariables.cScale0".to_string(), "\"#ff0000\"".to_string()),
+            ("themeVariables.xyChart.plotColorPalette".to_string(), "\"#00ff00\"".to_string()),
+            ("themeVariables.cScale1".to_string(), "\"#0000ff\"".to_string()),
+        ];
+        assert_eq!(
+            format!("{}", Frontmatter(&config, false, &options)),
+            "---\nconfig:\n  theme: default\n  look: classic\n  gitGraph:\n    \
+             showBranches: false\n  themeVariables:\n    cScale0: \"#ff0000\"\n    xyChart:\n      \
+             plotColorPalette: \"#00ff00\"\n    cScale1: \"#0000ff\"\n---\n"
+        );
+
+        let config = GenericConfigurationBuilder::default()
+            .renderer(Renderer::EclipseLayoutKernel)
+            .build()?;
+        assert_eq!(
+            format!("{}", Frontmatter(&config, true, &[])),
+            "---\nconfig:\n  layout: elk\n  theme: default\n  look: classic\n---\n"
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_generic_configuration_builder() -> Result<(), ConfigError> {
         let config = GenericConfigurationBuilder::default()
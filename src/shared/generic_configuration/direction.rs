@@ -1,7 +1,7 @@
 //! Submodule defining whether a flowchart is meant to extend in a horizontal or
 //! vertical direction.
 
-use core::fmt::Display;
+use core::{fmt::Display, str::FromStr};
 
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -46,6 +46,20 @@ impl Display for Direction {
     }
 }
 
+impl FromStr for Direction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "LR" => Ok(Self::LeftToRight),
+            "TB" | "TD" => Ok(Self::TopToBottom),
+            "RL" => Ok(Self::RightToLeft),
+            "BT" => Ok(Self::BottomToTop),
+            _ => Err(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::format;
@@ -60,6 +74,16 @@ mod tests {
         assert_eq!(format!("{}", Direction::BottomToTop), "BT");
     }
 
+    #[test]
+    fn test_direction_from_str() {
+        assert_eq!("LR".parse(), Ok(Direction::LeftToRight));
+        assert_eq!("TB".parse(), Ok(Direction::TopToBottom));
+        assert_eq!("TD".parse(), Ok(Direction::TopToBottom));
+        assert_eq!("RL".parse(), Ok(Direction::RightToLeft));
+        assert_eq!("BT".parse(), Ok(Direction::BottomToTop));
+        assert_eq!("lr".parse::<Direction>(), Err(()));
+    }
+
     #[test]
     fn test_direction_flip() {
         assert_eq!(Direction::LeftToRight.flip(), Direction::TopToBottom);
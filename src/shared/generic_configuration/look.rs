@@ -1,6 +1,6 @@
 //! The looks enumeration to use for rendering a Mermaid diagram.
 
-use core::fmt::Display;
+use core::{fmt::Display, str::FromStr};
 
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -29,6 +29,19 @@ impl Display for Look {
     }
 }
 
+impl FromStr for Look {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "neo" => Ok(Look::Neo),
+            "handDrawn" => Ok(Look::HandDrawn),
+            "classic" => Ok(Look::Classic),
+            _ => Err(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::format;
@@ -41,4 +54,12 @@ mod tests {
         assert_eq!(format!("{}", Look::HandDrawn), "handDrawn");
         assert_eq!(format!("{}", Look::Classic), "classic");
     }
+
+    #[test]
+    fn test_look_from_str() {
+        for look in [Look::Neo, Look::HandDrawn, Look::Classic] {
+            assert_eq!(format!("{look}").parse(), Ok(look));
+        }
+        assert_eq!("sketch".parse::<Look>(), Err(()));
+    }
 }
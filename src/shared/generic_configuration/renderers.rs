@@ -1,7 +1,7 @@
 //! Submodule defining the possible renderers which may be used in a flowchart
 //! configuration in Mermaid.
 
-use core::fmt::Display;
+use core::{fmt::Display, str::FromStr};
 
 #[derive(Default, Copy, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -24,6 +24,18 @@ impl Display for Renderer {
     }
 }
 
+impl FromStr for Renderer {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dagre" => Ok(Renderer::Dagre),
+            "elk" => Ok(Renderer::EclipseLayoutKernel),
+            _ => Err(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::format;
@@ -35,4 +47,11 @@ mod tests {
         assert_eq!(format!("{}", Renderer::Dagre), "dagre");
         assert_eq!(format!("{}", Renderer::EclipseLayoutKernel), "elk");
     }
+
+    #[test]
+    fn test_renderer_from_str() {
+        assert_eq!("dagre".parse(), Ok(Renderer::Dagre));
+        assert_eq!("elk".parse(), Ok(Renderer::EclipseLayoutKernel));
+        assert_eq!("\"elk\"".parse::<Renderer>(), Err(()));
+    }
 }
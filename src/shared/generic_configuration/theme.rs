@@ -1,6 +1,6 @@
 //! The themes enumeration to use for rendering a Mermaid diagram.
 
-use core::fmt::Display;
+use core::{fmt::Display, str::FromStr};
 
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -50,6 +50,26 @@ impl Display for Theme {
     }
 }
 
+impl FromStr for Theme {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mc" => Ok(Theme::MermaidChart),
+            "neo" => Ok(Theme::Neo),
+            "neo-dark" => Ok(Theme::NeoDark),
+            "default" => Ok(Theme::Default),
+            "forest" => Ok(Theme::Forest),
+            "base" => Ok(Theme::Base),
+            "dark" => Ok(Theme::Dark),
+            "neutral" => Ok(Theme::Neutral),
+            "redux" => Ok(Theme::Redux),
+            "redux-dark" => Ok(Theme::ReduxDark),
+            _ => Err(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::format;
@@ -69,4 +89,23 @@ mod tests {
         assert_eq!(format!("{}", Theme::Redux), "redux");
         assert_eq!(format!("{}", Theme::ReduxDark), "redux-dark");
     }
+
+    #[test]
+    fn test_theme_from_str() {
+        for theme in [
+            Theme::MermaidChart,
+            Theme::Neo,
+            Theme::NeoDark,
+            Theme::Default,
+            Theme::Forest,
+            Theme::Base,
+            Theme::Dark,
+            Theme::Neutral,
+            Theme::Redux,
+            Theme::ReduxDark,
+        ] {
+            assert_eq!(format!("{theme}").parse(), Ok(theme));
+        }
+        assert_eq!("Forest".parse::<Theme>(), Err(()));
+    }
 }
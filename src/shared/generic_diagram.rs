@@ -84,6 +84,13 @@ impl<Node, Edge, Config: Default> Default for GenericDiagramBuilder<Node, Edge,
     }
 }
 
+impl<Node, Edge, Config> GenericDiagramBuilder<Node, Edge, Config> {
+    /// Returns an iterator over the edges added so far.
+    pub(crate) fn edges(&self) -> impl Iterator<Item = &Rc<Edge>> {
+        self.generic_diagram.edges.iter()
+    }
+}
+
 impl<N: Node + Display, E: Edge<Node = N> + Display, C: Configuration>
     From<GenericDiagramBuilder<N, E, C>> for GenericDiagram<N, E, C>
 {
@@ -0,0 +1,279 @@
+//! Submodule providing helpers shared by the parsers reading Mermaid syntax
+//! back into diagrams.
+
+mod configuration;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::str::FromStr;
+
+pub(crate) use configuration::{ConfigurationEntry, ParsableConfiguration, parse_configuration};
+
+use crate::{
+    errors::{ParseError, ParseErrorKind},
+    shared::{
+        ClickEvent, StyleClassBuilder, StyleProperty,
+        click_event::{JsFunctionCall, Navigation},
+    },
+    traits::DiagramBuilder,
+};
+
+#[derive(Clone, Copy)]
+/// Position of a token in the parsed source.
+pub(crate) struct Position {
+    /// The line of the token, starting at one.
+    pub(crate) line: usize,
+    /// The column of the token, counted in characters and starting at one.
+    pub(crate) column: usize,
+}
+
+impl Position {
+    /// Returns the position of `token`, which must be a slice of `line`.
+    pub(crate) fn of(number: usize, line: &str, token: &str) -> Self {
+        let offset = token.as_ptr().addr() - line.as_ptr().addr();
+        Self { line: number, column: line[..offset].chars().count() + 1 }
+    }
+
+    /// Returns an error of the provided kind at this position.
+    pub(crate) fn error(self, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(self.line, self.column, kind)
+    }
+
+    /// Returns an error reporting the provided value as invalid.
+    pub(crate) fn invalid(self, value: &str) -> ParseError {
+        self.error(ParseErrorKind::InvalidValue(value.to_string()))
+    }
+
+    /// Returns a closure wrapping the errors raised while building the diagram.
+    pub(crate) fn diagram<E>(self) -> impl FnOnce(E) -> ParseError
+    where
+        crate::errors::Error: From<E>,
+    {
+        move |error| self.error(ParseErrorKind::Diagram(error.into()))
+    }
+}
+
+/// Parses the value of `token`, located in `line`.
+pub(crate) fn parse_value<T: FromStr>(
+    number: usize,
+    line: &str,
+    token: &str,
+) -> Result<T, ParseError> {
+    token.parse().map_err(|_| Position::of(number, line, token).invalid(token))
+}
+
+/// Parses a list of style declarations, such as `fill: #ff0000,stroke-width:
+/// 2px`, located in `line`.
+///
+/// Commas separate the declarations, but also appear within the values of
+/// the `stroke-dasharray` and of the `rx, ry` declarations: a comma only
+/// starts a new declaration when it is followed by a property name.
+pub(crate) fn parse_style_properties(
+    number: usize,
+    line: &str,
+    list: &str,
+) -> Result<Vec<StyleProperty>, ParseError> {
+    let mut declarations = Vec::new();
+    let mut start = 0;
+    for (index, _) in list.match_indices(',') {
+        let following = list[index + 1..].split(',').next().unwrap_or_default().trim();
+        if following.contains(':') && !following.starts_with("ry:") {
+            declarations.push(&list[start..index]);
+            start = index + 1;
+        }
+    }
+    declarations.push(&list[start..]);
+
+    declarations
+        .into_iter()
+        .map(|declaration| parse_value(number, line, declaration.trim()))
+        .collect()
+}
+
+/// Parses the arguments of a `classDef` statement, such as `a,b fill:#f9f`,
+/// and adds the style classes they declare to the diagram.
+pub(crate) fn define_style_classes(
+    builder: &mut impl DiagramBuilder<Error = crate::errors::Error>,
+    cursor: &Cursor<'_>,
+    rest: &str,
+) -> Result<(), ParseError> {
+    let (names, list) = rest.split_once(char::is_whitespace).ok_or_else(|| cursor.unexpected())?;
+    let mut properties = Vec::new();
+    for property in parse_style_properties(cursor.number, cursor.line, list.trim())? {
+        merge_property(&mut properties, property);
+    }
+    for name in names.split(',') {
+        let position = cursor.position_of(name);
+        let mut style_class =
+            StyleClassBuilder::default().name(name).map_err(position.diagram())?;
+        for &property in &properties {
+            style_class = style_class.property(property).map_err(position.diagram())?;
+        }
+        builder.style_class(style_class).map_err(position.diagram())?;
+    }
+    Ok(())
+}
+
+/// Parses the click event written after the node identifier of a `click`
+/// statement.
+pub(crate) fn parse_click_event(event: &str) -> Option<ClickEvent> {
+    let (anchor, navigation) =
+        event.strip_prefix("href ").map_or((false, event), |navigation| (true, navigation));
+
+    if let Some(quoted) = navigation.trim_start().strip_prefix('"') {
+        let (url, mut rest) = quoted.split_once('"')?;
+        let mut navigation = Navigation::new(url).anchor(anchor);
+        if let Some(tooltip) = rest.strip_prefix(" \"") {
+            let (tooltip, remainder) = tooltip.split_once('"')?;
+            navigation = navigation.tooltip(tooltip);
+            rest = remainder;
+        }
+        match rest {
+            "" => {}
+            " _blank" => navigation = navigation.new_tab(true),
+            _ => return None,
+        }
+        return Some(ClickEvent::Navigation(navigation));
+    }
+
+    if anchor {
+        return None;
+    }
+
+    let (function_name, args) = match event.split_once('(') {
+        Some((function_name, args)) => {
+            let args = args.strip_suffix(')')?;
+            (function_name, args.split(", ").map(String::from).collect())
+        }
+        None => (event, Vec::new()),
+    };
+    if function_name.is_empty() || function_name.contains([' ', '"', ')']) {
+        return None;
+    }
+    Some(ClickEvent::JsFunctionCall(JsFunctionCall::new(function_name, args)))
+}
+
+/// Splits `text` on the occurrences of `separator` which are not enclosed in
+/// double quotes.
+pub(crate) fn split_unquoted(text: &str, separator: char) -> impl Iterator<Item = &str> {
+    let mut quoted = false;
+    text.split(move |character| {
+        if character == '"' {
+            quoted = !quoted;
+        }
+        character == separator && !quoted
+    })
+}
+
+/// Returns whether the character may appear in an identifier.
+pub(crate) fn is_identifier_char(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+/// Returns the text of a label, without its quotes and markdown backticks.
+pub(crate) fn label_text(text: &str) -> &str {
+    let text = text.trim();
+    let text = text.strip_prefix('"').and_then(|text| text.strip_suffix('"')).unwrap_or(text);
+    text.strip_prefix('`').and_then(|text| text.strip_suffix('`')).unwrap_or(text)
+}
+
+/// Replaces the property of the same type as `property`, if any, or adds it:
+/// later declarations take precedence, as they do in Mermaid.
+pub(crate) fn merge_property(properties: &mut Vec<StyleProperty>, property: StyleProperty) {
+    properties.retain(|existing| !existing.is_same_type(property));
+    properties.push(property);
+}
+
+#[derive(Clone, Copy)]
+/// Cursor over the remainder of a statement.
+pub(crate) struct Cursor<'source> {
+    /// The number of the line containing the statement.
+    pub(crate) number: usize,
+    /// The line containing the statement.
+    pub(crate) line: &'source str,
+    /// The part of the statement which is still to be parsed.
+    pub(crate) rest: &'source str,
+}
+
+impl<'source> Cursor<'source> {
+    /// Returns the position of the provided token, a slice of the line.
+    pub(crate) fn position_of(&self, token: &str) -> Position {
+        Position::of(self.number, self.line, token)
+    }
+
+    /// Returns the position of the cursor.
+    pub(crate) fn position(&self) -> Position {
+        self.position_of(self.rest)
+    }
+
+    /// Returns an error reporting the remainder of the statement as
+    /// unexpected.
+    pub(crate) fn unexpected(&self) -> ParseError {
+        self.position().error(ParseErrorKind::UnexpectedStatement(self.rest.to_string()))
+    }
+
+    /// Skips the whitespace at the cursor.
+    pub(crate) fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    /// Consumes `prefix` if the remainder starts with it.
+    pub(crate) fn eat(&mut self, prefix: &str) -> bool {
+        match self.rest.strip_prefix(prefix) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Consumes the characters matching the predicate, and returns them.
+    pub(crate) fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'source str {
+        let end = self.rest.find(|character| !predicate(character)).unwrap_or(self.rest.len());
+        let (taken, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        taken
+    }
+
+    /// Consumes the text preceding `delimiter` and the delimiter itself, and
+    /// returns the text.
+    pub(crate) fn until(&mut self, delimiter: &str) -> Option<&'source str> {
+        let (text, rest) = self.rest.split_once(delimiter)?;
+        self.rest = rest;
+        Some(text)
+    }
+
+    /// Consumes the text preceding the first `delimiter` outside of double
+    /// quotes and the delimiter itself, and returns the text.
+    pub(crate) fn until_unquoted(&mut self, delimiter: char) -> Option<&'source str> {
+        let text = split_unquoted(self.rest, delimiter).next()?;
+        self.rest = self.rest[text.len()..].strip_prefix(delimiter)?;
+        Some(text)
+    }
+
+    /// Consumes an identifier, made of letters, digits and underscores,
+    /// possibly joined by single dashes or dots, as in `node-1`.
+    pub(crate) fn identifier(&mut self) -> Option<&'source str> {
+        let mut end = 0;
+        let mut characters = self.rest.char_indices().peekable();
+        while let Some((index, character)) = characters.next() {
+            if is_identifier_char(character) {
+                end = index + character.len_utf8();
+            } else if end != index
+                || end == 0
+                || !matches!(character, '-' | '.')
+                || !characters.peek().is_some_and(|&(_, next)| is_identifier_char(next))
+            {
+                break;
+            }
+        }
+        if end == 0 {
+            return None;
+        }
+        let (identifier, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(identifier)
+    }
+}
@@ -0,0 +1,584 @@
+//! Submodule reading the configuration of a diagram from its `---`
+//! frontmatter and its `%%{init: {...}}%%` directives.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::str::FromStr;
+
+use super::{Cursor, Position, is_identifier_char, parse_value};
+use crate::{
+    errors::{ConfigError, ParseError, ParseErrorKind},
+    shared::generic_configuration::GenericConfigurationBuilder,
+    traits::ConfigurationBuilder,
+};
+
+/// The lines of a diagram source with their numbers, starting at one.
+pub(crate) type NumberedLines<'source> = Vec<(usize, &'source str)>;
+
+/// A scalar value of the configuration, with the keys leading to it.
+pub(crate) struct ConfigurationEntry<'source> {
+    /// The keys leading to the value, from the outermost one, such as
+    /// `["config", "flowchart", "curve"]`.
+    path: Vec<&'source str>,
+    /// The position of the innermost key.
+    position: Position,
+    /// The number of the line containing the value.
+    number: usize,
+    /// The line containing the value.
+    line: &'source str,
+    /// The value, without its quotes.
+    value: &'source str,
+    /// The items of the value, written as in an init directive, when the
+    /// value is an array.
+    array: Option<String>,
+}
+
+impl ConfigurationEntry<'_> {
+    /// Parses the value of the entry.
+    pub(crate) fn value<T: FromStr>(&self) -> Result<T, ParseError> {
+        match &self.array {
+            Some(array) => Err(self.position.invalid(array)),
+            None => parse_value(self.number, self.line, self.value),
+        }
+    }
+
+    /// Returns the value of the entry as written in an init directive, such
+    /// as `'basis'` or `['Arial', 12]`.
+    fn directive_value(&self) -> String {
+        self.array.clone().unwrap_or_else(|| directive_scalar(self.value))
+    }
+}
+
+/// Returns the scalar as written in an init directive: numbers and booleans
+/// are written as they are, and other scalars are quoted.
+fn directive_scalar(value: &str) -> String {
+    if value.parse::<f64>().is_ok() || matches!(value, "true" | "false" | "null") {
+        value.to_string()
+    } else if value.contains('\'') {
+        format!("\"{value}\"")
+    } else {
+        format!("'{value}'")
+    }
+}
+
+/// Trait implemented by the configuration builders which may be read from
+/// the frontmatter and the init directives of a diagram.
+pub(crate) trait ParsableConfiguration:
+    ConfigurationBuilder<Error = ConfigError> + Default
+{
+    /// The section holding the options specific to the diagram, such as
+    /// `flowchart`, if any.
+    const SECTION: Option<&'static str> = None;
+
+    #[must_use]
+    /// Applies `update` to the options shared by all diagrams.
+    fn shared(
+        self,
+        update: impl FnOnce(GenericConfigurationBuilder) -> GenericConfigurationBuilder,
+    ) -> Self;
+
+    /// Applies the entry of the diagram section with the provided key,
+    /// keeping it as an extra option when the key is not modelled.
+    fn specific(self, _key: &str, entry: &ConfigurationEntry<'_>) -> Result<Self, ParseError> {
+        self.preserve(entry)
+    }
+
+    /// Keeps the entry of the `config` section whose key is not modelled,
+    /// such as `themeVariables` or `nodeSpacing`, as an extra option which
+    /// is written back with the configuration.
+    ///
+    /// # Errors
+    ///
+    /// * If the key is outside of the `config` section, as it could not be
+    ///   written back.
+    fn preserve(self, entry: &ConfigurationEntry<'_>) -> Result<Self, ParseError> {
+        match entry.path.split_first() {
+            Some((&"config", keys)) => {
+                let (key, value) = (keys.join("."), entry.directive_value());
+                Ok(self.shared(|generic| generic.extra_option(key, value)))
+            }
+            _ => Err(entry.position.error(ParseErrorKind::UnsupportedKey(entry.path.join(".")))),
+        }
+    }
+
+    /// Applies the entry, keeping it as an extra option when its key is not
+    /// modelled.
+    fn entry(self, entry: &ConfigurationEntry<'_>) -> Result<Self, ParseError> {
+        Ok(match entry.path.as_slice() {
+            ["title"] => self.title(entry.value).map_err(entry.position.diagram())?,
+            ["config", "theme"] => {
+                let theme = entry.value()?;
+                self.shared(|generic| generic.theme(theme))
+            }
+            ["config", "look"] => {
+                let look = entry.value()?;
+                self.shared(|generic| generic.look(look))
+            }
+            ["config", "layout"] => self.renderer(entry.value()?),
+            ["config", section, key] if Some(*section) == Self::SECTION => {
+                if *key == "defaultRenderer" {
+                    self.renderer(entry.value()?)
+                } else {
+                    return self.specific(key, entry);
+                }
+            }
+            _ => return self.preserve(entry),
+        })
+    }
+}
+
+impl ParsableConfiguration for GenericConfigurationBuilder {
+    fn shared(
+        self,
+        update: impl FnOnce(GenericConfigurationBuilder) -> GenericConfigurationBuilder,
+    ) -> Self {
+        update(self)
+    }
+}
+
+/// Returns the text of a quoted or plain scalar value.
+fn unquoted(value: &str) -> &str {
+    ['"', '\'']
+        .into_iter()
+        .find_map(|quote| value.strip_prefix(quote).and_then(|value| value.strip_suffix(quote)))
+        .unwrap_or(value)
+}
+
+/// Reader of the inline objects of init directives and of the frontmatter,
+/// such as `{ 'theme': 'forest', 'flowchart': { 'curve': 'basis' } }`,
+/// which may span several lines.
+struct ObjectReader<'source, 'lines, I> {
+    /// The lines following the one of the cursor.
+    lines: &'lines mut I,
+    /// The cursor over the line being read.
+    cursor: Cursor<'source>,
+    /// The position of the block being read, reported when it is not closed.
+    opening: Position,
+    /// The delimiter of the block being read, such as `%%{`.
+    delimiter: &'static str,
+}
+
+impl<'source, I: Iterator<Item = (usize, &'source str)>> ObjectReader<'source, '_, I> {
+    /// Skips the whitespace at the cursor, moving to the following lines as
+    /// needed.
+    fn skip_whitespace(&mut self) -> Result<(), ParseError> {
+        self.cursor.skip_whitespace();
+        while self.cursor.rest.is_empty() {
+            let (number, line) = self.lines.next().ok_or_else(|| {
+                self.opening.error(ParseErrorKind::UnclosedBlock(self.delimiter.to_string()))
+            })?;
+            self.cursor = Cursor { number, line, rest: line.trim_start() };
+        }
+        Ok(())
+    }
+
+    /// Consumes the prefix, after any whitespace, and returns whether it was
+    /// present.
+    fn eat(&mut self, prefix: &str) -> Result<bool, ParseError> {
+        self.skip_whitespace()?;
+        Ok(self.cursor.eat(prefix))
+    }
+
+    /// Consumes the prefix, after any whitespace, reporting the remainder of
+    /// the line as unexpected when it is not present.
+    fn expect(&mut self, prefix: &str) -> Result<(), ParseError> {
+        if self.eat(prefix)? { Ok(()) } else { Err(self.cursor.unexpected()) }
+    }
+
+    /// Consumes a quoted or plain scalar, after any whitespace.
+    fn scalar(&mut self) -> Result<&'source str, ParseError> {
+        self.skip_whitespace()?;
+        let position = self.cursor.position();
+        for quote in ["\"", "'"] {
+            if self.cursor.eat(quote) {
+                return self.cursor.until(quote).ok_or_else(|| {
+                    position.error(ParseErrorKind::UnclosedBlock(quote.to_string()))
+                });
+            }
+        }
+        let scalar = self.cursor.take_while(|character| {
+            is_identifier_char(character) || matches!(character, '-' | '.' | '#' | '+')
+        });
+        if scalar.is_empty() { Err(self.cursor.unexpected()) } else { Ok(scalar) }
+    }
+
+    /// Reads the value at the cursor, after any whitespace, and returns it
+    /// as written in an init directive.
+    fn directive_value(&mut self) -> Result<String, ParseError> {
+        if self.eat("[")? {
+            self.array()
+        } else if self.eat("{")? {
+            let mut items = Vec::new();
+            while !self.eat("}")? {
+                let key = self.scalar()?;
+                self.expect(":")?;
+                items.push(format!("{}: {}", directive_scalar(key), self.directive_value()?));
+                if !self.eat(",")? && !self.cursor.rest.starts_with('}') {
+                    return Err(self.cursor.unexpected());
+                }
+            }
+            Ok(format!("{{{}}}", items.join(", ")))
+        } else {
+            self.scalar().map(directive_scalar)
+        }
+    }
+
+    /// Reads the items of the array whose opening bracket was consumed, up to
+    /// its closing bracket, and returns it as written in an init directive.
+    fn array(&mut self) -> Result<String, ParseError> {
+        let mut items = Vec::new();
+        while !self.eat("]")? {
+            items.push(self.directive_value()?);
+            if !self.eat(",")? && !self.cursor.rest.starts_with(']') {
+                return Err(self.cursor.unexpected());
+            }
+        }
+        Ok(format!("[{}]", items.join(", ")))
+    }
+
+    /// Reads the entries of the object whose opening brace was consumed,
+    /// up to its closing brace, prefixing their keys with `path`.
+    fn object(
+        &mut self,
+        path: &mut Vec<&'source str>,
+        entries: &mut Vec<ConfigurationEntry<'source>>,
+    ) -> Result<(), ParseError> {
+        while !self.eat("}")? {
+            let position = self.cursor.position();
+            let key = self.scalar()?;
+            self.expect(":")?;
+            path.push(key);
+            if self.eat("{")? {
+                self.object(path, entries)?;
+            } else {
+                let array = if self.eat("[")? { Some(self.array()?) } else { None };
+                let value = if array.is_some() { "" } else { self.scalar()? };
+                entries.push(ConfigurationEntry {
+                    path: path.clone(),
+                    position,
+                    number: self.cursor.number,
+                    line: self.cursor.line,
+                    value,
+                    array,
+                });
+            }
+            path.pop();
+            if !self.eat(",")? && !self.cursor.rest.starts_with('}') {
+                return Err(self.cursor.unexpected());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads the entries of the frontmatter following its opening `---` line.
+fn frontmatter<'source>(
+    lines: &mut impl Iterator<Item = (usize, &'source str)>,
+    opening: Position,
+    entries: &mut Vec<ConfigurationEntry<'source>>,
+) -> Result<(), ParseError> {
+    // The keys of the sections enclosing the current line, with their
+    // indentation.
+    let mut sections: Vec<(usize, &str)> = Vec::new();
+
+    while let Some((number, line)) = lines.next() {
+        let entry = line.trim();
+        if entry == "---" {
+            return Ok(());
+        }
+        if entry.starts_with('#') {
+            continue;
+        }
+
+        let indentation = line.len() - line.trim_start().len();
+        while sections.last().is_some_and(|&(section, _)| section >= indentation) {
+            sections.pop();
+        }
+        let mut path: Vec<&str> = sections.iter().map(|&(_, key)| key).collect();
+
+        let mut cursor = Cursor { number, line, rest: entry };
+        let position = cursor.position();
+        let Some((key, value)) = entry.split_once(':') else {
+            return Err(cursor.unexpected());
+        };
+        let key = unquoted(key.trim_end());
+        let value = value.trim();
+        cursor.rest = value;
+
+        if value.is_empty() {
+            sections.push((indentation, key));
+        } else if value.starts_with(['{', '[']) {
+            path.push(key);
+            let mut reader = ObjectReader { lines: &mut *lines, cursor, opening, delimiter: "---" };
+            if reader.cursor.eat("{") {
+                reader.object(&mut path, entries)?;
+            } else {
+                reader.cursor.eat("[");
+                let array = reader.array()?;
+                entries.push(ConfigurationEntry {
+                    path,
+                    position,
+                    number,
+                    line,
+                    value: "",
+                    array: Some(array),
+                });
+            }
+            if !reader.cursor.rest.trim().is_empty() {
+                return Err(reader.cursor.unexpected());
+            }
+        } else {
+            path.push(key);
+            entries.push(ConfigurationEntry {
+                path,
+                position,
+                number,
+                line,
+                value: unquoted(value),
+                array: None,
+            });
+        }
+    }
+
+    Err(opening.error(ParseErrorKind::UnclosedBlock("---".to_string())))
+}
+
+/// Reads the entries of the directive opened at `opening`, whose `%%{` was
+/// consumed by the cursor, such as `init: { 'theme': 'forest' } }%%`.
+fn directive<'source>(
+    lines: &mut impl Iterator<Item = (usize, &'source str)>,
+    cursor: Cursor<'source>,
+    opening: Position,
+    entries: &mut Vec<ConfigurationEntry<'source>>,
+) -> Result<(), ParseError> {
+    let mut reader = ObjectReader { lines, cursor, opening, delimiter: "%%{" };
+
+    reader.skip_whitespace()?;
+    let name_position = reader.cursor.position();
+    let name = reader.scalar()?;
+    if name != "init" && name != "initialize" {
+        return Err(name_position.error(ParseErrorKind::UnsupportedSyntax(name.to_string())));
+    }
+    reader.expect(":")?;
+    reader.expect("{")?;
+    reader.object(&mut vec!["config"], entries)?;
+    if !reader.eat("}%%")? {
+        return Err(opening.error(ParseErrorKind::UnclosedBlock("%%{".to_string())));
+    }
+    if reader.cursor.rest.trim().is_empty() { Ok(()) } else { Err(reader.cursor.unexpected()) }
+}
+
+/// Reads the configuration of a diagram from its `---` frontmatter and its
+/// `%%{init: {...}}%%` directives, returning it together with the remaining
+/// lines and their numbers, starting at one, without blank lines and `%%`
+/// comments.
+pub(crate) fn parse_configuration<B: ParsableConfiguration>(
+    source: &str,
+) -> Result<(B, NumberedLines<'_>), ParseError> {
+    let mut lines = source
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line))
+        .filter(|(_, line)| !line.trim().is_empty())
+        .peekable();
+
+    let mut entries = Vec::new();
+    if let Some(&(number, line)) = lines.peek()
+        && line.trim() == "---"
+    {
+        lines.next();
+        frontmatter(&mut lines, Position::of(number, line, line.trim()), &mut entries)?;
+    }
+
+    let mut statements = Vec::new();
+    while let Some((number, line)) = lines.next() {
+        let mut cursor = Cursor { number, line, rest: line.trim() };
+        let opening = cursor.position();
+        if cursor.eat("%%{") {
+            directive(&mut lines, cursor, opening, &mut entries)?;
+        } else if !cursor.rest.starts_with("%%") {
+            statements.push((number, line));
+        }
+    }
+
+    let mut configuration = B::default();
+    for entry in &entries {
+        configuration = configuration.entry(entry)?;
+    }
+    Ok((configuration, statements))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{boxed::Box, string::ToString};
+
+    use super::*;
+    use crate::{
+        diagrams::{
+            class_diagram::{ClassDiagramConfiguration, ClassDiagramConfigurationBuilder},
+            flowchart::{CurveStyle, FlowchartConfigurationBuilder},
+        },
+        shared::{
+            Renderer,
+            generic_configuration::{GenericConfiguration, Look, Theme},
+        },
+        traits::Configuration,
+    };
+
+    #[test]
+    fn test_parse_frontmatter() -> Result<(), Box<dyn core::error::Error>> {
+        let (configuration, lines) = parse_configuration::<FlowchartConfigurationBuilder>(
+            "---\ntitle: \"My flowchart\"\n# A comment\nconfig:\n  theme: forest\n  look: handDrawn\n  \
+             flowchart:\n    defaultRenderer: 'elk'\n    htmlLabels: false\n    curve: basis\n\
+             ---\nflowchart LR\n  %% A comment\n\n  A --> B\n",
+        )?;
+        assert_eq!(lines, [(12, "flowchart LR"), (15, "  A --> B")]);
+        let configuration = configuration.build()?;
+        assert_eq!(configuration.title(), Some("My flowchart"));
+        assert_eq!(configuration.theme(), Theme::Forest);
+        assert_eq!(configuration.look(), Look::HandDrawn);
+        assert_eq!(configuration.renderer(), Renderer::EclipseLayoutKernel);
+        assert!(!configuration.html_labels());
+        assert_eq!(configuration.curve_style(), &CurveStyle::Basis);
+
+        let (configuration, _) = parse_configuration::<GenericConfigurationBuilder>(
+            "---\nconfig: { layout: elk, 'theme': dark }\n---\nerDiagram",
+        )?;
+        let configuration: GenericConfiguration = configuration.build()?;
+        assert_eq!(configuration.renderer(), Renderer::EclipseLayoutKernel);
+        assert_eq!(configuration.theme(), Theme::Dark);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_init_directives() -> Result<(), Box<dyn core::error::Error>> {
+        let (configuration, lines) = parse_configuration::<ClassDiagramConfigurationBuilder>(
+            "%%{init: {'theme': 'neutral', \"class\": {\"hideEmptyMembersBox\": true}}}%%\n\
+             classDiagram\n  %%{\n    initialize: {\n      look: neo,\n      layout: elk,\n    }\n  \
+             }%%\n  class A",
+        )?;
+        assert_eq!(lines, [(2, "classDiagram"), (9, "  class A")]);
+        let configuration: ClassDiagramConfiguration = configuration.build()?;
+        assert_eq!(configuration.theme(), Theme::Neutral);
+        assert_eq!(configuration.look(), Look::Neo);
+        assert_eq!(configuration.renderer(), Renderer::EclipseLayoutKernel);
+        assert!(configuration.hide_empty_members_box());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_unmodelled_keys() -> Result<(), Box<dyn core::error::Error>> {
+        let (configuration, lines) = parse_configuration::<FlowchartConfigurationBuilder>(
+            "---\nconfig:\n  logLevel: debug\n  theme: dark\n  themeVariables:\n    \
+             primaryColor: '#ff0000'\n  flowchart:\n    nodeSpacing: 50\n  class:\n    \
+             hideEmptyMembersBox: true\n---\n%%{init: {'fontFamily': ['Arial', {'size': 1}], \
+             'sequence': {'mirrorActors': false}}}%%\nflowchart LR",
+        )?;
+        assert_eq!(lines, [(13, "flowchart LR")]);
+        let configuration = configuration.build()?;
+        assert_eq!(configuration.theme(), Theme::Dark);
+        assert_eq!(
+            configuration
+                .extra_options()
+                .iter()
+                .map(|(path, value)| (path.as_str(), value.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                ("class.hideEmptyMembersBox", "true"),
+                ("flowchart.nodeSpacing", "50"),
+                ("fontFamily", "['Arial', {'size': 1}]"),
+                ("logLevel", "'debug'"),
+                ("sequence.mirrorActors", "false"),
+                ("themeVariables.primaryColor", "'#ff0000'"),
+            ]
+        );
+
+        // The options which are not modelled are written back as an init
+        // directive.
+        let displayed = configuration.to_string();
+        assert!(displayed.ends_with(
+            "---\n%%{init: {'class': {'hideEmptyMembersBox': true}, 'flowchart': \
+             {'nodeSpacing': 50}, 'fontFamily': ['Arial', {'size': 1}], 'logLevel': 'debug', \
+             'sequence': {'mirrorActors': false}, 'themeVariables': {'primaryColor': \
+             '#ff0000'}}}%%\n"
+        ));
+        let (reparsed, _) = parse_configuration::<FlowchartConfigurationBuilder>(&displayed)?;
+        assert_eq!(reparsed.build()?, configuration);
+
+        let (configuration, _) = parse_configuration::<GenericConfigurationBuilder>(
+            "---\nconfig:\n  fontFamily: [\"Don't\", 2]\n---\nerDiagram",
+        )?;
+        let configuration: GenericConfiguration = configuration.build()?;
+        assert_eq!(configuration.extra_options()["fontFamily"], "[\"Don't\", 2]");
+        assert_eq!(
+            configuration.to_string(),
+            "---\nconfig:\n  layout: dagre\n  theme: default\n  look: classic\n---\n\
+             %%{init: {'fontFamily': [\"Don't\", 2]}}%%\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_configuration_errors() {
+        for (source, line, column, kind) in [
+            ("---\ntitle: Open", 1, 1, ParseErrorKind::UnclosedBlock("---".to_string())),
+            ("---\nconfig\n---", 2, 1, ParseErrorKind::UnexpectedStatement("config".to_string())),
+            (
+                "---\nconfig:\n  theme: pink\n---",
+                3,
+                10,
+                ParseErrorKind::InvalidValue("pink".to_string()),
+            ),
+            (
+                "%%{init: {'look': 'sketch'}}%%",
+                1,
+                20,
+                ParseErrorKind::InvalidValue("sketch".to_string()),
+            ),
+            (
+                "graph LR\n  %%{init: {'theme': 'forest'}\n  A --> B",
+                2,
+                3,
+                ParseErrorKind::UnclosedBlock("%%{".to_string()),
+            ),
+            (
+                "%%{init: {'theme' 'forest'}}%%",
+                1,
+                19,
+                ParseErrorKind::UnexpectedStatement("'forest'}}%%".to_string()),
+            ),
+            ("%%{wrap}%%", 1, 4, ParseErrorKind::UnsupportedSyntax("wrap".to_string())),
+            (
+                "---\ndisplayMode: compact\n---",
+                2,
+                1,
+                ParseErrorKind::UnsupportedKey("displayMode".to_string()),
+            ),
+            (
+                "%%{init: {'theme': ['dark']}}%%",
+                1,
+                11,
+                ParseErrorKind::InvalidValue("['dark']".to_string()),
+            ),
+            (
+                "%%{init: {'fontFamily': ['Arial' 'Helvetica']}}%%",
+                1,
+                34,
+                ParseErrorKind::UnexpectedStatement("'Helvetica']}}%%".to_string()),
+            ),
+        ] {
+            let error = parse_configuration::<GenericConfigurationBuilder>(source)
+                .err()
+                .unwrap_or_else(|| panic!("{source}"));
+            assert_eq!(
+                (error.line(), error.column(), error.kind()),
+                (line, column, &kind),
+                "{source}"
+            );
+        }
+    }
+}
@@ -50,6 +50,33 @@ impl From<Color> for Hsl {
     }
 }
 
+impl core::str::FromStr for Color {
+    type Err = ();
+
+    /// Parses a color from its hexadecimal notation, either in the long
+    /// (`#ff0000`) or in the short (`#f00`) form.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix('#').ok_or(())?;
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(());
+        }
+        let channel = |digits: &str| u8::from_str_radix(digits, 16).map_err(|_| ());
+        match digits.len() {
+            6 => Ok(Color {
+                red: channel(&digits[0..2])?,
+                green: channel(&digits[2..4])?,
+                blue: channel(&digits[4..6])?,
+            }),
+            3 => Ok(Color {
+                red: channel(&digits[0..1])? * 17,
+                green: channel(&digits[1..2])? * 17,
+                blue: channel(&digits[2..3])? * 17,
+            }),
+            _ => Err(()),
+        }
+    }
+}
+
 impl Color {
     #[must_use]
     /// Returns a new pastel red color.
@@ -75,6 +102,15 @@ impl Color {
         format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
     }
 
+    #[must_use]
+    /// Returns the color in the CSS functional notation `rgb(r, g, b)`.
+    ///
+    /// Some Mermaid statements, such as the sequence diagram `box` and `rect`
+    /// blocks, only accept colors in this notation.
+    pub fn to_css_rgb(self) -> String {
+        format!("rgb({}, {}, {})", self.red, self.green, self.blue)
+    }
+
     #[must_use]
     /// Returns `n` maximally distinct colors.
     ///
@@ -132,6 +168,22 @@ mod tests {
         assert_eq!(color.to_hex(), "#ff0000");
     }
 
+    #[test]
+    fn test_color_from_str() {
+        assert_eq!("#ff8000".parse(), Ok(Color::from((255, 128, 0))));
+        assert_eq!("#F80".parse(), Ok(Color::from((255, 136, 0))));
+        assert_eq!(Color::pastel_cyan().to_hex().parse(), Ok(Color::pastel_cyan()));
+        assert_eq!("ff8000".parse::<Color>(), Err(()));
+        assert_eq!("#ff80".parse::<Color>(), Err(()));
+        assert_eq!("#gg8000".parse::<Color>(), Err(()));
+    }
+
+    #[test]
+    fn test_color_to_css_rgb() {
+        let color = Color::from((255, 128, 0));
+        assert_eq!(color.to_css_rgb(), "rgb(255, 128, 0)");
+    }
+
     #[test]
     fn test_pastel_colors() {
         let red = Color::pastel_red();
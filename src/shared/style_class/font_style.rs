@@ -1,7 +1,7 @@
 //! Submodule providing the enumeration `FontStyle` which defines
 //! different font styles that can be applied to text in Mermaid diagrams.
 
-use core::fmt::Display;
+use core::{fmt::Display, str::FromStr};
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -27,6 +27,19 @@ impl Display for FontStyle {
     }
 }
 
+impl FromStr for FontStyle {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "normal" => Ok(FontStyle::Normal),
+            "italic" => Ok(FontStyle::Italic),
+            "oblique" => Ok(FontStyle::Oblique),
+            _ => Err(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::format;
@@ -39,4 +52,12 @@ mod tests {
         assert_eq!(format!("{}", FontStyle::Italic), "italic");
         assert_eq!(format!("{}", FontStyle::Oblique), "oblique");
     }
+
+    #[test]
+    fn test_font_style_from_str() {
+        for style in [FontStyle::Normal, FontStyle::Italic, FontStyle::Oblique] {
+            assert_eq!(format!("{style}").parse(), Ok(style));
+        }
+        assert_eq!("slanted".parse::<FontStyle>(), Err(()));
+    }
 }
@@ -1,6 +1,6 @@
 //! Submodule providing the `FontWeight` enum used in Mermaid diagrams.
 
-use core::fmt::Display;
+use core::{fmt::Display, str::FromStr};
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -32,6 +32,20 @@ impl Display for FontWeight {
     }
 }
 
+impl FromStr for FontWeight {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "normal" => Ok(FontWeight::Normal),
+            "bold" => Ok(FontWeight::Bold),
+            "bolder" => Ok(FontWeight::Bolder),
+            "lighter" => Ok(FontWeight::Lighter),
+            _ => s.parse().map(FontWeight::Number).map_err(|_| ()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::format;
@@ -46,4 +60,18 @@ mod tests {
         assert_eq!(format!("{}", FontWeight::Lighter), "lighter");
         assert_eq!(format!("{}", FontWeight::Number(400)), "400");
     }
+
+    #[test]
+    fn test_font_weight_from_str() {
+        for weight in [
+            FontWeight::Normal,
+            FontWeight::Bold,
+            FontWeight::Bolder,
+            FontWeight::Lighter,
+            FontWeight::Number(600),
+        ] {
+            assert_eq!(format!("{weight}").parse(), Ok(weight));
+        }
+        assert_eq!("heavy".parse::<FontWeight>(), Err(()));
+    }
 }
@@ -1,7 +1,7 @@
 //! Enumeration of style properties which may be applied to nodes in a Mermaid
 //! diagram.
 
-use core::fmt::Display;
+use core::{fmt::Display, str::FromStr};
 
 use crate::shared::style_class::{
     color::Color, font_style::FontStyle, font_weight::FontWeight, units::Unit,
@@ -72,13 +72,73 @@ impl Display for StyleProperty {
             StyleProperty::StrokeDasharray(length, gap) => {
                 write!(f, "stroke-dasharray: {length}, {gap}")
             }
-            StyleProperty::StrokeDashoffset(offset) => write!(f, "stroke-dashoffset: {offset}",),
+            StyleProperty::StrokeDashoffset(offset) => write!(f, "stroke-dashoffset: {offset}"),
             StyleProperty::Opacity(value) => write!(f, "opacity: {:.2}", f32::from(*value) / 100.0),
             StyleProperty::BorderRadius(radius) => write!(f, "rx: {radius}, ry: {radius}"),
         }
     }
 }
 
+impl FromStr for StyleProperty {
+    type Err = ();
+
+    /// Parses a single style declaration in the notation produced by the
+    /// `Display` implementation, such as `fill: #ff0000` or
+    /// `rx: 5px, ry: 5px`.
+    ///
+    /// The CSS notations `stroke-dasharray: 5 2` and `opacity: 0.5` are also
+    /// accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = s.split_once(':').ok_or(())?;
+        let value = value.trim();
+        match name.trim() {
+            "fill" => value.parse().map(StyleProperty::Fill),
+            "stroke" => value.parse().map(StyleProperty::Stroke),
+            "color" => value.parse().map(StyleProperty::Color),
+            "stroke-width" => value.parse().map(StyleProperty::StrokeWidth),
+            "font-size" => value.parse().map(StyleProperty::FontSize),
+            "font-weight" => value.parse().map(StyleProperty::FontWeight),
+            "font-style" => value.parse().map(StyleProperty::FontStyle),
+            "stroke-dasharray" => {
+                let (length, gap) = value
+                    .split_once(',')
+                    .or_else(|| value.split_once(char::is_whitespace))
+                    .ok_or(())?;
+                Ok(StyleProperty::StrokeDasharray(
+                    length.trim().parse().map_err(|_| ())?,
+                    gap.trim().parse().map_err(|_| ())?,
+                ))
+            }
+            "stroke-dashoffset" => {
+                value.parse().map(StyleProperty::StrokeDashoffset).map_err(|_| ())
+            }
+            "opacity" => {
+                let (units, decimals) = value.split_once('.').unwrap_or((value, "0"));
+                let hundredths: u8 = match decimals.len() {
+                    1 => decimals.parse::<u8>().map_err(|_| ())? * 10,
+                    2 => decimals.parse().map_err(|_| ())?,
+                    _ => return Err(()),
+                };
+                let units: u8 = units.parse().map_err(|_| ())?;
+                units
+                    .checked_mul(100)
+                    .and_then(|value| value.checked_add(hundredths))
+                    .map(StyleProperty::Opacity)
+                    .ok_or(())
+            }
+            "rx" => {
+                let (rx, ry) = value.split_once(',').ok_or(())?;
+                let (ry_name, ry) = ry.split_once(':').ok_or(())?;
+                if ry_name.trim() != "ry" || rx.trim() != ry.trim() {
+                    return Err(());
+                }
+                rx.trim().parse().map(StyleProperty::BorderRadius)
+            }
+            _ => Err(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::format;
@@ -104,6 +164,35 @@ mod tests {
         assert_eq!(format!("{}", StyleProperty::BorderRadius(Unit::Pixel(5))), "rx: 5px, ry: 5px");
     }
 
+    #[test]
+    fn test_style_property_from_str() {
+        let color = Color::from((255, 0, 0));
+        for property in [
+            StyleProperty::Fill(color),
+            StyleProperty::Stroke(color),
+            StyleProperty::Color(color),
+            StyleProperty::StrokeWidth(Unit::Pixel(2)),
+            StyleProperty::FontSize(Unit::Point(12)),
+            StyleProperty::FontWeight(FontWeight::Number(700)),
+            StyleProperty::FontStyle(FontStyle::Oblique),
+            StyleProperty::StrokeDasharray(5, 2),
+            StyleProperty::StrokeDashoffset(4),
+            StyleProperty::Opacity(5),
+            StyleProperty::Opacity(150),
+            StyleProperty::BorderRadius(Unit::Pixel(5)),
+        ] {
+            assert_eq!(format!("{property}").parse(), Ok(property));
+        }
+        assert_eq!("fill #ff0000".parse::<StyleProperty>(), Err(()));
+        assert_eq!("stroke-dasharray: 5 2".parse(), Ok(StyleProperty::StrokeDasharray(5, 2)));
+        assert_eq!("opacity: 0.5".parse(), Ok(StyleProperty::Opacity(50)));
+        assert_eq!("opacity: 1".parse(), Ok(StyleProperty::Opacity(100)));
+        assert_eq!("opacity: 0.505".parse::<StyleProperty>(), Err(()));
+        assert_eq!("opacity: 2.56".parse::<StyleProperty>(), Err(()));
+        assert_eq!("rx: 5px, ry: 6px".parse::<StyleProperty>(), Err(()));
+        assert_eq!("margin: 5px".parse::<StyleProperty>(), Err(()));
+    }
+
     #[test]
     fn test_style_property_is_same_type() {
         assert!(
@@ -2,7 +2,7 @@
 //! style class definitions in Mermaid diagrams, including pixel and
 //! point units.
 
-use core::fmt::Display;
+use core::{fmt::Display, str::FromStr};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -23,6 +23,20 @@ impl Display for Unit {
     }
 }
 
+impl FromStr for Unit {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(value) = s.strip_suffix("px") {
+            value.parse().map(Unit::Pixel).map_err(|_| ())
+        } else if let Some(value) = s.strip_suffix("pt") {
+            value.parse().map(Unit::Point).map_err(|_| ())
+        } else {
+            Err(())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::format;
@@ -34,4 +48,12 @@ mod tests {
         assert_eq!(format!("{}", Unit::Pixel(10)), "10px");
         assert_eq!(format!("{}", Unit::Point(12)), "12pt");
     }
+
+    #[test]
+    fn test_unit_from_str() {
+        assert_eq!("10px".parse(), Ok(Unit::Pixel(10)));
+        assert_eq!("12pt".parse(), Ok(Unit::Point(12)));
+        assert_eq!("12".parse::<Unit>(), Err(()));
+        assert_eq!("300px".parse::<Unit>(), Err(()));
+    }
 }